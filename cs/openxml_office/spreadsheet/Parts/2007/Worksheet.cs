@@ -1,5 +1,7 @@
 
 using System;
+using System.Runtime.InteropServices;
+using draviavemal.openxml_office.global_2007;
 
 namespace draviavemal.openxml_office.spreadsheet_2007
 {
@@ -8,10 +10,82 @@ namespace draviavemal.openxml_office.spreadsheet_2007
     {
         private readonly IntPtr ffiWorksheet;
 
+        /// <summary>
+        /// Merge the cell range of the worksheet, error message is set on failure
+        /// </summary>
+        [DllImport("lib/draviavemal_openxml_office_ffi", CallingConvention = CallingConvention.Cdecl)]
+        public static extern sbyte worksheet_set_merge_cell(
+            IntPtr worksheetPtr,
+            [MarshalAs(UnmanagedType.LPStr)] string range_ref,
+            out IntPtr error_msg
+        );
+
+        /// <summary>
+        /// Space separated merged ranges of the worksheet, release the list with free_string
+        /// </summary>
+        [DllImport("lib/draviavemal_openxml_office_ffi", CallingConvention = CallingConvention.Cdecl)]
+        public static extern sbyte worksheet_list_merge_cell(
+            IntPtr worksheetPtr,
+            out IntPtr merge_cells,
+            out IntPtr error_msg
+        );
+
+        /// <summary>
+        /// Remove the merged cell range of the worksheet, error message is set on failure
+        /// </summary>
+        [DllImport("lib/draviavemal_openxml_office_ffi", CallingConvention = CallingConvention.Cdecl)]
+        public static extern sbyte worksheet_remove_merge_cell(
+            IntPtr worksheetPtr,
+            [MarshalAs(UnmanagedType.LPStr)] string range_ref,
+            out IntPtr error_msg
+        );
+
+        /// <summary>
+        /// Release the string returned by the library, null pointer is ignored
+        /// </summary>
+        [DllImport("lib/draviavemal_openxml_office_ffi", CallingConvention = CallingConvention.Cdecl)]
+        public static extern void free_string(IntPtr str_ptr);
+
         public Worksheet(IntPtr ffiWorksheet)
         {
             this.ffiWorksheet = ffiWorksheet;
         }
+
+        /// <summary>
+        /// Merge the cell range like "A1:C2". Range can't overlap existing merged range
+        /// </summary>
+        public void SetMergeCell(string rangeRef)
+        {
+            sbyte statusCode = worksheet_set_merge_cell(ffiWorksheet, rangeRef, out IntPtr errorMsg);
+            StatusCode.ProcessStatusCode(statusCode, errorMsg);
+        }
+
+        /// <summary>
+        /// List all merged cell range of the worksheet
+        /// </summary>
+        public string[] ListMergeCell()
+        {
+            sbyte statusCode = worksheet_list_merge_cell(ffiWorksheet, out IntPtr mergeCells, out IntPtr errorMsg);
+            StatusCode.ProcessStatusCode(statusCode, errorMsg);
+            try
+            {
+                string mergeCellList = Marshal.PtrToStringAnsi(mergeCells) ?? string.Empty;
+                return mergeCellList.Split(new[] { ' ' }, StringSplitOptions.RemoveEmptyEntries);
+            }
+            finally
+            {
+                free_string(mergeCells);
+            }
+        }
+
+        /// <summary>
+        /// Remove merged cell range from the worksheet
+        /// </summary>
+        public void RemoveMergeCell(string rangeRef)
+        {
+            sbyte statusCode = worksheet_remove_merge_cell(ffiWorksheet, rangeRef, out IntPtr errorMsg);
+            StatusCode.ProcessStatusCode(statusCode, errorMsg);
+        }
     }
 
-}
\ No newline at end of file
+}
@@ -1,18 +1,103 @@
 use crate::{
-    file_handling::{compress_content, decompress_content},
+    file_handling::{compress_stream, decompress_stream},
     files::{XmlDeSerializer, XmlDocument, XmlSerializer},
     global_2007::parts::ContentTypesPart,
 };
 use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use flate2::{write::GzEncoder, Compression};
 use std::{
     cell::RefCell,
     collections::{HashMap, HashSet},
-    fs::{metadata, remove_file, File},
-    io::{Cursor, Read, Write},
+    fs::File,
+    io::{BufReader, Read, Seek, Write},
+    path::Path,
     rc::{Rc, Weak},
 };
+use tempfile::{Builder, NamedTempFile, TempDir, TempPath};
 use zip::{write::SimpleFileOptions, ZipArchive, ZipWriter};
 
+/// Storage location of the compressed part content
+#[derive(Debug)]
+pub(crate) enum ArchiveContent {
+    /// Compressed content kept in process memory
+    Memory(Vec<u8>),
+    /// Compressed content spilled into temp file, removed when dropped
+    TempFile(TempPath),
+}
+
+#[derive(Debug)]
+enum ArchiveContentTarget {
+    Memory(Vec<u8>),
+    TempFile(NamedTempFile),
+}
+
+impl Write for ArchiveContentTarget {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Memory(content) => content.write(buf),
+            Self::TempFile(temp_file) => temp_file.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Memory(content) => content.flush(),
+            Self::TempFile(temp_file) => temp_file.flush(),
+        }
+    }
+}
+
+/// Forward only compressed writer into the part store, used for content streamed without xml tree
+#[derive(Debug)]
+pub(crate) struct ArchiveContentWriter {
+    encoder: GzEncoder<ArchiveContentTarget>,
+    uncompressed_size: usize,
+}
+
+impl Write for ArchiveContentWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let size = self.encoder.write(buf)?;
+        self.uncompressed_size += size;
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.encoder.flush()
+    }
+}
+
+impl ArchiveContentWriter {
+    /// Close the stream and return (Content, Compressed Size, Uncompressed Size)
+    fn finish(self) -> AnyResult<(ArchiveContent, usize, usize), AnyError> {
+        match self
+            .encoder
+            .finish()
+            .context("Finishing Compressed Stream Failed")?
+        {
+            ArchiveContentTarget::Memory(content) => {
+                let compressed_size = content.len();
+                Ok((
+                    ArchiveContent::Memory(content),
+                    compressed_size,
+                    self.uncompressed_size,
+                ))
+            }
+            ArchiveContentTarget::TempFile(temp_file) => {
+                let compressed_size = temp_file
+                    .as_file()
+                    .metadata()
+                    .context("Temp File Metadata Read Failed")?
+                    .len() as usize;
+                Ok((
+                    ArchiveContent::TempFile(temp_file.into_temp_path()),
+                    compressed_size,
+                    self.uncompressed_size,
+                ))
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct OfficeDocument {
     /// Key : File_Name -> Value : (File Handle, Content Type, File Extension, Extension Type)
@@ -28,31 +113,153 @@ pub(crate) struct OfficeDocument {
             usize,
             usize,
             usize,
-            Option<Vec<u8>>,
+            Option<ArchiveContent>,
         ),
     >,
+    /// Temp directory used as part store when the document is not kept in memory
+    temp_directory: Option<TempDir>,
 }
 
 impl OfficeDocument {
     /// Create or Clone existing document to start with
     pub(crate) fn new(file_path: Option<String>, is_in_memory: bool) -> AnyResult<Self, AnyError> {
-        let mut archive_collection = HashMap::new();
         if let Some(file_path) = file_path {
+            let file: File = File::open(file_path).context("Open Existing archive File")?;
+            Self::new_from_reader(Some(file), is_in_memory)
+        } else {
+            Self::new_from_reader(None::<File>, is_in_memory)
+        }
+    }
+
+    /// Create or Clone existing document from any seekable source (file, buffer, stream)
+    pub(crate) fn new_from_reader<R: Read + Seek>(
+        reader: Option<R>,
+        is_in_memory: bool,
+    ) -> AnyResult<Self, AnyError> {
+        let temp_directory = if is_in_memory {
+            None
+        } else {
+            Some(
+                Builder::new()
+                    .prefix("openxml-office-")
+                    .tempdir()
+                    .context("Creating Temp Part Store Failed")?,
+            )
+        };
+        let mut office_document = Self {
+            xml_document_collection: HashMap::new(),
+            archive_collection: HashMap::new(),
+            temp_directory,
+        };
+        if let Some(reader) = reader {
             // Load existing file to our system
-            archive_collection = Self::load_archive_into_database(&file_path)
+            office_document
+                .load_archive_into_database_mut(reader)
                 .context("Load OpenXML Archive Into Database Failed")?;
         }
-        Ok(Self {
-            xml_document_collection: HashMap::new(),
-            archive_collection,
-        })
+        Ok(office_document)
     }
 
+    /// Check the part exist in store or open for edit
     pub(crate) fn check_file_exist(&self, file_path: String) -> bool {
         self.archive_collection.contains_key(&file_path)
+            || self.xml_document_collection.contains_key(&file_path)
+    }
+
+    /// Check the part is currently open for edit
+    pub(crate) fn check_file_open(&self, file_path: &str) -> bool {
+        self.xml_document_collection.contains_key(file_path)
+    }
+
+    /// List the stored part path having the content type
+    pub(crate) fn list_file_path_by_content_type(&self, content_type: &str) -> Vec<String> {
+        let mut file_paths = self
+            .archive_collection
+            .iter()
+            .filter(|(_, (_, _, item_content_type, _, _, _, _))| {
+                item_content_type.as_deref() == Some(content_type)
+            })
+            .map(|(file_path, _)| file_path.to_string())
+            .collect::<Vec<String>>();
+        file_paths.sort();
+        file_paths
+    }
+
+    /// List the part path inside the folder, stored or open for edit
+    pub(crate) fn list_file_path_by_folder(&self, folder_path: &str) -> Vec<String> {
+        let folder_prefix = format!("{}/", folder_path.trim_end_matches("/"));
+        let mut file_paths = self
+            .archive_collection
+            .keys()
+            .chain(self.xml_document_collection.keys())
+            .filter(|file_path| file_path.starts_with(&folder_prefix))
+            .map(|file_path| file_path.to_string())
+            .collect::<Vec<String>>();
+        file_paths.sort();
+        file_paths.dedup();
+        file_paths
+    }
+
+    /// Read the stored non xml part like media as it is
+    pub(crate) fn read_binary_part(&self, file_path: &str) -> AnyResult<Option<Vec<u8>>, AnyError> {
+        if let Some((_, _, _, _, _, _, Some(content))) = self.archive_collection.get(file_path) {
+            let mut decompressed_data = Vec::new();
+            Self::read_archive_content(content, &mut decompressed_data)
+                .context("Raw Content Decompression Failed")?;
+            Ok(Some(decompressed_data))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Store the non xml part like media, extension get registered as content type default
+    pub(crate) fn add_binary_part_mut(
+        &mut self,
+        file_path: &str,
+        content: &[u8],
+        file_extension: &str,
+        extension_type: &str,
+    ) -> AnyResult<(), AnyError> {
+        let compression_level = 4;
+        let (compressed, compressed_size, uncompressed_size) = self
+            .store_archive_content(&mut &content[..], compression_level)
+            .context("Compressing Binary Part Failed")?;
+        self.archive_collection.insert(
+            file_path.to_string(),
+            (
+                file_extension.to_string(),
+                extension_type.to_string(),
+                None,
+                compressed_size,
+                uncompressed_size,
+                compression_level,
+                Some(compressed),
+            ),
+        );
+        Ok(())
+    }
+
+    /// Parse the stored part into xml tree without taking it out of the store
+    pub(crate) fn read_xml_tree(
+        &self,
+        file_path: &str,
+    ) -> AnyResult<Option<XmlDocument>, AnyError> {
+        if let Some((_, _, _, _, _, _, Some(content))) = self.archive_collection.get(file_path) {
+            let mut decompressed_data = Vec::new();
+            Self::read_archive_content(content, &mut decompressed_data)
+                .context("Raw Content Decompression Failed")?;
+            Ok(Some(
+                XmlSerializer::vec_to_xml_doc_tree(decompressed_data, file_path)
+                    .context("Xml Serializer Failed")?,
+            ))
+        } else {
+            Ok(None)
+        }
     }
 
     pub(crate) fn delete_document_mut(&mut self, file_name: &str) {
+        // Drop the open handle as well, else the part gets written back on save
+        self.xml_document_collection.remove(file_name);
         self.archive_collection.remove(file_name);
     }
 
@@ -93,8 +300,9 @@ impl OfficeDocument {
             self.archive_collection.remove(file_path)
         {
             let content = file_content.ok_or(anyhow!("Failed To Get content vec"))?;
-            let decompressed_data =
-                decompress_content(&content).context("Raw Content Decompression Failed")?;
+            let mut decompressed_data = Vec::new();
+            Self::read_archive_content(&content, &mut decompressed_data)
+                .context("Raw Content Decompression Failed")?;
             let xml_tree: XmlDocument =
                 XmlSerializer::vec_to_xml_doc_tree(decompressed_data, file_path)
                     .context("Xml Serializer Failed")?;
@@ -123,34 +331,117 @@ impl OfficeDocument {
                 file_path
             ))?;
             let compression_level = 4;
-            let compressed = compress_content(&uncompressed_data, compression_level)
+            let (compressed, compressed_size, uncompressed_size) = self
+                .store_archive_content(&mut uncompressed_data.as_slice(), compression_level)
                 .context("Recompressing in GZip Failed")?;
-            self.archive_collection
-                .entry(file_path.to_string())
-                .and_modify(|value| {
-                    value.0 = file_extension.clone();
-                    value.1 = extension_type.clone();
-                    value.2 = content_type.clone();
-                    value.3 = compressed.len();
-                    value.4 = uncompressed_data.len();
-                    value.5 = compression_level;
-                    value.6 = Some(compressed.clone());
-                })
-                .or_insert((
+            self.archive_collection.insert(
+                file_path.to_string(),
+                (
+                    file_extension,
+                    extension_type,
+                    content_type,
+                    compressed_size,
+                    uncompressed_size,
+                    compression_level,
+                    Some(compressed),
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    /// Create forward only writer into the part store
+    pub(crate) fn create_content_writer(&self) -> AnyResult<ArchiveContentWriter, AnyError> {
+        let compression_level = 4;
+        let target = if let Some(temp_directory) = &self.temp_directory {
+            ArchiveContentTarget::TempFile(
+                NamedTempFile::new_in(temp_directory.path()).context("Temp File Create Failed")?,
+            )
+        } else {
+            ArchiveContentTarget::Memory(Vec::new())
+        };
+        Ok(ArchiveContentWriter {
+            encoder: GzEncoder::new(target, Compression::new(compression_level)),
+            uncompressed_size: 0,
+        })
+    }
+
+    /// Update the XML tree data to database with the streamed content placed in the element
+    /// carrying the marker value and close the refCell
+    pub(crate) fn close_xml_document_with_stream(
+        &mut self,
+        file_path: &str,
+        stream_marker: &str,
+        stream_content: ArchiveContentWriter,
+    ) -> AnyResult<(), AnyError> {
+        if let Some((xml_document, content_type, file_extension, extension_type)) =
+            self.xml_document_collection.remove(file_path)
+        {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("Failed to get document handle")?;
+            let uncompressed_data = XmlDeSerializer::xml_tree_to_vec(&mut xml_doc_mut, file_path)
+                .context(format!(
+                "Failed Xml Tree to String content, File : {}",
+                file_path
+            ))?;
+            let marker_position = uncompressed_data
+                .windows(stream_marker.len())
+                .position(|window| window == stream_marker.as_bytes())
+                .ok_or(anyhow!("Stream Marker Missing in File : {}", file_path))?;
+            let (stream_content, _, _) = stream_content
+                .finish()
+                .context("Closing Streamed Content Failed")?;
+            let mut content_writer = self
+                .create_content_writer()
+                .context("Creating Content Writer Failed")?;
+            content_writer
+                .write_all(&uncompressed_data[..marker_position])
+                .context("Writing Stream Head Failed")?;
+            Self::read_archive_content(&stream_content, &mut content_writer)
+                .context("Writing Stream Body Failed")?;
+            content_writer
+                .write_all(&uncompressed_data[marker_position + stream_marker.len()..])
+                .context("Writing Stream Tail Failed")?;
+            let compression_level = 4;
+            let (compressed, compressed_size, uncompressed_size) = content_writer
+                .finish()
+                .context("Closing Content Writer Failed")?;
+            self.archive_collection.insert(
+                file_path.to_string(),
+                (
                     file_extension,
                     extension_type,
                     content_type,
-                    compressed.len(),
-                    uncompressed_data.len(),
+                    compressed_size,
+                    uncompressed_size,
                     compression_level,
                     Some(compressed),
-                ));
+                ),
+            );
         }
         Ok(())
     }
 
-    /// Save Current Document to final result
+    /// Save Current Document to final result, target file is replaced only after the save succeeds
     pub(crate) fn save_as(&mut self, file_path: &str) -> AnyResult<(), AnyError> {
+        let target_directory = Path::new(file_path)
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let temp_file =
+            NamedTempFile::new_in(target_directory).context("Create Save File Failed")?;
+        let temp_file = self
+            .save_to_writer(temp_file)
+            .context("Save File Write Failed")?;
+        temp_file
+            .persist(file_path)
+            .context("Replace Save File Failed")?;
+        Ok(())
+    }
+
+    /// Save Current Document into the writer and return the writer back to the caller
+    pub(crate) fn save_to_writer<W: Write + Seek>(&mut self, writer: W) -> AnyResult<W, AnyError> {
         // Save the live content update object to database
         let keys = self
             .xml_document_collection
@@ -161,51 +452,35 @@ impl OfficeDocument {
             self.close_xml_document(&key_file_path)
                 .context(" Saving open object content failed")?;
         }
-        let file_content: Vec<u8> = self
-            .save_database_into_archive()
-            .context("Save Archive Data into Database")?;
-        if metadata(file_path).is_ok() {
-            remove_file(file_path).map_err(|e| anyhow!("Remove Save File Target Failed. {}", e))?;
-        }
-        let mut file = File::create(file_path).context("Create Save File Failed")?;
-        file.write_all(&file_content)
-            .context("Save File Write Failed")
+        self.save_database_into_archive(writer)
+            .context("Save Archive Data into Database")
     }
 
     /// Save the database content into file archive
-    fn save_database_into_archive(&self) -> AnyResult<Vec<u8>, AnyError> {
+    fn save_database_into_archive<W: Write + Seek>(&self, writer: W) -> AnyResult<W, AnyError> {
         let mut extensions: Vec<(String, String)> = Vec::new();
         let mut overrides: Vec<(String, String)> = Vec::new();
-        let mut buffer = Cursor::new(Vec::new());
-        let mut zip_writer: ZipWriter<&mut Cursor<Vec<u8>>> = ZipWriter::new(&mut buffer);
+        let mut zip_writer: ZipWriter<W> = ZipWriter::new(writer);
         let zip_option = SimpleFileOptions::default().compression_level(Some(4));
         // Load Files into Archive and add Override for content types
         {
-            for (
-                file_name,
-                (
-                    file_extension,
-                    extension_type,
-                    content_type,
-                    compress_size,
-                    uncompress_size,
-                    compression_level,
-                    file_content,
-                ),
-            ) in self.archive_collection.to_owned()
-            {
-                extensions.push((file_extension, extension_type));
+            // Keep archive entry order stable across saves
+            let mut file_names = self.archive_collection.keys().collect::<Vec<&String>>();
+            file_names.sort();
+            for file_name in file_names {
+                let (file_extension, extension_type, content_type, _, _, _, file_content) = self
+                    .archive_collection
+                    .get(file_name)
+                    .ok_or(anyhow!("Archive Entry Missing"))?;
+                extensions.push((file_extension.to_string(), extension_type.to_string()));
                 if let Some(content_type) = content_type {
-                    overrides.push((format!("/{}", file_name), content_type));
+                    overrides.push((format!("/{}", file_name), content_type.to_string()));
                 }
                 zip_writer
                     .start_file(file_name, zip_option)
                     .context("Zip File Write Start Fail")?;
                 if let Some(xml_content_compressed) = file_content {
-                    let uncompressed =
-                        decompress_content(&xml_content_compressed).context("Decompress Error")?;
-                    zip_writer
-                        .write_all(&uncompressed)
+                    Self::read_archive_content(xml_content_compressed, &mut zip_writer)
                         .context("Writing compressed data to ZIp")?;
                 }
             }
@@ -226,32 +501,15 @@ impl OfficeDocument {
         zip_writer
             .write_all(&content_type_file)
             .context("Writing compressed data to ZIp")?;
-        zip_writer.finish().context("Zip Close Failed")?;
-        Ok(buffer.into_inner())
+        zip_writer.finish().context("Zip Close Failed")
     }
 
     /// Read Zip file and load it into database after compression
-    fn load_archive_into_database(
-        file_path: &str,
-    ) -> AnyResult<
-        HashMap<
-            String,
-            (
-                String,
-                String,
-                Option<String>,
-                usize,
-                usize,
-                usize,
-                Option<Vec<u8>>,
-            ),
-        >,
-        AnyError,
-    > {
-        let file: File = File::open(file_path).context("Open Existing archive File")?;
-        let mut archive_collection = HashMap::new();
-        let mut zip_read: ZipArchive<File> =
-            ZipArchive::new(file).context("Archive read Failed")?;
+    fn load_archive_into_database_mut<R: Read + Seek>(
+        &mut self,
+        reader: R,
+    ) -> AnyResult<(), AnyError> {
+        let mut zip_read: ZipArchive<R> = ZipArchive::new(reader).context("Archive read Failed")?;
         let mut uncompressed_file = Vec::new();
         zip_read
             .by_name("[Content_Types].xml")
@@ -272,7 +530,6 @@ impl OfficeDocument {
         for file_name in file_names {
             let mut file_extension = String::new();
             let mut extension_type = String::new();
-            let mut uncompressed_data = Vec::new();
             let f_extension = file_name.rsplit(".").next();
             if let Some(f_extension) = f_extension {
                 if let Some(extensions) = extension_collection.clone() {
@@ -283,30 +540,84 @@ impl OfficeDocument {
                     }
                 }
             }
-            zip_read
-                .by_name(&file_name)
-                .context("Zip file extract failed")?
-                .read_to_end(&mut uncompressed_data)
-                .context("File Uncompressed failed")?;
+            let compression_level = 4;
+            let (compressed, compressed_size, uncompressed_size) = self
+                .store_archive_content(
+                    &mut zip_read
+                        .by_name(&file_name)
+                        .context("Zip file extract failed")?,
+                    compression_level,
+                )
+                .context("Recompressing in GZip Failed")?;
             let content_type = content_types_part
                 .get_override_content_type(&file_name)
                 .context("Failed to extract Content Type")?;
-            let compression_level = 4;
-            let compressed = compress_content(&uncompressed_data, compression_level)
-                .context("Recompressing in GZip Failed")?;
-            archive_collection.insert(
+            self.archive_collection.insert(
                 file_name,
                 (
                     file_extension,
                     extension_type,
                     content_type,
-                    compressed.len(),
-                    uncompressed_data.len(),
+                    compressed_size,
+                    uncompressed_size,
                     compression_level,
                     Some(compressed),
                 ),
             );
         }
-        Ok(archive_collection)
+        Ok(())
+    }
+
+    /// Compress the content into part store. Goes to temp file when document is not in memory.
+    /// Returns (Content, Compressed Size, Uncompressed Size)
+    fn store_archive_content<R: Read>(
+        &self,
+        reader: &mut R,
+        compression_level: usize,
+    ) -> AnyResult<(ArchiveContent, usize, usize), AnyError> {
+        if let Some(temp_directory) = &self.temp_directory {
+            let temp_file =
+                NamedTempFile::new_in(temp_directory.path()).context("Temp File Create Failed")?;
+            let (temp_file, uncompressed_size) =
+                compress_stream(reader, temp_file, compression_level)
+                    .context("Temp File Compression Failed")?;
+            let compressed_size = temp_file
+                .as_file()
+                .metadata()
+                .context("Temp File Metadata Read Failed")?
+                .len() as usize;
+            Ok((
+                ArchiveContent::TempFile(temp_file.into_temp_path()),
+                compressed_size,
+                uncompressed_size,
+            ))
+        } else {
+            let (compressed, uncompressed_size) =
+                compress_stream(reader, Vec::new(), compression_level)
+                    .context("Memory Compression Failed")?;
+            let compressed_size = compressed.len();
+            Ok((
+                ArchiveContent::Memory(compressed),
+                compressed_size,
+                uncompressed_size,
+            ))
+        }
+    }
+
+    /// Decompress the part store content into writer
+    fn read_archive_content<W: Write>(
+        content: &ArchiveContent,
+        writer: &mut W,
+    ) -> AnyResult<usize, AnyError> {
+        match content {
+            ArchiveContent::Memory(compressed) => {
+                decompress_stream(compressed.as_slice(), writer).context("Decompress Error")
+            }
+            ArchiveContent::TempFile(temp_path) => decompress_stream(
+                BufReader::new(File::open(temp_path).context("Temp File Open Failed")?),
+                writer,
+            )
+            .context("Decompress Error"),
+        }
     }
 }
@@ -4,6 +4,7 @@ use crate::{
 };
 use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
 use quick_xml::escape::escape;
+use std::collections::HashMap;
 
 pub struct XmlDeSerializer {}
 
@@ -144,18 +145,25 @@ impl XmlDeSerializer {
             }
         }
         if let Some(attributes) = xml_element.get_attribute() {
-            let mut keys = attributes.keys().cloned().collect::<Vec<String>>();
-            keys.sort();
-            element_tag.push_str(
-                format!(
-                    " {}",
-                    keys.iter()
-                        .map(|key| format!("{}=\"{}\"", key, escape(attributes.get(key).unwrap())))
-                        .collect::<Vec<String>>()
-                        .join(" ")
-                )
-                .as_str(),
-            );
+            element_tag.push_str(&Self::generate_xml_attribute(attributes));
+        }
+        if close {
+            element_tag.push_str(" />");
+        } else {
+            element_tag.push_str(" >");
+        }
+        element_tag
+    }
+
+    /// Generate element start tag for content written directly without xml tree
+    pub(crate) fn generate_xml_tag(
+        tag: &str,
+        attributes: &HashMap<String, String>,
+        close: bool,
+    ) -> String {
+        let mut element_tag = format!("<{}", tag);
+        if !attributes.is_empty() {
+            element_tag.push_str(&Self::generate_xml_attribute(attributes));
         }
         if close {
             element_tag.push_str(" />");
@@ -165,6 +173,23 @@ impl XmlDeSerializer {
         element_tag
     }
 
+    /// Generate value with close tag for content written directly without xml tree
+    pub(crate) fn generate_xml_value_tag(tag: &str, value: &str) -> String {
+        format!("<{}>{}</{}>", tag, escape(value), tag)
+    }
+
+    fn generate_xml_attribute(attributes: &HashMap<String, String>) -> String {
+        let mut keys = attributes.keys().cloned().collect::<Vec<String>>();
+        keys.sort();
+        format!(
+            " {}",
+            keys.iter()
+                .map(|key| format!("{}=\"{}\"", key, escape(attributes.get(key).unwrap())))
+                .collect::<Vec<String>>()
+                .join(" ")
+        )
+    }
+
     fn generate_xml_value_close(value: &str, xml_element: &XmlElement) -> String {
         format!("{}</{}>", escape(value), xml_element.get_tag())
     }
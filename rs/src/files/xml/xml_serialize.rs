@@ -11,6 +11,9 @@ use quick_xml::{
 };
 use std::{collections::HashMap, io::Cursor};
 
+/// Text element of the spreadsheet string item, the only element read with its preserved space
+const STRING_ITEM_TEXT_TAG: &str = "t";
+
 pub struct XmlSerializer {}
 
 impl XmlSerializer {
@@ -20,7 +23,8 @@ impl XmlSerializer {
     ) -> AnyResult<XmlDocument, AnyError> {
         let mut reader: NsReader<Cursor<Vec<u8>>> = NsReader::from_reader(Cursor::new(xml_str));
         let mut xml_document = XmlDocument::new();
-        reader.config_mut().trim_text(true);
+        // Text is trimmed while parsing except the preserved string item text
+        reader.config_mut().trim_text(false);
         log_elapsed!(
             || {
                 Self::xml_element_parser(&mut reader, &mut xml_document)
@@ -85,11 +89,24 @@ impl XmlSerializer {
                 // Read text content
                 Result::Ok(Event::Text(byte_text)) => {
                     let text = byte_text.unescape().context("XML Text parsing error")?;
-                    xml_document
+                    // Formatting space around the root element has no owner
+                    if let Some(element) = xml_document
                         .get_element_mut(&active_xml_element_id)
-                        .ok_or(anyhow!("Converting Option to Result Failed"))
-                        .context("Getting Target Element Failed")?
-                        .set_value_mut(text.to_string());
+                        .filter(|_| root_loaded)
+                    {
+                        let preserve_space = element.get_tag() == STRING_ITEM_TEXT_TAG
+                            && element
+                                .get_attribute()
+                                .and_then(|attributes| attributes.get("xml:space"))
+                                .is_some_and(|space| space == "preserve");
+                        if preserve_space {
+                            element.set_value_mut(text.to_string());
+                        } else if !text.trim().is_empty() {
+                            element.set_value_mut(text.trim().to_string());
+                        }
+                    } else if !text.trim().is_empty() {
+                        break Err(anyhow!("Text content found outside of root element"));
+                    }
                 }
 
                 // Handle end tag
@@ -125,7 +142,10 @@ impl XmlSerializer {
                 let attribute: Attribute<'_> =
                     attribute_result.context("Failed to parse attribute")?;
                 let key: String = String::from_utf8_lossy(attribute.key.into_inner()).to_string();
-                let value: String = String::from_utf8_lossy(&attribute.value).to_string();
+                let value: String = attribute
+                    .unescape_value()
+                    .context("Failed to unescape attribute value")?
+                    .to_string();
                 Ok((key, value))
             })
             .collect::<AnyResult<HashMap<String, String>>>()
@@ -64,7 +64,7 @@ impl XmlDocument {
         element_ids
     }
 
-    fn get_element_ids_by_tag(
+    pub(crate) fn get_element_ids_by_tag(
         &self,
         filter_tag: &str,
         parent_id: Option<&usize>,
@@ -85,6 +85,18 @@ impl XmlDocument {
         None
     }
 
+    /// Ids of the elements having the tag at any depth of the document
+    pub(crate) fn get_descendant_ids_by_tag(&self, filter_tag: &str) -> Vec<usize> {
+        let mut element_ids = self
+            .xml_element_collection
+            .iter()
+            .filter(|(_, element)| element.get_tag() == filter_tag)
+            .map(|(element_id, _)| *element_id)
+            .collect::<Vec<usize>>();
+        element_ids.sort();
+        element_ids
+    }
+
     pub(crate) fn get_element(&self, element_id: &usize) -> Option<&XmlElement> {
         self.xml_element_collection.get(element_id)
     }
@@ -138,6 +150,18 @@ impl XmlDocument {
     pub(crate) fn pop_element_mut(&mut self, element_id: &usize) -> Option<XmlElement> {
         self.xml_element_collection.remove(element_id)
     }
+
+    /// Removes the child reference from its parent and remove the element itself from collection
+    pub(crate) fn pop_element_from_parent_mut(&mut self, element_id: &usize) -> Option<XmlElement> {
+        let element = self.xml_element_collection.remove(element_id)?;
+        if let Some(parent_element) = self.xml_element_collection.get(&element.get_parent_id()) {
+            parent_element
+                .children
+                .borrow_mut()
+                .retain(|item| item.id != *element_id);
+        }
+        Some(element)
+    }
     /// Insert Children Before specific Tag
     pub(crate) fn insert_children_before_tag_mut(
         &mut self,
@@ -236,6 +260,26 @@ impl XmlDocument {
         }
     }
 
+    /// Add child element with the attributes, returns the element id
+    pub(crate) fn append_child_with_attributes_mut(
+        &mut self,
+        tag: &str,
+        parent_id: Option<&usize>,
+        attributes: Vec<(&str, &str)>,
+    ) -> AnyResult<usize, AnyError> {
+        Ok(self
+            .append_child_mut(tag, parent_id)
+            .context(format!("Failed to insert {} element", tag))?
+            .set_attribute_mut(
+                attributes
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            )
+            .context(format!("Failed to set {} attribute", tag))?
+            .get_id())
+    }
+
     pub(crate) fn get_first_element_id(
         &self,
         mut element_tree: Vec<&str>,
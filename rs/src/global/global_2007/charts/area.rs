@@ -0,0 +1,104 @@
+use crate::{
+    files::XmlDocument,
+    global_2007::{
+        charts::{
+            append_axis_ids_mut, append_data_label_mut, append_number_data_mut,
+            append_series_color_mut, append_series_head_mut, append_text_data_mut,
+            append_trendline_mut, append_val_element_mut, validate_series, BaseChart,
+            ChartAxesLayout, ChartAxisIds, ChartGroupingValues, ChartPlot, ChartSeries,
+        },
+        traits::Enum,
+    },
+};
+use anyhow::{Context, Error as AnyError, Result as AnyResult};
+
+/// Area chart
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AreaChart {
+    pub base_chart: BaseChart,
+    pub grouping: ChartGroupingValues,
+    pub series: Vec<ChartSeries>,
+}
+
+impl ChartPlot for AreaChart {
+    fn get_base_chart(&self) -> &BaseChart {
+        &self.base_chart
+    }
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries> {
+        self.series.iter_mut().collect()
+    }
+
+    fn get_series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout> {
+        Some(ChartAxesLayout {
+            category_axis_tag: "c:catAx",
+            category_axis_position: "b",
+            value_axis_position: "l",
+            value_format_code: if self.grouping == ChartGroupingValues::PercentStacked {
+                Some("0%")
+            } else {
+                None
+            },
+            cross_between: "midCat",
+        })
+    }
+
+    fn validate(&self) -> AnyResult<(), AnyError> {
+        // Area labels sit at the fixed position
+        validate_series(
+            &self.base_chart,
+            &self.series,
+            &[],
+            self.grouping == ChartGroupingValues::Standard,
+        )
+    }
+
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError> {
+        let area_chart_id = xml_doc_mut
+            .append_child_mut("c:areaChart", Some(plot_area_id))
+            .context("Failed to insert area chart element")?
+            .get_id();
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&area_chart_id),
+            "c:grouping",
+            &ChartGroupingValues::get_string(self.grouping.clone()),
+        )?;
+        append_val_element_mut(xml_doc_mut, Some(&area_chart_id), "c:varyColors", "0")?;
+        for (series_index, series) in self.series.iter().enumerate() {
+            let series_id = xml_doc_mut
+                .append_child_mut("c:ser", Some(&area_chart_id))
+                .context("Failed to insert series element")?
+                .get_id();
+            append_series_head_mut(
+                xml_doc_mut,
+                &series_id,
+                series_offset + series_index,
+                series,
+            )?;
+            append_series_color_mut(xml_doc_mut, &series_id, &series.color)?;
+            if let Some(data_label) = &series.data_label {
+                append_data_label_mut(xml_doc_mut, &series_id, data_label)?;
+            }
+            append_trendline_mut(xml_doc_mut, &series_id, &series.trendline)?;
+            if let Some(categories) = &series.categories {
+                append_text_data_mut(xml_doc_mut, &series_id, "c:cat", categories)?;
+            }
+            append_number_data_mut(xml_doc_mut, &series_id, "c:val", &series.values)?;
+        }
+        if let Some(data_label) = &self.base_chart.data_label {
+            append_data_label_mut(xml_doc_mut, &area_chart_id, data_label)?;
+        }
+        append_axis_ids_mut(xml_doc_mut, &area_chart_id, axis_ids)
+    }
+}
@@ -1,6 +1,210 @@
-use crate::global_2007::charts::BaseChart;
+use crate::{
+    files::XmlDocument,
+    global_2007::{
+        charts::{
+            append_axis_ids_mut, append_data_label_mut, append_number_data_mut,
+            append_series_color_mut, append_series_head_mut, append_text_data_mut,
+            append_trendline_mut, append_val_element_mut, validate_series, BaseChart,
+            ChartAxesLayout, ChartAxisIds, ChartDataLabelPositionValues, ChartPlot, ChartSeries,
+        },
+        traits::Enum,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
 
-struct BarColumnChart {
-    base_chart: BaseChart,
+/// Direction of the bars
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum BarDirectionValues {
+    /// Horizontal bars
+    Bar,
+    /// Vertical bars
+    #[default]
+    Column,
+}
+
+impl Enum<BarDirectionValues> for BarDirectionValues {
+    fn get_string(input_enum: BarDirectionValues) -> String {
+        match input_enum {
+            BarDirectionValues::Bar => "bar".to_string(),
+            BarDirectionValues::Column => "col".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> BarDirectionValues {
+        match input_string {
+            "bar" => BarDirectionValues::Bar,
+            _ => BarDirectionValues::Column,
+        }
+    }
+}
+
+/// How the series bars of the same category are placed
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum BarGroupingValues {
+    #[default]
+    Clustered,
+    Stacked,
+    /// Stacked to 100% of the category total
+    PercentStacked,
+}
+
+impl Enum<BarGroupingValues> for BarGroupingValues {
+    fn get_string(input_enum: BarGroupingValues) -> String {
+        match input_enum {
+            BarGroupingValues::Clustered => "clustered".to_string(),
+            BarGroupingValues::Stacked => "stacked".to_string(),
+            BarGroupingValues::PercentStacked => "percentStacked".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> BarGroupingValues {
+        match input_string {
+            "stacked" => BarGroupingValues::Stacked,
+            "percentStacked" => BarGroupingValues::PercentStacked,
+            _ => BarGroupingValues::Clustered,
+        }
+    }
+}
+
+/// Bar or column chart
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BarColumnChart {
+    pub base_chart: BaseChart,
+    pub direction: BarDirectionValues,
+    pub grouping: BarGroupingValues,
+    /// Space between the categories as percent of the bar width, 0 to 500. Default 150
+    pub gap_width: Option<u16>,
+    /// Overlap of the bars in the category as percent, -100 to 100. Stacked bars always overlap fully
+    pub overlap: Option<i8>,
+    pub series: Vec<ChartSeries>,
+}
+
+impl ChartPlot for BarColumnChart {
+    fn get_base_chart(&self) -> &BaseChart {
+        &self.base_chart
+    }
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries> {
+        self.series.iter_mut().collect()
+    }
+
+    fn get_series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout> {
+        let (category_axis_position, value_axis_position) = match self.direction {
+            BarDirectionValues::Bar => ("l", "b"),
+            BarDirectionValues::Column => ("b", "l"),
+        };
+        Some(ChartAxesLayout {
+            category_axis_tag: "c:catAx",
+            category_axis_position,
+            value_axis_position,
+            value_format_code: if self.grouping == BarGroupingValues::PercentStacked {
+                Some("0%")
+            } else {
+                None
+            },
+            cross_between: "between",
+        })
+    }
+
+    fn validate(&self) -> AnyResult<(), AnyError> {
+        let is_clustered = self.grouping == BarGroupingValues::Clustered;
+        let mut label_positions = vec![
+            ChartDataLabelPositionValues::Center,
+            ChartDataLabelPositionValues::InsideEnd,
+            ChartDataLabelPositionValues::InsideBase,
+        ];
+        if is_clustered {
+            label_positions.push(ChartDataLabelPositionValues::OutsideEnd);
+        }
+        validate_series(
+            &self.base_chart,
+            &self.series,
+            &label_positions,
+            is_clustered,
+        )?;
+        if self.gap_width.is_some_and(|gap_width| gap_width > 500) {
+            return Err(anyhow!("Bar gap width must be between 0 and 500"));
+        }
+        if self
+            .overlap
+            .is_some_and(|overlap| !(-100..=100).contains(&overlap))
+        {
+            return Err(anyhow!("Bar overlap must be between -100 and 100"));
+        }
+        Ok(())
+    }
+
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError> {
+        let bar_chart_id = xml_doc_mut
+            .append_child_mut("c:barChart", Some(plot_area_id))
+            .context("Failed to insert bar chart element")?
+            .get_id();
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&bar_chart_id),
+            "c:barDir",
+            &BarDirectionValues::get_string(self.direction.clone()),
+        )?;
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&bar_chart_id),
+            "c:grouping",
+            &BarGroupingValues::get_string(self.grouping.clone()),
+        )?;
+        append_val_element_mut(xml_doc_mut, Some(&bar_chart_id), "c:varyColors", "0")?;
+        for (series_index, series) in self.series.iter().enumerate() {
+            let series_id = xml_doc_mut
+                .append_child_mut("c:ser", Some(&bar_chart_id))
+                .context("Failed to insert series element")?
+                .get_id();
+            append_series_head_mut(
+                xml_doc_mut,
+                &series_id,
+                series_offset + series_index,
+                series,
+            )?;
+            append_series_color_mut(xml_doc_mut, &series_id, &series.color)?;
+            append_val_element_mut(xml_doc_mut, Some(&series_id), "c:invertIfNegative", "0")?;
+            if let Some(data_label) = &series.data_label {
+                append_data_label_mut(xml_doc_mut, &series_id, data_label)?;
+            }
+            append_trendline_mut(xml_doc_mut, &series_id, &series.trendline)?;
+            if let Some(categories) = &series.categories {
+                append_text_data_mut(xml_doc_mut, &series_id, "c:cat", categories)?;
+            }
+            append_number_data_mut(xml_doc_mut, &series_id, "c:val", &series.values)?;
+        }
+        if let Some(data_label) = &self.base_chart.data_label {
+            append_data_label_mut(xml_doc_mut, &bar_chart_id, data_label)?;
+        }
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&bar_chart_id),
+            "c:gapWidth",
+            &self.gap_width.unwrap_or(150).to_string(),
+        )?;
+        let overlap = if self.grouping == BarGroupingValues::Clustered {
+            self.overlap
+        } else {
+            Some(100)
+        };
+        if let Some(overlap) = overlap {
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&bar_chart_id),
+                "c:overlap",
+                &overlap.to_string(),
+            )?;
+        }
+        append_axis_ids_mut(xml_doc_mut, &bar_chart_id, axis_ids)?;
+        Ok(())
+    }
 }
-impl BarColumnChart {}
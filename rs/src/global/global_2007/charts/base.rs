@@ -1,3 +1,890 @@
-pub struct BaseChart {}
+use crate::{
+    files::XmlDocument,
+    global_2007::{
+        charts::{
+            ChartAxis, ChartAxisCrossesValues, ChartDataLabel, ChartDataLabelPositionValues,
+            ChartLegendPositionValues, ChartMarkerValues, ChartNumberData, ChartSeries,
+            ChartTextData, ChartTrendline, ChartTrendlineTypeValues,
+        },
+        traits::Enum,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
 
-impl BaseChart {}
+/// Axis ids of the plot group as (category axis id, value axis id)
+pub(crate) type ChartAxisIds = (u32, u32);
+
+/// Axis ids of the primary axes, ids only need to be unique inside the chart
+pub(crate) const PRIMARY_AXIS_IDS: ChartAxisIds = (500_000_001, 500_000_002);
+
+/// Axis ids of the secondary axes used by the combo chart
+pub(crate) const SECONDARY_AXIS_IDS: ChartAxisIds = (500_000_003, 500_000_004);
+
+/// Setting shared by all the chart types
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BaseChart {
+    pub title: Option<String>,
+    /// Legend is hidden when not set
+    pub legend_position: Option<ChartLegendPositionValues>,
+    /// Data label of all the series, series data label replace it
+    pub data_label: Option<ChartDataLabel>,
+    /// Horizontal axis of column chart, vertical axis of bar chart
+    pub category_axis: ChartAxis,
+    pub value_axis: ChartAxis,
+}
+
+/// Axes the plot is drawn on
+#[derive(Debug)]
+pub(crate) struct ChartAxesLayout {
+    /// Element of the category axis, c:catAx or c:valAx for the XY charts
+    pub(crate) category_axis_tag: &'static str,
+    /// Side of the chart the category axis is drawn
+    pub(crate) category_axis_position: &'static str,
+    pub(crate) value_axis_position: &'static str,
+    /// Number format of the value axis labels, linked to the source when not set
+    pub(crate) value_format_code: Option<&'static str>,
+    /// Value axis cross the category axis between the categories or at the mid of it
+    pub(crate) cross_between: &'static str,
+}
+
+/// Category and value axis pair with the plots drawn on it
+#[derive(Debug)]
+pub(crate) struct ChartAxisGroup {
+    pub(crate) axis_ids: ChartAxisIds,
+    pub(crate) layout: ChartAxesLayout,
+    pub(crate) category_axis: ChartAxis,
+    pub(crate) value_axis: ChartAxis,
+    /// Secondary value axis is drawn at the opposite side and cross at the maximum
+    pub(crate) is_secondary: bool,
+}
+
+/// Chart type plugged into the base chart plumbing
+pub(crate) trait ChartPlot {
+    fn get_base_chart(&self) -> &BaseChart;
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries>;
+
+    fn get_series_count(&self) -> usize;
+
+    /// Axes of the plot, none for the charts without axis like pie
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout>;
+
+    /// Axis groups of the chart, primary axes drawn with the base chart axis setting
+    fn get_axis_groups(&self) -> Vec<ChartAxisGroup> {
+        self.get_axes_layout()
+            .map(|layout| ChartAxisGroup {
+                axis_ids: PRIMARY_AXIS_IDS,
+                layout,
+                category_axis: self.get_base_chart().category_axis.clone(),
+                value_axis: self.get_base_chart().value_axis.clone(),
+                is_secondary: false,
+            })
+            .into_iter()
+            .collect()
+    }
+
+    /// Check the plot setting is valid for the chart type
+    fn validate(&self) -> AnyResult<(), AnyError>;
+
+    /// Check the plot and the axis setting
+    fn validate_chart(&self) -> AnyResult<(), AnyError> {
+        self.validate()?;
+        for axis_group in self.get_axis_groups() {
+            axis_group
+                .category_axis
+                .validate(axis_group.layout.category_axis_tag == "c:valAx")
+                .context("Invalid category axis")?;
+            axis_group
+                .value_axis
+                .validate(true)
+                .context("Invalid value axis")?;
+        }
+        Ok(())
+    }
+
+    /// Add the plot group element like c:barChart into the plot area.
+    /// Series index start from the offset to stay unique across the plot groups
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError>;
+}
+
+impl BaseChart {
+    /// Write the chart element of the chart space with the plot
+    pub(crate) fn serialize_chart_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot: &dyn ChartPlot,
+    ) -> AnyResult<(), AnyError> {
+        plot.validate_chart().context("Chart validation failed")?;
+        append_val_element_mut(xml_doc_mut, None, "c:date1904", "0")?;
+        append_val_element_mut(xml_doc_mut, None, "c:roundedCorners", "0")?;
+        let chart_id = xml_doc_mut
+            .append_child_mut("c:chart", None)
+            .context("Failed to insert chart element")?
+            .get_id();
+        if let Some(title) = &self.title {
+            append_title_mut(xml_doc_mut, &chart_id, title)?;
+        }
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&chart_id),
+            "c:autoTitleDeleted",
+            if self.title.is_some() { "0" } else { "1" },
+        )?;
+        let plot_area_id = xml_doc_mut
+            .append_child_mut("c:plotArea", Some(&chart_id))
+            .context("Failed to insert plot area element")?
+            .get_id();
+        xml_doc_mut
+            .append_child_mut("c:layout", Some(&plot_area_id))
+            .context("Failed to insert plot area layout")?;
+        plot.append_plot_mut(xml_doc_mut, &plot_area_id, &PRIMARY_AXIS_IDS, 0)
+            .context("Failed to insert plot")?;
+        for axis_group in plot.get_axis_groups() {
+            append_axes_mut(xml_doc_mut, &plot_area_id, &axis_group)
+                .context("Failed to insert chart axes")?;
+        }
+        if let Some(legend_position) = &self.legend_position {
+            let legend_id = xml_doc_mut
+                .append_child_mut("c:legend", Some(&chart_id))
+                .context("Failed to insert legend element")?
+                .get_id();
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&legend_id),
+                "c:legendPos",
+                &ChartLegendPositionValues::get_string(legend_position.clone()),
+            )?;
+            append_val_element_mut(xml_doc_mut, Some(&legend_id), "c:overlay", "0")?;
+        }
+        append_val_element_mut(xml_doc_mut, Some(&chart_id), "c:plotVisOnly", "1")?;
+        append_val_element_mut(xml_doc_mut, Some(&chart_id), "c:dispBlanksAs", "gap")?;
+        Ok(())
+    }
+}
+
+/// Add the category and value axis of the axis group
+fn append_axes_mut(
+    xml_doc_mut: &mut XmlDocument,
+    plot_area_id: &usize,
+    axis_group: &ChartAxisGroup,
+) -> AnyResult<(), AnyError> {
+    let (category_axis_id, value_axis_id) = axis_group.axis_ids;
+    let layout = &axis_group.layout;
+    let category_axis_element_id = append_axis_mut(
+        xml_doc_mut,
+        plot_area_id,
+        layout.category_axis_tag,
+        (category_axis_id, value_axis_id),
+        layout.category_axis_position,
+        &axis_group.category_axis,
+        (None, false, ChartAxisCrossesValues::AutoZero),
+    )?;
+    if layout.category_axis_tag == "c:catAx" {
+        append_val_element_mut(xml_doc_mut, Some(&category_axis_element_id), "c:auto", "1")?;
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&category_axis_element_id),
+            "c:lblAlgn",
+            "ctr",
+        )?;
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&category_axis_element_id),
+            "c:lblOffset",
+            "100",
+        )?;
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&category_axis_element_id),
+            "c:noMultiLvlLbl",
+            "0",
+        )?;
+    } else {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&category_axis_element_id),
+            "c:crossBetween",
+            "midCat",
+        )?;
+        append_major_unit_mut(
+            xml_doc_mut,
+            &category_axis_element_id,
+            &axis_group.category_axis,
+        )?;
+    }
+    let (value_axis_position, default_crosses) = if axis_group.is_secondary {
+        (
+            match layout.value_axis_position {
+                "b" => "t",
+                _ => "r",
+            },
+            ChartAxisCrossesValues::Max,
+        )
+    } else {
+        (layout.value_axis_position, ChartAxisCrossesValues::AutoZero)
+    };
+    let value_axis_element_id = append_axis_mut(
+        xml_doc_mut,
+        plot_area_id,
+        "c:valAx",
+        (value_axis_id, category_axis_id),
+        value_axis_position,
+        &axis_group.value_axis,
+        (
+            layout.value_format_code,
+            !axis_group.is_secondary,
+            default_crosses,
+        ),
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&value_axis_element_id),
+        "c:crossBetween",
+        layout.cross_between,
+    )?;
+    append_major_unit_mut(xml_doc_mut, &value_axis_element_id, &axis_group.value_axis)
+}
+
+/// Add the axis common part till the crossing, returns the axis element id.
+/// Defaults are (number format, major gridlines, crosses) used when the axis not set them
+fn append_axis_mut(
+    xml_doc_mut: &mut XmlDocument,
+    plot_area_id: &usize,
+    tag: &str,
+    (axis_id, cross_axis_id): ChartAxisIds,
+    axis_position: &str,
+    axis: &ChartAxis,
+    (default_format_code, default_major_gridlines, default_crosses): (
+        Option<&str>,
+        bool,
+        ChartAxisCrossesValues,
+    ),
+) -> AnyResult<usize, AnyError> {
+    let axis_element_id = xml_doc_mut
+        .append_child_mut(tag, Some(plot_area_id))
+        .context("Failed to insert axis element")?
+        .get_id();
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&axis_element_id),
+        "c:axId",
+        &axis_id.to_string(),
+    )?;
+    let scaling_id = xml_doc_mut
+        .append_child_mut("c:scaling", Some(&axis_element_id))
+        .context("Failed to insert axis scaling")?
+        .get_id();
+    if let Some(log_base) = axis.log_base {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&scaling_id),
+            "c:logBase",
+            &log_base.to_string(),
+        )?;
+    }
+    append_val_element_mut(xml_doc_mut, Some(&scaling_id), "c:orientation", "minMax")?;
+    if let Some(max) = axis.max {
+        append_val_element_mut(xml_doc_mut, Some(&scaling_id), "c:max", &max.to_string())?;
+    }
+    if let Some(min) = axis.min {
+        append_val_element_mut(xml_doc_mut, Some(&scaling_id), "c:min", &min.to_string())?;
+    }
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&axis_element_id),
+        "c:delete",
+        if axis.hidden { "1" } else { "0" },
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&axis_element_id),
+        "c:axPos",
+        axis_position,
+    )?;
+    if axis.major_gridlines.unwrap_or(default_major_gridlines) {
+        xml_doc_mut
+            .append_child_mut("c:majorGridlines", Some(&axis_element_id))
+            .context("Failed to insert axis major grid lines")?;
+    }
+    if axis.minor_gridlines {
+        xml_doc_mut
+            .append_child_mut("c:minorGridlines", Some(&axis_element_id))
+            .context("Failed to insert axis minor grid lines")?;
+    }
+    if let Some(title) = &axis.title {
+        append_title_mut(xml_doc_mut, &axis_element_id, title)?;
+    }
+    let format_code = axis.number_format.as_deref().or(default_format_code);
+    xml_doc_mut.append_child_with_attributes_mut(
+        "c:numFmt",
+        Some(&axis_element_id),
+        vec![
+            ("formatCode", format_code.unwrap_or("General")),
+            (
+                "sourceLinked",
+                if format_code.is_some() { "0" } else { "1" },
+            ),
+        ],
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&axis_element_id),
+        "c:majorTickMark",
+        "out",
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&axis_element_id),
+        "c:minorTickMark",
+        "none",
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&axis_element_id),
+        "c:tickLblPos",
+        "nextTo",
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&axis_element_id),
+        "c:crossAx",
+        &cross_axis_id.to_string(),
+    )?;
+    match axis.crosses.clone().unwrap_or(default_crosses) {
+        ChartAxisCrossesValues::At { value } => {
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&axis_element_id),
+                "c:crossesAt",
+                &value.to_string(),
+            )?;
+        }
+        crosses => {
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&axis_element_id),
+                "c:crosses",
+                match crosses {
+                    ChartAxisCrossesValues::Min => "min",
+                    ChartAxisCrossesValues::Max => "max",
+                    _ => "autoZero",
+                },
+            )?;
+        }
+    }
+    Ok(axis_element_id)
+}
+
+/// Add the major unit closing the value axis
+fn append_major_unit_mut(
+    xml_doc_mut: &mut XmlDocument,
+    axis_element_id: &usize,
+    axis: &ChartAxis,
+) -> AnyResult<(), AnyError> {
+    if let Some(major_unit) = axis.major_unit {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(axis_element_id),
+            "c:majorUnit",
+            &major_unit.to_string(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Add element carrying the value in val attribute like <c:order val="0" />
+pub(crate) fn append_val_element_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: Option<&usize>,
+    tag: &str,
+    value: &str,
+) -> AnyResult<usize, AnyError> {
+    xml_doc_mut.append_child_with_attributes_mut(tag, parent_id, vec![("val", value)])
+}
+
+/// Add title with the plain rich text
+pub(crate) fn append_title_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    title: &str,
+) -> AnyResult<(), AnyError> {
+    let title_id = xml_doc_mut
+        .append_child_mut("c:title", Some(parent_id))
+        .context("Failed to insert title element")?
+        .get_id();
+    let text_id = xml_doc_mut
+        .append_child_mut("c:tx", Some(&title_id))
+        .context("Failed to insert title text")?
+        .get_id();
+    let rich_text_id = xml_doc_mut
+        .append_child_mut("c:rich", Some(&text_id))
+        .context("Failed to insert title rich text")?
+        .get_id();
+    xml_doc_mut
+        .append_child_mut("a:bodyPr", Some(&rich_text_id))
+        .context("Failed to insert title body property")?;
+    xml_doc_mut
+        .append_child_mut("a:lstStyle", Some(&rich_text_id))
+        .context("Failed to insert title list style")?;
+    let paragraph_id = xml_doc_mut
+        .append_child_mut("a:p", Some(&rich_text_id))
+        .context("Failed to insert title paragraph")?
+        .get_id();
+    let run_id = xml_doc_mut
+        .append_child_mut("a:r", Some(&paragraph_id))
+        .context("Failed to insert title run")?
+        .get_id();
+    xml_doc_mut
+        .append_child_mut("a:t", Some(&run_id))
+        .context("Failed to insert title value")?
+        .set_value_mut(title.to_string());
+    append_val_element_mut(xml_doc_mut, Some(&title_id), "c:overlay", "0")?;
+    Ok(())
+}
+
+/// Add the index, order and name of the series
+pub(crate) fn append_series_head_mut(
+    xml_doc_mut: &mut XmlDocument,
+    series_id: &usize,
+    series_index: usize,
+    series: &ChartSeries,
+) -> AnyResult<(), AnyError> {
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(series_id),
+        "c:idx",
+        &series_index.to_string(),
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(series_id),
+        "c:order",
+        &series_index.to_string(),
+    )?;
+    let name_id = xml_doc_mut
+        .append_child_mut("c:tx", Some(series_id))
+        .context("Failed to insert series name")?
+        .get_id();
+    if series.name.reference.is_some() {
+        append_text_reference_mut(xml_doc_mut, &name_id, &series.name)?;
+    } else {
+        xml_doc_mut
+            .append_child_mut("c:v", Some(&name_id))
+            .context("Failed to insert series name value")?
+            .set_value_mut(series.name.values.concat());
+    }
+    Ok(())
+}
+
+/// Add line shape property of the series color, line is hidden when not drawn
+pub(crate) fn append_series_line_mut(
+    xml_doc_mut: &mut XmlDocument,
+    series_id: &usize,
+    color: &Option<String>,
+    draw_line: bool,
+) -> AnyResult<(), AnyError> {
+    if color.is_none() && draw_line {
+        return Ok(());
+    }
+    let shape_property_id = xml_doc_mut
+        .append_child_mut("c:spPr", Some(series_id))
+        .context("Failed to insert series shape property")?
+        .get_id();
+    let line_id = xml_doc_mut
+        .append_child_mut("a:ln", Some(&shape_property_id))
+        .context("Failed to insert series line")?
+        .get_id();
+    match color {
+        Some(color) if draw_line => {
+            let solid_fill_id = xml_doc_mut
+                .append_child_mut("a:solidFill", Some(&line_id))
+                .context("Failed to insert series line fill")?
+                .get_id();
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&solid_fill_id),
+                "a:srgbClr",
+                &color.to_uppercase(),
+            )?;
+        }
+        _ => {
+            xml_doc_mut
+                .append_child_mut("a:noFill", Some(&line_id))
+                .context("Failed to insert series line fill")?;
+        }
+    }
+    Ok(())
+}
+
+/// Add marker symbol of the series, filled with the series color
+pub(crate) fn append_series_marker_mut(
+    xml_doc_mut: &mut XmlDocument,
+    series_id: &usize,
+    marker: &ChartMarkerValues,
+    color: &Option<String>,
+) -> AnyResult<(), AnyError> {
+    if *marker == ChartMarkerValues::Auto && color.is_none() {
+        return Ok(());
+    }
+    let marker_id = xml_doc_mut
+        .append_child_mut("c:marker", Some(series_id))
+        .context("Failed to insert series marker")?
+        .get_id();
+    if *marker != ChartMarkerValues::Auto {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&marker_id),
+            "c:symbol",
+            &ChartMarkerValues::get_string(marker.clone()),
+        )?;
+    }
+    if *marker != ChartMarkerValues::None {
+        append_series_color_mut(xml_doc_mut, &marker_id, color)?;
+    }
+    Ok(())
+}
+
+/// Add the trendline of the series
+pub(crate) fn append_trendline_mut(
+    xml_doc_mut: &mut XmlDocument,
+    series_id: &usize,
+    trendline: &Option<ChartTrendline>,
+) -> AnyResult<(), AnyError> {
+    let Some(trendline) = trendline else {
+        return Ok(());
+    };
+    let trendline_id = xml_doc_mut
+        .append_child_mut("c:trendline", Some(series_id))
+        .context("Failed to insert trendline")?
+        .get_id();
+    if let Some(name) = &trendline.name {
+        xml_doc_mut
+            .append_child_mut("c:name", Some(&trendline_id))
+            .context("Failed to insert trendline name")?
+            .set_value_mut(name.to_string());
+    }
+    let (trendline_type, order, period) = match &trendline.trendline_type {
+        ChartTrendlineTypeValues::Linear => ("linear", None, None),
+        ChartTrendlineTypeValues::Exponential => ("exp", None, None),
+        ChartTrendlineTypeValues::Logarithmic => ("log", None, None),
+        ChartTrendlineTypeValues::Power => ("power", None, None),
+        ChartTrendlineTypeValues::Polynomial { order } => ("poly", Some(*order), None),
+        ChartTrendlineTypeValues::MovingAverage { period } => ("movingAvg", None, Some(*period)),
+    };
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&trendline_id),
+        "c:trendlineType",
+        trendline_type,
+    )?;
+    if let Some(order) = order {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&trendline_id),
+            "c:order",
+            &order.to_string(),
+        )?;
+    }
+    if let Some(period) = period {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&trendline_id),
+            "c:period",
+            &period.to_string(),
+        )?;
+    }
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&trendline_id),
+        "c:dispRSqr",
+        if trendline.display_r_squared {
+            "1"
+        } else {
+            "0"
+        },
+    )?;
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&trendline_id),
+        "c:dispEq",
+        if trendline.display_equation { "1" } else { "0" },
+    )?;
+    Ok(())
+}
+
+/// Add c:xVal and c:yVal of the XY series, categories are the x values when x values not set
+pub(crate) fn append_xy_values_mut(
+    xml_doc_mut: &mut XmlDocument,
+    series_id: &usize,
+    series: &ChartSeries,
+) -> AnyResult<(), AnyError> {
+    if let Some(x_values) = &series.x_values {
+        append_number_data_mut(xml_doc_mut, series_id, "c:xVal", x_values)?;
+    } else if let Some(categories) = &series.categories {
+        append_text_data_mut(xml_doc_mut, series_id, "c:xVal", categories)?;
+    }
+    append_number_data_mut(xml_doc_mut, series_id, "c:yVal", &series.values)
+}
+
+/// Add the axis ids the plot group is drawn on
+pub(crate) fn append_axis_ids_mut(
+    xml_doc_mut: &mut XmlDocument,
+    plot_id: &usize,
+    (category_axis_id, value_axis_id): &ChartAxisIds,
+) -> AnyResult<(), AnyError> {
+    for axis_id in [category_axis_id, value_axis_id] {
+        append_val_element_mut(xml_doc_mut, Some(plot_id), "c:axId", &axis_id.to_string())?;
+    }
+    Ok(())
+}
+
+/// Add solid fill shape property of the series color
+pub(crate) fn append_series_color_mut(
+    xml_doc_mut: &mut XmlDocument,
+    series_id: &usize,
+    color: &Option<String>,
+) -> AnyResult<(), AnyError> {
+    if let Some(color) = color {
+        let shape_property_id = xml_doc_mut
+            .append_child_mut("c:spPr", Some(series_id))
+            .context("Failed to insert series shape property")?
+            .get_id();
+        let solid_fill_id = xml_doc_mut
+            .append_child_mut("a:solidFill", Some(&shape_property_id))
+            .context("Failed to insert series fill")?
+            .get_id();
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&solid_fill_id),
+            "a:srgbClr",
+            &color.to_uppercase(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Add the data labels element
+pub(crate) fn append_data_label_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    data_label: &ChartDataLabel,
+) -> AnyResult<(), AnyError> {
+    let data_label_id = xml_doc_mut
+        .append_child_mut("c:dLbls", Some(parent_id))
+        .context("Failed to insert data label element")?
+        .get_id();
+    if let Some(position) = &data_label.position {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&data_label_id),
+            "c:dLblPos",
+            &ChartDataLabelPositionValues::get_string(position.clone()),
+        )?;
+    }
+    for (tag, flag) in [
+        ("c:showLegendKey", data_label.show_legend_key),
+        ("c:showVal", data_label.show_value),
+        ("c:showCatName", data_label.show_category_name),
+        ("c:showSerName", data_label.show_series_name),
+        ("c:showPercent", data_label.show_percent),
+        ("c:showBubbleSize", data_label.show_bubble_size),
+    ] {
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&data_label_id),
+            tag,
+            if flag { "1" } else { "0" },
+        )?;
+    }
+    Ok(())
+}
+
+/// Add text data like c:cat as reference with cache or as literal
+pub(crate) fn append_text_data_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    tag: &str,
+    text_data: &ChartTextData,
+) -> AnyResult<(), AnyError> {
+    let data_id = xml_doc_mut
+        .append_child_mut(tag, Some(parent_id))
+        .context("Failed to insert chart text data")?
+        .get_id();
+    if text_data.reference.is_some() {
+        append_text_reference_mut(xml_doc_mut, &data_id, text_data)
+    } else {
+        let literal_id = xml_doc_mut
+            .append_child_mut("c:strLit", Some(&data_id))
+            .context("Failed to insert chart text literal")?
+            .get_id();
+        append_text_points_mut(xml_doc_mut, &literal_id, &text_data.values)
+    }
+}
+
+fn append_text_reference_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    text_data: &ChartTextData,
+) -> AnyResult<(), AnyError> {
+    let reference_id = xml_doc_mut
+        .append_child_mut("c:strRef", Some(parent_id))
+        .context("Failed to insert chart text reference")?
+        .get_id();
+    xml_doc_mut
+        .append_child_mut("c:f", Some(&reference_id))
+        .context("Failed to insert chart text formula")?
+        .set_value_mut(text_data.reference.clone().unwrap_or_default());
+    let cache_id = xml_doc_mut
+        .append_child_mut("c:strCache", Some(&reference_id))
+        .context("Failed to insert chart text cache")?
+        .get_id();
+    append_text_points_mut(xml_doc_mut, &cache_id, &text_data.values)
+}
+
+fn append_text_points_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    values: &[String],
+) -> AnyResult<(), AnyError> {
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(parent_id),
+        "c:ptCount",
+        &values.len().to_string(),
+    )?;
+    for (point_index, value) in values.iter().enumerate() {
+        let point_id = xml_doc_mut.append_child_with_attributes_mut(
+            "c:pt",
+            Some(parent_id),
+            vec![("idx", &point_index.to_string())],
+        )?;
+        xml_doc_mut
+            .append_child_mut("c:v", Some(&point_id))
+            .context("Failed to insert chart point value")?
+            .set_value_mut(value.to_string());
+    }
+    Ok(())
+}
+
+/// Add number data like c:val as reference with cache or as literal
+pub(crate) fn append_number_data_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    tag: &str,
+    number_data: &ChartNumberData,
+) -> AnyResult<(), AnyError> {
+    let data_id = xml_doc_mut
+        .append_child_mut(tag, Some(parent_id))
+        .context("Failed to insert chart number data")?
+        .get_id();
+    let points_id = if let Some(reference) = &number_data.reference {
+        let reference_id = xml_doc_mut
+            .append_child_mut("c:numRef", Some(&data_id))
+            .context("Failed to insert chart number reference")?
+            .get_id();
+        xml_doc_mut
+            .append_child_mut("c:f", Some(&reference_id))
+            .context("Failed to insert chart number formula")?
+            .set_value_mut(reference.to_string());
+        xml_doc_mut
+            .append_child_mut("c:numCache", Some(&reference_id))
+            .context("Failed to insert chart number cache")?
+            .get_id()
+    } else {
+        xml_doc_mut
+            .append_child_mut("c:numLit", Some(&data_id))
+            .context("Failed to insert chart number literal")?
+            .get_id()
+    };
+    xml_doc_mut
+        .append_child_mut("c:formatCode", Some(&points_id))
+        .context("Failed to insert chart number format")?
+        .set_value_mut(
+            number_data
+                .format_code
+                .clone()
+                .unwrap_or("General".to_string()),
+        );
+    append_val_element_mut(
+        xml_doc_mut,
+        Some(&points_id),
+        "c:ptCount",
+        &number_data.values.len().to_string(),
+    )?;
+    for (point_index, value) in number_data.values.iter().enumerate() {
+        if let Some(value) = value {
+            let point_id = xml_doc_mut.append_child_with_attributes_mut(
+                "c:pt",
+                Some(&points_id),
+                vec![("idx", &point_index.to_string())],
+            )?;
+            xml_doc_mut
+                .append_child_mut("c:v", Some(&point_id))
+                .context("Failed to insert chart point value")?
+                .set_value_mut(value.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Check the series carry data and valid color, the data label position and trendline
+/// are supported by the chart type
+pub(crate) fn validate_series(
+    base_chart: &BaseChart,
+    series: &[ChartSeries],
+    label_positions: &[ChartDataLabelPositionValues],
+    supports_trendline: bool,
+) -> AnyResult<(), AnyError> {
+    if series.is_empty() {
+        return Err(anyhow!("Chart need at least one series"));
+    }
+    for data_label in base_chart
+        .data_label
+        .iter()
+        .chain(series.iter().filter_map(|item| item.data_label.as_ref()))
+    {
+        if let Some(position) = &data_label.position {
+            if !label_positions.contains(position) {
+                return Err(anyhow!(
+                    "Data label position {:?} is not supported by the chart type",
+                    position
+                ));
+            }
+        }
+    }
+    for chart_series in series {
+        if chart_series.values.reference.is_none() && chart_series.values.values.is_empty() {
+            return Err(anyhow!("Chart series values missing"));
+        }
+        if let Some(color) = &chart_series.color {
+            if color.len() != 6 || !color.chars().all(|item| item.is_ascii_hexdigit()) {
+                return Err(anyhow!("Series color must be 6 digit hex : {}", color));
+            }
+        }
+        if let Some(trendline) = &chart_series.trendline {
+            if !supports_trendline {
+                return Err(anyhow!("Trendline is not supported by the chart type"));
+            }
+            match trendline.trendline_type {
+                ChartTrendlineTypeValues::Polynomial { order } if !(2..=6).contains(&order) => {
+                    return Err(anyhow!(
+                        "Polynomial trendline order must be between 2 and 6"
+                    ));
+                }
+                ChartTrendlineTypeValues::MovingAverage { period } if period < 2 => {
+                    return Err(anyhow!(
+                        "Moving average trendline period must be at least 2"
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use crate::{
+    files::XmlDocument,
+    global_2007::charts::{
+        append_axis_ids_mut, append_data_label_mut, append_number_data_mut,
+        append_series_color_mut, append_series_head_mut, append_trendline_mut,
+        append_val_element_mut, append_xy_values_mut, validate_series, BaseChart, ChartAxesLayout,
+        ChartAxisIds, ChartDataLabelPositionValues, ChartPlot, ChartSeries,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+
+/// Bubble chart, series need the bubble sizes along with the x and y values
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BubbleChart {
+    pub base_chart: BaseChart,
+    /// Bubble size as percent of the default size, 0 to 300. Default 100
+    pub bubble_scale: Option<u16>,
+    /// Draw the bubbles of negative size
+    pub show_negative_bubbles: bool,
+    pub series: Vec<ChartSeries>,
+}
+
+impl ChartPlot for BubbleChart {
+    fn get_base_chart(&self) -> &BaseChart {
+        &self.base_chart
+    }
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries> {
+        self.series.iter_mut().collect()
+    }
+
+    fn get_series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout> {
+        Some(ChartAxesLayout {
+            category_axis_tag: "c:valAx",
+            category_axis_position: "b",
+            value_axis_position: "l",
+            value_format_code: None,
+            cross_between: "midCat",
+        })
+    }
+
+    fn validate(&self) -> AnyResult<(), AnyError> {
+        validate_series(
+            &self.base_chart,
+            &self.series,
+            &[
+                ChartDataLabelPositionValues::Center,
+                ChartDataLabelPositionValues::Left,
+                ChartDataLabelPositionValues::Right,
+                ChartDataLabelPositionValues::Top,
+                ChartDataLabelPositionValues::Bottom,
+            ],
+            true,
+        )?;
+        if self
+            .series
+            .iter()
+            .any(|series| series.bubble_sizes.is_none())
+        {
+            return Err(anyhow!("Bubble chart series need the bubble sizes"));
+        }
+        if self
+            .bubble_scale
+            .is_some_and(|bubble_scale| bubble_scale > 300)
+        {
+            return Err(anyhow!("Bubble scale must be between 0 and 300"));
+        }
+        Ok(())
+    }
+
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError> {
+        let bubble_chart_id = xml_doc_mut
+            .append_child_mut("c:bubbleChart", Some(plot_area_id))
+            .context("Failed to insert bubble chart element")?
+            .get_id();
+        append_val_element_mut(xml_doc_mut, Some(&bubble_chart_id), "c:varyColors", "0")?;
+        for (series_index, series) in self.series.iter().enumerate() {
+            let series_id = xml_doc_mut
+                .append_child_mut("c:ser", Some(&bubble_chart_id))
+                .context("Failed to insert series element")?
+                .get_id();
+            append_series_head_mut(
+                xml_doc_mut,
+                &series_id,
+                series_offset + series_index,
+                series,
+            )?;
+            append_series_color_mut(xml_doc_mut, &series_id, &series.color)?;
+            append_val_element_mut(xml_doc_mut, Some(&series_id), "c:invertIfNegative", "0")?;
+            if let Some(data_label) = &series.data_label {
+                append_data_label_mut(xml_doc_mut, &series_id, data_label)?;
+            }
+            append_trendline_mut(xml_doc_mut, &series_id, &series.trendline)?;
+            append_xy_values_mut(xml_doc_mut, &series_id, series)?;
+            if let Some(bubble_sizes) = &series.bubble_sizes {
+                append_number_data_mut(xml_doc_mut, &series_id, "c:bubbleSize", bubble_sizes)?;
+            }
+            append_val_element_mut(xml_doc_mut, Some(&series_id), "c:bubble3D", "0")?;
+        }
+        if let Some(data_label) = &self.base_chart.data_label {
+            append_data_label_mut(xml_doc_mut, &bubble_chart_id, data_label)?;
+        }
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&bubble_chart_id),
+            "c:bubbleScale",
+            &self.bubble_scale.unwrap_or(100).to_string(),
+        )?;
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&bubble_chart_id),
+            "c:showNegBubbles",
+            if self.show_negative_bubbles { "1" } else { "0" },
+        )?;
+        append_axis_ids_mut(xml_doc_mut, &bubble_chart_id, axis_ids)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    files::XmlDocument,
+    global_2007::charts::{
+        BaseChart, ChartAxesLayout, ChartAxis, ChartAxisGroup, ChartAxisIds, ChartPlot,
+        ChartSeries, ChartTypeValues, PRIMARY_AXIS_IDS, SECONDARY_AXIS_IDS,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+
+/// Plot group of the combo chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComboChartPlot {
+    /// Chart type of the group. Title, legend and axes of its base chart are taken from the combo chart
+    pub chart: ChartTypeValues,
+    /// Draw the group on the secondary axes
+    pub secondary_axis: bool,
+}
+
+/// Chart of multiple plot groups like column series with line series on the secondary axis
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ComboChart {
+    pub base_chart: BaseChart,
+    /// Value axis of the secondary plot groups, drawn at the opposite side of the primary value axis
+    pub secondary_value_axis: ChartAxis,
+    pub plots: Vec<ComboChartPlot>,
+}
+
+impl ComboChart {
+    /// Axes layout of the first plot group drawn on the axes
+    fn get_group_layout(&self, secondary_axis: bool) -> Option<ChartAxesLayout> {
+        self.plots
+            .iter()
+            .find(|plot| plot.secondary_axis == secondary_axis)
+            .and_then(|plot| plot.chart.get_plot().get_axes_layout())
+    }
+}
+
+impl ChartPlot for ComboChart {
+    fn get_base_chart(&self) -> &BaseChart {
+        &self.base_chart
+    }
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries> {
+        self.plots
+            .iter_mut()
+            .flat_map(|plot| plot.chart.get_plot_mut().get_series_mut())
+            .collect()
+    }
+
+    fn get_series_count(&self) -> usize {
+        self.plots
+            .iter()
+            .map(|plot| plot.chart.get_plot().get_series_count())
+            .sum()
+    }
+
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout> {
+        self.get_group_layout(false)
+    }
+
+    fn get_axis_groups(&self) -> Vec<ChartAxisGroup> {
+        let mut axis_groups = Vec::new();
+        if let Some(layout) = self.get_group_layout(false) {
+            axis_groups.push(ChartAxisGroup {
+                axis_ids: PRIMARY_AXIS_IDS,
+                layout,
+                category_axis: self.base_chart.category_axis.clone(),
+                value_axis: self.base_chart.value_axis.clone(),
+                is_secondary: false,
+            });
+        }
+        if let Some(layout) = self.get_group_layout(true) {
+            // Secondary plots share the primary categories
+            axis_groups.push(ChartAxisGroup {
+                axis_ids: SECONDARY_AXIS_IDS,
+                layout,
+                category_axis: ChartAxis {
+                    hidden: true,
+                    ..Default::default()
+                },
+                value_axis: self.secondary_value_axis.clone(),
+                is_secondary: true,
+            });
+        }
+        axis_groups
+    }
+
+    fn validate(&self) -> AnyResult<(), AnyError> {
+        if !self.plots.iter().any(|plot| !plot.secondary_axis) {
+            return Err(anyhow!("Combo chart need a plot group on the primary axes"));
+        }
+        for (plot_index, combo_plot) in self.plots.iter().enumerate() {
+            if let ChartTypeValues::Combo(_) = combo_plot.chart {
+                return Err(anyhow!("Combo chart can't be nested"));
+            }
+            let plot = combo_plot.chart.get_plot();
+            let layout = plot
+                .get_axes_layout()
+                .ok_or(anyhow!("Chart type without axes can't be combined"))?;
+            let group_layout = self
+                .get_group_layout(combo_plot.secondary_axis)
+                .ok_or(anyhow!("Failed to get the axes of the plot group"))?;
+            if layout.category_axis_tag != group_layout.category_axis_tag
+                || layout.category_axis_position != group_layout.category_axis_position
+            {
+                return Err(anyhow!(
+                    "Plot group {} don't share the category axis of the other groups on the axes",
+                    plot_index
+                ));
+            }
+            plot.validate()
+                .context(format!("Plot group {} validation failed", plot_index))?;
+        }
+        Ok(())
+    }
+
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        _axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError> {
+        let mut series_offset = series_offset;
+        for combo_plot in &self.plots {
+            let plot = combo_plot.chart.get_plot();
+            plot.append_plot_mut(
+                xml_doc_mut,
+                plot_area_id,
+                if combo_plot.secondary_axis {
+                    &SECONDARY_AXIS_IDS
+                } else {
+                    &PRIMARY_AXIS_IDS
+                },
+                series_offset,
+            )?;
+            series_offset += plot.get_series_count();
+        }
+        Ok(())
+    }
+}
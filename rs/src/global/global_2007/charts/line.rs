@@ -0,0 +1,131 @@
+use crate::{
+    files::XmlDocument,
+    global_2007::{
+        charts::{
+            append_axis_ids_mut, append_data_label_mut, append_number_data_mut,
+            append_series_head_mut, append_series_line_mut, append_series_marker_mut,
+            append_text_data_mut, append_trendline_mut, append_val_element_mut, validate_series,
+            BaseChart, ChartAxesLayout, ChartAxisIds, ChartDataLabelPositionValues,
+            ChartGroupingValues, ChartMarkerValues, ChartPlot, ChartSeries,
+        },
+        traits::Enum,
+    },
+};
+use anyhow::{Context, Error as AnyError, Result as AnyResult};
+
+/// Line chart
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LineChart {
+    pub base_chart: BaseChart,
+    pub grouping: ChartGroupingValues,
+    /// Marker of the data points, none draw the line only
+    pub marker: ChartMarkerValues,
+    /// Draw the line as smooth curve
+    pub smooth: bool,
+    pub series: Vec<ChartSeries>,
+}
+
+impl ChartPlot for LineChart {
+    fn get_base_chart(&self) -> &BaseChart {
+        &self.base_chart
+    }
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries> {
+        self.series.iter_mut().collect()
+    }
+
+    fn get_series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout> {
+        Some(ChartAxesLayout {
+            category_axis_tag: "c:catAx",
+            category_axis_position: "b",
+            value_axis_position: "l",
+            value_format_code: if self.grouping == ChartGroupingValues::PercentStacked {
+                Some("0%")
+            } else {
+                None
+            },
+            cross_between: "between",
+        })
+    }
+
+    fn validate(&self) -> AnyResult<(), AnyError> {
+        validate_series(
+            &self.base_chart,
+            &self.series,
+            &[
+                ChartDataLabelPositionValues::Center,
+                ChartDataLabelPositionValues::Left,
+                ChartDataLabelPositionValues::Right,
+                ChartDataLabelPositionValues::Top,
+                ChartDataLabelPositionValues::Bottom,
+            ],
+            self.grouping == ChartGroupingValues::Standard,
+        )
+    }
+
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError> {
+        let line_chart_id = xml_doc_mut
+            .append_child_mut("c:lineChart", Some(plot_area_id))
+            .context("Failed to insert line chart element")?
+            .get_id();
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&line_chart_id),
+            "c:grouping",
+            &ChartGroupingValues::get_string(self.grouping.clone()),
+        )?;
+        append_val_element_mut(xml_doc_mut, Some(&line_chart_id), "c:varyColors", "0")?;
+        for (series_index, series) in self.series.iter().enumerate() {
+            let series_id = xml_doc_mut
+                .append_child_mut("c:ser", Some(&line_chart_id))
+                .context("Failed to insert series element")?
+                .get_id();
+            append_series_head_mut(
+                xml_doc_mut,
+                &series_id,
+                series_offset + series_index,
+                series,
+            )?;
+            append_series_line_mut(xml_doc_mut, &series_id, &series.color, true)?;
+            append_series_marker_mut(xml_doc_mut, &series_id, &self.marker, &series.color)?;
+            if let Some(data_label) = &series.data_label {
+                append_data_label_mut(xml_doc_mut, &series_id, data_label)?;
+            }
+            append_trendline_mut(xml_doc_mut, &series_id, &series.trendline)?;
+            if let Some(categories) = &series.categories {
+                append_text_data_mut(xml_doc_mut, &series_id, "c:cat", categories)?;
+            }
+            append_number_data_mut(xml_doc_mut, &series_id, "c:val", &series.values)?;
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&series_id),
+                "c:smooth",
+                if self.smooth { "1" } else { "0" },
+            )?;
+        }
+        if let Some(data_label) = &self.base_chart.data_label {
+            append_data_label_mut(xml_doc_mut, &line_chart_id, data_label)?;
+        }
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&line_chart_id),
+            "c:marker",
+            if self.marker == ChartMarkerValues::None {
+                "0"
+            } else {
+                "1"
+            },
+        )?;
+        append_axis_ids_mut(xml_doc_mut, &line_chart_id, axis_ids)
+    }
+}
@@ -1,5 +1,19 @@
+pub(crate) mod area;
 pub(crate) mod bar_column;
 pub(crate) mod base;
+pub(crate) mod bubble;
+pub(crate) mod combo;
+pub(crate) mod line;
+pub(crate) mod models;
+pub(crate) mod pie;
+pub(crate) mod scatter;
 
-pub(crate) use bar_column::*;
-pub(crate) use base::*;
+pub use area::*;
+pub use bar_column::*;
+pub use base::*;
+pub use bubble::*;
+pub use combo::*;
+pub use line::*;
+pub use models::*;
+pub use pie::*;
+pub use scatter::*;
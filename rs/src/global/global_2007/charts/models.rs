@@ -0,0 +1,395 @@
+use crate::global_2007::{
+    charts::{
+        AreaChart, BarColumnChart, BubbleChart, ChartPlot, ComboChart, LineChart, PieChart,
+        ScatterChart,
+    },
+    traits::Enum,
+};
+use anyhow::{anyhow, Error as AnyError, Result as AnyResult};
+
+/// Position of the chart legend
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ChartLegendPositionValues {
+    #[default]
+    Right,
+    Left,
+    Top,
+    Bottom,
+    TopRight,
+}
+
+impl Enum<ChartLegendPositionValues> for ChartLegendPositionValues {
+    fn get_string(input_enum: ChartLegendPositionValues) -> String {
+        match input_enum {
+            ChartLegendPositionValues::Right => "r".to_string(),
+            ChartLegendPositionValues::Left => "l".to_string(),
+            ChartLegendPositionValues::Top => "t".to_string(),
+            ChartLegendPositionValues::Bottom => "b".to_string(),
+            ChartLegendPositionValues::TopRight => "tr".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> ChartLegendPositionValues {
+        match input_string {
+            "l" => ChartLegendPositionValues::Left,
+            "t" => ChartLegendPositionValues::Top,
+            "b" => ChartLegendPositionValues::Bottom,
+            "tr" => ChartLegendPositionValues::TopRight,
+            _ => ChartLegendPositionValues::Right,
+        }
+    }
+}
+
+/// Position of the data label around the data point, allowed values depend on the chart type
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartDataLabelPositionValues {
+    Center,
+    InsideEnd,
+    InsideBase,
+    OutsideEnd,
+    BestFit,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Enum<ChartDataLabelPositionValues> for ChartDataLabelPositionValues {
+    fn get_string(input_enum: ChartDataLabelPositionValues) -> String {
+        match input_enum {
+            ChartDataLabelPositionValues::Center => "ctr".to_string(),
+            ChartDataLabelPositionValues::InsideEnd => "inEnd".to_string(),
+            ChartDataLabelPositionValues::InsideBase => "inBase".to_string(),
+            ChartDataLabelPositionValues::OutsideEnd => "outEnd".to_string(),
+            ChartDataLabelPositionValues::BestFit => "bestFit".to_string(),
+            ChartDataLabelPositionValues::Left => "l".to_string(),
+            ChartDataLabelPositionValues::Right => "r".to_string(),
+            ChartDataLabelPositionValues::Top => "t".to_string(),
+            ChartDataLabelPositionValues::Bottom => "b".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> ChartDataLabelPositionValues {
+        match input_string {
+            "inEnd" => ChartDataLabelPositionValues::InsideEnd,
+            "inBase" => ChartDataLabelPositionValues::InsideBase,
+            "outEnd" => ChartDataLabelPositionValues::OutsideEnd,
+            "bestFit" => ChartDataLabelPositionValues::BestFit,
+            "l" => ChartDataLabelPositionValues::Left,
+            "r" => ChartDataLabelPositionValues::Right,
+            "t" => ChartDataLabelPositionValues::Top,
+            "b" => ChartDataLabelPositionValues::Bottom,
+            _ => ChartDataLabelPositionValues::Center,
+        }
+    }
+}
+
+/// How the series of line and area chart are placed
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ChartGroupingValues {
+    #[default]
+    Standard,
+    Stacked,
+    /// Stacked to 100% of the category total
+    PercentStacked,
+}
+
+impl Enum<ChartGroupingValues> for ChartGroupingValues {
+    fn get_string(input_enum: ChartGroupingValues) -> String {
+        match input_enum {
+            ChartGroupingValues::Standard => "standard".to_string(),
+            ChartGroupingValues::Stacked => "stacked".to_string(),
+            ChartGroupingValues::PercentStacked => "percentStacked".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> ChartGroupingValues {
+        match input_string {
+            "stacked" => ChartGroupingValues::Stacked,
+            "percentStacked" => ChartGroupingValues::PercentStacked,
+            _ => ChartGroupingValues::Standard,
+        }
+    }
+}
+
+/// Marker symbol drawn on the data points of line and scatter chart
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ChartMarkerValues {
+    /// Symbol picked by the series order
+    #[default]
+    Auto,
+    None,
+    Circle,
+    Square,
+    Diamond,
+    Triangle,
+    X,
+    Star,
+    Dash,
+    Dot,
+    Plus,
+}
+
+impl Enum<ChartMarkerValues> for ChartMarkerValues {
+    fn get_string(input_enum: ChartMarkerValues) -> String {
+        match input_enum {
+            ChartMarkerValues::Auto => "auto".to_string(),
+            ChartMarkerValues::None => "none".to_string(),
+            ChartMarkerValues::Circle => "circle".to_string(),
+            ChartMarkerValues::Square => "square".to_string(),
+            ChartMarkerValues::Diamond => "diamond".to_string(),
+            ChartMarkerValues::Triangle => "triangle".to_string(),
+            ChartMarkerValues::X => "x".to_string(),
+            ChartMarkerValues::Star => "star".to_string(),
+            ChartMarkerValues::Dash => "dash".to_string(),
+            ChartMarkerValues::Dot => "dot".to_string(),
+            ChartMarkerValues::Plus => "plus".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> ChartMarkerValues {
+        match input_string {
+            "none" => ChartMarkerValues::None,
+            "circle" => ChartMarkerValues::Circle,
+            "square" => ChartMarkerValues::Square,
+            "diamond" => ChartMarkerValues::Diamond,
+            "triangle" => ChartMarkerValues::Triangle,
+            "x" => ChartMarkerValues::X,
+            "star" => ChartMarkerValues::Star,
+            "dash" => ChartMarkerValues::Dash,
+            "dot" => ChartMarkerValues::Dot,
+            "plus" => ChartMarkerValues::Plus,
+            _ => ChartMarkerValues::Auto,
+        }
+    }
+}
+
+/// Curve fitted to the series data
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ChartTrendlineTypeValues {
+    #[default]
+    Linear,
+    Exponential,
+    Logarithmic,
+    Power,
+    /// Order from 2 to 6
+    Polynomial {
+        order: u8,
+    },
+    /// Average of the last period points, period from 2
+    MovingAverage {
+        period: u32,
+    },
+}
+
+/// Trendline of the series, supported by the charts without stacking
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChartTrendline {
+    pub trendline_type: ChartTrendlineTypeValues,
+    /// Legend name, generated from the type when not set
+    pub name: Option<String>,
+    pub display_equation: bool,
+    pub display_r_squared: bool,
+}
+
+/// Labels shown next to the data points
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChartDataLabel {
+    pub show_value: bool,
+    pub show_category_name: bool,
+    pub show_series_name: bool,
+    /// Share of the whole, used by pie and doughnut
+    pub show_percent: bool,
+    pub show_legend_key: bool,
+    /// Size value of the bubble chart point
+    pub show_bubble_size: bool,
+    /// Chart type default position is used when not set
+    pub position: Option<ChartDataLabelPositionValues>,
+}
+
+/// Text data of the chart. Values are the cache shown until the reference is recalculated
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChartTextData {
+    /// Range like 'Sheet 1'!$A$2:$A$5, values are written as literal when not set
+    pub reference: Option<String>,
+    pub values: Vec<String>,
+}
+
+impl ChartTextData {
+    /// Referred data, worksheet fill the cache from its cells
+    pub fn from_reference(reference: &str) -> Self {
+        Self {
+            reference: Some(reference.to_string()),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn from_values(values: Vec<&str>) -> Self {
+        Self {
+            reference: None,
+            values: values.into_iter().map(|value| value.to_string()).collect(),
+        }
+    }
+}
+
+/// Number data of the chart. Values are the cache shown until the reference is recalculated
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChartNumberData {
+    /// Range like 'Sheet 1'!$B$2:$B$5, values are written as literal when not set
+    pub reference: Option<String>,
+    /// Empty cell of the range stay as none
+    pub values: Vec<Option<f64>>,
+    /// Number format of the values, General when not set
+    pub format_code: Option<String>,
+}
+
+impl ChartNumberData {
+    /// Referred data, worksheet fill the cache from its cells
+    pub fn from_reference(reference: &str) -> Self {
+        Self {
+            reference: Some(reference.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_values(values: Vec<f64>) -> Self {
+        Self {
+            values: values.into_iter().map(Some).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Data series plotted by the chart
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChartSeries {
+    /// Series name shown in the legend, single value
+    pub name: ChartTextData,
+    pub categories: Option<ChartTextData>,
+    /// Y values for scatter and bubble chart
+    pub values: ChartNumberData,
+    /// X values of scatter and bubble chart, categories are used when not set
+    pub x_values: Option<ChartNumberData>,
+    /// Bubble size of bubble chart
+    pub bubble_sizes: Option<ChartNumberData>,
+    /// Fill color as hex like "4472C4", theme color is used when not set
+    pub color: Option<String>,
+    /// Data label of the series, replace the chart data label
+    pub data_label: Option<ChartDataLabel>,
+    pub trendline: Option<ChartTrendline>,
+}
+
+impl ChartSeries {
+    /// Series fed by the sheet ranges like "Sheet1!$B$1", "Sheet1!$A$2:$A$5", "Sheet1!$B$2:$B$5"
+    pub fn from_reference(name_ref: &str, categories_ref: &str, values_ref: &str) -> Self {
+        Self {
+            name: ChartTextData::from_reference(name_ref),
+            categories: Some(ChartTextData::from_reference(categories_ref)),
+            values: ChartNumberData::from_reference(values_ref),
+            ..Default::default()
+        }
+    }
+}
+
+/// Position on the other axis where the axis cross it
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ChartAxisCrossesValues {
+    /// Zero of the other axis or its minimum when zero is out of the scale
+    #[default]
+    AutoZero,
+    Min,
+    Max,
+    At {
+        value: f64,
+    },
+}
+
+/// Axis setting of the chart
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChartAxis {
+    pub title: Option<String>,
+    /// Hide the axis, the plot still use its scale
+    pub hidden: bool,
+    /// Scale minimum, auto when not set. Value axis only
+    pub min: Option<f64>,
+    /// Scale maximum, auto when not set. Value axis only
+    pub max: Option<f64>,
+    /// Distance between the major tick marks. Value axis only
+    pub major_unit: Option<f64>,
+    /// Number format of the labels like "#,##0.00", chart type default when not set
+    pub number_format: Option<String>,
+    /// Logarithmic scale base from 2 to 1000. Value axis only
+    pub log_base: Option<f64>,
+    /// Primary value axis show the major gridlines when not set
+    pub major_gridlines: Option<bool>,
+    pub minor_gridlines: bool,
+    /// Crossing on the other axis, auto zero when not set. Secondary value axis cross at the maximum
+    pub crosses: Option<ChartAxisCrossesValues>,
+}
+
+impl ChartAxis {
+    /// Check the scale setting, category axis of text has no scale
+    pub(crate) fn validate(&self, is_value_axis: bool) -> AnyResult<(), AnyError> {
+        if !is_value_axis
+            && (self.min.is_some()
+                || self.max.is_some()
+                || self.major_unit.is_some()
+                || self.log_base.is_some())
+        {
+            return Err(anyhow!("Category axis has no scale setting"));
+        }
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min >= max {
+                return Err(anyhow!("Axis minimum must be less than maximum"));
+            }
+        }
+        if self.major_unit.is_some_and(|major_unit| major_unit <= 0.0) {
+            return Err(anyhow!("Axis major unit must be greater than 0"));
+        }
+        if let Some(log_base) = self.log_base {
+            if !(2.0..=1000.0).contains(&log_base) {
+                return Err(anyhow!("Axis log base must be between 2 and 1000"));
+            }
+            if self.min.is_some_and(|min| min <= 0.0) {
+                return Err(anyhow!("Logarithmic axis minimum must be greater than 0"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Chart added to the document
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartTypeValues {
+    BarColumn(BarColumnChart),
+    Line(LineChart),
+    Area(AreaChart),
+    Scatter(ScatterChart),
+    /// Pie or doughnut chart
+    Pie(PieChart),
+    Bubble(BubbleChart),
+    /// Plot groups of different chart types sharing the chart
+    Combo(ComboChart),
+}
+
+impl ChartTypeValues {
+    pub(crate) fn get_plot(&self) -> &dyn ChartPlot {
+        match self {
+            ChartTypeValues::BarColumn(chart) => chart,
+            ChartTypeValues::Line(chart) => chart,
+            ChartTypeValues::Area(chart) => chart,
+            ChartTypeValues::Scatter(chart) => chart,
+            ChartTypeValues::Pie(chart) => chart,
+            ChartTypeValues::Bubble(chart) => chart,
+            ChartTypeValues::Combo(chart) => chart,
+        }
+    }
+
+    pub(crate) fn get_plot_mut(&mut self) -> &mut dyn ChartPlot {
+        match self {
+            ChartTypeValues::BarColumn(chart) => chart,
+            ChartTypeValues::Line(chart) => chart,
+            ChartTypeValues::Area(chart) => chart,
+            ChartTypeValues::Scatter(chart) => chart,
+            ChartTypeValues::Pie(chart) => chart,
+            ChartTypeValues::Bubble(chart) => chart,
+            ChartTypeValues::Combo(chart) => chart,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    files::XmlDocument,
+    global_2007::charts::{
+        append_data_label_mut, append_number_data_mut, append_series_color_mut,
+        append_series_head_mut, append_text_data_mut, append_val_element_mut, validate_series,
+        BaseChart, ChartAxesLayout, ChartAxisIds, ChartDataLabelPositionValues, ChartPlot,
+        ChartSeries,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::collections::BTreeMap;
+
+/// Pie chart, doughnut chart when the hole size is set
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PieChart {
+    pub base_chart: BaseChart,
+    /// Hole of the doughnut as percent of the size, 10 to 90
+    pub hole_size: Option<u8>,
+    /// Angle of the first slice clockwise from the top, 0 to 360
+    pub first_slice_angle: u16,
+    /// Distance of the slices from the center as percent of the radius, applied to the first series
+    pub explosion: Option<u32>,
+    /// Explosion of the single slice keyed by the 0 based point index, replace the explosion
+    pub point_explosions: BTreeMap<u32, u32>,
+    /// Pie shows a single series, doughnut draw a ring per series
+    pub series: Vec<ChartSeries>,
+}
+
+impl ChartPlot for PieChart {
+    fn get_base_chart(&self) -> &BaseChart {
+        &self.base_chart
+    }
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries> {
+        self.series.iter_mut().collect()
+    }
+
+    fn get_series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout> {
+        None
+    }
+
+    fn validate(&self) -> AnyResult<(), AnyError> {
+        let label_positions = if self.hole_size.is_some() {
+            vec![]
+        } else {
+            vec![
+                ChartDataLabelPositionValues::Center,
+                ChartDataLabelPositionValues::InsideEnd,
+                ChartDataLabelPositionValues::OutsideEnd,
+                ChartDataLabelPositionValues::BestFit,
+            ]
+        };
+        validate_series(&self.base_chart, &self.series, &label_positions, false)?;
+        if self.hole_size.is_none() && self.series.len() > 1 {
+            return Err(anyhow!(
+                "Pie chart show a single series, use hole size for doughnut"
+            ));
+        }
+        if self
+            .hole_size
+            .is_some_and(|hole_size| !(10..=90).contains(&hole_size))
+        {
+            return Err(anyhow!("Doughnut hole size must be between 10 and 90"));
+        }
+        if self.first_slice_angle > 360 {
+            return Err(anyhow!("First slice angle must be between 0 and 360"));
+        }
+        if self
+            .explosion
+            .iter()
+            .chain(self.point_explosions.values())
+            .any(|explosion| *explosion > 400)
+        {
+            return Err(anyhow!("Slice explosion must be between 0 and 400"));
+        }
+        Ok(())
+    }
+
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        _axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError> {
+        let pie_chart_id = xml_doc_mut
+            .append_child_mut(
+                if self.hole_size.is_some() {
+                    "c:doughnutChart"
+                } else {
+                    "c:pieChart"
+                },
+                Some(plot_area_id),
+            )
+            .context("Failed to insert pie chart element")?
+            .get_id();
+        // Slice of the point get the own color
+        append_val_element_mut(xml_doc_mut, Some(&pie_chart_id), "c:varyColors", "1")?;
+        for (series_index, series) in self.series.iter().enumerate() {
+            let series_id = xml_doc_mut
+                .append_child_mut("c:ser", Some(&pie_chart_id))
+                .context("Failed to insert series element")?
+                .get_id();
+            append_series_head_mut(
+                xml_doc_mut,
+                &series_id,
+                series_offset + series_index,
+                series,
+            )?;
+            append_series_color_mut(xml_doc_mut, &series_id, &series.color)?;
+            if series_index == 0 {
+                if let Some(explosion) = self.explosion {
+                    append_val_element_mut(
+                        xml_doc_mut,
+                        Some(&series_id),
+                        "c:explosion",
+                        &explosion.to_string(),
+                    )?;
+                }
+                for (point_index, explosion) in &self.point_explosions {
+                    let data_point_id = xml_doc_mut
+                        .append_child_mut("c:dPt", Some(&series_id))
+                        .context("Failed to insert data point")?
+                        .get_id();
+                    append_val_element_mut(
+                        xml_doc_mut,
+                        Some(&data_point_id),
+                        "c:idx",
+                        &point_index.to_string(),
+                    )?;
+                    append_val_element_mut(xml_doc_mut, Some(&data_point_id), "c:bubble3D", "0")?;
+                    append_val_element_mut(
+                        xml_doc_mut,
+                        Some(&data_point_id),
+                        "c:explosion",
+                        &explosion.to_string(),
+                    )?;
+                }
+            }
+            if let Some(data_label) = &series.data_label {
+                append_data_label_mut(xml_doc_mut, &series_id, data_label)?;
+            }
+            if let Some(categories) = &series.categories {
+                append_text_data_mut(xml_doc_mut, &series_id, "c:cat", categories)?;
+            }
+            append_number_data_mut(xml_doc_mut, &series_id, "c:val", &series.values)?;
+        }
+        if let Some(data_label) = &self.base_chart.data_label {
+            append_data_label_mut(xml_doc_mut, &pie_chart_id, data_label)?;
+        }
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&pie_chart_id),
+            "c:firstSliceAng",
+            &self.first_slice_angle.to_string(),
+        )?;
+        if let Some(hole_size) = self.hole_size {
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&pie_chart_id),
+                "c:holeSize",
+                &hole_size.to_string(),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    files::XmlDocument,
+    global_2007::{
+        charts::{
+            append_axis_ids_mut, append_data_label_mut, append_series_head_mut,
+            append_series_line_mut, append_series_marker_mut, append_trendline_mut,
+            append_val_element_mut, append_xy_values_mut, validate_series, BaseChart,
+            ChartAxesLayout, ChartAxisIds, ChartDataLabelPositionValues, ChartMarkerValues,
+            ChartPlot, ChartSeries,
+        },
+        traits::Enum,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+
+/// How the scatter points are drawn
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ScatterStyleValues {
+    /// Points only
+    #[default]
+    Marker,
+    /// Straight line without the points
+    Line,
+    LineMarker,
+    /// Smooth curve without the points
+    Smooth,
+    SmoothMarker,
+}
+
+impl Enum<ScatterStyleValues> for ScatterStyleValues {
+    fn get_string(input_enum: ScatterStyleValues) -> String {
+        match input_enum {
+            ScatterStyleValues::Marker => "marker".to_string(),
+            ScatterStyleValues::Line => "line".to_string(),
+            ScatterStyleValues::LineMarker => "lineMarker".to_string(),
+            ScatterStyleValues::Smooth => "smooth".to_string(),
+            ScatterStyleValues::SmoothMarker => "smoothMarker".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> ScatterStyleValues {
+        match input_string {
+            "line" => ScatterStyleValues::Line,
+            "lineMarker" => ScatterStyleValues::LineMarker,
+            "smooth" => ScatterStyleValues::Smooth,
+            "smoothMarker" => ScatterStyleValues::SmoothMarker,
+            _ => ScatterStyleValues::Marker,
+        }
+    }
+}
+
+/// Scatter chart plotting the y values against the x values
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScatterChart {
+    pub base_chart: BaseChart,
+    pub style: ScatterStyleValues,
+    /// Marker of the styles drawing the points
+    pub marker: ChartMarkerValues,
+    pub series: Vec<ChartSeries>,
+}
+
+impl ChartPlot for ScatterChart {
+    fn get_base_chart(&self) -> &BaseChart {
+        &self.base_chart
+    }
+
+    fn get_series_mut(&mut self) -> Vec<&mut ChartSeries> {
+        self.series.iter_mut().collect()
+    }
+
+    fn get_series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    fn get_axes_layout(&self) -> Option<ChartAxesLayout> {
+        Some(ChartAxesLayout {
+            category_axis_tag: "c:valAx",
+            category_axis_position: "b",
+            value_axis_position: "l",
+            value_format_code: None,
+            cross_between: "midCat",
+        })
+    }
+
+    fn validate(&self) -> AnyResult<(), AnyError> {
+        validate_series(
+            &self.base_chart,
+            &self.series,
+            &[
+                ChartDataLabelPositionValues::Center,
+                ChartDataLabelPositionValues::Left,
+                ChartDataLabelPositionValues::Right,
+                ChartDataLabelPositionValues::Top,
+                ChartDataLabelPositionValues::Bottom,
+            ],
+            true,
+        )?;
+        if self.style == ScatterStyleValues::Marker && self.marker == ChartMarkerValues::None {
+            return Err(anyhow!("Scatter marker style need a marker symbol"));
+        }
+        Ok(())
+    }
+
+    fn append_plot_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        plot_area_id: &usize,
+        axis_ids: &ChartAxisIds,
+        series_offset: usize,
+    ) -> AnyResult<(), AnyError> {
+        let scatter_chart_id = xml_doc_mut
+            .append_child_mut("c:scatterChart", Some(plot_area_id))
+            .context("Failed to insert scatter chart element")?
+            .get_id();
+        append_val_element_mut(
+            xml_doc_mut,
+            Some(&scatter_chart_id),
+            "c:scatterStyle",
+            &ScatterStyleValues::get_string(self.style.clone()),
+        )?;
+        append_val_element_mut(xml_doc_mut, Some(&scatter_chart_id), "c:varyColors", "0")?;
+        // Scatter style is only a hint, the series line and marker decide the drawing
+        let (draw_line, marker, smooth) = match self.style {
+            ScatterStyleValues::Marker => (false, self.marker.clone(), false),
+            ScatterStyleValues::Line => (true, ChartMarkerValues::None, false),
+            ScatterStyleValues::LineMarker => (true, self.marker.clone(), false),
+            ScatterStyleValues::Smooth => (true, ChartMarkerValues::None, true),
+            ScatterStyleValues::SmoothMarker => (true, self.marker.clone(), true),
+        };
+        for (series_index, series) in self.series.iter().enumerate() {
+            let series_id = xml_doc_mut
+                .append_child_mut("c:ser", Some(&scatter_chart_id))
+                .context("Failed to insert series element")?
+                .get_id();
+            append_series_head_mut(
+                xml_doc_mut,
+                &series_id,
+                series_offset + series_index,
+                series,
+            )?;
+            append_series_line_mut(xml_doc_mut, &series_id, &series.color, draw_line)?;
+            append_series_marker_mut(xml_doc_mut, &series_id, &marker, &series.color)?;
+            if let Some(data_label) = &series.data_label {
+                append_data_label_mut(xml_doc_mut, &series_id, data_label)?;
+            }
+            append_trendline_mut(xml_doc_mut, &series_id, &series.trendline)?;
+            append_xy_values_mut(xml_doc_mut, &series_id, series)?;
+            append_val_element_mut(
+                xml_doc_mut,
+                Some(&series_id),
+                "c:smooth",
+                if smooth { "1" } else { "0" },
+            )?;
+        }
+        if let Some(data_label) = &self.base_chart.data_label {
+            append_data_label_mut(xml_doc_mut, &scatter_chart_id, data_label)?;
+        }
+        append_axis_ids_mut(xml_doc_mut, &scatter_chart_id, axis_ids)
+    }
+}
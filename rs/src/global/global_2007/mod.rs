@@ -1,4 +1,4 @@
-pub(crate) mod charts;
+pub mod charts;
 pub(crate) mod models;
 pub(crate) mod parts;
 pub mod traits;
@@ -1,9 +1,12 @@
 use crate::global_2007::traits::Enum;
 
-#[derive(Debug)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum HyperlinkPropertyTypeValues {
     EXISTING_FILE,
+    #[default]
     WEB_URL,
+    MAIL_TO,
     TARGET_SHEET,
     TARGET_SLIDE,
     NEXT_SLIDE,
@@ -12,22 +15,44 @@ pub enum HyperlinkPropertyTypeValues {
     LAST_SLIDE,
 }
 
-// impl Enum<HyperlinkPropertyTypeValues> for HyperlinkPropertyTypeValues {
-//     fn get_string(input_enum: HyperlinkPropertyTypeValues) -> String {
-//         match input_enum {
-//             HyperlinkPropertyTypeValues::Theme => "theme".to_string(),
-//             HyperlinkPropertyTypeValues::Rgb => "rgb".to_string(),
-//             HyperlinkPropertyTypeValues::Indexed => "indexed".to_string(),
-//         }
-//     }
-//     fn get_enum(input_string: &str) -> HyperlinkPropertyTypeValues {
-//         match input_string {
-//             "theme" => HyperlinkPropertyTypeValues::Theme,
-//             "rgb" => HyperlinkPropertyTypeValues::Rgb,
-//             _ => HyperlinkPropertyTypeValues::Indexed,
-//         }
-//     }
-// }
+impl Enum<HyperlinkPropertyTypeValues> for HyperlinkPropertyTypeValues {
+    fn get_string(input_enum: HyperlinkPropertyTypeValues) -> String {
+        match input_enum {
+            HyperlinkPropertyTypeValues::EXISTING_FILE => "existingFile".to_string(),
+            HyperlinkPropertyTypeValues::WEB_URL => "webUrl".to_string(),
+            HyperlinkPropertyTypeValues::MAIL_TO => "mailTo".to_string(),
+            HyperlinkPropertyTypeValues::TARGET_SHEET => "targetSheet".to_string(),
+            HyperlinkPropertyTypeValues::TARGET_SLIDE => "targetSlide".to_string(),
+            HyperlinkPropertyTypeValues::NEXT_SLIDE => "nextSlide".to_string(),
+            HyperlinkPropertyTypeValues::PREVIOUS_SLIDE => "previousSlide".to_string(),
+            HyperlinkPropertyTypeValues::FIRST_SLIDE => "firstSlide".to_string(),
+            HyperlinkPropertyTypeValues::LAST_SLIDE => "lastSlide".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> HyperlinkPropertyTypeValues {
+        match input_string {
+            "existingFile" => HyperlinkPropertyTypeValues::EXISTING_FILE,
+            "mailTo" => HyperlinkPropertyTypeValues::MAIL_TO,
+            "targetSheet" => HyperlinkPropertyTypeValues::TARGET_SHEET,
+            "targetSlide" => HyperlinkPropertyTypeValues::TARGET_SLIDE,
+            "nextSlide" => HyperlinkPropertyTypeValues::NEXT_SLIDE,
+            "previousSlide" => HyperlinkPropertyTypeValues::PREVIOUS_SLIDE,
+            "firstSlide" => HyperlinkPropertyTypeValues::FIRST_SLIDE,
+            "lastSlide" => HyperlinkPropertyTypeValues::LAST_SLIDE,
+            _ => HyperlinkPropertyTypeValues::WEB_URL,
+        }
+    }
+}
 
-#[derive(Debug, Clone)]
-pub struct HyperlinkProperties {}
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HyperlinkProperties {
+    pub hyperlink_type: HyperlinkPropertyTypeValues,
+    /// Url, file path, mail address or target sheet name based on hyperlink type
+    pub value: String,
+    /// Cell reference or defined name inside the target
+    pub cell_location: Option<String>,
+    /// Subject used with mail to link
+    pub email_subject: Option<String>,
+    pub tool_tip: Option<String>,
+    pub display: Option<String>,
+}
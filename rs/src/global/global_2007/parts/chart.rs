@@ -0,0 +1,97 @@
+use crate::{
+    element_dictionary::COMMON_TYPE_COLLECTION,
+    files::{OfficeDocument, XmlDocument, XmlSerializer},
+    global_2007::{charts::ChartTypeValues, traits::XmlDocumentPartCommon},
+    log_elapsed,
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::{cell::RefCell, rc::Weak};
+
+/// Chart space part of the chart, written from the chart setting
+#[derive(Debug)]
+pub(crate) struct ChartPart {
+    office_document: Weak<RefCell<OfficeDocument>>,
+    xml_document: Weak<RefCell<XmlDocument>>,
+    file_path: String,
+}
+
+impl Drop for ChartPart {
+    fn drop(&mut self) {
+        let _ = self.close_document();
+    }
+}
+
+impl XmlDocumentPartCommon for ChartPart {
+    /// Initialize xml content for this part from base template
+    fn initialize_content_xml() -> AnyResult<(XmlDocument, Option<String>, String, String), AnyError>
+    {
+        let content = COMMON_TYPE_COLLECTION.get("chart").unwrap();
+        let template_core_properties = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart"
+    xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+    xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"></c:chartSpace>"#;
+        Ok((
+            XmlSerializer::vec_to_xml_doc_tree(
+                template_core_properties.as_bytes().to_vec(),
+                "Default Chart",
+            )
+            .context("Initializing Chart Failed")?,
+            Some(content.content_type.to_string()),
+            content.extension.to_string(),
+            content.extension_type.to_string(),
+        ))
+    }
+
+    fn close_document(&mut self) -> AnyResult<(), AnyError>
+    where
+        Self: Sized,
+    {
+        log_elapsed!(
+            || {
+                if let Some(office_document) = self.office_document.upgrade() {
+                    office_document
+                        .try_borrow_mut()
+                        .context("Failed to pull office document")?
+                        .close_xml_document(&self.file_path)
+                        .context("Failed to close the chart document")?;
+                }
+                Ok(())
+            },
+            "Close Chart"
+        )
+    }
+}
+
+// ############################# Internal Function ######################################
+impl ChartPart {
+    /// Create the chart part at the file path
+    pub(crate) fn new(
+        office_document: Weak<RefCell<OfficeDocument>>,
+        file_path: &str,
+    ) -> AnyResult<Self, AnyError> {
+        let xml_document = Self::get_xml_document(&office_document, file_path)?;
+        Ok(Self {
+            office_document,
+            xml_document,
+            file_path: file_path.to_string(),
+        })
+    }
+
+    /// Write the chart into the empty chart space
+    pub(crate) fn serialize_chart_mut(
+        &mut self,
+        chart: &ChartTypeValues,
+    ) -> AnyResult<(), AnyError> {
+        let xml_document = self
+            .xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade chart document"))?;
+        let mut xml_doc_mut = xml_document
+            .try_borrow_mut()
+            .context("Failed to get XML doc handle")?;
+        let plot = chart.get_plot();
+        plot.get_base_chart()
+            .serialize_chart_mut(&mut xml_doc_mut, plot)
+            .context(format!("Failed to write chart : {}", self.file_path))
+    }
+}
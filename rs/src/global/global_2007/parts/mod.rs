@@ -1,8 +1,10 @@
+pub(crate) mod chart;
 pub(crate) mod content_types;
 pub(crate) mod core_properties;
 pub(crate) mod relations;
 pub(crate) mod theme;
 
+pub(crate) use chart::*;
 pub(crate) use content_types::*;
 pub(crate) use core_properties::*;
 pub(crate) use relations::*;
@@ -147,20 +147,46 @@ impl RelationsPart {
             .iter()
             .find(|item| item.0 == relationship_id)
         {
-            let file_path = record.1.clone();
-            let relative_path = self
-                .get_relative_path()
-                .context("Get Relative Path for Part File")?;
-            if file_path.starts_with("/") {
-                Ok(Some(file_path.strip_prefix("/").unwrap().to_string()))
-            } else {
-                Ok(Some(format!("{}{}", relative_path, file_path)))
-            }
+            Ok(Some(
+                self.get_target_path(&record.1)
+                    .context("Failed to resolve target path")?,
+            ))
         } else {
             Ok(None)
         }
     }
 
+    /// Package path of the target, relative target like "../media/image1.png" is resolved
+    /// against the folder of the part owning the relation
+    fn get_target_path(&self, target: &str) -> AnyResult<String, AnyError> {
+        Ok(Self::resolve_target_path(
+            &self
+                .get_relative_path()
+                .context("Get Relative Path for Part File")?,
+            target,
+        ))
+    }
+
+    /// Package path of the target stored in the relation part of the folder
+    pub(crate) fn resolve_target_path(relative_path: &str, target: &str) -> String {
+        let file_path = if let Some(file_path) = target.strip_prefix("/") {
+            file_path.to_string()
+        } else {
+            format!("{}{}", relative_path, target)
+        };
+        let mut path_segments: Vec<&str> = Vec::new();
+        for path_segment in file_path.split("/") {
+            match path_segment {
+                "." => {}
+                ".." => {
+                    path_segments.pop();
+                }
+                _ => path_segments.push(path_segment),
+            }
+        }
+        path_segments.join("/")
+    }
+
     /// Get Relation Target based on Type
     /// Note: This will get the first element match the criteria
     pub(crate) fn get_relationship_target_by_type_mut(
@@ -175,15 +201,8 @@ impl RelationsPart {
             .iter()
             .find(|item| item.2 == content_type)
         {
-            let file_path = relationship.1.clone();
-            let relative_path = self
-                .get_relative_path()
-                .context("Get Relative Path for Part File")?;
-            if file_path.starts_with("/") {
-                Ok(file_path.strip_prefix("/").unwrap().to_string())
-            } else {
-                Ok(format!("{}{}", relative_path, file_path))
-            }
+            self.get_target_path(&relationship.1)
+                .context("Failed to resolve target path")
         } else {
             self.set_new_relationship_mut(content, file_path.clone(), file_name.clone())
                 .context("Setting New Theme Relationship Failed.")?;
@@ -196,6 +215,31 @@ impl RelationsPart {
         }
     }
 
+    /// Get the first relationship id of the type without creating new relation
+    pub(crate) fn get_relationship_id_by_type(&self, content_type: &str) -> Option<String> {
+        self.relationships
+            .iter()
+            .find(|item| item.2 == content_type)
+            .map(|item| item.0.clone())
+    }
+
+    /// Get the relationship id pointing the part path without creating new relation
+    pub(crate) fn get_relationship_id_by_target(&self, file_path: &str) -> Option<String> {
+        let target = format!("/{}", file_path.trim_start_matches("/"));
+        self.relationships
+            .iter()
+            .find(|item| item.3.is_none() && item.1 == target)
+            .map(|item| item.0.clone())
+    }
+
+    /// Get target of external relation like hyperlink as it is stored
+    pub(crate) fn get_external_target_by_id(&self, relationship_id: &str) -> Option<String> {
+        self.relationships
+            .iter()
+            .find(|item| item.0 == relationship_id && item.3.as_deref() == Some("External"))
+            .map(|item| item.1.clone())
+    }
+
     /// Generate Next Relationship ID to add
     fn get_next_relationship_id(&self) -> String {
         let mut children = self.relationships.len() + 1;
@@ -270,6 +314,43 @@ impl RelationsPart {
         Ok(next_id)
     }
 
+    /// Create new relation pointing outside the package like hyperlink
+    pub(crate) fn set_new_external_relationship_mut(
+        &mut self,
+        content: &Content,
+        target: &str,
+    ) -> String {
+        let next_id = self.get_next_relationship_id();
+        self.relationships.push((
+            next_id.clone(),
+            target.to_string(),
+            content.schemas_type.to_string(),
+            Some("External".to_string()),
+        ));
+        next_id
+    }
+
+    /// Delete the relation by id
+    pub(crate) fn delete_relationship_by_id_mut(&mut self, relationship_id: &str) {
+        self.relationships.retain(|item| item.0 != relationship_id)
+    }
+
+    /// Remove all the relations. Returns the package path and type of the internal targets,
+    /// relation part without any relation gets deleted on close
+    pub(crate) fn take_relationships_mut(&mut self) -> AnyResult<Vec<(String, String)>, AnyError> {
+        let mut targets = Vec::new();
+        for (_, target, relationship_type, target_mode) in std::mem::take(&mut self.relationships) {
+            if target_mode.is_none() {
+                targets.push((
+                    self.get_target_path(&target)
+                        .context("Failed to resolve target path")?,
+                    relationship_type,
+                ));
+            }
+        }
+        Ok(targets)
+    }
+
     /// Delete the target file path
     pub(crate) fn delete_relationship_mut(&mut self, file_path: &str) {
         self.relationships.retain(|item| {
@@ -42,6 +42,16 @@ pub(crate) static COMMON_TYPE_COLLECTION: Map<&'static str, &'static Content> =
         default_path:".",
         default_name:""
     },
+    "hyperlink"=>&Content{
+        schemas_namespace:"",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink",
+        alias:"",
+        content_type:"",
+        extension:"",
+        extension_type:"",
+        default_path:"",
+        default_name:""
+    },
     "docProps_core"=>&Content{
         schemas_namespace:"http://schemas.openxmlformats.org/package/2006/metadata/core-properties",
         schemas_type:"http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties",
@@ -52,6 +62,56 @@ pub(crate) static COMMON_TYPE_COLLECTION: Map<&'static str, &'static Content> =
         default_path:"docProps",
         default_name:"core"
     },
+    "image_png"=>&Content{
+        schemas_namespace:"",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+        alias:"",
+        content_type:"",
+        extension:"png",
+        extension_type:"image/png",
+        default_path:"media",
+        default_name:"image"
+    },
+    "image_jpeg"=>&Content{
+        schemas_namespace:"",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+        alias:"",
+        content_type:"",
+        extension:"jpeg",
+        extension_type:"image/jpeg",
+        default_path:"media",
+        default_name:"image"
+    },
+    "image_gif"=>&Content{
+        schemas_namespace:"",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+        alias:"",
+        content_type:"",
+        extension:"gif",
+        extension_type:"image/gif",
+        default_path:"media",
+        default_name:"image"
+    },
+    "image_svg"=>&Content{
+        schemas_namespace:"",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+        alias:"",
+        content_type:"",
+        extension:"svg",
+        extension_type:"image/svg+xml",
+        default_path:"media",
+        default_name:"image"
+    },
+    "chart"=>&Content{
+        schemas_namespace:"http://schemas.openxmlformats.org/drawingml/2006/chart",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
+        alias:"c",
+        content_type:"application/vnd.openxmlformats-officedocument.drawingml.chart+xml",
+        extension:"xml",
+        extension_type:"application/xml",
+        default_path:"charts",
+        default_name:"chart"
+    },
     // TODO Move to Drawing
     "theme"=>&Content{
         schemas_namespace:"http://schemas.openxmlformats.org/drawingml/2006/main",
@@ -116,6 +176,56 @@ pub(crate) static EXCEL_TYPE_COLLECTION: Map<&'static str, &'static Content> = p
         default_path:"tables",
         default_name:"table1"
     },
+    "comments"=>&Content{
+        schemas_namespace:"http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments",
+        alias:"x",
+        content_type:"application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml",
+        extension:"xml",
+        extension_type:"application/xml",
+        default_path:"",
+        default_name:"comments"
+    },
+    "vml_drawing"=>&Content{
+        schemas_namespace:"urn:schemas-microsoft-com:vml",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/vmlDrawing",
+        alias:"v",
+        content_type:"",
+        extension:"vml",
+        extension_type:"application/vnd.openxmlformats-officedocument.vmlDrawing",
+        default_path:"drawings",
+        default_name:"vmlDrawing"
+    },
+    "drawing"=>&Content{
+        schemas_namespace:"http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
+        schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/drawing",
+        alias:"xdr",
+        content_type:"application/vnd.openxmlformats-officedocument.drawing+xml",
+        extension:"xml",
+        extension_type:"application/xml",
+        default_path:"drawings",
+        default_name:"drawing"
+    },
+    "threaded_comment"=>&Content{
+        schemas_namespace:"http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+        schemas_type:"http://schemas.microsoft.com/office/2017/10/relationships/threadedComment",
+        alias:"",
+        content_type:"application/vnd.ms-excel.threadedcomments+xml",
+        extension:"xml",
+        extension_type:"application/xml",
+        default_path:"threadedComments",
+        default_name:"threadedComment"
+    },
+    "person"=>&Content{
+        schemas_namespace:"http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+        schemas_type:"http://schemas.microsoft.com/office/2017/10/relationships/person",
+        alias:"",
+        content_type:"application/vnd.ms-excel.person+xml",
+        extension:"xml",
+        extension_type:"application/xml",
+        default_path:"xl/persons",
+        default_name:"person"
+    },
     "worksheet"=>&Content{
         schemas_namespace:"http://schemas.openxmlformats.org/spreadsheetml/2006/main",
         schemas_type:"http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet",
@@ -6,7 +6,11 @@ use crate::{
     },
 };
 use anyhow::{Context, Error as AnyError, Result as AnyResult};
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    io::{Cursor, Read, Seek, Write},
+    rc::Rc,
+};
 
 #[derive(Debug)]
 pub struct PowerPoint {
@@ -30,10 +34,62 @@ impl PowerPoint {
         file_name: Option<String>,
         power_point_setting: PowerPointPropertiesModel,
     ) -> AnyResult<Self, AnyError> {
-        let office_document = Rc::new(RefCell::new(
+        let office_document =
             OfficeDocument::new(file_name.clone(), power_point_setting.is_in_memory)
-                .context("Creating Office Document Struct Failed")?,
-        ));
+                .context("Creating Office Document Struct Failed")?;
+        Self::initialize_document(office_document)
+    }
+
+    /// Clone source content from any seekable reader to start working on Power Point
+    pub fn from_reader<R: Read + Seek>(
+        reader: R,
+        power_point_setting: PowerPointPropertiesModel,
+    ) -> AnyResult<Self, AnyError> {
+        let office_document =
+            OfficeDocument::new_from_reader(Some(reader), power_point_setting.is_in_memory)
+                .context("Creating Office Document Struct Failed")?;
+        Self::initialize_document(office_document)
+    }
+
+    /// Clone source content from in memory buffer to start working on Power Point
+    pub fn from_bytes(
+        file_content: &[u8],
+        power_point_setting: PowerPointPropertiesModel,
+    ) -> AnyResult<Self, AnyError> {
+        Self::from_reader(Cursor::new(file_content), power_point_setting)
+    }
+
+    /// Save/Replace the current file into target destination
+    pub fn save_as(self, file_name: &str) -> AnyResult<(), AnyError> {
+        self.close_parts()?
+            .try_borrow_mut()
+            .context("Save Office Document handle Failed")?
+            .save_as(file_name)
+            .context("File Save Failed for the target path.")
+    }
+
+    /// Save the current file into the writer and return the writer back
+    pub fn save_to_writer<W: Write + Seek>(self, writer: W) -> AnyResult<W, AnyError> {
+        self.close_parts()?
+            .try_borrow_mut()
+            .context("Save Office Document handle Failed")?
+            .save_to_writer(writer)
+            .context("File Save Failed for the target writer.")
+    }
+
+    /// Save the current file into in memory buffer
+    pub fn save_to_vec(self) -> AnyResult<Vec<u8>, AnyError> {
+        Ok(self
+            .save_to_writer(Cursor::new(Vec::new()))
+            .context("File Save Failed for the memory buffer.")?
+            .into_inner())
+    }
+}
+
+// ############################# Internal Function ######################################
+impl PowerPoint {
+    fn initialize_document(office_document: OfficeDocument) -> AnyResult<Self, AnyError> {
+        let office_document = Rc::new(RefCell::new(office_document));
         let root_relations = Rc::new(RefCell::new(
             RelationsPart::new(Rc::downgrade(&office_document), "_rels/.rels")
                 .context("Initialize Root Relation Part failed")?,
@@ -50,17 +106,13 @@ impl PowerPoint {
         })
     }
 
-    /// Save/Replace the current file into target destination
-    pub fn save_as(self, file_name: &str) -> AnyResult<(), AnyError> {
+    /// Flush all the open parts and return the document handle ready to be saved
+    fn close_parts(self) -> AnyResult<Rc<RefCell<OfficeDocument>>, AnyError> {
         self.core_properties.flush()?;
         self.root_relations
             .try_borrow_mut()
             .context("Failed To Pull Relation Handle")?
             .close_document()?;
-        self.office_document
-            .try_borrow_mut()
-            .context("Save Office Document handle Failed")?
-            .save_as(file_name)
-            .context("File Save Failed for the target path.")
+        Ok(self.office_document)
     }
 }
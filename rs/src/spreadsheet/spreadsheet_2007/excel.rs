@@ -6,12 +6,18 @@ use crate::{
     },
     log_elapsed,
     spreadsheet_2007::{
-        models::{StyleId, StyleSetting},
+        models::{
+            DefinedName, FormulaCalculationReport, StyleId, StyleSetting, WorkbookProtection,
+        },
         parts::{WorkSheet, WorkbookPart},
     },
 };
 use anyhow::{Context, Error as AnyError, Result as AnyResult};
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    io::{Cursor, Read, Seek, Write},
+    rc::Rc,
+};
 
 #[derive(Debug)]
 pub struct Excel {
@@ -45,37 +51,10 @@ impl Excel {
     ) -> AnyResult<Self, AnyError> {
         log_elapsed!(
             || {
-                let office_document = Rc::new(RefCell::new(
+                let office_document =
                     OfficeDocument::new(file_name.clone(), excel_setting.is_in_memory)
-                        .context("Creating Office Document Struct Failed")?,
-                ));
-                let root_relations = Rc::new(RefCell::new(
-                    RelationsPart::new(Rc::downgrade(&office_document), "_rels/.rels")
-                        .context("Initialize Root Relation Part failed")?,
-                ));
-                // Load relevant parts from root relations part
-                let core_properties = CorePropertiesPart::new(
-                    Rc::downgrade(&office_document),
-                    Rc::downgrade(&root_relations),
-                )
-                .context("Creating Core Property Part Failed.")?;
-                let workbook = WorkbookPart::new(
-                    Rc::downgrade(&office_document),
-                    Rc::downgrade(&root_relations),
-                )
-                .context("Creating Workbook part Failed")?;
-                let mut excel = Self {
-                    office_document,
-                    root_relations,
-                    core_properties,
-                    workbook,
-                };
-                if file_name.is_none() {
-                    excel
-                        .add_sheet_mut(None)
-                        .context("Failed To Add Default Sheet to excel")?;
-                }
-                Ok(excel)
+                        .context("Creating Office Document Struct Failed")?;
+                Self::initialize_document(office_document, file_name.is_none())
             },
             if file_name.is_some() {
                 "New Excel Edit File Object"
@@ -85,6 +64,30 @@ impl Excel {
         )
     }
 
+    /// Clone source content from any seekable reader to start working on Excel
+    pub fn from_reader<R: Read + Seek>(
+        reader: R,
+        excel_setting: ExcelPropertiesModel,
+    ) -> AnyResult<Self, AnyError> {
+        log_elapsed!(
+            || {
+                let office_document =
+                    OfficeDocument::new_from_reader(Some(reader), excel_setting.is_in_memory)
+                        .context("Creating Office Document Struct Failed")?;
+                Self::initialize_document(office_document, false)
+            },
+            "New Excel Edit Reader Object"
+        )
+    }
+
+    /// Clone source content from in memory buffer to start working on Excel
+    pub fn from_bytes(
+        file_content: &[u8],
+        excel_setting: ExcelPropertiesModel,
+    ) -> AnyResult<Self, AnyError> {
+        Self::from_reader(Cursor::new(file_content), excel_setting)
+    }
+
     /// Add sheet to the current excel
     pub fn add_sheet_mut(&mut self, sheet_name: Option<String>) -> AnyResult<WorkSheet, AnyError> {
         log_elapsed!(self.get_workbook_mut().add_sheet_mut(sheet_name))
@@ -150,17 +153,79 @@ impl Excel {
         log_elapsed!(self.get_workbook_mut().get_style_id_mut(style_setting))
     }
 
+    /// Add named range or named formula, sheet name of the model set the scope
+    pub fn add_defined_name_mut(&mut self, defined_name: DefinedName) -> AnyResult<(), AnyError> {
+        self.get_workbook_mut().add_defined_name_mut(defined_name)
+    }
+
+    /// Replace the defined name found by name and scope
+    pub fn update_defined_name_mut(
+        &mut self,
+        name: String,
+        sheet_name: Option<String>,
+        defined_name: DefinedName,
+    ) -> AnyResult<(), AnyError> {
+        self.get_workbook_mut()
+            .update_defined_name_mut(&name, sheet_name.as_deref(), defined_name)
+    }
+
+    pub fn remove_defined_name_mut(
+        &mut self,
+        name: String,
+        sheet_name: Option<String>,
+    ) -> AnyResult<(), AnyError> {
+        self.get_workbook_mut()
+            .remove_defined_name_mut(&name, sheet_name.as_deref())
+    }
+
+    /// Set print area of the sheet like "A1:D20", multiple area are space separated.
+    /// None remove the print area
+    pub fn set_print_area_mut(
+        &mut self,
+        sheet_name: String,
+        range_ref: Option<String>,
+    ) -> AnyResult<(), AnyError> {
+        self.get_workbook_mut()
+            .set_print_area_mut(&sheet_name, range_ref.as_deref())
+    }
+
+    /// Set (start, end) rows and columns repeated on each printed page.
+    /// None on both remove the print titles
+    pub fn set_print_titles_mut(
+        &mut self,
+        sheet_name: String,
+        row_range: Option<(u32, u32)>,
+        column_range: Option<(u16, u16)>,
+    ) -> AnyResult<(), AnyError> {
+        self.get_workbook_mut()
+            .set_print_titles_mut(&sheet_name, row_range, column_range)
+    }
+
+    /// Protect the workbook structure and windows, replace existing protection
+    pub fn protect_workbook_mut(
+        &mut self,
+        workbook_protection: WorkbookProtection,
+    ) -> AnyResult<(), AnyError> {
+        self.get_workbook_mut()
+            .protect_workbook_mut(workbook_protection)
+    }
+
+    /// Remove the workbook protection
+    pub fn unprotect_workbook_mut(&mut self) -> AnyResult<(), AnyError> {
+        self.get_workbook_mut().unprotect_workbook_mut()
+    }
+
+    /// Calculate the formula cells of all the sheets and store the results as cached values.
+    /// Drop the open worksheet handles first, unsupported formulas keep their old value
+    pub fn calculate_formulas_mut(&mut self) -> AnyResult<FormulaCalculationReport, AnyError> {
+        log_elapsed!(self.get_workbook_mut().calculate_formulas_mut())
+    }
+
     /// Save/Replace the current file into target destination
     pub fn save_as(self, file_name: &str) -> AnyResult<(), AnyError> {
         log_elapsed!(
             || {
-                self.workbook.flush()?;
-                self.core_properties.flush()?;
-                self.root_relations
-                    .try_borrow_mut()
-                    .context("Failed To Pull Relation Handle")?
-                    .close_document()?;
-                self.office_document
+                self.close_parts()?
                     .try_borrow_mut()
                     .context("Save Office Document handle Failed")?
                     .save_as(file_name)
@@ -170,11 +235,78 @@ impl Excel {
             "File Save"
         )
     }
+
+    /// Save the current file into the writer and return the writer back
+    pub fn save_to_writer<W: Write + Seek>(self, writer: W) -> AnyResult<W, AnyError> {
+        log_elapsed!(
+            || {
+                self.close_parts()?
+                    .try_borrow_mut()
+                    .context("Save Office Document handle Failed")?
+                    .save_to_writer(writer)
+                    .context("File Save Failed for the target writer.")
+            },
+            "Writer Save"
+        )
+    }
+
+    /// Save the current file into in memory buffer
+    pub fn save_to_vec(self) -> AnyResult<Vec<u8>, AnyError> {
+        Ok(self
+            .save_to_writer(Cursor::new(Vec::new()))
+            .context("File Save Failed for the memory buffer.")?
+            .into_inner())
+    }
 }
 
 // ############################# Internal Function ######################################
 // ############################# Mut Function      ######################################
 impl Excel {
+    fn initialize_document(
+        office_document: OfficeDocument,
+        is_blank: bool,
+    ) -> AnyResult<Self, AnyError> {
+        let office_document = Rc::new(RefCell::new(office_document));
+        let root_relations = Rc::new(RefCell::new(
+            RelationsPart::new(Rc::downgrade(&office_document), "_rels/.rels")
+                .context("Initialize Root Relation Part failed")?,
+        ));
+        // Load relevant parts from root relations part
+        let core_properties = CorePropertiesPart::new(
+            Rc::downgrade(&office_document),
+            Rc::downgrade(&root_relations),
+        )
+        .context("Creating Core Property Part Failed.")?;
+        let workbook = WorkbookPart::new(
+            Rc::downgrade(&office_document),
+            Rc::downgrade(&root_relations),
+        )
+        .context("Creating Workbook part Failed")?;
+        let mut excel = Self {
+            office_document,
+            root_relations,
+            core_properties,
+            workbook,
+        };
+        if is_blank {
+            excel
+                .add_sheet_mut(None)
+                .context("Failed To Add Default Sheet to excel")?;
+        }
+        Ok(excel)
+    }
+
+    /// Flush all the open parts and return the document handle ready to be saved
+    fn close_parts(self) -> AnyResult<Rc<RefCell<OfficeDocument>>, AnyError> {
+        self.workbook.flush()?;
+        self.core_properties.flush()?;
+        self.root_relations
+            .try_borrow_mut()
+            .context("Failed To Pull Relation Handle")?
+            .close_document()?;
+        Ok(self.office_document)
+    }
+
     fn get_workbook_mut(&mut self) -> &mut WorkbookPart {
         &mut self.workbook
     }
@@ -188,4 +320,14 @@ impl Excel {
     pub fn list_sheet_names(&self) -> AnyResult<Vec<String>, AnyError> {
         self.get_workbook().list_sheet_names()
     }
+
+    /// List named range and named formula including the built in names
+    pub fn list_defined_names(&self) -> AnyResult<Vec<DefinedName>, AnyError> {
+        self.get_workbook().list_defined_names()
+    }
+
+    /// Get workbook protection, password is never returned
+    pub fn get_workbook_protection(&self) -> Option<WorkbookProtection> {
+        self.get_workbook().get_workbook_protection()
+    }
 }
@@ -0,0 +1,206 @@
+use crate::global_2007::traits::Enum;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterOperatorValues {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl Enum<FilterOperatorValues> for FilterOperatorValues {
+    fn get_string(input_enum: FilterOperatorValues) -> String {
+        match input_enum {
+            FilterOperatorValues::Equal => "equal".to_string(),
+            FilterOperatorValues::NotEqual => "notEqual".to_string(),
+            FilterOperatorValues::LessThan => "lessThan".to_string(),
+            FilterOperatorValues::LessThanOrEqual => "lessThanOrEqual".to_string(),
+            FilterOperatorValues::GreaterThan => "greaterThan".to_string(),
+            FilterOperatorValues::GreaterThanOrEqual => "greaterThanOrEqual".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> FilterOperatorValues {
+        match input_string {
+            "notEqual" => FilterOperatorValues::NotEqual,
+            "lessThan" => FilterOperatorValues::LessThan,
+            "lessThanOrEqual" => FilterOperatorValues::LessThanOrEqual,
+            "greaterThan" => FilterOperatorValues::GreaterThan,
+            "greaterThanOrEqual" => FilterOperatorValues::GreaterThanOrEqual,
+            _ => FilterOperatorValues::Equal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynamicFilterTypeValues {
+    AboveAverage,
+    BelowAverage,
+    Tomorrow,
+    Today,
+    Yesterday,
+    NextWeek,
+    ThisWeek,
+    LastWeek,
+    NextMonth,
+    ThisMonth,
+    LastMonth,
+    NextQuarter,
+    ThisQuarter,
+    LastQuarter,
+    NextYear,
+    ThisYear,
+    LastYear,
+    YearToDate,
+    /// Quarter of any year, value 1 to 4
+    Quarter(u8),
+    /// Month of any year, value 1 to 12
+    Month(u8),
+}
+
+impl Enum<DynamicFilterTypeValues> for DynamicFilterTypeValues {
+    fn get_string(input_enum: DynamicFilterTypeValues) -> String {
+        match input_enum {
+            DynamicFilterTypeValues::AboveAverage => "aboveAverage".to_string(),
+            DynamicFilterTypeValues::BelowAverage => "belowAverage".to_string(),
+            DynamicFilterTypeValues::Tomorrow => "tomorrow".to_string(),
+            DynamicFilterTypeValues::Today => "today".to_string(),
+            DynamicFilterTypeValues::Yesterday => "yesterday".to_string(),
+            DynamicFilterTypeValues::NextWeek => "nextWeek".to_string(),
+            DynamicFilterTypeValues::ThisWeek => "thisWeek".to_string(),
+            DynamicFilterTypeValues::LastWeek => "lastWeek".to_string(),
+            DynamicFilterTypeValues::NextMonth => "nextMonth".to_string(),
+            DynamicFilterTypeValues::ThisMonth => "thisMonth".to_string(),
+            DynamicFilterTypeValues::LastMonth => "lastMonth".to_string(),
+            DynamicFilterTypeValues::NextQuarter => "nextQuarter".to_string(),
+            DynamicFilterTypeValues::ThisQuarter => "thisQuarter".to_string(),
+            DynamicFilterTypeValues::LastQuarter => "lastQuarter".to_string(),
+            DynamicFilterTypeValues::NextYear => "nextYear".to_string(),
+            DynamicFilterTypeValues::ThisYear => "thisYear".to_string(),
+            DynamicFilterTypeValues::LastYear => "lastYear".to_string(),
+            DynamicFilterTypeValues::YearToDate => "yearToDate".to_string(),
+            DynamicFilterTypeValues::Quarter(quarter) => format!("Q{}", quarter),
+            DynamicFilterTypeValues::Month(month) => format!("M{}", month),
+        }
+    }
+    fn get_enum(input_string: &str) -> DynamicFilterTypeValues {
+        match input_string {
+            "belowAverage" => DynamicFilterTypeValues::BelowAverage,
+            "tomorrow" => DynamicFilterTypeValues::Tomorrow,
+            "today" => DynamicFilterTypeValues::Today,
+            "yesterday" => DynamicFilterTypeValues::Yesterday,
+            "nextWeek" => DynamicFilterTypeValues::NextWeek,
+            "thisWeek" => DynamicFilterTypeValues::ThisWeek,
+            "lastWeek" => DynamicFilterTypeValues::LastWeek,
+            "nextMonth" => DynamicFilterTypeValues::NextMonth,
+            "thisMonth" => DynamicFilterTypeValues::ThisMonth,
+            "lastMonth" => DynamicFilterTypeValues::LastMonth,
+            "nextQuarter" => DynamicFilterTypeValues::NextQuarter,
+            "thisQuarter" => DynamicFilterTypeValues::ThisQuarter,
+            "lastQuarter" => DynamicFilterTypeValues::LastQuarter,
+            "nextYear" => DynamicFilterTypeValues::NextYear,
+            "thisYear" => DynamicFilterTypeValues::ThisYear,
+            "lastYear" => DynamicFilterTypeValues::LastYear,
+            "yearToDate" => DynamicFilterTypeValues::YearToDate,
+            _ => {
+                if let Some(quarter) = input_string
+                    .strip_prefix("Q")
+                    .and_then(|quarter| quarter.parse().ok())
+                {
+                    DynamicFilterTypeValues::Quarter(quarter)
+                } else if let Some(month) = input_string
+                    .strip_prefix("M")
+                    .and_then(|month| month.parse().ok())
+                {
+                    DynamicFilterTypeValues::Month(month)
+                } else {
+                    DynamicFilterTypeValues::AboveAverage
+                }
+            }
+        }
+    }
+}
+
+/// Single comparison of custom filter, text value can use * and ? wildcard
+#[derive(Debug, Clone)]
+pub struct CustomFilter {
+    pub operator: FilterOperatorValues,
+    pub value: String,
+}
+
+impl CustomFilter {
+    pub fn new(operator: FilterOperatorValues, value: &str) -> Self {
+        Self {
+            operator,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Criteria applied on the filter column
+#[derive(Debug, Clone)]
+pub enum FilterCriteriaValues {
+    /// Show rows matching any of the cell text
+    Values {
+        values: Vec<String>,
+        include_blank: bool,
+    },
+    /// One or two comparison joined with and / or
+    Custom {
+        filters: Vec<CustomFilter>,
+        match_all: bool,
+    },
+    /// Top or bottom items, value is item count or percent
+    Top10 {
+        value: f64,
+        is_bottom: bool,
+        is_percent: bool,
+    },
+    /// Date or average based filter Excel evaluate on open
+    Dynamic(DynamicFilterTypeValues),
+}
+
+#[derive(Debug, Clone)]
+pub struct FilterColumn {
+    /// Zero based column offset from the start of auto filter range
+    pub column_index: u16,
+    pub criteria: Option<FilterCriteriaValues>,
+    /// Hide the dropdown button of the column header
+    pub hide_button: bool,
+}
+
+impl FilterColumn {
+    pub fn new(column_index: u16, criteria: FilterCriteriaValues) -> Self {
+        Self {
+            column_index,
+            criteria: Some(criteria),
+            hide_button: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SortCondition {
+    /// Range of the sort key column like "B2:B10"
+    pub range_ref: String,
+    pub descending: bool,
+}
+
+/// Sort applied on the data rows, range exclude header row like "A2:D10"
+#[derive(Debug, Clone)]
+pub struct SortState {
+    pub range_ref: String,
+    pub conditions: Vec<SortCondition>,
+    pub case_sensitive: bool,
+}
+
+/// Filter buttons of the header row. Row visibility is not evaluated,
+/// Excel apply the criteria when the filter get reapplied
+#[derive(Debug, Clone)]
+pub struct AutoFilter {
+    /// Range including header row like "A1:D10"
+    pub range_ref: String,
+    pub filter_columns: Vec<FilterColumn>,
+    pub sort_state: Option<SortState>,
+}
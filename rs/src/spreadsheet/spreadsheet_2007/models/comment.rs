@@ -0,0 +1,77 @@
+use crate::spreadsheet_2007::models::RichTextRun;
+
+/// Note attached to the cell, shown in the box of the legacy drawing on hover
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub author: String,
+    /// Formatted runs of the note text
+    pub text: Vec<RichTextRun>,
+    /// Width of the note box in points
+    pub width: f64,
+    /// Height of the note box in points
+    pub height: f64,
+    /// Keep the note box shown instead of on hover
+    pub visible: bool,
+}
+
+impl Comment {
+    /// Plain text note of the author with the Excel default box size
+    pub fn new(author: &str, text: &str) -> Self {
+        Self {
+            author: author.to_string(),
+            text: vec![RichTextRun {
+                text: text.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    /// Text of the note without formatting
+    pub fn get_text(&self) -> String {
+        self.text
+            .iter()
+            .map(|rich_text_run| rich_text_run.text.as_str())
+            .collect()
+    }
+}
+
+impl Default for Comment {
+    fn default() -> Self {
+        Self {
+            author: String::new(),
+            text: Vec::new(),
+            width: 108.0,
+            height: 59.25,
+            visible: false,
+        }
+    }
+}
+
+/// Reply of the threaded comment
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadedCommentReply {
+    pub author: String,
+    pub text: String,
+}
+
+/// Modern threaded comment of the cell, older Excel versions show it as read only note
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadedComment {
+    pub author: String,
+    pub text: String,
+    /// Thread marked as resolved
+    pub done: bool,
+    pub replies: Vec<ThreadedCommentReply>,
+}
+
+impl ThreadedComment {
+    pub fn new(author: &str, text: &str) -> Self {
+        Self {
+            author: author.to_string(),
+            text: text.to_string(),
+            done: false,
+            replies: Vec::new(),
+        }
+    }
+}
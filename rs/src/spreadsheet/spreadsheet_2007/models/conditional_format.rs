@@ -0,0 +1,255 @@
+use crate::global_2007::traits::Enum;
+use crate::spreadsheet_2007::models::{ColorSetting, DifferentialStyleSetting};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionalFormatOperatorValues {
+    LessThan,
+    LessThanOrEqual,
+    Equal,
+    NotEqual,
+    GreaterThanOrEqual,
+    GreaterThan,
+    Between,
+    NotBetween,
+}
+
+impl Enum<ConditionalFormatOperatorValues> for ConditionalFormatOperatorValues {
+    fn get_string(input_enum: ConditionalFormatOperatorValues) -> String {
+        match input_enum {
+            ConditionalFormatOperatorValues::LessThan => "lessThan".to_string(),
+            ConditionalFormatOperatorValues::LessThanOrEqual => "lessThanOrEqual".to_string(),
+            ConditionalFormatOperatorValues::Equal => "equal".to_string(),
+            ConditionalFormatOperatorValues::NotEqual => "notEqual".to_string(),
+            ConditionalFormatOperatorValues::GreaterThanOrEqual => "greaterThanOrEqual".to_string(),
+            ConditionalFormatOperatorValues::GreaterThan => "greaterThan".to_string(),
+            ConditionalFormatOperatorValues::Between => "between".to_string(),
+            ConditionalFormatOperatorValues::NotBetween => "notBetween".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> ConditionalFormatOperatorValues {
+        match input_string {
+            "lessThan" => ConditionalFormatOperatorValues::LessThan,
+            "lessThanOrEqual" => ConditionalFormatOperatorValues::LessThanOrEqual,
+            "notEqual" => ConditionalFormatOperatorValues::NotEqual,
+            "greaterThanOrEqual" => ConditionalFormatOperatorValues::GreaterThanOrEqual,
+            "greaterThan" => ConditionalFormatOperatorValues::GreaterThan,
+            "between" => ConditionalFormatOperatorValues::Between,
+            "notBetween" => ConditionalFormatOperatorValues::NotBetween,
+            _ => ConditionalFormatOperatorValues::Equal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionalFormatValueTypeValues {
+    Number,
+    Percent,
+    Percentile,
+    Formula,
+    Min,
+    Max,
+}
+
+impl Enum<ConditionalFormatValueTypeValues> for ConditionalFormatValueTypeValues {
+    fn get_string(input_enum: ConditionalFormatValueTypeValues) -> String {
+        match input_enum {
+            ConditionalFormatValueTypeValues::Number => "num".to_string(),
+            ConditionalFormatValueTypeValues::Percent => "percent".to_string(),
+            ConditionalFormatValueTypeValues::Percentile => "percentile".to_string(),
+            ConditionalFormatValueTypeValues::Formula => "formula".to_string(),
+            ConditionalFormatValueTypeValues::Min => "min".to_string(),
+            ConditionalFormatValueTypeValues::Max => "max".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> ConditionalFormatValueTypeValues {
+        match input_string {
+            "percent" => ConditionalFormatValueTypeValues::Percent,
+            "percentile" => ConditionalFormatValueTypeValues::Percentile,
+            "formula" => ConditionalFormatValueTypeValues::Formula,
+            "min" => ConditionalFormatValueTypeValues::Min,
+            "max" => ConditionalFormatValueTypeValues::Max,
+            _ => ConditionalFormatValueTypeValues::Number,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IconSetValues {
+    ThreeArrows,
+    ThreeArrowsGray,
+    ThreeFlags,
+    ThreeTrafficLights,
+    ThreeTrafficLightsRimmed,
+    ThreeSigns,
+    ThreeSymbols,
+    ThreeSymbolsUncircled,
+    FourArrows,
+    FourArrowsGray,
+    FourRedToBlack,
+    FourRating,
+    FourTrafficLights,
+    FiveArrows,
+    FiveArrowsGray,
+    FiveRating,
+    FiveQuarters,
+}
+
+impl Enum<IconSetValues> for IconSetValues {
+    fn get_string(input_enum: IconSetValues) -> String {
+        match input_enum {
+            IconSetValues::ThreeArrows => "3Arrows".to_string(),
+            IconSetValues::ThreeArrowsGray => "3ArrowsGray".to_string(),
+            IconSetValues::ThreeFlags => "3Flags".to_string(),
+            IconSetValues::ThreeTrafficLights => "3TrafficLights1".to_string(),
+            IconSetValues::ThreeTrafficLightsRimmed => "3TrafficLights2".to_string(),
+            IconSetValues::ThreeSigns => "3Signs".to_string(),
+            IconSetValues::ThreeSymbols => "3Symbols".to_string(),
+            IconSetValues::ThreeSymbolsUncircled => "3Symbols2".to_string(),
+            IconSetValues::FourArrows => "4Arrows".to_string(),
+            IconSetValues::FourArrowsGray => "4ArrowsGray".to_string(),
+            IconSetValues::FourRedToBlack => "4RedToBlack".to_string(),
+            IconSetValues::FourRating => "4Rating".to_string(),
+            IconSetValues::FourTrafficLights => "4TrafficLights".to_string(),
+            IconSetValues::FiveArrows => "5Arrows".to_string(),
+            IconSetValues::FiveArrowsGray => "5ArrowsGray".to_string(),
+            IconSetValues::FiveRating => "5Rating".to_string(),
+            IconSetValues::FiveQuarters => "5Quarters".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> IconSetValues {
+        match input_string {
+            "3ArrowsGray" => IconSetValues::ThreeArrowsGray,
+            "3Flags" => IconSetValues::ThreeFlags,
+            "3TrafficLights1" => IconSetValues::ThreeTrafficLights,
+            "3TrafficLights2" => IconSetValues::ThreeTrafficLightsRimmed,
+            "3Signs" => IconSetValues::ThreeSigns,
+            "3Symbols" => IconSetValues::ThreeSymbols,
+            "3Symbols2" => IconSetValues::ThreeSymbolsUncircled,
+            "4Arrows" => IconSetValues::FourArrows,
+            "4ArrowsGray" => IconSetValues::FourArrowsGray,
+            "4RedToBlack" => IconSetValues::FourRedToBlack,
+            "4Rating" => IconSetValues::FourRating,
+            "4TrafficLights" => IconSetValues::FourTrafficLights,
+            "5Arrows" => IconSetValues::FiveArrows,
+            "5ArrowsGray" => IconSetValues::FiveArrowsGray,
+            "5Rating" => IconSetValues::FiveRating,
+            "5Quarters" => IconSetValues::FiveQuarters,
+            _ => IconSetValues::ThreeArrows,
+        }
+    }
+}
+
+impl IconSetValues {
+    /// Number of icon in the set, each icon needs a threshold value
+    pub fn get_icon_count(&self) -> usize {
+        match self {
+            IconSetValues::ThreeArrows
+            | IconSetValues::ThreeArrowsGray
+            | IconSetValues::ThreeFlags
+            | IconSetValues::ThreeTrafficLights
+            | IconSetValues::ThreeTrafficLightsRimmed
+            | IconSetValues::ThreeSigns
+            | IconSetValues::ThreeSymbols
+            | IconSetValues::ThreeSymbolsUncircled => 3,
+            IconSetValues::FourArrows
+            | IconSetValues::FourArrowsGray
+            | IconSetValues::FourRedToBlack
+            | IconSetValues::FourRating
+            | IconSetValues::FourTrafficLights => 4,
+            IconSetValues::FiveArrows
+            | IconSetValues::FiveArrowsGray
+            | IconSetValues::FiveRating
+            | IconSetValues::FiveQuarters => 5,
+        }
+    }
+}
+
+/// Threshold point used by color scale, data bar and icon set rule
+#[derive(Debug, Clone)]
+pub struct ConditionalFormatValue {
+    pub value_type: ConditionalFormatValueTypeValues,
+    /// Required except for Min and Max type
+    pub value: Option<String>,
+}
+
+impl ConditionalFormatValue {
+    pub fn min() -> Self {
+        Self {
+            value_type: ConditionalFormatValueTypeValues::Min,
+            value: None,
+        }
+    }
+
+    pub fn max() -> Self {
+        Self {
+            value_type: ConditionalFormatValueTypeValues::Max,
+            value: None,
+        }
+    }
+
+    pub fn new(value_type: ConditionalFormatValueTypeValues, value: &str) -> Self {
+        Self {
+            value_type,
+            value: Some(value.to_string()),
+        }
+    }
+}
+
+/// Condition of the rule. Formula are written without leading "="
+#[derive(Debug, Clone)]
+pub enum ConditionalFormatRuleValues {
+    /// Compare cell value, Between and NotBetween need two formula
+    CellValue {
+        operator: ConditionalFormatOperatorValues,
+        formulas: Vec<String>,
+    },
+    /// Apply when the formula evaluate to true for the top left cell of range
+    Expression {
+        formula: String,
+    },
+    /// Two or three point color gradient
+    ColorScale {
+        values: Vec<ConditionalFormatValue>,
+        colors: Vec<ColorSetting>,
+    },
+    DataBar {
+        min_value: ConditionalFormatValue,
+        max_value: ConditionalFormatValue,
+        color: ColorSetting,
+        show_value: bool,
+    },
+    /// Values are threshold of each icon starting from the lowest
+    IconSet {
+        icon_set: IconSetValues,
+        values: Vec<ConditionalFormatValue>,
+        show_value: bool,
+        is_reverse: bool,
+    },
+    TopBottom {
+        rank: u32,
+        is_bottom: bool,
+        is_percent: bool,
+    },
+    DuplicateValues,
+    UniqueValues,
+}
+
+/// Conditional formatting rule of the worksheet range
+#[derive(Debug, Clone)]
+pub struct ConditionalFormatRule {
+    pub rule: ConditionalFormatRuleValues,
+    /// Formatting applied when the rule match. Required for cell value, expression,
+    /// top bottom, duplicate and unique rule
+    pub style: Option<DifferentialStyleSetting>,
+    pub stop_if_true: bool,
+}
+
+impl ConditionalFormatRule {
+    pub fn new(rule: ConditionalFormatRuleValues, style: Option<DifferentialStyleSetting>) -> Self {
+        Self {
+            rule,
+            style,
+            stop_if_true: false,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::global_2007::traits::Enum;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataValidationTypeValues {
+    None,
+    Whole,
+    Decimal,
+    List,
+    Date,
+    Time,
+    TextLength,
+    Custom,
+}
+
+impl Enum<DataValidationTypeValues> for DataValidationTypeValues {
+    fn get_string(input_enum: DataValidationTypeValues) -> String {
+        match input_enum {
+            DataValidationTypeValues::None => "none".to_string(),
+            DataValidationTypeValues::Whole => "whole".to_string(),
+            DataValidationTypeValues::Decimal => "decimal".to_string(),
+            DataValidationTypeValues::List => "list".to_string(),
+            DataValidationTypeValues::Date => "date".to_string(),
+            DataValidationTypeValues::Time => "time".to_string(),
+            DataValidationTypeValues::TextLength => "textLength".to_string(),
+            DataValidationTypeValues::Custom => "custom".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> DataValidationTypeValues {
+        match input_string {
+            "whole" => DataValidationTypeValues::Whole,
+            "decimal" => DataValidationTypeValues::Decimal,
+            "list" => DataValidationTypeValues::List,
+            "date" => DataValidationTypeValues::Date,
+            "time" => DataValidationTypeValues::Time,
+            "textLength" => DataValidationTypeValues::TextLength,
+            "custom" => DataValidationTypeValues::Custom,
+            _ => DataValidationTypeValues::None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataValidationOperatorValues {
+    Between,
+    NotBetween,
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl Enum<DataValidationOperatorValues> for DataValidationOperatorValues {
+    fn get_string(input_enum: DataValidationOperatorValues) -> String {
+        match input_enum {
+            DataValidationOperatorValues::Between => "between".to_string(),
+            DataValidationOperatorValues::NotBetween => "notBetween".to_string(),
+            DataValidationOperatorValues::Equal => "equal".to_string(),
+            DataValidationOperatorValues::NotEqual => "notEqual".to_string(),
+            DataValidationOperatorValues::LessThan => "lessThan".to_string(),
+            DataValidationOperatorValues::LessThanOrEqual => "lessThanOrEqual".to_string(),
+            DataValidationOperatorValues::GreaterThan => "greaterThan".to_string(),
+            DataValidationOperatorValues::GreaterThanOrEqual => "greaterThanOrEqual".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> DataValidationOperatorValues {
+        match input_string {
+            "notBetween" => DataValidationOperatorValues::NotBetween,
+            "equal" => DataValidationOperatorValues::Equal,
+            "notEqual" => DataValidationOperatorValues::NotEqual,
+            "lessThan" => DataValidationOperatorValues::LessThan,
+            "lessThanOrEqual" => DataValidationOperatorValues::LessThanOrEqual,
+            "greaterThan" => DataValidationOperatorValues::GreaterThan,
+            "greaterThanOrEqual" => DataValidationOperatorValues::GreaterThanOrEqual,
+            _ => DataValidationOperatorValues::Between,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataValidationErrorStyleValues {
+    Stop,
+    Warning,
+    Information,
+}
+
+impl Enum<DataValidationErrorStyleValues> for DataValidationErrorStyleValues {
+    fn get_string(input_enum: DataValidationErrorStyleValues) -> String {
+        match input_enum {
+            DataValidationErrorStyleValues::Stop => "stop".to_string(),
+            DataValidationErrorStyleValues::Warning => "warning".to_string(),
+            DataValidationErrorStyleValues::Information => "information".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> DataValidationErrorStyleValues {
+        match input_string {
+            "warning" => DataValidationErrorStyleValues::Warning,
+            "information" => DataValidationErrorStyleValues::Information,
+            _ => DataValidationErrorStyleValues::Stop,
+        }
+    }
+}
+
+/// Input restriction of the worksheet range. Formula are written without leading "=",
+/// date and time bounds are serial value like "45292" or formula like "DATE(2024,1,1)"
+#[derive(Debug, Clone)]
+pub struct DataValidation {
+    pub validation_type: DataValidationTypeValues,
+    /// Used by whole, decimal, date, time and text length type
+    pub operator: DataValidationOperatorValues,
+    pub formula1: Option<String>,
+    /// Upper bound for between and not between operator
+    pub formula2: Option<String>,
+    pub allow_blank: bool,
+    /// Hide the in cell dropdown arrow of list type
+    pub hide_dropdown: bool,
+    pub show_input_message: bool,
+    pub prompt_title: Option<String>,
+    pub prompt: Option<String>,
+    pub show_error_message: bool,
+    pub error_style: DataValidationErrorStyleValues,
+    pub error_title: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Default for DataValidation {
+    fn default() -> Self {
+        Self {
+            validation_type: DataValidationTypeValues::None,
+            operator: DataValidationOperatorValues::Between,
+            formula1: None,
+            formula2: None,
+            allow_blank: true,
+            hide_dropdown: false,
+            show_input_message: true,
+            prompt_title: None,
+            prompt: None,
+            show_error_message: true,
+            error_style: DataValidationErrorStyleValues::Stop,
+            error_title: None,
+            error: None,
+        }
+    }
+}
+
+impl DataValidation {
+    /// Dropdown list from literal values
+    pub fn list_values(values: Vec<&str>) -> Self {
+        Self {
+            validation_type: DataValidationTypeValues::List,
+            formula1: Some(format!("\"{}\"", values.join(","))),
+            ..Default::default()
+        }
+    }
+
+    /// Dropdown list from the cell range like "$A$1:$A$10" or "'Sheet 1'!$A$1:$A$10"
+    pub fn list_range(range_ref: &str) -> Self {
+        Self {
+            validation_type: DataValidationTypeValues::List,
+            formula1: Some(range_ref.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Bounded value check, formula2 is used only by between and not between operator
+    pub fn bounded(
+        validation_type: DataValidationTypeValues,
+        operator: DataValidationOperatorValues,
+        formula1: &str,
+        formula2: Option<&str>,
+    ) -> Self {
+        Self {
+            validation_type,
+            operator,
+            formula1: Some(formula1.to_string()),
+            formula2: formula2.map(|formula| formula.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Value is valid when the formula evaluate to true
+    pub fn custom(formula: &str) -> Self {
+        Self {
+            validation_type: DataValidationTypeValues::Custom,
+            formula1: Some(formula.to_string()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::converters::ConverterUtil;
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+
+/// Built in sheet scoped name of rows and columns repeated on each printed page
+pub(crate) const PRINT_TITLES_NAME: &str = "_xlnm.Print_Titles";
+
+/// Named range or named formula of the workbook
+#[derive(Debug, Clone)]
+pub struct DefinedName {
+    pub name: String,
+    /// Reference or formula without leading "=" like "'Sheet 1'!$A$1:$B$5"
+    pub value: String,
+    /// Sheet the name is scoped to, None for workbook scope
+    pub sheet_name: Option<String>,
+    pub hidden: bool,
+    pub comment: Option<String>,
+}
+
+impl DefinedName {
+    /// Workbook scoped visible name
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+            sheet_name: None,
+            hidden: false,
+            comment: None,
+        }
+    }
+
+    /// Check the name follow Excel naming rule, table name use the same rule
+    pub(crate) fn is_valid_name(name: &str) -> bool {
+        let mut name_characters = name.chars();
+        let is_valid_characters = name_characters.next().is_some_and(|character| {
+            character.is_alphabetic() || character == '_' || character == '\\'
+        }) && name_characters
+            .all(|character| character.is_alphanumeric() || matches!(character, '_' | '.' | '\\'));
+        let letter_count = name
+            .chars()
+            .take_while(|character| character.is_ascii_alphabetic())
+            .count();
+        // Name looking like cell reference (A1, R1C1) is read as reference by Excel
+        let is_cell_reference = (1..=3).contains(&letter_count)
+            && name.len() > letter_count
+            && name[letter_count..]
+                .chars()
+                .all(|character| character.is_ascii_digit());
+        let upper_name = name.to_ascii_uppercase();
+        let is_r1c1_reference = matches!(upper_name.as_str(), "R" | "C")
+            || (upper_name.starts_with('R') || upper_name.starts_with('C'))
+                && upper_name[1..].starts_with(|character: char| character.is_ascii_digit());
+        name.chars().count() <= 255
+            && is_valid_characters
+            && !is_cell_reference
+            && !is_r1c1_reference
+    }
+
+    /// Print titles value like 'Sheet 1'!$A:$A,'Sheet 1'!$1:$2, None when no range is given
+    pub(crate) fn get_print_titles_value(
+        sheet_name: &str,
+        row_range: Option<(u32, u32)>,
+        column_range: Option<(u16, u16)>,
+    ) -> AnyResult<Option<String>, AnyError> {
+        let sheet_prefix = format!("'{}'!", sheet_name.replace("'", "''"));
+        let mut print_titles = Vec::new();
+        if let Some((start_col, end_col)) = column_range {
+            print_titles.push(format!(
+                "{}${}:${}",
+                sheet_prefix,
+                ConverterUtil::get_column_ref(start_col.min(end_col))
+                    .context("Failed to get start column ref")?,
+                ConverterUtil::get_column_ref(start_col.max(end_col))
+                    .context("Failed to get end column ref")?
+            ));
+        }
+        if let Some((start_row, end_row)) = row_range {
+            if start_row == 0 || end_row == 0 {
+                return Err(anyhow!("Row index must be greater than 0"));
+            }
+            print_titles.push(format!(
+                "{}${}:${}",
+                sheet_prefix,
+                start_row.min(end_row),
+                start_row.max(end_row)
+            ));
+        }
+        if print_titles.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(print_titles.join(",")))
+        }
+    }
+
+    /// Rows of the print titles value
+    pub(crate) fn get_print_title_rows(&self) -> Option<(u32, u32)> {
+        self.get_print_title_refs()
+            .find_map(|(start_ref, end_ref)| Some((start_ref.parse().ok()?, end_ref.parse().ok()?)))
+    }
+
+    /// Columns of the print titles value
+    pub(crate) fn get_print_title_columns(&self) -> Option<(u16, u16)> {
+        self.get_print_title_refs()
+            .find_map(|(start_ref, end_ref)| {
+                if start_ref
+                    .chars()
+                    .all(|character| character.is_ascii_alphabetic())
+                {
+                    Some((
+                        ConverterUtil::get_column_index(&start_ref).ok()?,
+                        ConverterUtil::get_column_index(&end_ref).ok()?,
+                    ))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Start and end part of each range like $A:$B in the value
+    fn get_print_title_refs(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        self.value.split(',').filter_map(|print_title| {
+            let (_, range_ref) = print_title.rsplit_once('!')?;
+            let (start_ref, end_ref) = range_ref.split_once(':')?;
+            Some((start_ref.replace("$", ""), end_ref.replace("$", "")))
+        })
+    }
+
+    /// Replace sheet prefix like 'Sheet 1'! or Sheet1! in the value, text literal stay as is
+    pub(crate) fn replace_sheet_reference_mut(&mut self, sheet_name: &str, replacement: &str) {
+        let sheet_name = sheet_name.to_lowercase();
+        let mut value = String::with_capacity(self.value.len());
+        let mut characters = self.value.chars().peekable();
+        while let Some(character) = characters.next() {
+            match character {
+                '"' => {
+                    value.push(character);
+                    while let Some(character) = characters.next() {
+                        value.push(character);
+                        if character == '"' {
+                            if characters.peek() == Some(&'"') {
+                                value.push('"');
+                                characters.next();
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                }
+                '\'' => {
+                    let mut quoted_name = String::new();
+                    while let Some(character) = characters.next() {
+                        if character == '\'' {
+                            if characters.peek() == Some(&'\'') {
+                                quoted_name.push('\'');
+                                characters.next();
+                            } else {
+                                break;
+                            }
+                        } else {
+                            quoted_name.push(character);
+                        }
+                    }
+                    if characters.peek() == Some(&'!') && quoted_name.to_lowercase() == sheet_name {
+                        characters.next();
+                        value.push_str(replacement);
+                    } else {
+                        value.push_str(&format!("'{}'", quoted_name.replace("'", "''")));
+                    }
+                }
+                _ if character.is_alphanumeric() || character == '_' => {
+                    let mut token = String::from(character);
+                    while let Some(character) = characters.next_if(|character| {
+                        character.is_alphanumeric() || matches!(character, '_' | '.')
+                    }) {
+                        token.push(character);
+                    }
+                    // Sheet of external workbook like [1]Sheet1! is left untouched
+                    if characters.peek() == Some(&'!')
+                        && !value.ends_with(']')
+                        && token.to_lowercase() == sheet_name
+                    {
+                        characters.next();
+                        value.push_str(replacement);
+                    } else {
+                        value.push_str(&token);
+                    }
+                }
+                _ => value.push(character),
+            }
+        }
+        self.value = value;
+    }
+}
@@ -0,0 +1,62 @@
+/// EMU of one pixel on the 96 DPI screen
+pub(crate) const EMU_PER_PIXEL: i64 = 9525;
+
+/// Format of the image bytes added to the sheet
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageFormatValues {
+    Png,
+    Jpeg,
+    Gif,
+    /// Vector image, PNG bytes are shown by the Excel versions without SVG support
+    Svg {
+        png_fallback: Vec<u8>,
+    },
+}
+
+/// Size of the drawing object
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawingSizeValues {
+    Emu {
+        width: i64,
+        height: i64,
+    },
+    /// Pixel on the 96 DPI screen
+    Pixel {
+        width: u32,
+        height: u32,
+    },
+}
+
+impl DrawingSizeValues {
+    /// Size in EMU as (width, height)
+    pub(crate) fn get_emu(&self) -> (i64, i64) {
+        match self {
+            DrawingSizeValues::Emu { width, height } => (*width, *height),
+            DrawingSizeValues::Pixel { width, height } => (
+                *width as i64 * EMU_PER_PIXEL,
+                *height as i64 * EMU_PER_PIXEL,
+            ),
+        }
+    }
+}
+
+/// Placement of the drawing object on the sheet
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawingAnchorValues {
+    /// Top left corner move with the cell, size stay as is when cells resize
+    OneCell {
+        cell_ref: String,
+        size: DrawingSizeValues,
+    },
+    /// Cover the cells from the start cell till the end cell, move and size with the cells
+    TwoCell {
+        from_cell_ref: String,
+        to_cell_ref: String,
+    },
+    /// Fixed position in EMU from the top left of the sheet
+    Absolute {
+        x: i64,
+        y: i64,
+        size: DrawingSizeValues,
+    },
+}
@@ -0,0 +1,10 @@
+/// Outcome of the workbook formula calculation. Cell reference is like 'Sheet 1'!B2
+#[derive(Debug, Clone, Default)]
+pub struct FormulaCalculationReport {
+    /// Formula cells with the calculated value cached
+    pub calculated_count: usize,
+    /// Formula cells left as is along with the reason, like unsupported function or invalid syntax
+    pub skipped_cells: Vec<(String, String)>,
+    /// Formula cells part of a circular reference, left as is
+    pub circular_references: Vec<String>,
+}
@@ -1,5 +1,25 @@
+pub mod auto_filter;
+pub mod comment;
+pub mod conditional_format;
+pub mod data_validation;
+pub mod defined_name;
+pub mod drawing;
+pub mod formula;
+pub mod page_setup;
+pub mod protection;
 pub(crate) mod style;
+pub mod table;
 pub mod worksheet;
 
+pub use auto_filter::*;
+pub use comment::*;
+pub use conditional_format::*;
+pub use data_validation::*;
+pub use defined_name::*;
+pub use drawing::*;
+pub use formula::*;
+pub use page_setup::*;
+pub use protection::*;
 pub use style::*;
+pub use table::*;
 pub use worksheet::*;
@@ -0,0 +1,263 @@
+use crate::global_2007::traits::Enum;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageOrientationValues {
+    Default,
+    Portrait,
+    Landscape,
+}
+
+impl Enum<PageOrientationValues> for PageOrientationValues {
+    fn get_string(input_enum: PageOrientationValues) -> String {
+        match input_enum {
+            PageOrientationValues::Default => "default".to_string(),
+            PageOrientationValues::Portrait => "portrait".to_string(),
+            PageOrientationValues::Landscape => "landscape".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> PageOrientationValues {
+        match input_string {
+            "portrait" => PageOrientationValues::Portrait,
+            "landscape" => PageOrientationValues::Landscape,
+            _ => PageOrientationValues::Default,
+        }
+    }
+}
+
+/// Paper size of the printed page, custom hold the paper size code of the spec
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaperSizeValues {
+    Letter,
+    Tabloid,
+    Legal,
+    Executive,
+    A3,
+    A4,
+    A5,
+    B4,
+    B5,
+    Envelope10,
+    Custom(u32),
+}
+
+impl Enum<PaperSizeValues> for PaperSizeValues {
+    fn get_string(input_enum: PaperSizeValues) -> String {
+        match input_enum {
+            PaperSizeValues::Letter => "1".to_string(),
+            PaperSizeValues::Tabloid => "3".to_string(),
+            PaperSizeValues::Legal => "5".to_string(),
+            PaperSizeValues::Executive => "7".to_string(),
+            PaperSizeValues::A3 => "8".to_string(),
+            PaperSizeValues::A4 => "9".to_string(),
+            PaperSizeValues::A5 => "11".to_string(),
+            PaperSizeValues::B4 => "12".to_string(),
+            PaperSizeValues::B5 => "13".to_string(),
+            PaperSizeValues::Envelope10 => "20".to_string(),
+            PaperSizeValues::Custom(paper_size) => paper_size.to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> PaperSizeValues {
+        match input_string {
+            "1" => PaperSizeValues::Letter,
+            "3" => PaperSizeValues::Tabloid,
+            "5" => PaperSizeValues::Legal,
+            "7" => PaperSizeValues::Executive,
+            "8" => PaperSizeValues::A3,
+            "9" => PaperSizeValues::A4,
+            "11" => PaperSizeValues::A5,
+            "12" => PaperSizeValues::B4,
+            "13" => PaperSizeValues::B5,
+            "20" => PaperSizeValues::Envelope10,
+            _ => input_string
+                .parse()
+                .map(PaperSizeValues::Custom)
+                .unwrap_or(PaperSizeValues::Letter),
+        }
+    }
+}
+
+/// Page margins in inches, default match the Excel normal margins
+#[derive(Debug, Clone)]
+pub struct PageMargins {
+    pub left: f64,
+    pub right: f64,
+    pub top: f64,
+    pub bottom: f64,
+    pub header: f64,
+    pub footer: f64,
+}
+
+impl Default for PageMargins {
+    fn default() -> Self {
+        Self {
+            left: 0.7,
+            right: 0.7,
+            top: 0.75,
+            bottom: 0.75,
+            header: 0.3,
+            footer: 0.3,
+        }
+    }
+}
+
+/// Left, center and right section of the header or footer. Text can carry the
+/// codes like &P page number, &N page count and &D date, literal & is written as &&
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HeaderFooterText {
+    pub left: Option<String>,
+    pub center: Option<String>,
+    pub right: Option<String>,
+}
+
+impl HeaderFooterText {
+    pub const PAGE_NUMBER: &'static str = "&P";
+    pub const PAGE_COUNT: &'static str = "&N";
+    pub const DATE: &'static str = "&D";
+    pub const TIME: &'static str = "&T";
+    pub const SHEET_NAME: &'static str = "&A";
+    pub const FILE_NAME: &'static str = "&F";
+
+    /// Center section only text
+    pub fn new(center: &str) -> Self {
+        Self {
+            center: Some(center.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Combine the sections into header footer value, None when all sections are empty
+    pub(crate) fn get_value(&self) -> Option<String> {
+        let value = [
+            ("&L", &self.left),
+            ("&C", &self.center),
+            ("&R", &self.right),
+        ]
+        .iter()
+        .filter_map(|(section_code, text)| {
+            text.as_ref()
+                .filter(|text| !text.is_empty())
+                .map(|text| format!("{}{}", section_code, text))
+        })
+        .collect::<String>();
+        if value.is_empty() {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    /// Split header footer value into sections, text before any section code is centered
+    pub(crate) fn from_value(value: &str) -> Self {
+        let mut sections = [String::new(), String::new(), String::new()];
+        let mut current_section = 1;
+        let mut characters = value.chars().peekable();
+        while let Some(character) = characters.next() {
+            if character == '&' {
+                let section = match characters.peek() {
+                    Some('L') => Some(0),
+                    Some('C') => Some(1),
+                    Some('R') => Some(2),
+                    _ => None,
+                };
+                if let Some(section) = section {
+                    characters.next();
+                    current_section = section;
+                    continue;
+                }
+                if characters.peek() == Some(&'&') {
+                    characters.next();
+                    sections[current_section].push_str("&&");
+                    continue;
+                }
+            }
+            sections[current_section].push(character);
+        }
+        let [left, center, right] = sections.map(|text| Some(text).filter(|text| !text.is_empty()));
+        Self {
+            left,
+            center,
+            right,
+        }
+    }
+}
+
+/// Header and footer of the printed pages. Even text is used when different odd even
+/// is set and first text when different first is set
+#[derive(Debug, Clone)]
+pub struct HeaderFooter {
+    pub odd_header: HeaderFooterText,
+    pub odd_footer: HeaderFooterText,
+    pub even_header: HeaderFooterText,
+    pub even_footer: HeaderFooterText,
+    pub first_header: HeaderFooterText,
+    pub first_footer: HeaderFooterText,
+    pub different_odd_even: bool,
+    pub different_first: bool,
+    pub scale_with_doc: bool,
+    pub align_with_margins: bool,
+}
+
+impl Default for HeaderFooter {
+    fn default() -> Self {
+        Self {
+            odd_header: HeaderFooterText::default(),
+            odd_footer: HeaderFooterText::default(),
+            even_header: HeaderFooterText::default(),
+            even_footer: HeaderFooterText::default(),
+            first_header: HeaderFooterText::default(),
+            first_footer: HeaderFooterText::default(),
+            different_odd_even: false,
+            different_first: false,
+            scale_with_doc: true,
+            align_with_margins: true,
+        }
+    }
+}
+
+/// Print setting of the worksheet
+#[derive(Debug, Clone)]
+pub struct PageSetup {
+    pub orientation: PageOrientationValues,
+    pub paper_size: PaperSizeValues,
+    /// Print scale percentage from 10 to 400, not used when fit to page is set
+    pub scale: u16,
+    /// Fit the print into (width, height) pages, 0 leave the side automatic
+    pub fit_to_page: Option<(u32, u32)>,
+    pub first_page_number: Option<u32>,
+    pub margins: PageMargins,
+    pub center_horizontally: bool,
+    pub center_vertically: bool,
+    pub print_grid_lines: bool,
+    pub print_headings: bool,
+    /// (start, end) rows repeated on top of each printed page
+    pub print_title_rows: Option<(u32, u32)>,
+    /// (start, end) columns repeated on left of each printed page
+    pub print_title_columns: Option<(u16, u16)>,
+    /// Rows the manual page break is placed after
+    pub row_breaks: Vec<u32>,
+    /// Columns the manual page break is placed after
+    pub column_breaks: Vec<u16>,
+    pub header_footer: HeaderFooter,
+}
+
+impl Default for PageSetup {
+    fn default() -> Self {
+        Self {
+            orientation: PageOrientationValues::Default,
+            paper_size: PaperSizeValues::Letter,
+            scale: 100,
+            fit_to_page: None,
+            first_page_number: None,
+            margins: PageMargins::default(),
+            center_horizontally: false,
+            center_vertically: false,
+            print_grid_lines: false,
+            print_headings: false,
+            print_title_rows: None,
+            print_title_columns: None,
+            row_breaks: Vec::new(),
+            column_breaks: Vec::new(),
+            header_footer: HeaderFooter::default(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/// Sheet protection with the actions allowed on the protected sheet.
+/// Password is hashed on set and never read back from file
+#[derive(Debug, Clone)]
+pub struct SheetProtection {
+    pub password: Option<String>,
+    pub allow_select_locked_cells: bool,
+    pub allow_select_unlocked_cells: bool,
+    pub allow_format_cells: bool,
+    pub allow_format_columns: bool,
+    pub allow_format_rows: bool,
+    pub allow_insert_columns: bool,
+    pub allow_insert_rows: bool,
+    pub allow_insert_hyperlinks: bool,
+    pub allow_delete_columns: bool,
+    pub allow_delete_rows: bool,
+    pub allow_sort: bool,
+    pub allow_auto_filter: bool,
+    pub allow_pivot_tables: bool,
+    pub allow_edit_objects: bool,
+    pub allow_edit_scenarios: bool,
+}
+
+impl Default for SheetProtection {
+    fn default() -> Self {
+        Self {
+            password: None,
+            allow_select_locked_cells: true,
+            allow_select_unlocked_cells: true,
+            allow_format_cells: false,
+            allow_format_columns: false,
+            allow_format_rows: false,
+            allow_insert_columns: false,
+            allow_insert_rows: false,
+            allow_insert_hyperlinks: false,
+            allow_delete_columns: false,
+            allow_delete_rows: false,
+            allow_sort: false,
+            allow_auto_filter: false,
+            allow_pivot_tables: false,
+            allow_edit_objects: false,
+            allow_edit_scenarios: false,
+        }
+    }
+}
+
+/// Range of the protected sheet the user can edit, with optional own password
+#[derive(Debug, Clone)]
+pub struct ProtectedRange {
+    pub name: String,
+    /// Space separated range like "A1:B5 D1"
+    pub range_ref: String,
+    pub password: Option<String>,
+}
+
+/// Workbook protection, lock structure block add, delete, rename and hide of sheets
+#[derive(Debug, Clone, Default)]
+pub struct WorkbookProtection {
+    pub password: Option<String>,
+    pub lock_structure: bool,
+    pub lock_windows: bool,
+}
@@ -233,7 +233,7 @@ impl Enum<BorderStyleValues> for BorderStyleValues {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Hash)]
 pub enum ColorSettingTypeValues {
     Indexed,
     Theme,
@@ -257,7 +257,7 @@ impl Enum<ColorSettingTypeValues> for ColorSettingTypeValues {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Hash)]
 pub struct ColorSetting {
     pub color_setting_type: ColorSettingTypeValues,
     pub value: String,
@@ -427,6 +427,8 @@ pub(crate) struct CellXfs {
     pub(crate) apply_border: u8,
     pub(crate) apply_number_format: u8,
     pub(crate) apply_protection: u8,
+    pub(crate) is_locked: u8,
+    pub(crate) is_hidden: u8,
     pub(crate) is_wrap_text: u8,
     pub(crate) horizontal_alignment: HorizontalAlignmentValues,
     pub(crate) vertical_alignment: VerticalAlignmentValues,
@@ -446,6 +448,8 @@ impl Default for CellXfs {
             apply_border: 0,
             apply_number_format: 0,
             apply_protection: 0,
+            is_locked: 1,
+            is_hidden: 0,
             is_wrap_text: 0,
             horizontal_alignment: HorizontalAlignmentValues::None,
             vertical_alignment: VerticalAlignmentValues::None,
@@ -498,8 +502,10 @@ pub struct StyleSetting {
     // xfs
     pub horizontal_alignment: HorizontalAlignmentValues,
     pub vertical_alignment: VerticalAlignmentValues,
-    // mis
-    pub(crate) protect: Option<()>,
+    // protection, applied only when the sheet is protected
+    pub is_locked: bool,
+    /// Hide the formula of the cell in formula bar
+    pub is_formula_hidden: bool,
 }
 
 impl Default for StyleSetting {
@@ -533,12 +539,28 @@ impl Default for StyleSetting {
             // xfs
             horizontal_alignment: HorizontalAlignmentValues::None,
             vertical_alignment: VerticalAlignmentValues::None,
-            // mis
-            protect: None,
+            // protection
+            is_locked: true,
+            is_formula_hidden: false,
         }
     }
 }
 
+/// Formatting applied over the cell style by conditional formatting rule.
+/// Unset property keeps the cell's own style
+#[derive(Debug, Clone, Default, Hash)]
+pub struct DifferentialStyleSetting {
+    pub custom_number_format: Option<String>,
+    pub text_color: Option<ColorSetting>,
+    pub is_bold: bool,
+    pub is_italic: bool,
+    pub is_underline: bool,
+    pub is_strike: bool,
+    pub background_color: Option<ColorSetting>,
+    /// Border applied on all side of the cell
+    pub border: Option<BorderSetting>,
+}
+
 #[derive(Debug, Clone)]
 pub struct StyleId {
     pub(crate) id: u32,
@@ -0,0 +1,146 @@
+use crate::global_2007::traits::Enum;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableTotalsRowFunctionValues {
+    None,
+    Sum,
+    Min,
+    Max,
+    Average,
+    /// Count of non empty cells
+    Count,
+    /// Count of numeric cells
+    CountNumbers,
+    StandardDeviation,
+    Variance,
+    /// Use the totals row formula of the column
+    Custom,
+}
+
+impl Enum<TableTotalsRowFunctionValues> for TableTotalsRowFunctionValues {
+    fn get_string(input_enum: TableTotalsRowFunctionValues) -> String {
+        match input_enum {
+            TableTotalsRowFunctionValues::None => "none".to_string(),
+            TableTotalsRowFunctionValues::Sum => "sum".to_string(),
+            TableTotalsRowFunctionValues::Min => "min".to_string(),
+            TableTotalsRowFunctionValues::Max => "max".to_string(),
+            TableTotalsRowFunctionValues::Average => "average".to_string(),
+            TableTotalsRowFunctionValues::Count => "count".to_string(),
+            TableTotalsRowFunctionValues::CountNumbers => "countNums".to_string(),
+            TableTotalsRowFunctionValues::StandardDeviation => "stdDev".to_string(),
+            TableTotalsRowFunctionValues::Variance => "var".to_string(),
+            TableTotalsRowFunctionValues::Custom => "custom".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> TableTotalsRowFunctionValues {
+        match input_string {
+            "sum" => TableTotalsRowFunctionValues::Sum,
+            "min" => TableTotalsRowFunctionValues::Min,
+            "max" => TableTotalsRowFunctionValues::Max,
+            "average" => TableTotalsRowFunctionValues::Average,
+            "count" => TableTotalsRowFunctionValues::Count,
+            "countNums" => TableTotalsRowFunctionValues::CountNumbers,
+            "stdDev" => TableTotalsRowFunctionValues::StandardDeviation,
+            "var" => TableTotalsRowFunctionValues::Variance,
+            "custom" => TableTotalsRowFunctionValues::Custom,
+            _ => TableTotalsRowFunctionValues::None,
+        }
+    }
+}
+
+impl TableTotalsRowFunctionValues {
+    /// Function number used by SUBTOTAL in the totals row cell
+    pub(crate) fn get_subtotal_number(&self) -> Option<u8> {
+        match self {
+            TableTotalsRowFunctionValues::Average => Some(101),
+            TableTotalsRowFunctionValues::CountNumbers => Some(102),
+            TableTotalsRowFunctionValues::Count => Some(103),
+            TableTotalsRowFunctionValues::Max => Some(104),
+            TableTotalsRowFunctionValues::Min => Some(105),
+            TableTotalsRowFunctionValues::StandardDeviation => Some(107),
+            TableTotalsRowFunctionValues::Sum => Some(109),
+            TableTotalsRowFunctionValues::Variance => Some(110),
+            TableTotalsRowFunctionValues::None | TableTotalsRowFunctionValues::Custom => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    /// Unique name of the column, written to the header row cell
+    pub name: String,
+    pub totals_row_function: TableTotalsRowFunctionValues,
+    /// Text shown in the totals row when no function is used
+    pub totals_row_label: Option<String>,
+    /// Formula of the custom totals row function
+    pub totals_row_formula: Option<String>,
+    /// Formula Excel fill into new rows of the column
+    pub calculated_column_formula: Option<String>,
+}
+
+impl TableColumn {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            totals_row_function: TableTotalsRowFunctionValues::None,
+            totals_row_label: None,
+            totals_row_formula: None,
+            calculated_column_formula: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableSetting {
+    /// Built in style like "TableStyleMedium2", None to leave the table without style
+    pub style_name: Option<String>,
+    pub show_header_row: bool,
+    /// Last row of the table range is used as totals row
+    pub show_totals_row: bool,
+    pub show_row_stripes: bool,
+    pub show_column_stripes: bool,
+    pub show_first_column: bool,
+    pub show_last_column: bool,
+}
+
+impl Default for TableSetting {
+    fn default() -> Self {
+        Self {
+            style_name: Some("TableStyleMedium2".to_string()),
+            show_header_row: true,
+            show_totals_row: false,
+            show_row_stripes: true,
+            show_column_stripes: false,
+            show_first_column: false,
+            show_last_column: false,
+        }
+    }
+}
+
+impl TableSetting {
+    /// Check the style is one of Excel built in table style
+    pub(crate) fn is_built_in_style(style_name: &str) -> bool {
+        [
+            ("TableStyleLight", 21),
+            ("TableStyleMedium", 28),
+            ("TableStyleDark", 11),
+        ]
+        .iter()
+        .any(|(prefix, style_count)| {
+            style_name
+                .strip_prefix(prefix)
+                .and_then(|style_number| style_number.parse::<u8>().ok())
+                .is_some_and(|style_number| (1..=*style_count).contains(&style_number))
+        })
+    }
+}
+
+/// Excel table (list object) of the worksheet
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub name: String,
+    /// Range of the table like "A1:D10" including header and totals row
+    pub range_ref: String,
+    pub columns: Vec<TableColumn>,
+    pub setting: TableSetting,
+}
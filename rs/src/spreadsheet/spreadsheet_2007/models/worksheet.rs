@@ -1,9 +1,12 @@
 use crate::global_2007::{models::HyperlinkProperties, traits::Enum};
-use crate::spreadsheet_2007::models::StyleId;
+use crate::spreadsheet_2007::models::{ColorSetting, StyleId};
+use chrono::{NaiveDate, NaiveDateTime};
 
+/// Storage type of the cell value.
+/// Value type decide the cell type, this only pick how text content get stored
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CellDataType {
-    /// Use if you want the package to auto detect best fit
+    /// Use if you want the package to pick best fit based on value
     Auto,
     Number,
     Boolean,
@@ -47,15 +50,15 @@ pub struct RowProperties {
     pub hidden: Option<bool>,
     pub thick_top: Option<bool>,
     pub thick_bottom: Option<bool>,
-    // Column group to use with collapse expand
-    pub(crate) group_level: Option<u8>,
-    // Collapse the current column
-    pub(crate) collapsed: Option<bool>,
+    // Outline level of the row group, 1 to 7
+    pub group_level: Option<u8>,
+    // Summary row of the collapsed group
+    pub collapsed: Option<bool>,
     pub(crate) place_holder: Option<bool>,
     pub(crate) span: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ColumnProperties {
     // Start Column index
     pub(crate) min: u16,
@@ -69,10 +72,10 @@ pub struct ColumnProperties {
     pub style_id: Option<StyleId>,
     // Best fit/auto fit column
     pub best_fit: Option<bool>,
-    // Column group to use with collapse expand
-    pub(crate) group_level: usize,
-    // Collapse the current column
-    pub(crate) collapsed: Option<bool>,
+    // Outline level of the column group, 0 to 7
+    pub group_level: usize,
+    // Summary column of the collapsed group
+    pub collapsed: Option<bool>,
 }
 
 impl Default for ColumnProperties {
@@ -90,30 +93,124 @@ impl Default for ColumnProperties {
     }
 }
 
+/// Rows or columns (start, end) of the outline group
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutlineGroupValues {
+    Rows(u32, u32),
+    Columns(u16, u16),
+}
+
 #[derive(Debug, Clone)]
 pub struct CellProperties {
     pub formula: Option<String>,
-    pub value: Option<String>,
+    pub value: CellValue,
     pub data_type: CellDataType,
     pub hyperlink_properties: Option<HyperlinkProperties>,
     pub style_id: Option<StyleId>,
-    // TODO: Future Items
-    pub(crate) metadata: Option<String>,
-    pub(crate) comment_id: Option<usize>,
-    pub(crate) place_holder: Option<bool>,
 }
 
 impl Default for CellProperties {
     fn default() -> Self {
         Self {
             formula: None,
-            value: None,
+            value: CellValue::Empty,
             data_type: CellDataType::Auto,
             hyperlink_properties: None,
             style_id: None,
-            metadata: None,
-            comment_id: None,
-            place_holder: None,
         }
     }
 }
+
+/// Typed value of the cell
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum CellValue {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    /// Error code like #DIV/0!, #N/A
+    Error(String),
+    /// Stored as excel serial number with date format applied when no style is provided
+    DateTime(NaiveDateTime),
+    /// Text with formatted runs
+    Rich(Vec<RichTextRun>),
+    #[default]
+    Empty,
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        CellValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        CellValue::Text(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+impl From<NaiveDate> for CellValue {
+    fn from(value: NaiveDate) -> Self {
+        CellValue::DateTime(value.and_time(Default::default()))
+    }
+}
+
+impl From<NaiveDateTime> for CellValue {
+    fn from(value: NaiveDateTime) -> Self {
+        CellValue::DateTime(value)
+    }
+}
+
+impl From<Vec<RichTextRun>> for CellValue {
+    fn from(value: Vec<RichTextRun>) -> Self {
+        CellValue::Rich(value)
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(CellValue::Empty, Into::into)
+    }
+}
+
+macro_rules! impl_number_cell_value {
+    ($($number_type:ty),*) => {
+        $(
+            impl From<$number_type> for CellValue {
+                fn from(value: $number_type) -> Self {
+                    CellValue::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_cell_value!(f64, f32, i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// Formatted text run of rich text value. Unset property follow the cell style
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RichTextRun {
+    pub text: String,
+    pub font_family: Option<String>,
+    pub font_size: Option<u8>,
+    pub text_color: Option<ColorSetting>,
+    pub is_bold: bool,
+    pub is_italic: bool,
+    pub is_underline: bool,
+    pub is_strike: bool,
+}
+
+/// Cell content read back from worksheet
+#[derive(Debug, Clone, Default)]
+pub struct CellRecord {
+    pub value: CellValue,
+    pub formula: Option<String>,
+    pub style_id: Option<StyleId>,
+    pub hyperlink_properties: Option<HyperlinkProperties>,
+}
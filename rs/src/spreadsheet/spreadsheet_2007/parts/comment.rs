@@ -0,0 +1,508 @@
+use crate::{
+    converters::ConverterUtil,
+    element_dictionary::EXCEL_TYPE_COLLECTION,
+    files::{OfficeDocument, XmlDocument, XmlSerializer},
+    global_2007::traits::XmlDocumentPartCommon,
+    log_elapsed,
+    spreadsheet_2007::{
+        models::{Comment, RichTextRun},
+        services::StringItem,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    rc::Weak,
+};
+
+/// Author prefix of the note Excel keeps in place of threaded comment for older versions
+pub(crate) const THREADED_COMMENT_AUTHOR_PREFIX: &str = "tc=";
+
+/// Default column width and row height in pixel used to anchor the note box
+const DEFAULT_COLUMN_WIDTH_PIXEL: u32 = 64;
+const DEFAULT_ROW_HEIGHT_PIXEL: u32 = 20;
+
+#[derive(Debug)]
+pub(crate) struct CommentPart {
+    office_document: Weak<RefCell<OfficeDocument>>,
+    xml_document: Weak<RefCell<XmlDocument>>,
+    file_path: String,
+    /// Relationship id of the comments in the sheet relationship
+    relationship_id: String,
+    /// Legacy drawing holding the note box shapes, regenerated from the comments on save
+    vml_document: Weak<RefCell<XmlDocument>>,
+    vml_file_path: String,
+    vml_relationship_id: String,
+    /// Note of the cell keyed by (row, column)
+    comments: BTreeMap<(u32, u16), Comment>,
+}
+
+impl Drop for CommentPart {
+    fn drop(&mut self) {
+        let _ = self.close_document();
+    }
+}
+
+impl XmlDocumentPartCommon for CommentPart {
+    /// Initialize xml content for this part from base template
+    fn initialize_content_xml() -> AnyResult<(XmlDocument, Option<String>, String, String), AnyError>
+    {
+        let content = EXCEL_TYPE_COLLECTION.get("comments").unwrap();
+        let template_core_properties = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<comments xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"
+    xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"
+    xmlns:xr="http://schemas.microsoft.com/office/spreadsheetml/2014/revision"
+    mc:Ignorable="xr"></comments>"#;
+        Ok((
+            XmlSerializer::vec_to_xml_doc_tree(
+                template_core_properties.as_bytes().to_vec(),
+                "Default Comments",
+            )
+            .context("Initializing Comments Failed")?,
+            Some(content.content_type.to_string()),
+            content.extension.to_string(),
+            content.extension_type.to_string(),
+        ))
+    }
+
+    fn close_document(&mut self) -> AnyResult<(), AnyError>
+    where
+        Self: Sized,
+    {
+        log_elapsed!(
+            || {
+                if let Some(xml_document) = self.xml_document.upgrade() {
+                    let mut xml_doc_mut = xml_document
+                        .try_borrow_mut()
+                        .context("Failed to Pull XML Handle")?;
+                    self.serialize_comments(&mut xml_doc_mut)
+                        .context("Failed to serialize comments")?;
+                }
+                if let Some(vml_document) = self.vml_document.upgrade() {
+                    let mut vml_doc_mut = vml_document
+                        .try_borrow_mut()
+                        .context("Failed to Pull VML Handle")?;
+                    self.serialize_vml_drawing(&mut vml_doc_mut)
+                        .context("Failed to serialize legacy drawing")?;
+                }
+                if let Some(office_document) = self.office_document.upgrade() {
+                    let mut office_doc_mut = office_document
+                        .try_borrow_mut()
+                        .context("Failed to pull office document")?;
+                    office_doc_mut
+                        .close_xml_document(&self.file_path)
+                        .context("Failed to close the comments document")?;
+                    office_doc_mut
+                        .close_xml_document(&self.vml_file_path)
+                        .context("Failed to close the legacy drawing document")?;
+                }
+                Ok(())
+            },
+            "Close Comments"
+        )
+    }
+}
+
+// ############################# Internal Function ######################################
+impl CommentPart {
+    /// Open the comments with its legacy drawing, new part start without comments
+    pub(crate) fn new(
+        office_document: Weak<RefCell<OfficeDocument>>,
+        file_path: &str,
+        relationship_id: &str,
+        vml_file_path: &str,
+        vml_relationship_id: &str,
+    ) -> AnyResult<Self, AnyError> {
+        let xml_document = Self::get_xml_document(&office_document, file_path)?;
+        let mut comments = Self::deserialize_comments(&xml_document)
+            .context(format!("Failed to load comments : {}", file_path))?;
+        let vml_document = {
+            let office_document = office_document
+                .upgrade()
+                .ok_or(anyhow!("Failed to pull office document"))?;
+            let mut office_doc_mut = office_document
+                .try_borrow_mut()
+                .context("Failed to pull office document")?;
+            // Drawing content is not always well formed, the note box setting is best effort
+            if let Ok(Some(vml_doc)) = office_doc_mut.read_xml_tree(vml_file_path) {
+                deserialize_vml_drawing(&vml_doc, &mut comments)
+                    .context("Failed to read legacy drawing")?;
+            }
+            let content = EXCEL_TYPE_COLLECTION.get("vml_drawing").unwrap();
+            office_doc_mut
+                .get_xml_document_ref(
+                    vml_file_path,
+                    None,
+                    content.extension.to_string(),
+                    content.extension_type.to_string(),
+                    initialize_vml_drawing_xml().context("Initializing Legacy Drawing Failed")?,
+                )
+                .context("Failed to open legacy drawing")?
+        };
+        Ok(Self {
+            office_document,
+            xml_document,
+            file_path: file_path.to_string(),
+            relationship_id: relationship_id.to_string(),
+            vml_document,
+            vml_file_path: vml_file_path.to_string(),
+            vml_relationship_id: vml_relationship_id.to_string(),
+            comments,
+        })
+    }
+
+    fn deserialize_comments(
+        xml_document: &Weak<RefCell<XmlDocument>>,
+    ) -> AnyResult<BTreeMap<(u32, u16), Comment>, AnyError> {
+        let mut comments = BTreeMap::new();
+        let xml_document = xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade comments document"))?;
+        let mut xml_doc_mut = xml_document
+            .try_borrow_mut()
+            .context("Failed to get XML doc handle")?;
+        let mut authors = Vec::new();
+        if let Some(authors_element) = xml_doc_mut
+            .pop_elements_by_tag_mut("authors", None)
+            .and_then(|mut authors_element| authors_element.pop())
+        {
+            while let Some((author_id, _)) = authors_element.pop_child_mut() {
+                if let Some(author_element) = xml_doc_mut.pop_element_mut(&author_id) {
+                    authors.push(author_element.get_value().clone().unwrap_or_default());
+                }
+            }
+        }
+        if let Some(comment_list_element) = xml_doc_mut
+            .pop_elements_by_tag_mut("commentList", None)
+            .and_then(|mut comment_list_element| comment_list_element.pop())
+        {
+            while let Some((comment_id, _)) = comment_list_element.pop_child_mut() {
+                if let Some(comment_element) = xml_doc_mut.pop_element_mut(&comment_id) {
+                    let attributes = comment_element.get_attribute().cloned().unwrap_or_default();
+                    let cell_key = ConverterUtil::get_cell_index(
+                        attributes
+                            .get("ref")
+                            .ok_or(anyhow!("Comment reference missing"))?,
+                    )
+                    .context("Failed to parse comment reference")?;
+                    let mut comment = Comment {
+                        author: attributes
+                            .get("authorId")
+                            .and_then(|author_id| author_id.parse::<usize>().ok())
+                            .and_then(|author_id| authors.get(author_id).cloned())
+                            .unwrap_or_default(),
+                        ..Default::default()
+                    };
+                    while let Some((child_id, _)) = comment_element.pop_child_mut() {
+                        if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                            if child_element.get_tag() == "text" {
+                                let string_item =
+                                    StringItem::deserialize_mut(&mut xml_doc_mut, &child_element)
+                                        .context("Failed to parse comment text")?;
+                                comment.text = if let Some(rich_text) = string_item.get_rich_text()
+                                {
+                                    rich_text.clone()
+                                } else {
+                                    vec![RichTextRun {
+                                        text: string_item.get_text().to_string(),
+                                        ..Default::default()
+                                    }]
+                                };
+                            }
+                        }
+                    }
+                    comments.insert(cell_key, comment);
+                }
+            }
+        }
+        Ok(comments)
+    }
+
+    fn serialize_comments(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        let mut authors: Vec<&str> = Vec::new();
+        for comment in self.comments.values() {
+            if !authors.contains(&comment.author.as_str()) {
+                authors.push(&comment.author);
+            }
+        }
+        let authors_id = xml_doc_mut
+            .append_child_mut("authors", None)
+            .context("Failed to insert authors element")?
+            .get_id();
+        for author in authors.iter() {
+            xml_doc_mut
+                .append_child_mut("author", Some(&authors_id))
+                .context("Failed to insert author element")?
+                .set_value_mut(author.to_string());
+        }
+        let comment_list_id = xml_doc_mut
+            .append_child_mut("commentList", None)
+            .context("Failed to insert comment list element")?
+            .get_id();
+        for ((row_index, col_index), comment) in self.comments.iter() {
+            let mut attributes = HashMap::from([
+                (
+                    "ref".to_string(),
+                    format!(
+                        "{}{}",
+                        ConverterUtil::get_column_ref(*col_index)
+                            .context("Failed to get comment column ref")?,
+                        row_index
+                    ),
+                ),
+                (
+                    "authorId".to_string(),
+                    authors
+                        .iter()
+                        .position(|author| *author == comment.author)
+                        .unwrap_or_default()
+                        .to_string(),
+                ),
+                ("shapeId".to_string(), "0".to_string()),
+            ]);
+            if let Some(thread_id) = comment.author.strip_prefix(THREADED_COMMENT_AUTHOR_PREFIX) {
+                attributes.insert("xr:uid".to_string(), thread_id.to_string());
+            }
+            let comment_id = xml_doc_mut
+                .append_child_mut("comment", Some(&comment_list_id))
+                .context("Failed to insert comment element")?
+                .set_attribute_mut(attributes)
+                .context("Failed to set comment attribute")?
+                .get_id();
+            let text_id = xml_doc_mut
+                .append_child_mut("text", Some(&comment_id))
+                .context("Failed to insert comment text element")?
+                .get_id();
+            StringItem::from_rich_text(comment.text.clone())
+                .serialize_mut(xml_doc_mut, &text_id)
+                .context("Failed to insert comment text")?;
+        }
+        Ok(())
+    }
+
+    /// Note box shape of each comment, anchored right of the cell
+    fn serialize_vml_drawing(&mut self, vml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        // Shape id block of the drawing, keeps the shape id unique across the sheets
+        let drawing_number = self
+            .vml_file_path
+            .rsplit("/")
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .filter(|character| character.is_ascii_digit())
+            .collect::<String>()
+            .parse::<u32>()
+            .unwrap_or(1);
+        if let Some(id_map) =
+            vml_doc_mut.get_first_element_mut(vec!["o:shapelayout", "o:idmap"], None)?
+        {
+            id_map
+                .set_attribute_mut(HashMap::from([
+                    ("v:ext".to_string(), "edit".to_string()),
+                    ("data".to_string(), drawing_number.to_string()),
+                ]))
+                .context("Failed to set shape id map")?;
+        }
+        for (shape_index, ((row_index, col_index), comment)) in self.comments.iter().enumerate() {
+            let width_pixel = (comment.width * 4.0 / 3.0).round() as u32;
+            let height_pixel = (comment.height * 4.0 / 3.0).round() as u32;
+            let (left_column, left_offset) = (*col_index as u32, 15);
+            let (top_row, top_offset) = if *row_index > 1 {
+                (row_index - 2, 10)
+            } else {
+                (0, 2)
+            };
+            let anchor = [
+                left_column,
+                left_offset,
+                top_row,
+                top_offset,
+                left_column + (left_offset + width_pixel) / DEFAULT_COLUMN_WIDTH_PIXEL,
+                (left_offset + width_pixel) % DEFAULT_COLUMN_WIDTH_PIXEL,
+                top_row + (top_offset + height_pixel) / DEFAULT_ROW_HEIGHT_PIXEL,
+                (top_offset + height_pixel) % DEFAULT_ROW_HEIGHT_PIXEL,
+            ]
+            .map(|anchor_value| anchor_value.to_string())
+            .join(", ");
+            let shape_id = vml_doc_mut
+                .append_child_mut("v:shape", None)
+                .context("Failed to insert note shape")?
+                .set_attribute_mut(HashMap::from([
+                    (
+                        "id".to_string(),
+                        format!("_x0000_s{}", drawing_number * 1024 + 1 + shape_index as u32),
+                    ),
+                    ("type".to_string(), "#_x0000_t202".to_string()),
+                    (
+                        "style".to_string(),
+                        format!(
+                            "position:absolute;margin-left:{}pt;margin-top:{}pt;width:{}pt;height:{}pt;z-index:{};visibility:{}",
+                            (left_column * DEFAULT_COLUMN_WIDTH_PIXEL + left_offset) as f64 * 0.75,
+                            (top_row * DEFAULT_ROW_HEIGHT_PIXEL + top_offset) as f64 * 0.75,
+                            comment.width,
+                            comment.height,
+                            shape_index + 1,
+                            if comment.visible { "visible" } else { "hidden" }
+                        ),
+                    ),
+                    ("fillcolor".to_string(), "#ffffe1".to_string()),
+                    ("o:insetmode".to_string(), "auto".to_string()),
+                ]))
+                .context("Failed to set note shape attribute")?
+                .get_id();
+            for (tag, attributes) in [
+                ("v:fill", vec![("color2", "#ffffe1")]),
+                (
+                    "v:shadow",
+                    vec![("on", "t"), ("color", "black"), ("obscured", "t")],
+                ),
+                ("v:path", vec![("o:connecttype", "none")]),
+            ] {
+                vml_doc_mut.append_child_with_attributes_mut(tag, Some(&shape_id), attributes)?;
+            }
+            let text_box_id = vml_doc_mut.append_child_with_attributes_mut(
+                "v:textbox",
+                Some(&shape_id),
+                vec![("style", "mso-direction-alt:auto")],
+            )?;
+            vml_doc_mut.append_child_with_attributes_mut(
+                "div",
+                Some(&text_box_id),
+                vec![("style", "text-align:left")],
+            )?;
+            let client_data_id = vml_doc_mut.append_child_with_attributes_mut(
+                "x:ClientData",
+                Some(&shape_id),
+                vec![("ObjectType", "Note")],
+            )?;
+            let mut client_data = vec![
+                ("x:MoveWithCells", None),
+                ("x:SizeWithCells", None),
+                ("x:Anchor", Some(anchor)),
+                ("x:AutoFill", Some("False".to_string())),
+                ("x:Row", Some((row_index - 1).to_string())),
+                ("x:Column", Some((col_index - 1).to_string())),
+            ];
+            if comment.visible {
+                client_data.push(("x:Visible", None));
+            }
+            for (tag, value) in client_data {
+                let client_data_element = vml_doc_mut
+                    .append_child_mut(tag, Some(&client_data_id))
+                    .context("Failed to insert note client data")?;
+                if let Some(value) = value {
+                    client_data_element.set_value_mut(value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Base legacy drawing with the text box shape type the note shapes refer
+fn initialize_vml_drawing_xml() -> AnyResult<XmlDocument, AnyError> {
+    let template_core_properties = r#"<xml xmlns:v="urn:schemas-microsoft-com:vml"
+    xmlns:o="urn:schemas-microsoft-com:office:office"
+    xmlns:x="urn:schemas-microsoft-com:office:excel">
+    <o:shapelayout v:ext="edit">
+        <o:idmap v:ext="edit" data="1" />
+    </o:shapelayout>
+    <v:shapetype id="_x0000_t202" coordsize="21600,21600" o:spt="202" path="m,l,21600r21600,l21600,xe">
+        <v:stroke joinstyle="miter" />
+        <v:path gradientshapeok="t" o:connecttype="rect" />
+    </v:shapetype>
+</xml>"#;
+    XmlSerializer::vec_to_xml_doc_tree(
+        template_core_properties.as_bytes().to_vec(),
+        "Default Legacy Drawing",
+    )
+}
+
+/// Read the note box size and visibility of the comments from the legacy drawing
+fn deserialize_vml_drawing(
+    vml_doc: &XmlDocument,
+    comments: &mut BTreeMap<(u32, u16), Comment>,
+) -> AnyResult<(), AnyError> {
+    for shape_id in vml_doc
+        .get_element_ids_by_tag("v:shape", None)
+        .unwrap_or_default()
+    {
+        let get_client_data = |tag: &str| -> AnyResult<Option<usize>, AnyError> {
+            vml_doc.get_first_element_id(vec!["x:ClientData", tag], Some(&shape_id))
+        };
+        let get_client_value = |tag: &str| -> AnyResult<Option<u32>, AnyError> {
+            Ok(get_client_data(tag)?
+                .and_then(|element_id| vml_doc.get_element(&element_id))
+                .and_then(|element| element.get_value().clone())
+                .and_then(|value| value.trim().parse::<u32>().ok()))
+        };
+        if let (Some(row_index), Some(col_index)) =
+            (get_client_value("x:Row")?, get_client_value("x:Column")?)
+        {
+            if let Some(comment) = comments.get_mut(&(row_index + 1, col_index as u16 + 1)) {
+                comment.visible = get_client_data("x:Visible")?.is_some();
+                let style = vml_doc
+                    .get_element(&shape_id)
+                    .and_then(|shape| shape.get_attribute())
+                    .and_then(|attributes| attributes.get("style").cloned())
+                    .unwrap_or_default();
+                for style_item in style.split(";") {
+                    if let Some((key, value)) = style_item.split_once(":") {
+                        let size = value.trim().strip_suffix("pt").map_or_else(
+                            || {
+                                value
+                                    .trim()
+                                    .strip_suffix("px")
+                                    .and_then(|size| size.parse::<f64>().ok())
+                                    .map(|size| size * 0.75)
+                            },
+                            |size| size.parse::<f64>().ok(),
+                        );
+                        match (key.trim(), size) {
+                            ("width", Some(width)) => comment.width = width,
+                            ("height", Some(height)) => comment.height = height,
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// ############################# Feature Function ######################################
+impl CommentPart {
+    pub(crate) fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    pub(crate) fn get_relationship_id(&self) -> &str {
+        &self.relationship_id
+    }
+
+    pub(crate) fn get_vml_file_path(&self) -> &str {
+        &self.vml_file_path
+    }
+
+    pub(crate) fn get_vml_relationship_id(&self) -> &str {
+        &self.vml_relationship_id
+    }
+
+    pub(crate) fn get_comments(&self) -> &BTreeMap<(u32, u16), Comment> {
+        &self.comments
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+
+    pub(crate) fn set_comment_mut(&mut self, cell_key: (u32, u16), comment: Comment) {
+        self.comments.insert(cell_key, comment);
+    }
+
+    pub(crate) fn remove_comment_mut(&mut self, cell_key: &(u32, u16)) -> Option<Comment> {
+        self.comments.remove(cell_key)
+    }
+}
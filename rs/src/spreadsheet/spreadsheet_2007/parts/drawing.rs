@@ -0,0 +1,459 @@
+use crate::{
+    converters::ConverterUtil,
+    element_dictionary::{Content, EXCEL_TYPE_COLLECTION},
+    files::{OfficeDocument, XmlDocument, XmlSerializer},
+    global_2007::{parts::RelationsPart, traits::XmlDocumentPartCommon},
+    log_elapsed,
+    spreadsheet_2007::models::DrawingAnchorValues,
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+/// Extension uri of the SVG image attached to the PNG fallback blip
+const SVG_BLIP_EXTENSION_URI: &str = "{96DAC541-7B7A-43D3-8B79-37D633B846F1}";
+
+#[derive(Debug)]
+pub(crate) struct DrawingPart {
+    office_document: Weak<RefCell<OfficeDocument>>,
+    xml_document: Weak<RefCell<XmlDocument>>,
+    drawing_relationship_part: Rc<RefCell<RelationsPart>>,
+    file_path: String,
+    /// Relationship id of the drawing in the sheet relationship
+    relationship_id: String,
+    /// Last shape id used by the drawing objects
+    shape_id: u32,
+}
+
+impl Drop for DrawingPart {
+    fn drop(&mut self) {
+        let _ = self.close_document();
+    }
+}
+
+impl XmlDocumentPartCommon for DrawingPart {
+    /// Initialize xml content for this part from base template
+    fn initialize_content_xml() -> AnyResult<(XmlDocument, Option<String>, String, String), AnyError>
+    {
+        let content = EXCEL_TYPE_COLLECTION.get("drawing").unwrap();
+        let template_core_properties = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing"
+    xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+    xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"></xdr:wsDr>"#;
+        Ok((
+            XmlSerializer::vec_to_xml_doc_tree(
+                template_core_properties.as_bytes().to_vec(),
+                "Default Drawing",
+            )
+            .context("Initializing Drawing Failed")?,
+            Some(content.content_type.to_string()),
+            content.extension.to_string(),
+            content.extension_type.to_string(),
+        ))
+    }
+
+    fn close_document(&mut self) -> AnyResult<(), AnyError>
+    where
+        Self: Sized,
+    {
+        log_elapsed!(
+            || {
+                if let Some(office_document) = self.office_document.upgrade() {
+                    office_document
+                        .try_borrow_mut()
+                        .context("Failed to pull office document")?
+                        .close_xml_document(&self.file_path)
+                        .context("Failed to close the drawing document")?;
+                }
+                Ok(())
+            },
+            "Close Drawing"
+        )
+    }
+}
+
+// ############################# Internal Function ######################################
+impl DrawingPart {
+    /// Open the drawing part, new objects take shape id after the highest one in the part
+    pub(crate) fn new(
+        office_document: Weak<RefCell<OfficeDocument>>,
+        file_path: &str,
+        relationship_id: &str,
+    ) -> AnyResult<Self, AnyError> {
+        let xml_document = Self::get_xml_document(&office_document, file_path)?;
+        let drawing_relationship_part = Rc::new(RefCell::new(
+            RelationsPart::new(
+                office_document.clone(),
+                &format!(
+                    "{}/_rels/{}.rels",
+                    &file_path[..file_path.rfind("/").unwrap_or(0)],
+                    file_path.rsplit("/").next().unwrap()
+                ),
+            )
+            .context("Creating Relation ship part for drawing failed.")?,
+        ));
+        let shape_id = Self::get_last_shape_id(&xml_document)
+            .context(format!("Failed to load drawing : {}", file_path))?;
+        Ok(Self {
+            office_document,
+            xml_document,
+            drawing_relationship_part,
+            file_path: file_path.to_string(),
+            relationship_id: relationship_id.to_string(),
+            shape_id,
+        })
+    }
+
+    fn get_last_shape_id(xml_document: &Weak<RefCell<XmlDocument>>) -> AnyResult<u32, AnyError> {
+        let xml_document = xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade drawing document"))?;
+        let xml_doc = xml_document
+            .try_borrow()
+            .context("Failed to get XML doc handle")?;
+        Ok(xml_doc
+            .get_descendant_ids_by_tag("xdr:cNvPr")
+            .iter()
+            .filter_map(|element_id| xml_doc.get_element(element_id))
+            .filter_map(|element| element.get_attribute())
+            .filter_map(|attributes| attributes.get("id"))
+            .filter_map(|shape_id| shape_id.parse::<u32>().ok())
+            .max()
+            .unwrap_or(1))
+    }
+
+    /// Relationship id of the media or chart part, relation is added when the drawing not yet refer it
+    fn get_part_relationship_id_mut(
+        &mut self,
+        file_path: &str,
+        content: &Content,
+    ) -> AnyResult<String, AnyError> {
+        let mut drawing_relationship_part = self
+            .drawing_relationship_part
+            .try_borrow_mut()
+            .context("Failed to pull drawing relationship part")?;
+        if let Some(relationship_id) =
+            drawing_relationship_part.get_relationship_id_by_target(file_path)
+        {
+            return Ok(relationship_id);
+        }
+        let (folder_path, file_name) = file_path
+            .rsplit_once("/")
+            .ok_or(anyhow!("Part path missing folder : {}", file_path))?;
+        drawing_relationship_part
+            .set_new_relationship_mut(
+                content,
+                Some(folder_path.to_string()),
+                Some(
+                    file_name
+                        .trim_end_matches(&format!(".{}", content.extension))
+                        .to_string(),
+                ),
+            )
+            .context("Failed to add drawing part relationship")
+    }
+
+    /// Add the anchor of the drawing object, object element is added by the caller
+    /// before the closing client data
+    fn append_anchor_mut<F>(
+        &mut self,
+        anchor: &DrawingAnchorValues,
+        append_object: F,
+    ) -> AnyResult<(), AnyError>
+    where
+        F: FnOnce(&mut XmlDocument, &usize, u32) -> AnyResult<(), AnyError>,
+    {
+        self.shape_id += 1;
+        let xml_document = self
+            .xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade drawing document"))?;
+        let mut xml_doc_mut = xml_document
+            .try_borrow_mut()
+            .context("Failed to get XML doc handle")?;
+        let anchor_id = match anchor {
+            DrawingAnchorValues::OneCell { cell_ref, size } => {
+                let anchor_id = xml_doc_mut
+                    .append_child_mut("xdr:oneCellAnchor", None)
+                    .context("Failed to insert one cell anchor")?
+                    .get_id();
+                let (row_index, col_index) = ConverterUtil::get_cell_index(cell_ref)
+                    .context("Failed to parse anchor cell reference")?;
+                append_marker_mut(
+                    &mut xml_doc_mut,
+                    &anchor_id,
+                    "xdr:from",
+                    row_index,
+                    col_index,
+                )?;
+                let (width, height) = size.get_emu();
+                xml_doc_mut.append_child_with_attributes_mut(
+                    "xdr:ext",
+                    Some(&anchor_id),
+                    vec![("cx", &width.to_string()), ("cy", &height.to_string())],
+                )?;
+                anchor_id
+            }
+            DrawingAnchorValues::TwoCell {
+                from_cell_ref,
+                to_cell_ref,
+            } => {
+                let anchor_id = xml_doc_mut.append_child_with_attributes_mut(
+                    "xdr:twoCellAnchor",
+                    None,
+                    vec![("editAs", "twoCell")],
+                )?;
+                let (from_row, from_col) = ConverterUtil::get_cell_index(from_cell_ref)
+                    .context("Failed to parse anchor start cell reference")?;
+                let (to_row, to_col) = ConverterUtil::get_cell_index(to_cell_ref)
+                    .context("Failed to parse anchor end cell reference")?;
+                append_marker_mut(&mut xml_doc_mut, &anchor_id, "xdr:from", from_row, from_col)?;
+                // Object end at the top left of the next cell to cover the end cell
+                append_marker_mut(
+                    &mut xml_doc_mut,
+                    &anchor_id,
+                    "xdr:to",
+                    to_row + 1,
+                    to_col + 1,
+                )?;
+                anchor_id
+            }
+            DrawingAnchorValues::Absolute { x, y, size } => {
+                let anchor_id = xml_doc_mut
+                    .append_child_mut("xdr:absoluteAnchor", None)
+                    .context("Failed to insert absolute anchor")?
+                    .get_id();
+                xml_doc_mut.append_child_with_attributes_mut(
+                    "xdr:pos",
+                    Some(&anchor_id),
+                    vec![("x", &x.to_string()), ("y", &y.to_string())],
+                )?;
+                let (width, height) = size.get_emu();
+                xml_doc_mut.append_child_with_attributes_mut(
+                    "xdr:ext",
+                    Some(&anchor_id),
+                    vec![("cx", &width.to_string()), ("cy", &height.to_string())],
+                )?;
+                anchor_id
+            }
+        };
+        append_object(&mut xml_doc_mut, &anchor_id, self.shape_id)
+            .context("Failed to insert drawing object")?;
+        xml_doc_mut
+            .append_child_mut("xdr:clientData", Some(&anchor_id))
+            .context("Failed to insert client data")?;
+        Ok(())
+    }
+}
+
+/// Add the cell position marker of the anchor, row and column index are 1 based
+fn append_marker_mut(
+    xml_doc_mut: &mut XmlDocument,
+    anchor_id: &usize,
+    tag: &str,
+    row_index: u32,
+    col_index: u16,
+) -> AnyResult<(), AnyError> {
+    let marker_id = xml_doc_mut
+        .append_child_mut(tag, Some(anchor_id))
+        .context("Failed to insert anchor marker")?
+        .get_id();
+    for (marker_tag, value) in [
+        ("xdr:col", (col_index - 1).to_string()),
+        ("xdr:colOff", "0".to_string()),
+        ("xdr:row", (row_index - 1).to_string()),
+        ("xdr:rowOff", "0".to_string()),
+    ] {
+        xml_doc_mut
+            .append_child_mut(marker_tag, Some(&marker_id))
+            .context("Failed to insert anchor marker value")?
+            .set_value_mut(value);
+    }
+    Ok(())
+}
+
+// ############################# Feature Function ######################################
+impl DrawingPart {
+    pub(crate) fn get_relationship_id(&self) -> &str {
+        &self.relationship_id
+    }
+
+    pub(crate) fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    /// Remove all the relations of the drawing. Returns the package path and type of the
+    /// media and chart parts it referred
+    pub(crate) fn take_part_paths_mut(&mut self) -> AnyResult<Vec<(String, String)>, AnyError> {
+        self.drawing_relationship_part
+            .try_borrow_mut()
+            .context("Failed to pull drawing relationship part")?
+            .take_relationships_mut()
+    }
+
+    /// Add picture referring the stored media. SVG media carry the PNG fallback
+    /// shown by the versions without SVG support
+    pub(crate) fn add_picture_mut(
+        &mut self,
+        anchor: &DrawingAnchorValues,
+        media: (&str, &Content),
+        svg_media: Option<(&str, &Content)>,
+    ) -> AnyResult<(), AnyError> {
+        let embed_id = self
+            .get_part_relationship_id_mut(media.0, media.1)
+            .context("Failed to get picture media relationship")?;
+        let svg_embed_id = if let Some((svg_file_path, svg_content)) = svg_media {
+            Some(
+                self.get_part_relationship_id_mut(svg_file_path, svg_content)
+                    .context("Failed to get picture svg relationship")?,
+            )
+        } else {
+            None
+        };
+        self.append_anchor_mut(anchor, |xml_doc_mut, anchor_id, shape_id| {
+            let picture_id = xml_doc_mut
+                .append_child_mut("xdr:pic", Some(anchor_id))
+                .context("Failed to insert picture")?
+                .get_id();
+            let non_visual_id = xml_doc_mut
+                .append_child_mut("xdr:nvPicPr", Some(&picture_id))
+                .context("Failed to insert picture non visual property")?
+                .get_id();
+            xml_doc_mut.append_child_with_attributes_mut(
+                "xdr:cNvPr",
+                Some(&non_visual_id),
+                vec![
+                    ("id", &shape_id.to_string()),
+                    ("name", &format!("Picture {}", shape_id - 1)),
+                ],
+            )?;
+            let picture_property_id = xml_doc_mut
+                .append_child_mut("xdr:cNvPicPr", Some(&non_visual_id))
+                .context("Failed to insert picture property")?
+                .get_id();
+            xml_doc_mut.append_child_with_attributes_mut(
+                "a:picLocks",
+                Some(&picture_property_id),
+                vec![("noChangeAspect", "1")],
+            )?;
+            let blip_fill_id = xml_doc_mut
+                .append_child_mut("xdr:blipFill", Some(&picture_id))
+                .context("Failed to insert picture blip fill")?
+                .get_id();
+            let blip_id = xml_doc_mut.append_child_with_attributes_mut(
+                "a:blip",
+                Some(&blip_fill_id),
+                vec![("r:embed", &embed_id)],
+            )?;
+            if let Some(svg_embed_id) = svg_embed_id {
+                let extension_list_id = xml_doc_mut
+                    .append_child_mut("a:extLst", Some(&blip_id))
+                    .context("Failed to insert blip extension list")?
+                    .get_id();
+                let extension_id = xml_doc_mut.append_child_with_attributes_mut(
+                    "a:ext",
+                    Some(&extension_list_id),
+                    vec![("uri", SVG_BLIP_EXTENSION_URI)],
+                )?;
+                xml_doc_mut.append_child_with_attributes_mut(
+                    "asvg:svgBlip",
+                    Some(&extension_id),
+                    vec![
+                        (
+                            "xmlns:asvg",
+                            "http://schemas.microsoft.com/office/drawing/2016/SVG/main",
+                        ),
+                        ("r:embed", &svg_embed_id),
+                    ],
+                )?;
+            }
+            let stretch_id = xml_doc_mut
+                .append_child_mut("a:stretch", Some(&blip_fill_id))
+                .context("Failed to insert picture stretch")?
+                .get_id();
+            xml_doc_mut
+                .append_child_mut("a:fillRect", Some(&stretch_id))
+                .context("Failed to insert picture fill rect")?;
+            let shape_property_id = xml_doc_mut
+                .append_child_mut("xdr:spPr", Some(&picture_id))
+                .context("Failed to insert picture shape property")?
+                .get_id();
+            let geometry_id = xml_doc_mut.append_child_with_attributes_mut(
+                "a:prstGeom",
+                Some(&shape_property_id),
+                vec![("prst", "rect")],
+            )?;
+            xml_doc_mut
+                .append_child_mut("a:avLst", Some(&geometry_id))
+                .context("Failed to insert picture geometry")?;
+            Ok(())
+        })
+    }
+
+    /// Add graphic frame showing the chart part
+    pub(crate) fn add_chart_mut(
+        &mut self,
+        anchor: &DrawingAnchorValues,
+        chart: (&str, &Content),
+    ) -> AnyResult<(), AnyError> {
+        let chart_relationship_id = self
+            .get_part_relationship_id_mut(chart.0, chart.1)
+            .context("Failed to get chart relationship")?;
+        self.append_anchor_mut(anchor, |xml_doc_mut, anchor_id, shape_id| {
+            let frame_id = xml_doc_mut.append_child_with_attributes_mut(
+                "xdr:graphicFrame",
+                Some(anchor_id),
+                vec![("macro", "")],
+            )?;
+            let non_visual_id = xml_doc_mut
+                .append_child_mut("xdr:nvGraphicFramePr", Some(&frame_id))
+                .context("Failed to insert graphic frame non visual property")?
+                .get_id();
+            xml_doc_mut.append_child_with_attributes_mut(
+                "xdr:cNvPr",
+                Some(&non_visual_id),
+                vec![
+                    ("id", &shape_id.to_string()),
+                    ("name", &format!("Chart {}", shape_id - 1)),
+                ],
+            )?;
+            xml_doc_mut
+                .append_child_mut("xdr:cNvGraphicFramePr", Some(&non_visual_id))
+                .context("Failed to insert graphic frame property")?;
+            let transform_id = xml_doc_mut
+                .append_child_mut("xdr:xfrm", Some(&frame_id))
+                .context("Failed to insert graphic frame transform")?
+                .get_id();
+            xml_doc_mut.append_child_with_attributes_mut(
+                "a:off",
+                Some(&transform_id),
+                vec![("x", "0"), ("y", "0")],
+            )?;
+            xml_doc_mut.append_child_with_attributes_mut(
+                "a:ext",
+                Some(&transform_id),
+                vec![("cx", "0"), ("cy", "0")],
+            )?;
+            let graphic_id = xml_doc_mut
+                .append_child_mut("a:graphic", Some(&frame_id))
+                .context("Failed to insert graphic")?
+                .get_id();
+            let graphic_data_id = xml_doc_mut.append_child_with_attributes_mut(
+                "a:graphicData",
+                Some(&graphic_id),
+                vec![("uri", chart.1.schemas_namespace)],
+            )?;
+            xml_doc_mut.append_child_with_attributes_mut(
+                "c:chart",
+                Some(&graphic_data_id),
+                vec![
+                    ("xmlns:c", chart.1.schemas_namespace),
+                    ("r:id", &chart_relationship_id),
+                ],
+            )?;
+            Ok(())
+        })
+    }
+}
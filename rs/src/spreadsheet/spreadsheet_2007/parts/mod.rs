@@ -1,5 +1,13 @@
+pub(crate) mod comment;
+pub(crate) mod drawing;
+pub(crate) mod table;
+pub(crate) mod threaded_comment;
 pub(crate) mod workbook;
 pub mod worksheet;
 
+pub(crate) use comment::*;
+pub(crate) use drawing::*;
+pub(crate) use table::*;
+pub(crate) use threaded_comment::*;
 pub(crate) use workbook::*;
 pub use worksheet::*;
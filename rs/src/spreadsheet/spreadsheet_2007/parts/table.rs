@@ -0,0 +1,372 @@
+use crate::{
+    element_dictionary::EXCEL_TYPE_COLLECTION,
+    files::{OfficeDocument, XmlDocument, XmlSerializer},
+    global_2007::traits::{Enum, XmlDocumentPartCommon},
+    log_elapsed,
+    order_dictionary::EXCEL_ORDER_COLLECTION,
+    spreadsheet_2007::models::{Table, TableColumn, TableSetting, TableTotalsRowFunctionValues},
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::{cell::RefCell, collections::HashMap, rc::Weak};
+
+#[derive(Debug)]
+pub(crate) struct TablePart {
+    office_document: Weak<RefCell<OfficeDocument>>,
+    xml_document: Weak<RefCell<XmlDocument>>,
+    file_path: String,
+    /// Relationship id of the table in the sheet relationship
+    relationship_id: String,
+    table_id: u32,
+    table: Table,
+}
+
+impl Drop for TablePart {
+    fn drop(&mut self) {
+        let _ = self.close_document();
+    }
+}
+
+impl XmlDocumentPartCommon for TablePart {
+    /// Initialize xml content for this part from base template
+    fn initialize_content_xml() -> AnyResult<(XmlDocument, Option<String>, String, String), AnyError>
+    {
+        let content = EXCEL_TYPE_COLLECTION.get("table").unwrap();
+        let template_core_properties = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<table xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"></table>"#;
+        Ok((
+            XmlSerializer::vec_to_xml_doc_tree(
+                template_core_properties.as_bytes().to_vec(),
+                "Default Table",
+            )
+            .context("Initializing Table Failed")?,
+            Some(content.content_type.to_string()),
+            content.extension.to_string(),
+            content.extension_type.to_string(),
+        ))
+    }
+
+    fn close_document(&mut self) -> AnyResult<(), AnyError>
+    where
+        Self: Sized,
+    {
+        log_elapsed!(
+            || {
+                if let Some(xml_document) = self.xml_document.upgrade() {
+                    let mut xml_doc_mut = xml_document
+                        .try_borrow_mut()
+                        .context("Failed to Pull XML Handle")?;
+                    self.serialize_table(&mut xml_doc_mut)
+                        .context("Failed to serialize table")?;
+                }
+                if let Some(office_document) = self.office_document.upgrade() {
+                    office_document
+                        .try_borrow_mut()
+                        .context("Failed to pull office document")?
+                        .close_xml_document(&self.file_path)
+                        .context("Failed to close the table document")?;
+                }
+                Ok(())
+            },
+            "Close Table"
+        )
+    }
+}
+
+// ############################# Internal Function ######################################
+impl TablePart {
+    /// Open the table part, new part start with empty table to be set by the caller
+    pub(crate) fn new(
+        office_document: Weak<RefCell<OfficeDocument>>,
+        file_path: &str,
+        relationship_id: &str,
+    ) -> AnyResult<Self, AnyError> {
+        let xml_document = Self::get_xml_document(&office_document, file_path)?;
+        let (table_id, table) = Self::deserialize_table(&xml_document)
+            .context(format!("Failed to load table : {}", file_path))?;
+        Ok(Self {
+            office_document,
+            xml_document,
+            file_path: file_path.to_string(),
+            relationship_id: relationship_id.to_string(),
+            table_id,
+            table,
+        })
+    }
+
+    fn deserialize_table(
+        xml_document: &Weak<RefCell<XmlDocument>>,
+    ) -> AnyResult<(u32, Table), AnyError> {
+        let xml_document = xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade table document"))?;
+        let mut xml_doc_mut = xml_document
+            .try_borrow_mut()
+            .context("Failed to get XML doc handle")?;
+        let attributes = xml_doc_mut
+            .get_root()
+            .ok_or(anyhow!("Table root element missing"))?
+            .get_attribute()
+            .cloned()
+            .unwrap_or_default();
+        let mut table = Table {
+            name: attributes
+                .get("displayName")
+                .or(attributes.get("name"))
+                .cloned()
+                .unwrap_or_default(),
+            range_ref: attributes.get("ref").cloned().unwrap_or_default(),
+            columns: Vec::new(),
+            setting: TableSetting {
+                style_name: None,
+                show_header_row: attributes
+                    .get("headerRowCount")
+                    .is_none_or(|header_row_count| header_row_count != "0"),
+                show_totals_row: attributes
+                    .get("totalsRowCount")
+                    .is_some_and(|totals_row_count| totals_row_count != "0"),
+                show_row_stripes: false,
+                ..Default::default()
+            },
+        };
+        let table_id = attributes
+            .get("id")
+            .map_or(Ok(0), |table_id| table_id.parse::<u32>())
+            .context("Failed to parse table id")?;
+        if let Some(mut table_columns_element) =
+            xml_doc_mut.pop_elements_by_tag_mut("tableColumns", None)
+        {
+            if let Some(table_columns) = table_columns_element.pop() {
+                while let Some((table_column_id, _)) = table_columns.pop_child_mut() {
+                    if let Some(table_column_element) =
+                        xml_doc_mut.pop_element_mut(&table_column_id)
+                    {
+                        let column_attributes = table_column_element
+                            .get_attribute()
+                            .cloned()
+                            .unwrap_or_default();
+                        let mut table_column = TableColumn {
+                            totals_row_function: TableTotalsRowFunctionValues::get_enum(
+                                column_attributes
+                                    .get("totalsRowFunction")
+                                    .map_or("none", |value| value.as_str()),
+                            ),
+                            totals_row_label: column_attributes.get("totalsRowLabel").cloned(),
+                            ..TableColumn::new(
+                                column_attributes
+                                    .get("name")
+                                    .map_or("", |value| value.as_str()),
+                            )
+                        };
+                        while let Some((formula_id, _)) = table_column_element.pop_child_mut() {
+                            if let Some(formula_element) = xml_doc_mut.pop_element_mut(&formula_id)
+                            {
+                                match formula_element.get_tag() {
+                                    "totalsRowFormula" => {
+                                        table_column.totals_row_formula =
+                                            formula_element.get_value().clone()
+                                    }
+                                    "calculatedColumnFormula" => {
+                                        table_column.calculated_column_formula =
+                                            formula_element.get_value().clone()
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        table.columns.push(table_column);
+                    }
+                }
+            }
+        }
+        if let Some(mut table_style_info_element) =
+            xml_doc_mut.pop_elements_by_tag_mut("tableStyleInfo", None)
+        {
+            if let Some(table_style_info) = table_style_info_element.pop() {
+                if let Some(style_attributes) = table_style_info.get_attribute() {
+                    let is_enabled = |key: &str| {
+                        style_attributes
+                            .get(key)
+                            .is_some_and(|value| matches!(value.as_str(), "1" | "true"))
+                    };
+                    table.setting.style_name = style_attributes.get("name").cloned();
+                    table.setting.show_row_stripes = is_enabled("showRowStripes");
+                    table.setting.show_column_stripes = is_enabled("showColumnStripes");
+                    table.setting.show_first_column = is_enabled("showFirstColumn");
+                    table.setting.show_last_column = is_enabled("showLastColumn");
+                }
+            }
+        }
+        Ok((table_id, table))
+    }
+
+    fn serialize_table(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        let root_element = xml_doc_mut
+            .get_root_mut()
+            .ok_or(anyhow!("Table root element missing"))?;
+        // Update the known attributes, leave the rest from loaded file as is
+        let mut attributes = root_element.get_attribute().cloned().unwrap_or_default();
+        attributes.insert("id".to_string(), self.table_id.to_string());
+        attributes.insert("name".to_string(), self.table.name.clone());
+        attributes.insert("displayName".to_string(), self.table.name.clone());
+        attributes.insert("ref".to_string(), self.table.range_ref.clone());
+        if self.table.setting.show_header_row {
+            attributes.remove("headerRowCount");
+        } else {
+            attributes.insert("headerRowCount".to_string(), "0".to_string());
+        }
+        if self.table.setting.show_totals_row {
+            attributes.insert("totalsRowCount".to_string(), "1".to_string());
+            attributes.remove("totalsRowShown");
+        } else {
+            attributes.remove("totalsRowCount");
+            attributes.insert("totalsRowShown".to_string(), "0".to_string());
+        }
+        root_element
+            .set_attribute_mut(attributes)
+            .context("Failed to set table attribute")?;
+        // Filter buttons are part of the header row
+        if self.table.setting.show_header_row {
+            let auto_filter_ref = self.get_auto_filter_ref()?;
+            if let Some(auto_filter_id) = xml_doc_mut
+                .get_first_element_id(vec!["autoFilter"], None)
+                .context("Failed to find auto filter element")?
+            {
+                let auto_filter = xml_doc_mut
+                    .get_element_mut(&auto_filter_id)
+                    .ok_or(anyhow!("Failed to get auto filter element"))?;
+                let mut attributes = auto_filter.get_attribute().cloned().unwrap_or_default();
+                attributes.insert("ref".to_string(), auto_filter_ref);
+                auto_filter
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set auto filter attribute")?;
+            } else {
+                xml_doc_mut
+                    .append_child_mut("autoFilter", None)
+                    .context("Failed to insert auto filter element")?
+                    .set_attribute_mut(HashMap::from([("ref".to_string(), auto_filter_ref)]))
+                    .context("Failed to set auto filter attribute")?;
+            }
+        } else {
+            xml_doc_mut.pop_elements_by_tag_mut("autoFilter", None);
+            xml_doc_mut.pop_elements_by_tag_mut("sortState", None);
+        }
+        let table_columns_id = xml_doc_mut
+            .append_child_mut("tableColumns", None)
+            .context("Failed to insert table columns element")?
+            .set_attribute_mut(HashMap::from([(
+                "count".to_string(),
+                self.table.columns.len().to_string(),
+            )]))
+            .context("Failed to set table columns attribute")?
+            .get_id();
+        for (index, table_column) in self.table.columns.iter().enumerate() {
+            let mut attributes = HashMap::from([
+                ("id".to_string(), (index + 1).to_string()),
+                ("name".to_string(), table_column.name.clone()),
+            ]);
+            if table_column.totals_row_function != TableTotalsRowFunctionValues::None {
+                attributes.insert(
+                    "totalsRowFunction".to_string(),
+                    TableTotalsRowFunctionValues::get_string(
+                        table_column.totals_row_function.clone(),
+                    ),
+                );
+            }
+            if let Some(totals_row_label) = table_column.totals_row_label.as_ref() {
+                attributes.insert("totalsRowLabel".to_string(), totals_row_label.clone());
+            }
+            let table_column_id = xml_doc_mut
+                .append_child_mut("tableColumn", Some(&table_columns_id))
+                .context("Failed to insert table column element")?
+                .set_attribute_mut(attributes)
+                .context("Failed to set table column attribute")?
+                .get_id();
+            for (tag, formula) in [
+                (
+                    "calculatedColumnFormula",
+                    table_column.calculated_column_formula.as_ref(),
+                ),
+                ("totalsRowFormula", table_column.totals_row_formula.as_ref()),
+            ] {
+                if let Some(formula) = formula {
+                    xml_doc_mut
+                        .append_child_mut(tag, Some(&table_column_id))
+                        .context("Failed to insert table column formula")?
+                        .set_value_mut(formula.clone());
+                }
+            }
+        }
+        let mut style_attributes = HashMap::new();
+        if let Some(style_name) = self.table.setting.style_name.as_ref() {
+            style_attributes.insert("name".to_string(), style_name.clone());
+        }
+        for (key, enabled) in [
+            ("showFirstColumn", self.table.setting.show_first_column),
+            ("showLastColumn", self.table.setting.show_last_column),
+            ("showRowStripes", self.table.setting.show_row_stripes),
+            ("showColumnStripes", self.table.setting.show_column_stripes),
+        ] {
+            style_attributes.insert(key.to_string(), if enabled { "1" } else { "0" }.to_string());
+        }
+        xml_doc_mut
+            .append_child_mut("tableStyleInfo", None)
+            .context("Failed to insert table style element")?
+            .set_attribute_mut(style_attributes)
+            .context("Failed to set table style attribute")?;
+        if let Some(root_element) = xml_doc_mut.get_root_mut() {
+            root_element
+                .order_child_mut(
+                    EXCEL_ORDER_COLLECTION
+                        .get("table")
+                        .ok_or(anyhow!("Failed to get table default order"))?,
+                )
+                .context("Failed Reorder the element child's")?;
+        }
+        Ok(())
+    }
+
+    /// Table range without the totals row
+    fn get_auto_filter_ref(&self) -> AnyResult<String, AnyError> {
+        if !self.table.setting.show_totals_row {
+            return Ok(self.table.range_ref.clone());
+        }
+        let (start_ref, end_ref) = self
+            .table
+            .range_ref
+            .split_once(":")
+            .ok_or(anyhow!("Invalid table range : {}", self.table.range_ref))?;
+        let row_position = end_ref
+            .find(|character: char| character.is_ascii_digit())
+            .ok_or(anyhow!("Invalid table range : {}", self.table.range_ref))?;
+        let end_row = end_ref[row_position..]
+            .parse::<u32>()
+            .context("Failed to parse table end row")?;
+        Ok(format!(
+            "{}:{}{}",
+            start_ref,
+            &end_ref[..row_position],
+            end_row - 1
+        ))
+    }
+
+    pub(crate) fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    pub(crate) fn get_relationship_id(&self) -> &str {
+        &self.relationship_id
+    }
+
+    pub(crate) fn get_table_id(&self) -> u32 {
+        self.table_id
+    }
+
+    pub(crate) fn get_table(&self) -> &Table {
+        &self.table
+    }
+
+    pub(crate) fn set_table_mut(&mut self, table_id: u32, table: Table) {
+        self.table_id = table_id;
+        self.table = table;
+    }
+}
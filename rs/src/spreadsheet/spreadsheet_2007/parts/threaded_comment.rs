@@ -0,0 +1,293 @@
+use crate::{
+    converters::ConverterUtil,
+    element_dictionary::EXCEL_TYPE_COLLECTION,
+    files::{OfficeDocument, XmlDocument, XmlSerializer},
+    global_2007::traits::XmlDocumentPartCommon,
+    log_elapsed,
+    spreadsheet_2007::{
+        models::{ThreadedComment, ThreadedCommentReply},
+        services::{get_new_guid, CommonServices},
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    rc::Weak,
+};
+
+/// Thread id with the thread of the cell keyed by (row, column)
+pub(crate) type ThreadedCommentCollection = BTreeMap<(u32, u16), (String, ThreadedComment)>;
+
+#[derive(Debug)]
+pub(crate) struct ThreadedCommentPart {
+    office_document: Weak<RefCell<OfficeDocument>>,
+    xml_document: Weak<RefCell<XmlDocument>>,
+    common_service: Weak<RefCell<CommonServices>>,
+    file_path: String,
+    /// Relationship id of the threaded comments in the sheet relationship
+    relationship_id: String,
+    threaded_comments: ThreadedCommentCollection,
+}
+
+impl Drop for ThreadedCommentPart {
+    fn drop(&mut self) {
+        let _ = self.close_document();
+    }
+}
+
+impl XmlDocumentPartCommon for ThreadedCommentPart {
+    /// Initialize xml content for this part from base template
+    fn initialize_content_xml() -> AnyResult<(XmlDocument, Option<String>, String, String), AnyError>
+    {
+        let content = EXCEL_TYPE_COLLECTION.get("threaded_comment").unwrap();
+        let template_core_properties = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<ThreadedComments xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments"
+    xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main"></ThreadedComments>"#;
+        Ok((
+            XmlSerializer::vec_to_xml_doc_tree(
+                template_core_properties.as_bytes().to_vec(),
+                "Default Threaded Comments",
+            )
+            .context("Initializing Threaded Comments Failed")?,
+            Some(content.content_type.to_string()),
+            content.extension.to_string(),
+            content.extension_type.to_string(),
+        ))
+    }
+
+    fn close_document(&mut self) -> AnyResult<(), AnyError>
+    where
+        Self: Sized,
+    {
+        log_elapsed!(
+            || {
+                if let Some(xml_document) = self.xml_document.upgrade() {
+                    let mut xml_doc_mut = xml_document
+                        .try_borrow_mut()
+                        .context("Failed to Pull XML Handle")?;
+                    self.serialize_threaded_comments(&mut xml_doc_mut)
+                        .context("Failed to serialize threaded comments")?;
+                }
+                if let Some(office_document) = self.office_document.upgrade() {
+                    office_document
+                        .try_borrow_mut()
+                        .context("Failed to pull office document")?
+                        .close_xml_document(&self.file_path)
+                        .context("Failed to close the threaded comments document")?;
+                }
+                Ok(())
+            },
+            "Close Threaded Comments"
+        )
+    }
+}
+
+// ############################# Internal Function ######################################
+impl ThreadedCommentPart {
+    /// Open the threaded comments part, new part start without comments
+    pub(crate) fn new(
+        office_document: Weak<RefCell<OfficeDocument>>,
+        common_service: Weak<RefCell<CommonServices>>,
+        file_path: &str,
+        relationship_id: &str,
+    ) -> AnyResult<Self, AnyError> {
+        let xml_document = Self::get_xml_document(&office_document, file_path)?;
+        let threaded_comments = Self::deserialize_threaded_comments(&xml_document, &common_service)
+            .context(format!("Failed to load threaded comments : {}", file_path))?;
+        Ok(Self {
+            office_document,
+            xml_document,
+            common_service,
+            file_path: file_path.to_string(),
+            relationship_id: relationship_id.to_string(),
+            threaded_comments,
+        })
+    }
+
+    fn deserialize_threaded_comments(
+        xml_document: &Weak<RefCell<XmlDocument>>,
+        common_service: &Weak<RefCell<CommonServices>>,
+    ) -> AnyResult<ThreadedCommentCollection, AnyError> {
+        let mut threaded_comments: ThreadedCommentCollection = BTreeMap::new();
+        let xml_document = xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade threaded comments document"))?;
+        let mut xml_doc_mut = xml_document
+            .try_borrow_mut()
+            .context("Failed to get XML doc handle")?;
+        let common_service = common_service
+            .upgrade()
+            .ok_or(anyhow!("Failed to pull common service"))?;
+        let common_service = common_service
+            .try_borrow()
+            .context("Failed to pull common service")?;
+        for threaded_comment_element in xml_doc_mut
+            .pop_elements_by_tag_mut("threadedComment", None)
+            .unwrap_or_default()
+        {
+            let attributes = threaded_comment_element
+                .get_attribute()
+                .cloned()
+                .unwrap_or_default();
+            let mut text = String::new();
+            while let Some((child_id, _)) = threaded_comment_element.pop_child_mut() {
+                if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                    if child_element.get_tag() == "text" {
+                        text = child_element.get_value().clone().unwrap_or_default();
+                    }
+                }
+            }
+            let author = attributes
+                .get("personId")
+                .and_then(|person_id| common_service.get_person_name(person_id))
+                .unwrap_or_default();
+            let cell_key = ConverterUtil::get_cell_index(
+                attributes
+                    .get("ref")
+                    .ok_or(anyhow!("Threaded comment reference missing"))?,
+            )
+            .context("Failed to parse threaded comment reference")?;
+            if attributes.contains_key("parentId") {
+                if let Some((_, threaded_comment)) = threaded_comments.get_mut(&cell_key) {
+                    threaded_comment
+                        .replies
+                        .push(ThreadedCommentReply { author, text });
+                }
+            } else {
+                threaded_comments.insert(
+                    cell_key,
+                    (
+                        attributes.get("id").cloned().unwrap_or_else(get_new_guid),
+                        ThreadedComment {
+                            author,
+                            text,
+                            done: attributes.get("done").is_some_and(|done| {
+                                ConverterUtil::normalize_bool_property_bool(done)
+                            }),
+                            replies: Vec::new(),
+                        },
+                    ),
+                );
+            }
+        }
+        Ok(threaded_comments)
+    }
+
+    fn serialize_threaded_comments(
+        &mut self,
+        xml_doc_mut: &mut XmlDocument,
+    ) -> AnyResult<(), AnyError> {
+        let common_service = self
+            .common_service
+            .upgrade()
+            .ok_or(anyhow!("Failed to pull common service"))?;
+        let mut common_service = common_service
+            .try_borrow_mut()
+            .context("Failed to pull common service")?;
+        for ((row_index, col_index), (thread_id, threaded_comment)) in self.threaded_comments.iter()
+        {
+            let cell_ref = format!(
+                "{}{}",
+                ConverterUtil::get_column_ref(*col_index)
+                    .context("Failed to get threaded comment column ref")?,
+                row_index
+            );
+            let mut attributes = HashMap::from([
+                ("ref".to_string(), cell_ref.clone()),
+                (
+                    "personId".to_string(),
+                    common_service.get_person_id_mut(&threaded_comment.author),
+                ),
+                ("id".to_string(), thread_id.to_string()),
+            ]);
+            if threaded_comment.done {
+                attributes.insert("done".to_string(), "1".to_string());
+            }
+            let mut thread = vec![(attributes, threaded_comment.text.clone())];
+            for reply in threaded_comment.replies.iter() {
+                thread.push((
+                    HashMap::from([
+                        ("ref".to_string(), cell_ref.clone()),
+                        (
+                            "personId".to_string(),
+                            common_service.get_person_id_mut(&reply.author),
+                        ),
+                        ("id".to_string(), get_new_guid()),
+                        ("parentId".to_string(), thread_id.to_string()),
+                    ]),
+                    reply.text.clone(),
+                ));
+            }
+            for (attributes, text) in thread {
+                let threaded_comment_id = xml_doc_mut
+                    .append_child_mut("threadedComment", None)
+                    .context("Failed to insert threaded comment element")?
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set threaded comment attribute")?
+                    .get_id();
+                xml_doc_mut
+                    .append_child_mut("text", Some(&threaded_comment_id))
+                    .context("Failed to insert threaded comment text")?
+                    .set_value_mut(text);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Read only note text Excel shows for the thread in the versions without threaded comments
+pub(crate) fn get_threaded_comment_placeholder(threaded_comment: &ThreadedComment) -> String {
+    let mut placeholder = format!(
+        "[Threaded comment]\n\nYour version of Excel allows you to read this threaded comment; however, any edits to it will get removed if the file is opened in a newer version of Excel. Learn more: https://go.microsoft.com/fwlink/?linkid=870924\n\nComment:\n    {}",
+        threaded_comment.text
+    );
+    for reply in threaded_comment.replies.iter() {
+        placeholder.push_str(&format!("\nReply:\n    {}", reply.text));
+    }
+    placeholder
+}
+
+// ############################# Feature Function ######################################
+impl ThreadedCommentPart {
+    pub(crate) fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    pub(crate) fn get_relationship_id(&self) -> &str {
+        &self.relationship_id
+    }
+
+    pub(crate) fn get_threaded_comments(&self) -> &ThreadedCommentCollection {
+        &self.threaded_comments
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.threaded_comments.is_empty()
+    }
+
+    /// Set the thread of the cell, existing thread keep its id. Return the thread id
+    pub(crate) fn set_threaded_comment_mut(
+        &mut self,
+        cell_key: (u32, u16),
+        threaded_comment: ThreadedComment,
+    ) -> String {
+        let thread_id = self
+            .threaded_comments
+            .get(&cell_key)
+            .map(|(thread_id, _)| thread_id.clone())
+            .unwrap_or_else(get_new_guid);
+        self.threaded_comments
+            .insert(cell_key, (thread_id.clone(), threaded_comment));
+        thread_id
+    }
+
+    pub(crate) fn remove_threaded_comment_mut(
+        &mut self,
+        cell_key: &(u32, u16),
+    ) -> Option<ThreadedComment> {
+        self.threaded_comments
+            .remove(cell_key)
+            .map(|(_, threaded_comment)| threaded_comment)
+    }
+}
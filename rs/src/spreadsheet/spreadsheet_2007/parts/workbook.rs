@@ -9,9 +9,18 @@ use crate::{
     log_elapsed,
     order_dictionary::EXCEL_ORDER_COLLECTION,
     spreadsheet_2007::{
-        models::{StyleId, StyleSetting},
+        models::{
+            defined_name::PRINT_TITLES_NAME, DefinedName, FormulaCalculationReport, StyleId,
+            StyleSetting, WorkbookProtection,
+        },
         parts::WorkSheet,
-        services::{CalculationChainPart, CommonServices, ShareStringPart, StylePart},
+        services::{
+            CalculationChainPart, CommonServices, FormulaCalculator, PersonPart, ShareStringPart,
+            StylePart,
+        },
+    },
+    utils::password_hash::{
+        filter_password_hash_attributes, get_password_hash_attributes, PasswordHashAttributes,
     },
 };
 use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
@@ -21,6 +30,11 @@ use std::{
     rc::{Rc, Weak},
 };
 
+/// Built in sheet scoped name of the print range
+const PRINT_AREA_NAME: &str = "_xlnm.Print_Area";
+/// Attribute prefix of the workbook password hash
+const WORKBOOK_HASH_PREFIX: &str = "workbook";
+
 #[derive(Debug)]
 pub struct WorkbookPart {
     office_document: Weak<RefCell<OfficeDocument>>,
@@ -31,6 +45,11 @@ pub struct WorkbookPart {
     theme_part: ThemePart,
     /// This contain the sheet name, relationId, active sheet, hide sheet
     sheet_collection: Rc<RefCell<Vec<(String, String, bool, bool)>>>,
+    /// This contain the table id, table name and table file path of all sheets
+    table_collection: Rc<RefCell<Vec<(u32, String, String)>>>,
+    defined_name_collection: Rc<RefCell<Vec<DefinedName>>>,
+    /// Workbook protection with its password hash attributes
+    workbook_protection: Option<(WorkbookProtection, PasswordHashAttributes)>,
     workbook_view: Option<WorkbookView>,
 }
 
@@ -210,6 +229,12 @@ impl XmlDocumentPartCommon for WorkbookPart {
                             .context("Sheet Attributes Failed")?;
                         sheet_count += 1;
                     }
+                    // Create and set Defined Names
+                    self.serialize_defined_names(&mut xml_doc_mut)
+                        .context("Failed to set Defined Names")?;
+                    // Create and set Workbook Protection
+                    self.serialize_workbook_protection(&mut xml_doc_mut)
+                        .context("Failed to set Workbook Protection")?;
                     if let Some(root_element) = xml_doc_mut.get_root_mut() {
                         root_element
                             .order_child_mut(
@@ -280,13 +305,27 @@ impl XmlDocumentPart for WorkbookPart {
                     Rc::downgrade(&workbook_relationship_part),
                 )
                 .context("Loading Style Part Failed")?;
+                // Person
+                let person = PersonPart::new(
+                    office_document.clone(),
+                    Rc::downgrade(&workbook_relationship_part),
+                )
+                .context("Loading Person Part Failed")?;
                 let common_service = Rc::new(RefCell::new(CommonServices::new(
                     calculation_chain,
                     share_string,
                     style,
+                    person,
                 )));
                 let (sheet_collection, workbook_view) =
                     Self::load_sheet_names(&mut file_tree).context("Loading Sheet Names Failed")?;
+                let table_collection = Self::load_table_collection(&office_document)
+                    .context("Loading Table Collection Failed")?;
+                let defined_name_collection =
+                    Self::load_defined_names(&mut file_tree, &sheet_collection)
+                        .context("Loading Defined Names Failed")?;
+                let workbook_protection = Self::load_workbook_protection(&mut file_tree)
+                    .context("Loading Workbook Protection Failed")?;
                 Ok(Self {
                     office_document,
                     xml_document: file_tree,
@@ -295,6 +334,9 @@ impl XmlDocumentPart for WorkbookPart {
                     workbook_relationship_part,
                     theme_part,
                     sheet_collection: Rc::new(RefCell::new(sheet_collection)),
+                    table_collection: Rc::new(RefCell::new(table_collection)),
+                    defined_name_collection: Rc::new(RefCell::new(defined_name_collection)),
+                    workbook_protection,
                     workbook_view,
                 })
             },
@@ -386,8 +428,9 @@ impl WorkbookPart {
                                                 minimize: if let Some(minimize) =
                                                     attributes.get("minimized")
                                                 {
-                                                    ConverterUtil::normalize_bool_property_u8(minimize)
-                                                        == 1
+                                                    ConverterUtil::normalize_bool_property_u8(
+                                                        minimize,
+                                                    ) == 1
                                                 } else {
                                                     false
                                                 },
@@ -463,6 +506,187 @@ impl WorkbookPart {
             "Load Existing Workbook"
         )
     }
+
+    /// Read defined names, local sheet id is resolved to the sheet name
+    fn load_defined_names(
+        xml_document: &mut Weak<RefCell<XmlDocument>>,
+        sheet_collection: &[(String, String, bool, bool)],
+    ) -> AnyResult<Vec<DefinedName>, AnyError> {
+        let mut defined_name_collection = Vec::new();
+        if let Some(xml_document) = xml_document.upgrade() {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("xml doc borrow failed")?;
+            if let Some(mut defined_names_vec) =
+                xml_doc_mut.pop_elements_by_tag_mut("definedNames", None)
+            {
+                if let Some(defined_names) = defined_names_vec.pop() {
+                    while let Some((defined_name_id, _)) = defined_names.pop_child_mut() {
+                        if let Some(defined_name) = xml_doc_mut.pop_element_mut(&defined_name_id) {
+                            let attributes =
+                                defined_name.get_attribute().cloned().unwrap_or_default();
+                            defined_name_collection.push(DefinedName {
+                                name: attributes
+                                    .get("name")
+                                    .ok_or(anyhow!("Defined name missing name attribute"))?
+                                    .to_string(),
+                                value: defined_name.get_value().clone().unwrap_or_default(),
+                                sheet_name: attributes
+                                    .get("localSheetId")
+                                    .and_then(|local_sheet_id| local_sheet_id.parse::<usize>().ok())
+                                    .and_then(|local_sheet_id| sheet_collection.get(local_sheet_id))
+                                    .map(|(sheet_name, _, _, _)| sheet_name.to_string()),
+                                hidden: attributes.get("hidden").is_some_and(|hidden| {
+                                    ConverterUtil::normalize_bool_property_bool(hidden)
+                                }),
+                                comment: attributes.get("comment").cloned(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(defined_name_collection)
+    }
+
+    /// Write defined names, sheet scoped name of missing sheet is dropped
+    fn serialize_defined_names(&self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        let sheet_collection = self
+            .sheet_collection
+            .try_borrow()
+            .context("Failed to pull Sheet Name Collection")?;
+        let defined_name_collection = self
+            .defined_name_collection
+            .try_borrow()
+            .context("Failed to pull Defined Name Collection")?;
+        if !defined_name_collection.is_empty() {
+            let defined_names_id = xml_doc_mut
+                .append_child_mut("definedNames", None)
+                .context("Create Defined Names Node Failed")?
+                .get_id();
+            for defined_name in defined_name_collection.iter() {
+                let mut attributes =
+                    HashMap::from([("name".to_string(), defined_name.name.clone())]);
+                if let Some(sheet_name) = &defined_name.sheet_name {
+                    if let Some(local_sheet_id) = sheet_collection
+                        .iter()
+                        .position(|(current_sheet_name, _, _, _)| current_sheet_name == sheet_name)
+                    {
+                        attributes.insert("localSheetId".to_string(), local_sheet_id.to_string());
+                    } else {
+                        continue;
+                    }
+                }
+                if defined_name.hidden {
+                    attributes.insert("hidden".to_string(), "1".to_string());
+                }
+                if let Some(comment) = &defined_name.comment {
+                    attributes.insert("comment".to_string(), comment.clone());
+                }
+                xml_doc_mut
+                    .append_child_mut("definedName", Some(&defined_names_id))
+                    .context("Create Defined Name Node Failed")?
+                    .set_attribute_mut(attributes)
+                    .context("Defined Name Attributes Failed")?
+                    .set_value_mut(defined_name.value.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Read workbook protection, password hash is kept as loaded
+    fn load_workbook_protection(
+        xml_document: &mut Weak<RefCell<XmlDocument>>,
+    ) -> AnyResult<Option<(WorkbookProtection, PasswordHashAttributes)>, AnyError> {
+        if let Some(xml_document) = xml_document.upgrade() {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("xml doc borrow failed")?;
+            if let Some(mut workbook_protection_vec) =
+                xml_doc_mut.pop_elements_by_tag_mut("workbookProtection", None)
+            {
+                if let Some(workbook_protection) = workbook_protection_vec.pop() {
+                    let attributes = workbook_protection
+                        .get_attribute()
+                        .cloned()
+                        .unwrap_or_default();
+                    let get_flag = |key: &str| {
+                        attributes
+                            .get(key)
+                            .is_some_and(|value| ConverterUtil::normalize_bool_property_bool(value))
+                    };
+                    return Ok(Some((
+                        WorkbookProtection {
+                            password: None,
+                            lock_structure: get_flag("lockStructure"),
+                            lock_windows: get_flag("lockWindows"),
+                        },
+                        filter_password_hash_attributes(&attributes, WORKBOOK_HASH_PREFIX),
+                    )));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn serialize_workbook_protection(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+    ) -> AnyResult<(), AnyError> {
+        if let Some((workbook_protection, hash_attributes)) = &self.workbook_protection {
+            let mut attributes = hash_attributes.clone();
+            if workbook_protection.lock_structure {
+                attributes.insert("lockStructure".to_string(), "1".to_string());
+            }
+            if workbook_protection.lock_windows {
+                attributes.insert("lockWindows".to_string(), "1".to_string());
+            }
+            xml_doc_mut
+                .append_child_mut("workbookProtection", None)
+                .context("Create Workbook Protection Node Failed")?
+                .set_attribute_mut(attributes)
+                .context("Workbook Protection Attributes Failed")?;
+        }
+        Ok(())
+    }
+
+    /// Read id and name of the table parts, used to keep them unique across sheets
+    fn load_table_collection(
+        office_document: &Weak<RefCell<OfficeDocument>>,
+    ) -> AnyResult<Vec<(u32, String, String)>, AnyError> {
+        let mut table_collection = Vec::new();
+        if let Some(office_document) = office_document.upgrade() {
+            let office_doc = office_document
+                .try_borrow()
+                .context("Failed to Borrow Document")?;
+            let table_content = EXCEL_TYPE_COLLECTION.get("table").unwrap();
+            for file_path in office_doc.list_file_path_by_content_type(table_content.content_type) {
+                if let Some(xml_document) = office_doc
+                    .read_xml_tree(&file_path)
+                    .context("Failed to read table part")?
+                {
+                    if let Some(attributes) = xml_document
+                        .get_root()
+                        .and_then(|root_element| root_element.get_attribute())
+                    {
+                        table_collection.push((
+                            attributes
+                                .get("id")
+                                .and_then(|table_id| table_id.parse::<u32>().ok())
+                                .unwrap_or_default(),
+                            attributes
+                                .get("displayName")
+                                .or(attributes.get("name"))
+                                .cloned()
+                                .unwrap_or_default(),
+                            file_path,
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(table_collection)
+    }
 }
 
 // ############################# Feature Function ######################################
@@ -498,6 +722,99 @@ impl WorkbookPart {
             .map(|(sheet_name, _, _, _)| sheet_name.to_string())
             .collect::<Vec<String>>())
     }
+
+    /// Get workbook protection, password is never returned
+    pub(crate) fn get_workbook_protection(&self) -> Option<WorkbookProtection> {
+        self.workbook_protection
+            .as_ref()
+            .map(|(workbook_protection, _)| workbook_protection.clone())
+    }
+
+    pub(crate) fn list_defined_names(&self) -> AnyResult<Vec<DefinedName>, AnyError> {
+        Ok(self
+            .defined_name_collection
+            .try_borrow()
+            .context("Failed to pull Defined Name Collection")?
+            .clone())
+    }
+
+    /// Position of the defined name with the same name and scope
+    fn find_defined_name(
+        &self,
+        name: &str,
+        sheet_name: Option<&str>,
+    ) -> AnyResult<Option<usize>, AnyError> {
+        Ok(self
+            .defined_name_collection
+            .try_borrow()
+            .context("Failed to pull Defined Name Collection")?
+            .iter()
+            .position(|defined_name| {
+                defined_name.name.eq_ignore_ascii_case(name)
+                    && defined_name.sheet_name.as_deref() == sheet_name
+            }))
+    }
+
+    /// Quoted sheet prefix like 'Sheet 1'! of the existing sheet
+    fn get_sheet_prefix(&self, sheet_name: &str) -> AnyResult<String, AnyError> {
+        if self
+            .list_sheet_names()?
+            .iter()
+            .any(|current_sheet_name| current_sheet_name == sheet_name)
+        {
+            Ok(format!("'{}'!", sheet_name.replace("'", "''")))
+        } else {
+            Err(anyhow!("Sheet not found : {}", sheet_name))
+        }
+    }
+
+    /// Check name, scope and value of the user defined name
+    fn validate_defined_name(
+        &self,
+        defined_name: &mut DefinedName,
+        skip_position: Option<usize>,
+    ) -> AnyResult<(), AnyError> {
+        if !DefinedName::is_valid_name(&defined_name.name) {
+            return Err(anyhow!("Invalid defined name : {}", defined_name.name));
+        }
+        if defined_name.name.to_lowercase().starts_with("_xlnm.") {
+            return Err(anyhow!(
+                "Built in name {} is reserved, use the print setting function",
+                defined_name.name
+            ));
+        }
+        if let Some(sheet_name) = &defined_name.sheet_name {
+            self.get_sheet_prefix(sheet_name)?;
+        }
+        defined_name.value = defined_name
+            .value
+            .trim()
+            .trim_start_matches('=')
+            .to_string();
+        if defined_name.value.is_empty() {
+            return Err(anyhow!("Defined name {} needs a value", defined_name.name));
+        }
+        if let Some(position) =
+            self.find_defined_name(&defined_name.name, defined_name.sheet_name.as_deref())?
+        {
+            if Some(position) != skip_position {
+                return Err(anyhow!(
+                    "Defined name already exist : {}",
+                    defined_name.name
+                ));
+            }
+        }
+        if self
+            .table_collection
+            .try_borrow()
+            .context("Failed to pull table collection")?
+            .iter()
+            .any(|(_, table_name, _)| table_name.eq_ignore_ascii_case(&defined_name.name))
+        {
+            return Err(anyhow!("Table name already exist : {}", defined_name.name));
+        }
+        Ok(())
+    }
 }
 
 // ############################# mut Function ######################################
@@ -509,6 +826,8 @@ impl WorkbookPart {
         Ok(WorkSheet::new(
             self.office_document.clone(),
             Rc::downgrade(&self.sheet_collection),
+            Rc::downgrade(&self.table_collection),
+            Rc::downgrade(&self.defined_name_collection),
             Rc::downgrade(&self.workbook_relationship_part),
             Rc::downgrade(&self.common_service),
             sheet_name,
@@ -522,6 +841,8 @@ impl WorkbookPart {
                 WorkSheet::new(
                     self.office_document.clone(),
                     Rc::downgrade(&self.sheet_collection),
+                    Rc::downgrade(&self.table_collection),
+                    Rc::downgrade(&self.defined_name_collection),
                     Rc::downgrade(&self.workbook_relationship_part),
                     Rc::downgrade(&self.common_service),
                     Some(sheet_name.to_string()),
@@ -668,13 +989,240 @@ impl WorkbookPart {
                 .find(|item| item.0 == old_sheet_name)
             {
                 record.0 = new_sheet_name.to_string();
-                Ok(())
             } else {
-                Err(anyhow!("Old Sheet Name not found in the stack"))
+                return Err(anyhow!("Old Sheet Name not found in the stack"));
             }
+            // Move sheet scoped names and the reference inside defined names
+            let sheet_prefix = self.get_sheet_prefix(new_sheet_name)?;
+            for defined_name in self
+                .defined_name_collection
+                .try_borrow_mut()
+                .context("Failed to pull Defined Name Collection")?
+                .iter_mut()
+            {
+                if defined_name.sheet_name.as_deref() == Some(old_sheet_name) {
+                    defined_name.sheet_name = Some(new_sheet_name.to_string());
+                }
+                defined_name.replace_sheet_reference_mut(old_sheet_name, &sheet_prefix);
+            }
+            Ok(())
         }
     }
 
+    /// Add named range or named formula
+    pub(crate) fn add_defined_name_mut(
+        &mut self,
+        mut defined_name: DefinedName,
+    ) -> AnyResult<(), AnyError> {
+        self.validate_defined_name(&mut defined_name, None)?;
+        self.defined_name_collection
+            .try_borrow_mut()
+            .context("Failed to pull Defined Name Collection")?
+            .push(defined_name);
+        Ok(())
+    }
+
+    /// Replace the defined name found by name and scope
+    pub(crate) fn update_defined_name_mut(
+        &mut self,
+        name: &str,
+        sheet_name: Option<&str>,
+        mut defined_name: DefinedName,
+    ) -> AnyResult<(), AnyError> {
+        let position = self
+            .find_defined_name(name, sheet_name)?
+            .ok_or(anyhow!("Defined name not found : {}", name))?;
+        self.validate_defined_name(&mut defined_name, Some(position))?;
+        self.defined_name_collection
+            .try_borrow_mut()
+            .context("Failed to pull Defined Name Collection")?[position] = defined_name;
+        Ok(())
+    }
+
+    pub(crate) fn remove_defined_name_mut(
+        &mut self,
+        name: &str,
+        sheet_name: Option<&str>,
+    ) -> AnyResult<(), AnyError> {
+        let position = self
+            .find_defined_name(name, sheet_name)?
+            .ok_or(anyhow!("Defined name not found : {}", name))?;
+        self.defined_name_collection
+            .try_borrow_mut()
+            .context("Failed to pull Defined Name Collection")?
+            .remove(position);
+        Ok(())
+    }
+
+    /// Calculate the formula cells of all the sheets and store the results as cached values
+    pub(crate) fn calculate_formulas_mut(
+        &mut self,
+    ) -> AnyResult<FormulaCalculationReport, AnyError> {
+        let sheet_names = self.list_sheet_names()?;
+        if let Some(sheet_name) = self.find_open_sheet_name()? {
+            return Err(anyhow!(
+                "Sheet {} is still open, drop the sheet handle before calculating formulas",
+                sheet_name
+            ));
+        }
+        let mut formula_calculator =
+            FormulaCalculator::new(sheet_names.clone(), self.list_defined_names()?);
+        let mut worksheets = Vec::with_capacity(sheet_names.len());
+        for (sheet_index, sheet_name) in sheet_names.iter().enumerate() {
+            let worksheet = self
+                .get_worksheet_mut(sheet_name)
+                .context(format!("Failed to open the sheet {}", sheet_name))?;
+            for row in worksheet.iter_rows() {
+                let (row_index, cell_records) = row.context("Failed to read the sheet rows")?;
+                for (col_index, cell_record) in cell_records {
+                    formula_calculator.add_cell_mut(
+                        sheet_index,
+                        row_index,
+                        col_index,
+                        &cell_record.value,
+                        cell_record.formula.as_deref(),
+                    );
+                }
+            }
+            worksheets.push(worksheet);
+        }
+        let (calculated_values, report) = formula_calculator
+            .calculate_mut()
+            .context("Formula calculation failed")?;
+        for ((sheet_index, row_index, col_index), cell_value) in calculated_values {
+            worksheets[sheet_index]
+                .set_formula_value_mut(row_index, col_index, cell_value)
+                .context("Failed to store the calculated value")?;
+        }
+        // Sheets get saved back on drop
+        Ok(report)
+    }
+
+    /// Find the sheet having an open handle elsewhere
+    fn find_open_sheet_name(&self) -> AnyResult<Option<String>, AnyError> {
+        let office_document = self
+            .office_document
+            .upgrade()
+            .ok_or(anyhow!("Document Upgrade Handled Failed"))?;
+        let office_document = office_document
+            .try_borrow()
+            .context("Failed to Borrow Document")?;
+        let workbook_relationship_part = self
+            .workbook_relationship_part
+            .try_borrow()
+            .context("Failed to Get Workbook relationship")?;
+        for (sheet_name, rel_id, _, _) in self
+            .sheet_collection
+            .try_borrow()
+            .context("Failed to pull Sheet Collection Handle")?
+            .iter()
+        {
+            if let Some(file_path) = workbook_relationship_part
+                .get_target_by_id(rel_id)
+                .context("Failed to Get Target Path")?
+            {
+                if office_document.check_file_open(&file_path) {
+                    return Ok(Some(sheet_name.clone()));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Replace or remove the built in name of the sheet
+    fn set_built_in_name_mut(
+        &mut self,
+        name: &str,
+        sheet_name: &str,
+        value: Option<String>,
+    ) -> AnyResult<(), AnyError> {
+        let position = self.find_defined_name(name, Some(sheet_name))?;
+        let mut defined_name_collection = self
+            .defined_name_collection
+            .try_borrow_mut()
+            .context("Failed to pull Defined Name Collection")?;
+        match (position, value) {
+            (Some(position), Some(value)) => defined_name_collection[position].value = value,
+            (Some(position), None) => {
+                defined_name_collection.remove(position);
+            }
+            (None, Some(value)) => defined_name_collection.push(DefinedName {
+                sheet_name: Some(sheet_name.to_string()),
+                ..DefinedName::new(name, &value)
+            }),
+            (None, None) => {}
+        }
+        Ok(())
+    }
+
+    /// Set print area of the sheet, multiple area are space separated
+    pub(crate) fn set_print_area_mut(
+        &mut self,
+        sheet_name: &str,
+        range_ref: Option<&str>,
+    ) -> AnyResult<(), AnyError> {
+        let sheet_prefix = self.get_sheet_prefix(sheet_name)?;
+        let value = if let Some(range_ref) = range_ref {
+            let print_area = range_ref
+                .split_whitespace()
+                .map(|range_ref| {
+                    ConverterUtil::get_range_index(range_ref)
+                        .and_then(ConverterUtil::get_absolute_range_ref)
+                        .map(|range_ref| format!("{}{}", sheet_prefix, range_ref))
+                        .context("Failed to parse print area")
+                })
+                .collect::<AnyResult<Vec<String>, AnyError>>()?;
+            if print_area.is_empty() {
+                return Err(anyhow!("Print area is empty"));
+            }
+            Some(print_area.join(","))
+        } else {
+            None
+        };
+        self.set_built_in_name_mut(PRINT_AREA_NAME, sheet_name, value)
+    }
+
+    /// Set rows and columns repeated on each printed page, None on both remove the titles
+    pub(crate) fn set_print_titles_mut(
+        &mut self,
+        sheet_name: &str,
+        row_range: Option<(u32, u32)>,
+        column_range: Option<(u16, u16)>,
+    ) -> AnyResult<(), AnyError> {
+        self.get_sheet_prefix(sheet_name)?;
+        self.set_built_in_name_mut(
+            PRINT_TITLES_NAME,
+            sheet_name,
+            DefinedName::get_print_titles_value(sheet_name, row_range, column_range)?,
+        )
+    }
+
+    /// Protect the workbook structure and windows, only the hash of password get stored
+    pub(crate) fn protect_workbook_mut(
+        &mut self,
+        workbook_protection: WorkbookProtection,
+    ) -> AnyResult<(), AnyError> {
+        let hash_attributes = workbook_protection
+            .password
+            .as_deref()
+            .filter(|password| !password.is_empty())
+            .map(|password| get_password_hash_attributes(password, WORKBOOK_HASH_PREFIX))
+            .unwrap_or_default();
+        self.workbook_protection = Some((
+            WorkbookProtection {
+                password: None,
+                ..workbook_protection
+            },
+            hash_attributes,
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn unprotect_workbook_mut(&mut self) -> AnyResult<(), AnyError> {
+        self.workbook_protection = None;
+        Ok(())
+    }
+
     /// Return Style Id for the said combination
     pub(crate) fn get_style_id_mut(
         &mut self,
@@ -1,30 +1,130 @@
 use crate::{
-    converters::ConverterUtil,
-    element_dictionary::EXCEL_TYPE_COLLECTION,
-    files::{OfficeDocument, XmlDocument, XmlSerializer},
+    converters::{CellRange, ConverterUtil},
+    element_dictionary::{Content, COMMON_TYPE_COLLECTION, EXCEL_TYPE_COLLECTION},
+    files::{
+        ArchiveContentWriter, OfficeDocument, XmlDeSerializer, XmlDocument, XmlElement,
+        XmlSerializer,
+    },
     global_2007::{
-        parts::RelationsPart,
+        charts::{ChartSeries, ChartTypeValues},
+        models::{HyperlinkProperties, HyperlinkPropertyTypeValues},
+        parts::{ChartPart, RelationsPart},
         traits::{Enum, XmlDocumentPartCommon},
     },
     log_elapsed,
     order_dictionary::EXCEL_ORDER_COLLECTION,
     spreadsheet_2007::{
-        models::{CellDataType, CellProperties, ColumnProperties, RowProperties, StyleId},
-        services::CommonServices,
+        models::{
+            defined_name::PRINT_TITLES_NAME, AutoFilter, CellDataType, CellProperties, CellRecord,
+            CellValue, ColorSetting, ColorSettingTypeValues, ColumnProperties, Comment,
+            ConditionalFormatOperatorValues, ConditionalFormatRule, ConditionalFormatRuleValues,
+            ConditionalFormatValue, ConditionalFormatValueTypeValues, CustomFilter, DataValidation,
+            DataValidationErrorStyleValues, DataValidationOperatorValues, DataValidationTypeValues,
+            DefinedName, DifferentialStyleSetting, DrawingAnchorValues, DynamicFilterTypeValues,
+            FilterColumn, FilterCriteriaValues, FilterOperatorValues, HeaderFooterText,
+            IconSetValues, ImageFormatValues, OutlineGroupValues, PageOrientationValues, PageSetup,
+            PaperSizeValues, ProtectedRange, RichTextRun, RowProperties, SheetProtection,
+            SortCondition, SortState, StyleId, Table, TableColumn, TableSetting,
+            TableTotalsRowFunctionValues, ThreadedComment,
+        },
+        parts::{
+            get_threaded_comment_placeholder, CommentPart, DrawingPart, TablePart,
+            ThreadedCommentPart, THREADED_COMMENT_AUTHOR_PREFIX,
+        },
+        services::{
+            CommonServices, FormulaExpressionValues, FormulaParser, StringItem, StylePart,
+            MAX_COL_INDEX, MAX_ROW_INDEX,
+        },
+    },
+    utils::password_hash::{
+        filter_password_hash_attributes, get_password_hash_attributes, PasswordHashAttributes,
     },
 };
 use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
 use std::{
     cell::RefCell,
     cmp::{max, min},
-    collections::{BTreeMap, HashMap, VecDeque},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    io::Write,
     rc::{Rc, Weak},
 };
 
+/// Placeholder value of sheetData element replaced by streamed rows while saving
+const SHEET_DATA_STREAM_MARKER: &str = "DVMO-SHEET-DATA-STREAM";
+
+/// Hidden sheet scoped name Excel use to track the auto filter range
+const FILTER_DATABASE_NAME: &str = "_xlnm._FilterDatabase";
+
+/// Deepest outline level of row and column groups
+const MAX_OUTLINE_LEVEL: u8 = 7;
+
 #[derive(Debug)]
 pub(crate) struct RowData {
     row_record: RowProperties,
-    cell_records: Option<BTreeMap<u16, CellProperties>>,
+    cell_records: Option<BTreeMap<u16, CellData>>,
+}
+
+/// Cell content in the form it get stored in sheet data
+#[derive(Debug, Clone)]
+pub(crate) struct CellData {
+    value: Option<String>,
+    rich_text: Option<Vec<RichTextRun>>,
+    data_type: CellDataType,
+    formula: Option<String>,
+    style_id: Option<StyleId>,
+    metadata: Option<String>,
+    comment_id: Option<usize>,
+    place_holder: Option<bool>,
+}
+
+impl Default for CellData {
+    fn default() -> Self {
+        Self {
+            value: None,
+            rich_text: None,
+            data_type: CellDataType::Number,
+            formula: None,
+            style_id: None,
+            metadata: None,
+            comment_id: None,
+            place_holder: None,
+        }
+    }
+}
+
+impl CellData {
+    /// Inline string content of the cell
+    fn get_string_item(&self) -> StringItem {
+        if let Some(rich_text) = self.rich_text.as_ref() {
+            StringItem::from_rich_text(rich_text.clone())
+        } else {
+            StringItem::from_text(self.value.clone().unwrap_or_default())
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SheetDataStream {
+    content_writer: ArchiveContentWriter,
+    start_row: Option<u32>,
+    end_row: Option<u32>,
+    /// Write text cells as inline string instead of share string table lookup
+    inline_string: bool,
+}
+
+/// Conditional format rules of the range
+#[derive(Debug)]
+pub(crate) struct ConditionalFormatData {
+    sq_ref: String,
+    rules: Vec<ConditionalFormatRuleData>,
+}
+
+/// Rule with the dxf id of its style, rule loaded from the sheet keeps its priority
+#[derive(Debug)]
+pub(crate) struct ConditionalFormatRuleData {
+    rule: ConditionalFormatRule,
+    dxf_id: Option<u32>,
+    priority: Option<u32>,
 }
 
 #[derive(Debug)]
@@ -42,7 +142,7 @@ impl Default for Dimension {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub(crate) struct WorkSheetViewSelection {
     pane: Option<String>,
     active_cell: Option<String>,
@@ -50,8 +150,18 @@ pub(crate) struct WorkSheetViewSelection {
     sq_ref: Option<String>,
 }
 
+#[derive(Debug, Default)]
+pub(crate) struct WorkSheetViewPane {
+    x_split: Option<f64>,
+    y_split: Option<f64>,
+    top_left_cell: Option<String>,
+    active_pane: Option<String>,
+    state: Option<String>,
+}
+
 #[derive(Debug)]
 pub(crate) struct WorkSheetView {
+    pane: Option<WorkSheetViewPane>,
     selection_collection: Option<Vec<WorkSheetViewSelection>>,
     workbook_view_id: String,
     default_grid_color: Option<bool>,
@@ -77,6 +187,7 @@ impl Default for WorkSheetView {
     fn default() -> Self {
         Self {
             workbook_view_id: "0".to_string(),
+            pane: None,
             selection_collection: None,
             default_grid_color: None,
             view_right_to_left: None,
@@ -119,6 +230,9 @@ pub struct WorkSheet {
     common_service: Weak<RefCell<CommonServices>>,
     workbook_relationship_part: Weak<RefCell<RelationsPart>>,
     sheet_collection: Weak<RefCell<Vec<(String, String, bool, bool)>>>,
+    /// Table id, name and file path of all sheets in the workbook
+    table_collection: Weak<RefCell<Vec<(u32, String, String)>>>,
+    defined_name_collection: Weak<RefCell<Vec<DefinedName>>>,
     sheet_relationship_part: Rc<RefCell<RelationsPart>>,
     dimension: Dimension,
     // sheet_property: Option<_>,
@@ -126,10 +240,27 @@ pub struct WorkSheet {
     // sheet_format_property: Option<_>,
     column_collection: Option<VecDeque<ColumnProperties>>,
     sheet_data: Option<BTreeMap<u32, RowData>>,
+    sheet_data_stream: Option<SheetDataStream>,
     // sheet_calculation_property:Option<_>
-    // protected_range:Option<_>
-    // merge_cells:Option<_>
-    // hyperlinks:Option<_>
+    /// Sheet protection with its password hash attributes
+    protection: Option<(SheetProtection, PasswordHashAttributes)>,
+    /// Protected range with its password hash attributes
+    protected_ranges: Option<Vec<(ProtectedRange, PasswordHashAttributes)>>,
+    auto_filter: Option<AutoFilter>,
+    merge_cells: Option<Vec<CellRange>>,
+    /// Conditional formats the rule model cover, both loaded from the sheet and added later.
+    /// Written back on close, blocks with unsupported rules are left in the sheet
+    conditional_formats: Vec<ConditionalFormatData>,
+    /// Data validation with its sqref
+    data_validations: Option<Vec<(String, DataValidation)>>,
+    /// Print setting, print titles are kept in the defined names
+    page_setup: Option<PageSetup>,
+    tables: Vec<TablePart>,
+    /// Notes of the sheet along with the legacy drawing showing them
+    comment_part: Option<CommentPart>,
+    threaded_comment_part: Option<ThreadedCommentPart>,
+    drawing_part: Option<DrawingPart>,
+    hyperlinks: Option<BTreeMap<CellRange, HyperlinkProperties>>,
     file_path: String,
     sheet_name: String,
 }
@@ -167,6 +298,18 @@ impl XmlDocumentPartCommon for WorkSheet {
     {
         log_elapsed!(
             || {
+                for table_part in self.tables.iter_mut() {
+                    table_part
+                        .close_document()
+                        .context("Failed to close worksheet table")?;
+                }
+                self.close_comment_parts()
+                    .context("Failed to close worksheet comments")?;
+                if let Some(drawing_part) = self.drawing_part.as_mut() {
+                    drawing_part
+                        .close_document()
+                        .context("Failed to close worksheet drawing")?;
+                }
                 if let Some(office_document) = self.office_document.upgrade() {
                     let mut office_doc_mut = office_document
                         .try_borrow_mut()
@@ -177,12 +320,41 @@ impl XmlDocumentPartCommon for WorkSheet {
                             .context("Failed to Pull XML Handle")?;
                         // Add dimension
                         log_elapsed!(self.serialize_dimension(&mut xml_doc_mut))?;
+                        // Add Outline Levels to Document
+                        log_elapsed!(self.serialize_outline_levels(&mut xml_doc_mut))?;
                         // Add Cols Record to Document
                         log_elapsed!(self.serialize_cols(&mut xml_doc_mut))?;
                         // Add Sheet Views to Document
                         log_elapsed!(self.serialize_sheet_views(&mut xml_doc_mut))?;
                         // Add Sheet Data to Document
-                        log_elapsed!(self.serialize_sheet_data(&mut xml_doc_mut))?;
+                        if self.sheet_data_stream.is_some() {
+                            xml_doc_mut
+                                .insert_children_after_tag_mut("sheetData", "cols", None)
+                                .context("Failed to Insert Sheet Data Element")?
+                                .set_value_mut(SHEET_DATA_STREAM_MARKER.to_string());
+                        } else {
+                            log_elapsed!(self.serialize_sheet_data(&mut xml_doc_mut))?;
+                        }
+                        // Add Sheet Protection to Document
+                        log_elapsed!(self.serialize_sheet_protection(&mut xml_doc_mut))?;
+                        // Add Auto Filter to Document
+                        log_elapsed!(self.serialize_auto_filter(&mut xml_doc_mut))?;
+                        // Add Merge Cells to Document
+                        log_elapsed!(self.serialize_merge_cells(&mut xml_doc_mut))?;
+                        // Add Conditional Formatting to Document
+                        log_elapsed!(self.serialize_conditional_formats(&mut xml_doc_mut))?;
+                        // Add Data Validation to Document
+                        log_elapsed!(self.serialize_data_validations(&mut xml_doc_mut))?;
+                        // Add Hyperlinks to Document
+                        log_elapsed!(self.serialize_hyperlinks(&mut xml_doc_mut))?;
+                        // Add Page Setup to Document
+                        log_elapsed!(self.serialize_page_setup(&mut xml_doc_mut))?;
+                        // Add Drawing to Document
+                        log_elapsed!(self.serialize_drawing(&mut xml_doc_mut))?;
+                        // Add Legacy Drawing of Comments to Document
+                        log_elapsed!(self.serialize_legacy_drawing(&mut xml_doc_mut))?;
+                        // Add Table Parts to Document
+                        log_elapsed!(self.serialize_table_parts(&mut xml_doc_mut))?;
                         if let Some(root_element) = xml_doc_mut.get_root_mut() {
                             log_elapsed!(root_element
                                 .order_child_mut(
@@ -195,9 +367,19 @@ impl XmlDocumentPartCommon for WorkSheet {
                     }
                     log_elapsed!(
                         || {
-                            office_doc_mut
-                                .close_xml_document(&self.file_path)
-                                .context("Failed to close the current tree document")
+                            if let Some(sheet_data_stream) = self.sheet_data_stream.take() {
+                                office_doc_mut
+                                    .close_xml_document_with_stream(
+                                        &self.file_path,
+                                        SHEET_DATA_STREAM_MARKER,
+                                        sheet_data_stream.content_writer,
+                                    )
+                                    .context("Failed to close the current stream document")
+                            } else {
+                                office_doc_mut
+                                    .close_xml_document(&self.file_path)
+                                    .context("Failed to close the current tree document")
+                            }
                         },
                         "Close worksheet document"
                     )?;
@@ -215,6 +397,8 @@ impl WorkSheet {
     pub(crate) fn new(
         office_document: Weak<RefCell<OfficeDocument>>,
         sheet_collection: Weak<RefCell<Vec<(String, String, bool, bool)>>>,
+        table_collection: Weak<RefCell<Vec<(u32, String, String)>>>,
+        defined_name_collection: Weak<RefCell<Vec<DefinedName>>>,
         workbook_relationship_part: Weak<RefCell<RelationsPart>>,
         common_service: Weak<RefCell<CommonServices>>,
         sheet_name: Option<String>,
@@ -238,10 +422,70 @@ impl WorkSheet {
             )
             .context("Creating Relation ship part for workbook failed.")?,
         ));
-        let (column_collection, sheet_data, sheet_views, dimension) = log_elapsed!(
-            || { Self::initialize_worksheet(&xml_document).context("Failed to open Worksheet") },
+        let (
+            column_collection,
+            sheet_data,
+            sheet_views,
+            dimension,
+            protection,
+            protected_ranges,
+            auto_filter,
+            merge_cells,
+            conditional_formats,
+            data_validations,
+            hyperlinks,
+            page_setup,
+        ) = log_elapsed!(
+            || {
+                Self::initialize_worksheet(&xml_document, &sheet_relationship_part)
+                    .context("Failed to open Worksheet")
+            },
             "Worksheet Initialize Time"
         )?;
+        let tables = log_elapsed!(
+            || {
+                Self::deserialize_table_parts(
+                    &office_document,
+                    &xml_document,
+                    &sheet_relationship_part,
+                )
+                .context("Failed to load Worksheet Tables")
+            },
+            "Worksheet Tables Load Time"
+        )?;
+        let comment_part = log_elapsed!(
+            || {
+                Self::deserialize_comment_part(
+                    &office_document,
+                    &xml_document,
+                    &sheet_relationship_part,
+                )
+                .context("Failed to load Worksheet Comments")
+            },
+            "Worksheet Comments Load Time"
+        )?;
+        let threaded_comment_part = log_elapsed!(
+            || {
+                Self::deserialize_threaded_comment_part(
+                    &office_document,
+                    &common_service,
+                    &sheet_relationship_part,
+                )
+                .context("Failed to load Worksheet Threaded Comments")
+            },
+            "Worksheet Threaded Comments Load Time"
+        )?;
+        let drawing_part = log_elapsed!(
+            || {
+                Self::deserialize_drawing_part(
+                    &office_document,
+                    &xml_document,
+                    &sheet_relationship_part,
+                )
+                .context("Failed to load Worksheet Drawing")
+            },
+            "Worksheet Drawing Load Time"
+        )?;
         Ok(Self {
             office_document,
             xml_document,
@@ -251,8 +495,23 @@ impl WorkSheet {
             dimension,
             sheet_views,
             sheet_collection,
+            table_collection,
+            defined_name_collection,
             column_collection,
             sheet_data,
+            sheet_data_stream: None,
+            protection,
+            protected_ranges,
+            auto_filter,
+            merge_cells,
+            conditional_formats,
+            data_validations,
+            page_setup,
+            tables,
+            comment_part,
+            threaded_comment_part,
+            drawing_part,
+            hyperlinks,
             file_path: file_path.to_string(),
             sheet_name,
         })
@@ -260,12 +519,21 @@ impl WorkSheet {
 
     fn initialize_worksheet(
         xml_document: &Weak<RefCell<XmlDocument>>,
+        sheet_relationship_part: &Rc<RefCell<RelationsPart>>,
     ) -> AnyResult<
         (
             Option<VecDeque<ColumnProperties>>,
             Option<BTreeMap<u32, RowData>>,
             WorkSheetViews,
             Dimension,
+            Option<(SheetProtection, PasswordHashAttributes)>,
+            Option<Vec<(ProtectedRange, PasswordHashAttributes)>>,
+            Option<AutoFilter>,
+            Option<Vec<CellRange>>,
+            Vec<ConditionalFormatData>,
+            Option<Vec<(String, DataValidation)>>,
+            Option<BTreeMap<CellRange, HyperlinkProperties>>,
+            Option<PageSetup>,
         ),
         AnyError,
     > {
@@ -295,12 +563,120 @@ impl WorkSheet {
                 },
                 "Worksheet View Deserialization"
             )?;
-            Ok((column_collection, sheet_data, worksheet_views, dimension))
+            let protection = log_elapsed!(
+                || {
+                    deserialize_sheet_protection(&mut xml_doc_mut)
+                        .context("Failed to deserialize Sheet Protection")
+                },
+                "Sheet Protection Deserialization"
+            )?;
+            let protected_ranges = log_elapsed!(
+                || {
+                    deserialize_protected_ranges(&mut xml_doc_mut)
+                        .context("Failed to deserialize Protected Ranges")
+                },
+                "Protected Ranges Deserialization"
+            )?;
+            let auto_filter = log_elapsed!(
+                || {
+                    deserialize_auto_filter(&mut xml_doc_mut)
+                        .context("Failed to deserialize Auto Filter")
+                },
+                "Auto Filter Deserialization"
+            )?;
+            let merge_cells = log_elapsed!(
+                || {
+                    deserialize_merge_cells(&mut xml_doc_mut)
+                        .context("Failed to deserialize Merge Cells")
+                },
+                "Merge Cells Deserialization"
+            )?;
+            let conditional_formats = log_elapsed!(
+                || {
+                    deserialize_conditional_formats(&mut xml_doc_mut)
+                        .context("Failed to deserialize Conditional Formats")
+                },
+                "Conditional Formats Deserialization"
+            )?;
+            let data_validations = log_elapsed!(
+                || {
+                    deserialize_data_validations(&mut xml_doc_mut)
+                        .context("Failed to deserialize Data Validations")
+                },
+                "Data Validations Deserialization"
+            )?;
+            let hyperlinks = log_elapsed!(
+                || {
+                    deserialize_hyperlinks(&mut xml_doc_mut, sheet_relationship_part)
+                        .context("Failed to deserialize Hyperlinks")
+                },
+                "Hyperlinks Deserialization"
+            )?;
+            let page_setup = log_elapsed!(
+                || {
+                    deserialize_page_setup(&mut xml_doc_mut)
+                        .context("Failed to deserialize Page Setup")
+                },
+                "Page Setup Deserialization"
+            )?;
+            Ok((
+                column_collection,
+                sheet_data,
+                worksheet_views,
+                dimension,
+                protection,
+                protected_ranges,
+                auto_filter,
+                merge_cells,
+                conditional_formats,
+                data_validations,
+                hyperlinks,
+                page_setup,
+            ))
         } else {
-            Ok((None, None, WorkSheetViews::default(), Dimension::default()))
+            Ok((
+                None,
+                None,
+                WorkSheetViews::default(),
+                Dimension::default(),
+                None,
+                None,
+                None,
+                None,
+                Vec::new(),
+                None,
+                None,
+                None,
+            ))
         }
     }
 
+    /// Primary sheet view the public view setting apply to
+    fn get_sheet_view_mut(&mut self) -> AnyResult<&mut WorkSheetView, AnyError> {
+        self.sheet_views
+            .view_collection
+            .first_mut()
+            .ok_or(anyhow!("Worksheet view not found"))
+    }
+
+    fn get_active_pane(has_x_split: bool, has_y_split: bool) -> String {
+        match (has_x_split, has_y_split) {
+            (true, true) => "bottomRight",
+            (true, false) => "topRight",
+            _ => "bottomLeft",
+        }
+        .to_string()
+    }
+
+    fn set_pane_mut(&mut self, pane: WorkSheetViewPane) -> AnyResult<(), AnyError> {
+        let sheet_view = self.get_sheet_view_mut()?;
+        for selection in sheet_view.selection_collection.iter_mut().flatten() {
+            selection.pane = pane.active_pane.clone();
+        }
+        sheet_view.pane = Some(pane);
+        Ok(())
+    }
+
     fn serialize_dimension(&mut self, xml_doc_mut: &mut XmlDocument) -> Result<(), AnyError> {
         fn set_default(xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
             let mut dimension_attribute = HashMap::new();
@@ -312,33 +688,35 @@ impl WorkSheet {
                 .context("Failed to set attribute value to dimension")?;
             Ok(())
         }
-        if let Some(sheet_data) = self.sheet_data.as_ref() {
-            if let Some(first_item) = sheet_data.first_key_value() {
-                let mut dimension_attribute = HashMap::new();
-                dimension_attribute.insert(
-                    "ref".to_string(),
-                    format!(
-                        "{}{}:{}{}",
-                        ConverterUtil::get_column_ref(self.dimension.start_col)
-                            .context("Failed to convert dim col start")?,
-                        first_item.0,
-                        ConverterUtil::get_column_ref(self.dimension.end_col)
-                            .context("Failed to convert dim col end")?,
-                        if let Some(row_end) = sheet_data.last_key_value() {
-                            row_end.0
-                        } else {
-                            first_item.0
-                        }
-                    ),
-                );
-                xml_doc_mut
-                    .append_child_mut("dimension", None)
-                    .context("Failed to Add Dimension node to worksheet")?
-                    .set_attribute_mut(dimension_attribute)
-                    .context("Failed to set attribute value to dimension")?;
-            } else {
-                set_default(xml_doc_mut)?;
-            }
+        let row_range = if let Some(sheet_data_stream) = self.sheet_data_stream.as_ref() {
+            sheet_data_stream.start_row.zip(sheet_data_stream.end_row)
+        } else if let Some(sheet_data) = self.sheet_data.as_ref() {
+            sheet_data
+                .first_key_value()
+                .zip(sheet_data.last_key_value())
+                .map(|((start_row, _), (end_row, _))| (*start_row, *end_row))
+        } else {
+            None
+        };
+        if let Some((start_row, end_row)) = row_range {
+            let mut dimension_attribute = HashMap::new();
+            dimension_attribute.insert(
+                "ref".to_string(),
+                format!(
+                    "{}{}:{}{}",
+                    ConverterUtil::get_column_ref(self.dimension.start_col)
+                        .context("Failed to convert dim col start")?,
+                    start_row,
+                    ConverterUtil::get_column_ref(self.dimension.end_col)
+                        .context("Failed to convert dim col end")?,
+                    end_row
+                ),
+            );
+            xml_doc_mut
+                .append_child_mut("dimension", None)
+                .context("Failed to Add Dimension node to worksheet")?
+                .set_attribute_mut(dimension_attribute)
+                .context("Failed to set attribute value to dimension")?;
         } else {
             set_default(xml_doc_mut)?;
         }
@@ -348,6 +726,9 @@ impl WorkSheet {
     fn serialize_cols(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
         if let Some(mut column_collection) = self.column_collection.take() {
             if column_collection.len() > 0 {
+                column_collection
+                    .make_contiguous()
+                    .sort_by_key(|column_properties| column_properties.min);
                 let cols_id = xml_doc_mut
                     .insert_children_after_tag_mut("cols", "sheetFormatPr", None)
                     .context("Failed to Insert Cols Element")?
@@ -370,6 +751,17 @@ impl WorkSheet {
                         if let Some(_) = item.best_fit {
                             attribute.insert("bestFit".to_string(), "1".to_string());
                         }
+                        if item.group_level > 0 {
+                            attribute
+                                .insert("outlineLevel".to_string(), item.group_level.to_string());
+                        }
+                        if let Some(true) = item.collapsed {
+                            attribute.insert("collapsed".to_string(), "1".to_string());
+                        }
+                        // Range left without any property after ungroup is dropped
+                        if attribute.len() == 2 {
+                            continue;
+                        }
                         xml_doc_mut
                             .append_child_mut("col", Some(&cols_id))
                             .context("Failed to insert col record")?
@@ -384,13 +776,71 @@ impl WorkSheet {
         Ok(())
     }
 
+    /// Deepest row and column outline level used by Excel to size the outline bar
+    fn serialize_outline_levels(
+        &mut self,
+        xml_doc_mut: &mut XmlDocument,
+    ) -> AnyResult<(), AnyError> {
+        let row_level = self
+            .sheet_data
+            .iter()
+            .flatten()
+            .filter_map(|(_, row)| row.row_record.group_level)
+            .max()
+            .unwrap_or(0);
+        let column_level = self
+            .column_collection
+            .iter()
+            .flatten()
+            .map(|column_properties| column_properties.group_level)
+            .max()
+            .unwrap_or(0);
+        let sheet_format_id = xml_doc_mut
+            .get_first_element_id(vec!["sheetFormatPr"], None)
+            .context("Failed to find sheet format property")?;
+        if sheet_format_id.is_none() && row_level == 0 && column_level == 0 {
+            return Ok(());
+        }
+        let sheet_format_property = match sheet_format_id {
+            Some(sheet_format_id) => xml_doc_mut
+                .get_element_mut(&sheet_format_id)
+                .ok_or(anyhow!("Failed to get sheet format property"))?,
+            None => xml_doc_mut
+                .append_child_mut("sheetFormatPr", None)
+                .context("Failed to add sheet format property")?,
+        };
+        let mut attributes = sheet_format_property
+            .get_attribute()
+            .cloned()
+            .unwrap_or_default();
+        attributes
+            .entry("defaultRowHeight".to_string())
+            .or_insert("15".to_string());
+        let mut outline_levels = vec![("outlineLevelCol", column_level)];
+        // Streamed rows are already written out, keep the loaded row level as is
+        if self.sheet_data_stream.is_none() {
+            outline_levels.push(("outlineLevelRow", row_level as usize));
+        }
+        for (key, level) in outline_levels {
+            if level > 0 {
+                attributes.insert(key.to_string(), level.to_string());
+            } else {
+                attributes.remove(key);
+            }
+        }
+        sheet_format_property
+            .set_attribute_mut(attributes)
+            .context("Failed to set sheet format property attribute")?;
+        Ok(())
+    }
+
     fn serialize_sheet_views(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
         let sheet_views_id = xml_doc_mut
             .insert_children_after_tag_mut("sheetViews", "dimension", None)
             .context("Failed to Insert Sheet Views Element")?
             .get_id();
-        loop {
-            if let Some(sheet_view) = self.sheet_views.view_collection.pop() {
+        for sheet_view in self.sheet_views.view_collection.drain(..) {
+            {
                 let mut attribute: HashMap<String, String> = HashMap::new();
                 attribute.insert("workbookViewId".to_string(), sheet_view.workbook_view_id);
                 if let Some(window_protection) = sheet_view.window_protection {
@@ -483,13 +933,55 @@ impl WorkSheet {
                         zoom_scale_page_layout.to_string(),
                     );
                 }
-                xml_doc_mut
+                let sheet_view_id = xml_doc_mut
                     .append_child_mut("sheetView", Some(&sheet_views_id))
                     .context("Failed to insert sheetView record")?
                     .set_attribute_mut(attribute)
-                    .context("Failed to Add Attribute to sheetView element")?;
-            } else {
-                break;
+                    .context("Failed to Add Attribute to sheetView element")?
+                    .get_id();
+                if let Some(pane) = sheet_view.pane {
+                    let mut attribute: HashMap<String, String> = HashMap::new();
+                    if let Some(x_split) = pane.x_split {
+                        attribute.insert("xSplit".to_string(), x_split.to_string());
+                    }
+                    if let Some(y_split) = pane.y_split {
+                        attribute.insert("ySplit".to_string(), y_split.to_string());
+                    }
+                    if let Some(top_left_cell) = pane.top_left_cell {
+                        attribute.insert("topLeftCell".to_string(), top_left_cell);
+                    }
+                    if let Some(active_pane) = pane.active_pane {
+                        attribute.insert("activePane".to_string(), active_pane);
+                    }
+                    if let Some(state) = pane.state {
+                        attribute.insert("state".to_string(), state);
+                    }
+                    xml_doc_mut
+                        .append_child_mut("pane", Some(&sheet_view_id))
+                        .context("Failed to insert pane record")?
+                        .set_attribute_mut(attribute)
+                        .context("Failed to Add Attribute to pane element")?;
+                }
+                for selection in sheet_view.selection_collection.into_iter().flatten() {
+                    let mut attribute: HashMap<String, String> = HashMap::new();
+                    if let Some(pane) = selection.pane {
+                        attribute.insert("pane".to_string(), pane);
+                    }
+                    if let Some(active_cell) = selection.active_cell {
+                        attribute.insert("activeCell".to_string(), active_cell);
+                    }
+                    if let Some(active_cell_id) = selection.active_cell_id {
+                        attribute.insert("activeCellId".to_string(), active_cell_id);
+                    }
+                    if let Some(sq_ref) = selection.sq_ref {
+                        attribute.insert("sqref".to_string(), sq_ref);
+                    }
+                    xml_doc_mut
+                        .append_child_mut("selection", Some(&sheet_view_id))
+                        .context("Failed to insert selection record")?
+                        .set_attribute_mut(attribute)
+                        .context("Failed to Add Attribute to selection element")?;
+                }
             }
         }
         Ok(())
@@ -506,39 +998,8 @@ impl WorkSheet {
                     .append_child_mut("row", Some(&sheet_data_id))
                     .context("Failed to insert row element")?;
                 let row_element_id = row_element.get_id();
-                let mut row_attribute = HashMap::new();
-                row_attribute.insert("r".to_string(), row_index.to_string());
-                if let Some(row_span) = db_row.row_record.span {
-                    row_attribute.insert("spans".to_string(), row_span);
-                }
-                if let Some(row_style_id) = db_row.row_record.style_id {
-                    row_attribute.insert("customFormat".to_string(), "1".to_string());
-                    row_attribute.insert("s".to_string(), row_style_id.id.to_string());
-                }
-                if let Some(row_height) = db_row.row_record.height {
-                    row_attribute.insert("customHeight".to_string(), "1".to_string());
-                    row_attribute.insert("ht".to_string(), row_height.to_string());
-                }
-                if let Some(_) = db_row.row_record.hidden {
-                    row_attribute.insert("hidden".to_string(), "1".to_string());
-                }
-                if let Some(row_group_level) = db_row.row_record.group_level {
-                    row_attribute.insert("outlineLevel".to_string(), row_group_level.to_string());
-                }
-                if let Some(_) = db_row.row_record.collapsed {
-                    row_attribute.insert("collapsed".to_string(), "1".to_string());
-                }
-                if let Some(_) = db_row.row_record.thick_top {
-                    row_attribute.insert("thickTop".to_string(), "1".to_string());
-                }
-                if let Some(_) = db_row.row_record.thick_bottom {
-                    row_attribute.insert("thickBot".to_string(), "1".to_string());
-                }
-                if let Some(_) = db_row.row_record.place_holder {
-                    row_attribute.insert("ph".to_string(), "1".to_string());
-                }
                 row_element
-                    .set_attribute_mut(row_attribute)
+                    .set_attribute_mut(get_row_attribute(&row_index, &db_row.row_record))
                     .context("Failed to set attribute for row")?;
                 if let Some(cols) = db_row.cell_records {
                     for (col_index, cell_record) in cols {
@@ -547,36 +1008,11 @@ impl WorkSheet {
                             .append_child_mut("c", Some(&row_element_id))
                             .context("Failed to insert row element")?;
                         let cell_id = cell_element.get_id();
-                        let mut cell_attribute = HashMap::new();
-                        cell_attribute.insert(
-                            "r".to_string(),
-                            format!(
-                                "{}{}",
-                                ConverterUtil::get_column_ref(col_index)
-                                    .context("Failed to get Char Id from Int")?,
-                                row_index
-                            ),
-                        );
-                        if let Some(cell_style_id) = cell_record.style_id {
-                            cell_attribute.insert("s".to_string(), cell_style_id.id.to_string());
-                        }
-                        if cell_record.data_type != CellDataType::Number {
-                            cell_attribute.insert(
-                                "t".to_string(),
-                                CellDataType::get_string(cell_record.data_type),
-                            );
-                        }
-                        if let Some(cell_comment_id) = cell_record.comment_id {
-                            cell_attribute.insert("cm".to_string(), cell_comment_id.to_string());
-                        }
-                        if let Some(cell_metadata) = cell_record.metadata {
-                            cell_attribute.insert("vm".to_string(), cell_metadata.to_string());
-                        }
-                        if let Some(_) = cell_record.place_holder {
-                            cell_attribute.insert("ph".to_string(), "1".to_string());
-                        }
                         cell_element
-                            .set_attribute_mut(cell_attribute)
+                            .set_attribute_mut(
+                                get_cell_attribute(&row_index, &col_index, &cell_record)
+                                    .context("Failed to Get Attribute for cell")?,
+                            )
                             .context("Failed to Set Attribute for cell")?;
                         // Create cell's child element
                         match cell_record.data_type {
@@ -585,16 +1021,10 @@ impl WorkSheet {
                                     .append_child_mut("is", Some(&cell_id))
                                     .context("Failed to insert Inline string element")?
                                     .get_id();
-                                let text_element = xml_doc_mut
-                                    .append_child_mut("t", Some(&inline_string_id))
+                                cell_record
+                                    .get_string_item()
+                                    .serialize_mut(xml_doc_mut, &inline_string_id)
                                     .context("Failed To insert Text Value to inline string")?;
-                                text_element.set_value_mut(
-                                    if let Some(value) = cell_record.value {
-                                        value
-                                    } else {
-                                        "".to_string()
-                                    },
-                                );
                             }
                             _ => {
                                 if let Some(formula) = cell_record.formula {
@@ -619,745 +1049,5387 @@ impl WorkSheet {
         }
         Ok(())
     }
-}
 
-fn deserialize_cols(
-    xml_doc_mut: &mut XmlDocument,
-) -> AnyResult<Option<VecDeque<ColumnProperties>>, AnyError> {
-    if let Some(mut cols_element) = xml_doc_mut.pop_elements_by_tag_mut("cols", None) {
-        // Process the columns record if parent node exist
-        if let Some(cols) = cols_element.pop() {
-            let mut column_collection = VecDeque::with_capacity(cols.get_child_count());
-            loop {
-                if let Some((col_elements, _)) = cols.pop_child_mut() {
-                    if let Some(col) = xml_doc_mut.pop_element_mut(&col_elements) {
-                        let mut column_properties = ColumnProperties::default();
-                        let attributes = col
-                            .get_attribute()
-                            .ok_or(anyhow!("Error Getting col attribute"))?;
-                        if let Some(min) = attributes.get("min") {
-                            column_properties.min =
-                                min.parse().context("Failed to parse min value")?;
-                        }
-                        if let Some(max) = attributes.get("max") {
-                            column_properties.max =
-                                max.parse().context("Failed to parse min value")?;
-                        }
-                        if let Some(best_fit) = attributes.get("bestFit") {
-                            column_properties.best_fit =
-                                if best_fit == "1" { Some(true) } else { None }
-                        }
-                        if let Some(hidden) = attributes.get("hidden") {
-                            column_properties.hidden = if hidden == "1" { Some(true) } else { None }
-                        }
-                        if let Some(style) = attributes.get("style") {
-                            column_properties.style_id = Some(StyleId::new(
-                                style.parse().context("Failed to parse style ID")?,
-                            ));
-                        }
-                        if let Some(outline_level) = attributes.get("outlineLevel") {
-                            column_properties.group_level =
-                                outline_level.parse().context("Failed to parse style ID")?;
+    fn serialize_conditional_formats(
+        &mut self,
+        xml_doc_mut: &mut XmlDocument,
+    ) -> AnyResult<(), AnyError> {
+        if self.conditional_formats.is_empty() {
+            return Ok(());
+        }
+        // New rules take priority after the rules already in the sheet
+        let raw_priority = xml_doc_mut
+            .get_element_ids_by_tag("conditionalFormatting", None)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|element_id| xml_doc_mut.get_element_ids_by_tag("cfRule", Some(element_id)))
+            .flatten()
+            .filter_map(|rule_id| {
+                xml_doc_mut
+                    .get_element(&rule_id)
+                    .and_then(|rule| rule.get_attribute())
+                    .and_then(|attributes| attributes.get("priority"))
+                    .and_then(|priority| priority.parse::<u32>().ok())
+            })
+            .max()
+            .unwrap_or_default();
+        let mut priority = self
+            .conditional_formats
+            .iter()
+            .flat_map(|conditional_format| &conditional_format.rules)
+            .filter_map(|rule_data| rule_data.priority)
+            .max()
+            .unwrap_or_default()
+            .max(raw_priority);
+        for conditional_format in self.conditional_formats.drain(..) {
+            let conditional_format_id = xml_doc_mut
+                .append_child_mut("conditionalFormatting", None)
+                .context("Failed to insert conditional formatting element")?
+                .set_attribute_mut(HashMap::from([(
+                    "sqref".to_string(),
+                    conditional_format.sq_ref,
+                )]))
+                .context("Failed to set conditional formatting attribute")?
+                .get_id();
+            for rule_data in conditional_format.rules {
+                let rule_priority = rule_data.priority.unwrap_or_else(|| {
+                    priority += 1;
+                    priority
+                });
+                serialize_conditional_format_rule(
+                    xml_doc_mut,
+                    &conditional_format_id,
+                    rule_data.rule,
+                    rule_data.dxf_id,
+                    rule_priority,
+                )
+                .context("Failed to serialize conditional format rule")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_data_validations(
+        &mut self,
+        xml_doc_mut: &mut XmlDocument,
+    ) -> AnyResult<(), AnyError> {
+        if let Some(data_validations) = self.data_validations.take() {
+            if !data_validations.is_empty() {
+                let data_validations_id = xml_doc_mut
+                    .append_child_mut("dataValidations", None)
+                    .context("Failed to insert data validations element")?
+                    .set_attribute_mut(HashMap::from([(
+                        "count".to_string(),
+                        data_validations.len().to_string(),
+                    )]))
+                    .context("Failed to set data validations attribute")?
+                    .get_id();
+                for (sq_ref, data_validation) in data_validations {
+                    let mut attributes = HashMap::from([("sqref".to_string(), sq_ref)]);
+                    if data_validation.validation_type != DataValidationTypeValues::None {
+                        attributes.insert(
+                            "type".to_string(),
+                            DataValidationTypeValues::get_string(
+                                data_validation.validation_type.clone(),
+                            ),
+                        );
+                    }
+                    if data_validation.operator != DataValidationOperatorValues::Between {
+                        attributes.insert(
+                            "operator".to_string(),
+                            DataValidationOperatorValues::get_string(data_validation.operator),
+                        );
+                    }
+                    if data_validation.error_style != DataValidationErrorStyleValues::Stop {
+                        attributes.insert(
+                            "errorStyle".to_string(),
+                            DataValidationErrorStyleValues::get_string(data_validation.error_style),
+                        );
+                    }
+                    for (key, enabled) in [
+                        ("allowBlank", data_validation.allow_blank),
+                        ("showDropDown", data_validation.hide_dropdown),
+                        ("showInputMessage", data_validation.show_input_message),
+                        ("showErrorMessage", data_validation.show_error_message),
+                    ] {
+                        if enabled {
+                            attributes.insert(key.to_string(), "1".to_string());
                         }
-                        if let Some(custom_width) = attributes.get("customWidth") {
-                            if custom_width == "1" {
-                                column_properties.width = Some(
-                                    attributes
-                                        .get("width")
-                                        .ok_or(anyhow!("Failed to get custom width"))?
-                                        .parse()
-                                        .context("Failed to parse custom width")?,
-                                );
-                            }
+                    }
+                    for (key, value) in [
+                        ("promptTitle", data_validation.prompt_title),
+                        ("prompt", data_validation.prompt),
+                        ("errorTitle", data_validation.error_title),
+                        ("error", data_validation.error),
+                    ] {
+                        if let Some(value) = value {
+                            attributes.insert(key.to_string(), value);
                         }
-                        if let Some(collapsed) = attributes.get("collapsed") {
-                            column_properties.collapsed =
-                                if collapsed == "1" { Some(true) } else { None }
+                    }
+                    let data_validation_id = xml_doc_mut
+                        .append_child_mut("dataValidation", Some(&data_validations_id))
+                        .context("Failed to insert data validation element")?
+                        .set_attribute_mut(attributes)
+                        .context("Failed to set data validation attribute")?
+                        .get_id();
+                    for (tag, formula) in [
+                        ("formula1", data_validation.formula1),
+                        ("formula2", data_validation.formula2),
+                    ] {
+                        if let Some(formula) = formula {
+                            xml_doc_mut
+                                .append_child_mut(tag, Some(&data_validation_id))
+                                .context("Failed to insert data validation formula")?
+                                .set_value_mut(formula);
                         }
-                        column_collection.push_back(column_properties);
                     }
-                } else {
-                    break;
                 }
             }
-            return Ok(Some(column_collection));
         }
+        Ok(())
     }
-    Ok(None)
-}
 
-/// DeSerializing Worksheet View
-fn deserialize_worksheet_views(
-    xml_doc_mut: &mut XmlDocument,
-) -> AnyResult<WorkSheetViews, AnyError> {
-    let mut worksheet_views = WorkSheetViews::default();
-    if let Some(mut sheet_views_elements) = xml_doc_mut.pop_elements_by_tag_mut("sheetViews", None)
-    {
-        if let Some(sheet_views_element) = sheet_views_elements.pop() {
-            loop {
-                if let Some((element_id, element_tag)) = sheet_views_element.pop_child_mut() {
-                    // Validate element that are not accounted
-                    if element_tag != "sheetView" {
-                        return Err(anyhow!("Failed to Process Sheet Views child"));
-                    } else {
-                        let sheet_view_element = xml_doc_mut
-                            .pop_element_mut(&element_id)
-                            .context("Failed to get Sheet View Element")?;
-                        let mut worksheet_view = WorkSheetView::default();
-                        let attributes = sheet_view_element
-                            .get_attribute()
-                            .context("Failed to Get Attributes of Sheet View")?;
-                        worksheet_view.workbook_view_id = attributes
-                            .get("workbookViewId")
-                            .context(
-                                "Mandatory attribute \"workbookViewId\" is missing from sheetView",
-                            )?
-                            .clone();
-                        // Windows protection
-                        if let Some(window_protection) = attributes.get("windowProtection") {
-                            worksheet_view.window_protection = Some(
-                                ConverterUtil::normalize_bool_property_bool(&window_protection),
-                            );
-                        }
-                        // Show formula
-                        if let Some(show_formula_bar) = attributes.get("showFormulas") {
-                            worksheet_view.show_formula_bar = Some(
-                                ConverterUtil::normalize_bool_property_bool(&show_formula_bar),
-                            );
-                        }
-                        // Show Grid Line
-                        if let Some(show_grid_line) = attributes.get("showGridLines") {
-                            worksheet_view.show_grid_line =
-                                Some(ConverterUtil::normalize_bool_property_bool(&show_grid_line));
-                        }
-                        // Show row column header
-                        if let Some(show_row_col_header) = attributes.get("showRowColHeaders") {
-                            worksheet_view.show_row_col_header = Some(
-                                ConverterUtil::normalize_bool_property_bool(&show_row_col_header),
-                            );
-                        }
-                        // Show Zero
-                        if let Some(show_zero) = attributes.get("showZeros") {
-                            worksheet_view.show_zero =
-                                Some(ConverterUtil::normalize_bool_property_bool(&show_zero));
-                        }
-                        // Right to Left
-                        if let Some(view_right_to_left) = attributes.get("rightToLeft") {
-                            worksheet_view.view_right_to_left = Some(
-                                ConverterUtil::normalize_bool_property_bool(&view_right_to_left),
-                            );
-                        }
-                        // Tab Selected
-                        if let Some(tab_selected) = attributes.get("tabSelected") {
-                            worksheet_view.tab_selected =
-                                Some(ConverterUtil::normalize_bool_property_bool(&tab_selected));
-                        }
-                        // show ruler
-                        if let Some(show_ruler) = attributes.get("showRuler") {
-                            worksheet_view.show_ruler =
-                                Some(ConverterUtil::normalize_bool_property_bool(&show_ruler));
-                        }
-                        // show white space
-                        if let Some(show_white_space) = attributes.get("showWhiteSpace") {
-                            worksheet_view.show_white_space =
-                                Some(ConverterUtil::normalize_bool_property_bool(&show_white_space));
-                        }
-                        // Show outlined Symbols
-                        if let Some(show_outline_symbol) = attributes.get("showOutlineSymbols") {
-                            worksheet_view.show_outline_symbol = Some(
-                                ConverterUtil::normalize_bool_property_bool(&show_outline_symbol),
-                            );
-                        }
-                        // Default Grid Color
-                        if let Some(default_grid_color) = attributes.get("defaultGridColor") {
-                            worksheet_view.default_grid_color = Some(
-                                ConverterUtil::normalize_bool_property_bool(&default_grid_color),
-                            );
-                        }
-                        // Top Left Cell
-                        if let Some(top_left_cell) = attributes.get("topLeftCell") {
-                            worksheet_view.top_left_cell = Some(top_left_cell.clone());
-                        }
-                        // View Setting
-                        if let Some(view) = attributes.get("view") {
-                            worksheet_view.view = Some(view.clone());
-                        }
-                        // Zoom Scale
-                        if let Some(zoom_scale) = attributes.get("zoomScale") {
-                            worksheet_view.zoom_scale = Some(
-                                zoom_scale
-                                    .parse()
-                                    .context("Failed to Convert Zoom Normal to i16")?,
-                            );
-                        }
-                        // Zoom Scale Normal
-                        if let Some(zoom_scale_normal) = attributes.get("zoomScaleNormal") {
-                            worksheet_view.zoom_scale_normal = Some(
-                                zoom_scale_normal
-                                    .parse()
-                                    .context("Failed to Convert Zoom Normal to i16")?,
-                            );
+    fn serialize_sheet_protection(
+        &mut self,
+        xml_doc_mut: &mut XmlDocument,
+    ) -> AnyResult<(), AnyError> {
+        if let Some((mut protection, mut attributes)) = self.protection.take() {
+            attributes.insert("sheet".to_string(), "1".to_string());
+            for (key, default_blocked, is_allowed) in
+                get_sheet_protection_flags_mut(&mut protection)
+            {
+                if *is_allowed == default_blocked {
+                    attributes.insert(key.to_string(), ConverterUtil::bool_xml_flag(&!*is_allowed));
+                }
+            }
+            xml_doc_mut
+                .append_child_mut("sheetProtection", None)
+                .context("Failed to add sheet protection")?
+                .set_attribute_mut(attributes)
+                .context("Failed to set sheet protection attribute")?;
+        }
+        if let Some(protected_ranges) = self.protected_ranges.take() {
+            if !protected_ranges.is_empty() {
+                let protected_ranges_id = xml_doc_mut
+                    .append_child_mut("protectedRanges", None)
+                    .context("Failed to add protected ranges")?
+                    .get_id();
+                for (protected_range, mut attributes) in protected_ranges {
+                    attributes.insert("name".to_string(), protected_range.name);
+                    attributes.insert("sqref".to_string(), protected_range.range_ref);
+                    xml_doc_mut
+                        .append_child_mut("protectedRange", Some(&protected_ranges_id))
+                        .context("Failed to add protected range")?
+                        .set_attribute_mut(attributes)
+                        .context("Failed to set protected range attribute")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_auto_filter(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        if let Some(auto_filter) = self.auto_filter.take() {
+            let auto_filter_id = xml_doc_mut
+                .append_child_mut("autoFilter", None)
+                .context("Failed to insert auto filter element")?
+                .set_attribute_mut(HashMap::from([("ref".to_string(), auto_filter.range_ref)]))
+                .context("Failed to set auto filter attribute")?
+                .get_id();
+            for filter_column in auto_filter.filter_columns {
+                let mut attributes =
+                    HashMap::from([("colId".to_string(), filter_column.column_index.to_string())]);
+                if filter_column.hide_button {
+                    attributes.insert("hiddenButton".to_string(), "1".to_string());
+                }
+                let filter_column_id = xml_doc_mut
+                    .append_child_mut("filterColumn", Some(&auto_filter_id))
+                    .context("Failed to insert filter column element")?
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set filter column attribute")?
+                    .get_id();
+                if let Some(criteria) = filter_column.criteria {
+                    serialize_filter_criteria(xml_doc_mut, &filter_column_id, criteria)
+                        .context("Failed to serialize filter criteria")?;
+                }
+            }
+            if let Some(sort_state) = auto_filter.sort_state {
+                let mut attributes = HashMap::from([("ref".to_string(), sort_state.range_ref)]);
+                if sort_state.case_sensitive {
+                    attributes.insert("caseSensitive".to_string(), "1".to_string());
+                }
+                let sort_state_id = xml_doc_mut
+                    .append_child_mut("sortState", None)
+                    .context("Failed to insert sort state element")?
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set sort state attribute")?
+                    .get_id();
+                for sort_condition in sort_state.conditions {
+                    let mut attributes =
+                        HashMap::from([("ref".to_string(), sort_condition.range_ref)]);
+                    if sort_condition.descending {
+                        attributes.insert("descending".to_string(), "1".to_string());
+                    }
+                    xml_doc_mut
+                        .append_child_mut("sortCondition", Some(&sort_state_id))
+                        .context("Failed to insert sort condition element")?
+                        .set_attribute_mut(attributes)
+                        .context("Failed to set sort condition attribute")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_table_parts(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        if !self.tables.is_empty() {
+            let table_parts_id = xml_doc_mut
+                .append_child_mut("tableParts", None)
+                .context("Failed to insert table parts element")?
+                .set_attribute_mut(HashMap::from([(
+                    "count".to_string(),
+                    self.tables.len().to_string(),
+                )]))
+                .context("Failed to set table parts attribute")?
+                .get_id();
+            for table_part in &self.tables {
+                xml_doc_mut
+                    .append_child_mut("tablePart", Some(&table_parts_id))
+                    .context("Failed to insert table part element")?
+                    .set_attribute_mut(HashMap::from([(
+                        "r:id".to_string(),
+                        table_part.get_relationship_id().to_string(),
+                    )]))
+                    .context("Failed to set table part attribute")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_drawing(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        if let Some(drawing_part) = self.drawing_part.as_ref() {
+            xml_doc_mut
+                .append_child_mut("drawing", None)
+                .context("Failed to insert drawing element")?
+                .set_attribute_mut(HashMap::from([(
+                    "r:id".to_string(),
+                    drawing_part.get_relationship_id().to_string(),
+                )]))
+                .context("Failed to set drawing attribute")?;
+        }
+        Ok(())
+    }
+
+    fn serialize_legacy_drawing(
+        &mut self,
+        xml_doc_mut: &mut XmlDocument,
+    ) -> AnyResult<(), AnyError> {
+        if let Some(comment_part) = self.comment_part.as_ref() {
+            xml_doc_mut
+                .append_child_mut("legacyDrawing", None)
+                .context("Failed to insert legacy drawing element")?
+                .set_attribute_mut(HashMap::from([(
+                    "r:id".to_string(),
+                    comment_part.get_vml_relationship_id().to_string(),
+                )]))
+                .context("Failed to set legacy drawing attribute")?;
+        }
+        Ok(())
+    }
+
+    fn serialize_merge_cells(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        if let Some(merge_cells) = self.merge_cells.take() {
+            if !merge_cells.is_empty() {
+                let merge_cells_element = xml_doc_mut
+                    .append_child_mut("mergeCells", None)
+                    .context("Failed to insert merge cells element")?;
+                let merge_cells_id = merge_cells_element.get_id();
+                merge_cells_element
+                    .set_attribute_mut(HashMap::from([(
+                        "count".to_string(),
+                        merge_cells.len().to_string(),
+                    )]))
+                    .context("Failed to set merge cells attribute")?;
+                for merge_cell in merge_cells {
+                    xml_doc_mut
+                        .append_child_mut("mergeCell", Some(&merge_cells_id))
+                        .context("Failed to insert merge cell element")?
+                        .set_attribute_mut(HashMap::from([(
+                            "ref".to_string(),
+                            get_range_ref(&merge_cell).context("Failed to get merge cell ref")?,
+                        )]))
+                        .context("Failed to set merge cell attribute")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_page_setup(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        if let Some(page_setup) = self.page_setup.take() {
+            if page_setup.fit_to_page.is_some() {
+                let page_setup_property = get_sheet_property_child_mut(xml_doc_mut, "pageSetUpPr")?;
+                let mut attributes = page_setup_property
+                    .get_attribute()
+                    .cloned()
+                    .unwrap_or_default();
+                attributes.insert("fitToPage".to_string(), "1".to_string());
+                page_setup_property
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set page setup property attribute")?;
+            }
+            let mut attributes = HashMap::new();
+            for (key, is_enabled) in [
+                ("horizontalCentered", page_setup.center_horizontally),
+                ("verticalCentered", page_setup.center_vertically),
+                ("headings", page_setup.print_headings),
+                ("gridLines", page_setup.print_grid_lines),
+            ] {
+                if is_enabled {
+                    attributes.insert(key.to_string(), "1".to_string());
+                }
+            }
+            if !attributes.is_empty() {
+                xml_doc_mut
+                    .append_child_mut("printOptions", None)
+                    .context("Failed to add print options")?
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set print options attribute")?;
+            }
+            xml_doc_mut
+                .append_child_mut("pageMargins", None)
+                .context("Failed to add page margins")?
+                .set_attribute_mut(HashMap::from([
+                    ("left".to_string(), page_setup.margins.left.to_string()),
+                    ("right".to_string(), page_setup.margins.right.to_string()),
+                    ("top".to_string(), page_setup.margins.top.to_string()),
+                    ("bottom".to_string(), page_setup.margins.bottom.to_string()),
+                    ("header".to_string(), page_setup.margins.header.to_string()),
+                    ("footer".to_string(), page_setup.margins.footer.to_string()),
+                ]))
+                .context("Failed to set page margins attribute")?;
+            let mut attributes = HashMap::new();
+            if page_setup.paper_size != PaperSizeValues::Letter {
+                attributes.insert(
+                    "paperSize".to_string(),
+                    PaperSizeValues::get_string(page_setup.paper_size),
+                );
+            }
+            if page_setup.orientation != PageOrientationValues::Default {
+                attributes.insert(
+                    "orientation".to_string(),
+                    PageOrientationValues::get_string(page_setup.orientation),
+                );
+            }
+            if page_setup.scale != 100 {
+                attributes.insert("scale".to_string(), page_setup.scale.to_string());
+            }
+            if let Some((fit_to_width, fit_to_height)) = page_setup.fit_to_page {
+                if fit_to_width != 1 {
+                    attributes.insert("fitToWidth".to_string(), fit_to_width.to_string());
+                }
+                if fit_to_height != 1 {
+                    attributes.insert("fitToHeight".to_string(), fit_to_height.to_string());
+                }
+            }
+            if let Some(first_page_number) = page_setup.first_page_number {
+                attributes.insert("firstPageNumber".to_string(), first_page_number.to_string());
+                attributes.insert("useFirstPageNumber".to_string(), "1".to_string());
+            }
+            if !attributes.is_empty() {
+                xml_doc_mut
+                    .append_child_mut("pageSetup", None)
+                    .context("Failed to add page setup")?
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set page setup attribute")?;
+            }
+            let header_footer = page_setup.header_footer;
+            let header_footer_values = [
+                ("oddHeader", header_footer.odd_header.get_value()),
+                ("oddFooter", header_footer.odd_footer.get_value()),
+                ("evenHeader", header_footer.even_header.get_value()),
+                ("evenFooter", header_footer.even_footer.get_value()),
+                ("firstHeader", header_footer.first_header.get_value()),
+                ("firstFooter", header_footer.first_footer.get_value()),
+            ];
+            let mut attributes = HashMap::new();
+            if header_footer.different_odd_even {
+                attributes.insert("differentOddEven".to_string(), "1".to_string());
+            }
+            if header_footer.different_first {
+                attributes.insert("differentFirst".to_string(), "1".to_string());
+            }
+            if !header_footer.scale_with_doc {
+                attributes.insert("scaleWithDoc".to_string(), "0".to_string());
+            }
+            if !header_footer.align_with_margins {
+                attributes.insert("alignWithMargins".to_string(), "0".to_string());
+            }
+            if !attributes.is_empty()
+                || header_footer_values
+                    .iter()
+                    .any(|(_, value)| value.is_some())
+            {
+                let header_footer_element = xml_doc_mut
+                    .append_child_mut("headerFooter", None)
+                    .context("Failed to add header footer")?;
+                if !attributes.is_empty() {
+                    header_footer_element
+                        .set_attribute_mut(attributes)
+                        .context("Failed to set header footer attribute")?;
+                }
+                let header_footer_id = header_footer_element.get_id();
+                for (tag, value) in header_footer_values {
+                    if let Some(value) = value {
+                        xml_doc_mut
+                            .append_child_mut(tag, Some(&header_footer_id))
+                            .context("Failed to add header footer text")?
+                            .set_value_mut(value);
+                    }
+                }
+            }
+            for (tag, breaks, max_index) in [
+                (
+                    "rowBreaks",
+                    page_setup
+                        .row_breaks
+                        .iter()
+                        .map(|row_index| *row_index as usize)
+                        .collect::<Vec<usize>>(),
+                    16_383,
+                ),
+                (
+                    "colBreaks",
+                    page_setup
+                        .column_breaks
+                        .iter()
+                        .map(|col_index| *col_index as usize)
+                        .collect::<Vec<usize>>(),
+                    1_048_575,
+                ),
+            ] {
+                if !breaks.is_empty() {
+                    let breaks_id = xml_doc_mut
+                        .append_child_mut(tag, None)
+                        .context("Failed to add page breaks")?
+                        .set_attribute_mut(HashMap::from([
+                            ("count".to_string(), breaks.len().to_string()),
+                            ("manualBreakCount".to_string(), breaks.len().to_string()),
+                        ]))
+                        .context("Failed to set page breaks attribute")?
+                        .get_id();
+                    for break_index in breaks {
+                        xml_doc_mut
+                            .append_child_mut("brk", Some(&breaks_id))
+                            .context("Failed to add page break")?
+                            .set_attribute_mut(HashMap::from([
+                                ("id".to_string(), break_index.to_string()),
+                                ("max".to_string(), max_index.to_string()),
+                                ("man".to_string(), "1".to_string()),
+                            ]))
+                            .context("Failed to set page break attribute")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_hyperlinks(&mut self, xml_doc_mut: &mut XmlDocument) -> AnyResult<(), AnyError> {
+        if let Some(hyperlinks) = self.hyperlinks.take() {
+            if !hyperlinks.is_empty() {
+                let mut sheet_relationship_part = self
+                    .sheet_relationship_part
+                    .try_borrow_mut()
+                    .context("Failed to pull sheet relationship part")?;
+                let hyperlinks_id = xml_doc_mut
+                    .append_child_mut("hyperlinks", None)
+                    .context("Failed to insert hyperlinks element")?
+                    .get_id();
+                for (cell_range, hyperlink) in hyperlinks {
+                    let mut attributes = HashMap::new();
+                    let ((start_row, start_col), (end_row, end_col)) = cell_range;
+                    attributes.insert(
+                        "ref".to_string(),
+                        if start_row == end_row && start_col == end_col {
+                            format!(
+                                "{}{}",
+                                ConverterUtil::get_column_ref(start_col)
+                                    .context("Failed to get hyperlink column ref")?,
+                                start_row
+                            )
+                        } else {
+                            get_range_ref(&cell_range).context("Failed to get hyperlink ref")?
+                        },
+                    );
+                    let location = match hyperlink.hyperlink_type {
+                        HyperlinkPropertyTypeValues::TARGET_SHEET => {
+                            Some(if let Some(cell_location) = hyperlink.cell_location {
+                                format!(
+                                    "'{}'!{}",
+                                    hyperlink.value.replace("'", "''"),
+                                    cell_location
+                                )
+                            } else {
+                                hyperlink.value
+                            })
                         }
-                        // Zoom Scale Sheet Layout View
-                        if let Some(zoom_scale_sheet_layout) =
-                            attributes.get("zoomScaleSheetLayoutView")
-                        {
-                            worksheet_view.zoom_scale_sheet_layout = Some(
-                                zoom_scale_sheet_layout
-                                    .parse()
-                                    .context("Failed to Convert Zoom Normal to i16")?,
+                        hyperlink_type => {
+                            let target = if hyperlink_type == HyperlinkPropertyTypeValues::MAIL_TO {
+                                get_mail_to_target(&hyperlink.value, hyperlink.email_subject)
+                            } else {
+                                hyperlink.value
+                            };
+                            attributes.insert(
+                                "r:id".to_string(),
+                                sheet_relationship_part.set_new_external_relationship_mut(
+                                    COMMON_TYPE_COLLECTION
+                                        .get("hyperlink")
+                                        .ok_or(anyhow!("Failed to get hyperlink content"))?,
+                                    &target,
+                                ),
                             );
+                            hyperlink.cell_location
                         }
-                        // Zoom Scale Page Layout View
-                        if let Some(zoom_scale_page_layout) =
-                            attributes.get("zoomScalePageLayoutView")
-                        {
-                            worksheet_view.zoom_scale_page_layout = Some(
-                                zoom_scale_page_layout
-                                    .parse()
-                                    .context("Failed to Convert Zoom Normal to i16")?,
-                            );
+                    };
+                    if let Some(location) = location {
+                        attributes.insert("location".to_string(), location);
+                    }
+                    if let Some(tool_tip) = hyperlink.tool_tip {
+                        attributes.insert("tooltip".to_string(), tool_tip);
+                    }
+                    if let Some(display) = hyperlink.display {
+                        attributes.insert("display".to_string(), display);
+                    }
+                    xml_doc_mut
+                        .append_child_mut("hyperlink", Some(&hyperlinks_id))
+                        .context("Failed to insert hyperlink element")?
+                        .set_attribute_mut(attributes)
+                        .context("Failed to set hyperlink attribute")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the row straight into the sheet data stream
+    fn serialize_stream_row(
+        sheet_data_stream: &mut SheetDataStream,
+        row_index: u32,
+        db_row: RowData,
+    ) -> AnyResult<(), AnyError> {
+        let mut row_content = String::new();
+        let row_attribute = get_row_attribute(&row_index, &db_row.row_record);
+        if let Some(cols) = db_row.cell_records {
+            row_content.push_str(&XmlDeSerializer::generate_xml_tag(
+                "row",
+                &row_attribute,
+                false,
+            ));
+            for (col_index, cell_record) in cols {
+                row_content.push_str(&XmlDeSerializer::generate_xml_tag(
+                    "c",
+                    &get_cell_attribute(&row_index, &col_index, &cell_record)
+                        .context("Failed to Get Attribute for cell")?,
+                    false,
+                ));
+                match cell_record.data_type {
+                    CellDataType::InlineString => {
+                        row_content.push_str("<is>");
+                        row_content.push_str(&cell_record.get_string_item().generate_xml());
+                        row_content.push_str("</is>");
+                    }
+                    _ => {
+                        if let Some(formula) = cell_record.formula {
+                            row_content
+                                .push_str(&XmlDeSerializer::generate_xml_value_tag("f", &formula));
                         }
-                        worksheet_views.view_collection.push(worksheet_view);
+                        row_content.push_str(&XmlDeSerializer::generate_xml_value_tag(
+                            "v",
+                            &cell_record.value.unwrap_or_default(),
+                        ));
                     }
-                } else {
-                    break;
                 }
+                row_content.push_str("</c>");
             }
+            row_content.push_str("</row>");
+        } else {
+            row_content.push_str(&XmlDeSerializer::generate_xml_tag(
+                "row",
+                &row_attribute,
+                true,
+            ));
         }
+        sheet_data_stream
+            .content_writer
+            .write_all(row_content.as_bytes())
+            .context("Failed to write row into sheet data stream")?;
+        sheet_data_stream.start_row = Some(sheet_data_stream.start_row.unwrap_or(row_index));
+        sheet_data_stream.end_row = Some(row_index);
+        Ok(())
     }
-    Ok(worksheet_views)
 }
 
-/// Deserialize Sheet Data
-fn deserialize_sheet_data(
-    xml_doc_mut: &mut XmlDocument,
-) -> AnyResult<(Option<BTreeMap<u32, RowData>>, Dimension), AnyError> {
-    let mut dimension = Dimension::default();
-    if let Some(mut sheet_data_element) = xml_doc_mut.pop_elements_by_tag_mut("sheetData", None) {
-        if let Some(sheet_data) = sheet_data_element.pop() {
-            let mut sheet_data_collection: BTreeMap<u32, RowData> = BTreeMap::new();
-            // Loop All rows of sheet data
-            loop {
-                if let Some((row_element_id, _)) = sheet_data.pop_child_mut() {
-                    if let Some(row_element) = xml_doc_mut.pop_element_mut(&row_element_id) {
-                        let mut row_record = RowProperties::default();
-                        let row_attribute = row_element
-                            .get_attribute()
-                            .ok_or(anyhow!("Failed to pull Row Attribute."))?;
-                        // Get Row Id
-                        let row_index = row_attribute
-                            .get("r")
-                            .ok_or(anyhow!("Missing mandatory row id attribute"))?
-                            .parse()
-                            .context("Failed to parse row id")?;
-                        if let Some(row_span) = row_attribute.get("spans") {
-                            row_record.span = Some(row_span.to_string());
-                        }
-                        if let Some(style_id) = row_attribute.get("s") {
-                            if let Some(custom_formant) = row_attribute.get("customFormat") {
-                                row_record.style_id = if custom_formant == "1" {
-                                    Some(StyleId::new(
-                                        style_id
-                                            .parse()
-                                            .context("Failed to parse the row style id")?,
-                                    ))
-                                } else {
-                                    None
-                                };
-                            }
-                        }
-                        if let Some(hidden) = row_attribute.get("hidden") {
-                            row_record.hidden = if hidden == "1" { Some(true) } else { None };
+fn get_row_attribute(row_index: &u32, row_record: &RowProperties) -> HashMap<String, String> {
+    let mut row_attribute = HashMap::new();
+    row_attribute.insert("r".to_string(), row_index.to_string());
+    if let Some(row_span) = row_record.span.as_ref() {
+        row_attribute.insert("spans".to_string(), row_span.to_string());
+    }
+    if let Some(row_style_id) = row_record.style_id.as_ref() {
+        row_attribute.insert("customFormat".to_string(), "1".to_string());
+        row_attribute.insert("s".to_string(), row_style_id.id.to_string());
+    }
+    if let Some(row_height) = row_record.height {
+        row_attribute.insert("customHeight".to_string(), "1".to_string());
+        row_attribute.insert("ht".to_string(), row_height.to_string());
+    }
+    if let Some(_) = row_record.hidden {
+        row_attribute.insert("hidden".to_string(), "1".to_string());
+    }
+    if let Some(row_group_level) = row_record.group_level {
+        row_attribute.insert("outlineLevel".to_string(), row_group_level.to_string());
+    }
+    if let Some(_) = row_record.collapsed {
+        row_attribute.insert("collapsed".to_string(), "1".to_string());
+    }
+    if let Some(_) = row_record.thick_top {
+        row_attribute.insert("thickTop".to_string(), "1".to_string());
+    }
+    if let Some(_) = row_record.thick_bottom {
+        row_attribute.insert("thickBot".to_string(), "1".to_string());
+    }
+    if let Some(_) = row_record.place_holder {
+        row_attribute.insert("ph".to_string(), "1".to_string());
+    }
+    row_attribute
+}
+
+fn get_cell_attribute(
+    row_index: &u32,
+    col_index: &u16,
+    cell_record: &CellData,
+) -> AnyResult<HashMap<String, String>, AnyError> {
+    let mut cell_attribute = HashMap::new();
+    cell_attribute.insert(
+        "r".to_string(),
+        format!(
+            "{}{}",
+            ConverterUtil::get_column_ref(*col_index).context("Failed to get Char Id from Int")?,
+            row_index
+        ),
+    );
+    if let Some(cell_style_id) = cell_record.style_id.as_ref() {
+        cell_attribute.insert("s".to_string(), cell_style_id.id.to_string());
+    }
+    if cell_record.data_type != CellDataType::Number {
+        cell_attribute.insert(
+            "t".to_string(),
+            CellDataType::get_string(cell_record.data_type),
+        );
+    }
+    if let Some(cell_comment_id) = cell_record.comment_id {
+        cell_attribute.insert("cm".to_string(), cell_comment_id.to_string());
+    }
+    if let Some(cell_metadata) = cell_record.metadata.as_ref() {
+        cell_attribute.insert("vm".to_string(), cell_metadata.to_string());
+    }
+    if let Some(_) = cell_record.place_holder {
+        cell_attribute.insert("ph".to_string(), "1".to_string());
+    }
+    Ok(cell_attribute)
+}
+
+/// Check the cell values before any of them gets stored, rejected write leaves the sheet as is
+fn validate_cell_properties(column_cell: &[CellProperties]) -> AnyResult<(), AnyError> {
+    for cell_properties in column_cell {
+        if let Some(formula) = cell_properties.formula.as_ref() {
+            FormulaParser::parse(formula).context(format!("Invalid formula : {}", formula))?;
+        }
+        match &cell_properties.value {
+            CellValue::Number(number) if !number.is_finite() => {
+                return Err(anyhow!("Cell number value must be finite : {}", number));
+            }
+            CellValue::DateTime(date_time) => {
+                ConverterUtil::get_excel_serial_date(date_time)
+                    .context("Failed to convert date into serial number")?;
+            }
+            _ => {}
+        }
+        if let Some(hyperlink) = cell_properties.hyperlink_properties.as_ref() {
+            if !matches!(
+                hyperlink.hyperlink_type,
+                HyperlinkPropertyTypeValues::EXISTING_FILE
+                    | HyperlinkPropertyTypeValues::WEB_URL
+                    | HyperlinkPropertyTypeValues::MAIL_TO
+                    | HyperlinkPropertyTypeValues::TARGET_SHEET
+            ) {
+                return Err(anyhow!(
+                    "Hyperlink type {} is not supported in worksheet",
+                    HyperlinkPropertyTypeValues::get_string(hyperlink.hyperlink_type)
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_color_attribute(color_setting: ColorSetting) -> HashMap<String, String> {
+    HashMap::from([(
+        ColorSettingTypeValues::get_string(color_setting.color_setting_type),
+        color_setting.value,
+    )])
+}
+
+fn append_conditional_format_values_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    values: Vec<ConditionalFormatValue>,
+) -> AnyResult<(), AnyError> {
+    for value in values {
+        let mut attributes = HashMap::from([(
+            "type".to_string(),
+            ConditionalFormatValueTypeValues::get_string(value.value_type),
+        )]);
+        if let Some(value) = value.value {
+            attributes.insert("val".to_string(), value);
+        }
+        xml_doc_mut
+            .append_child_mut("cfvo", Some(parent_id))
+            .context("Failed to insert conditional format value")?
+            .set_attribute_mut(attributes)
+            .context("Failed to set conditional format value attribute")?;
+    }
+    Ok(())
+}
+
+/// Relation part path of the part like "xl/charts/_rels/chart1.xml.rels"
+fn get_relationship_file_path(file_path: &str) -> String {
+    let (folder_path, file_name) = file_path.rsplit_once("/").unwrap_or(("", file_path));
+    format!("{}/_rels/{}.rels", folder_path, file_name)
+}
+
+/// Check any drawing relation in the folder refer the media. Relation open for edit is
+/// considered as referring since its content is not in store
+fn is_media_referred(
+    office_document: &OfficeDocument,
+    rels_folder_path: &str,
+    media_file_path: &str,
+) -> AnyResult<bool, AnyError> {
+    let relative_path = format!("{}/", rels_folder_path.trim_end_matches("/_rels"));
+    for file_path in office_document.list_file_path_by_folder(rels_folder_path) {
+        if office_document.check_file_open(&file_path) {
+            return Ok(true);
+        }
+        if let Some(mut xml_tree) = office_document
+            .read_xml_tree(&file_path)
+            .context("Failed to read drawing relation")?
+        {
+            if let Some(relationship_elements) =
+                xml_tree.pop_elements_by_tag_mut("Relationship", None)
+            {
+                if relationship_elements.iter().any(|relationship_element| {
+                    relationship_element
+                        .get_attribute()
+                        .and_then(|attributes| attributes.get("Target"))
+                        .is_some_and(|target| {
+                            RelationsPart::resolve_target_path(&relative_path, target)
+                                == media_file_path
+                        })
+                }) {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn serialize_conditional_format_rule(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    rule: ConditionalFormatRule,
+    dxf_id: Option<u32>,
+    priority: u32,
+) -> AnyResult<(), AnyError> {
+    let mut attributes = HashMap::from([("priority".to_string(), priority.to_string())]);
+    if let Some(dxf_id) = dxf_id {
+        attributes.insert("dxfId".to_string(), dxf_id.to_string());
+    }
+    if rule.stop_if_true {
+        attributes.insert("stopIfTrue".to_string(), "1".to_string());
+    }
+    let mut formulas = Vec::new();
+    let rule_type = match &rule.rule {
+        ConditionalFormatRuleValues::CellValue { operator, .. } => {
+            attributes.insert(
+                "operator".to_string(),
+                ConditionalFormatOperatorValues::get_string(operator.clone()),
+            );
+            "cellIs"
+        }
+        ConditionalFormatRuleValues::Expression { .. } => "expression",
+        ConditionalFormatRuleValues::ColorScale { .. } => "colorScale",
+        ConditionalFormatRuleValues::DataBar { .. } => "dataBar",
+        ConditionalFormatRuleValues::IconSet { .. } => "iconSet",
+        ConditionalFormatRuleValues::TopBottom {
+            rank,
+            is_bottom,
+            is_percent,
+        } => {
+            attributes.insert("rank".to_string(), rank.to_string());
+            if *is_bottom {
+                attributes.insert("bottom".to_string(), "1".to_string());
+            }
+            if *is_percent {
+                attributes.insert("percent".to_string(), "1".to_string());
+            }
+            "top10"
+        }
+        ConditionalFormatRuleValues::DuplicateValues => "duplicateValues",
+        ConditionalFormatRuleValues::UniqueValues => "uniqueValues",
+    };
+    attributes.insert("type".to_string(), rule_type.to_string());
+    let rule_id = xml_doc_mut
+        .append_child_mut("cfRule", Some(parent_id))
+        .context("Failed to insert conditional format rule")?
+        .set_attribute_mut(attributes)
+        .context("Failed to set conditional format rule attribute")?
+        .get_id();
+    match rule.rule {
+        ConditionalFormatRuleValues::CellValue {
+            formulas: cell_formulas,
+            ..
+        } => formulas = cell_formulas,
+        ConditionalFormatRuleValues::Expression { formula } => formulas.push(formula),
+        ConditionalFormatRuleValues::ColorScale { values, colors } => {
+            let color_scale_id = xml_doc_mut
+                .append_child_mut("colorScale", Some(&rule_id))
+                .context("Failed to insert color scale")?
+                .get_id();
+            append_conditional_format_values_mut(xml_doc_mut, &color_scale_id, values)?;
+            for color in colors {
+                xml_doc_mut
+                    .append_child_mut("color", Some(&color_scale_id))
+                    .context("Failed to insert color scale color")?
+                    .set_attribute_mut(get_color_attribute(color))
+                    .context("Failed to set color scale color")?;
+            }
+        }
+        ConditionalFormatRuleValues::DataBar {
+            min_value,
+            max_value,
+            color,
+            show_value,
+        } => {
+            let data_bar = xml_doc_mut
+                .append_child_mut("dataBar", Some(&rule_id))
+                .context("Failed to insert data bar")?;
+            if !show_value {
+                data_bar
+                    .set_attribute_mut(HashMap::from([("showValue".to_string(), "0".to_string())]))
+                    .context("Failed to set data bar attribute")?;
+            }
+            let data_bar_id = data_bar.get_id();
+            append_conditional_format_values_mut(
+                xml_doc_mut,
+                &data_bar_id,
+                vec![min_value, max_value],
+            )?;
+            xml_doc_mut
+                .append_child_mut("color", Some(&data_bar_id))
+                .context("Failed to insert data bar color")?
+                .set_attribute_mut(get_color_attribute(color))
+                .context("Failed to set data bar color")?;
+        }
+        ConditionalFormatRuleValues::IconSet {
+            icon_set,
+            values,
+            show_value,
+            is_reverse,
+        } => {
+            let mut icon_set_attributes =
+                HashMap::from([("iconSet".to_string(), IconSetValues::get_string(icon_set))]);
+            if !show_value {
+                icon_set_attributes.insert("showValue".to_string(), "0".to_string());
+            }
+            if is_reverse {
+                icon_set_attributes.insert("reverse".to_string(), "1".to_string());
+            }
+            let icon_set_id = xml_doc_mut
+                .append_child_mut("iconSet", Some(&rule_id))
+                .context("Failed to insert icon set")?
+                .set_attribute_mut(icon_set_attributes)
+                .context("Failed to set icon set attribute")?
+                .get_id();
+            append_conditional_format_values_mut(xml_doc_mut, &icon_set_id, values)?;
+        }
+        _ => {}
+    }
+    for formula in formulas {
+        xml_doc_mut
+            .append_child_mut("formula", Some(&rule_id))
+            .context("Failed to insert conditional format formula")?
+            .set_value_mut(formula.trim_start_matches('=').to_string());
+    }
+    Ok(())
+}
+
+/// Check the rule carry the values its type needs
+fn validate_conditional_format_rule(rule: &ConditionalFormatRule) -> AnyResult<(), AnyError> {
+    let needs_style = match &rule.rule {
+        ConditionalFormatRuleValues::CellValue { operator, formulas } => {
+            let formula_count = match operator {
+                ConditionalFormatOperatorValues::Between
+                | ConditionalFormatOperatorValues::NotBetween => 2,
+                _ => 1,
+            };
+            if formulas.len() != formula_count {
+                return Err(anyhow!(
+                    "Cell value rule with {} operator needs {} formula",
+                    ConditionalFormatOperatorValues::get_string(operator.clone()),
+                    formula_count
+                ));
+            }
+            true
+        }
+        ConditionalFormatRuleValues::Expression { formula } => {
+            if formula.trim_start_matches('=').trim().is_empty() {
+                return Err(anyhow!("Expression rule needs a formula"));
+            }
+            true
+        }
+        ConditionalFormatRuleValues::ColorScale { values, colors } => {
+            if !(2..=3).contains(&values.len()) || values.len() != colors.len() {
+                return Err(anyhow!(
+                    "Color scale needs two or three values with a color for each value"
+                ));
+            }
+            false
+        }
+        ConditionalFormatRuleValues::DataBar { .. } => false,
+        ConditionalFormatRuleValues::IconSet {
+            icon_set, values, ..
+        } => {
+            if values.len() != icon_set.get_icon_count() {
+                return Err(anyhow!(
+                    "Icon set {} needs {} values",
+                    IconSetValues::get_string(icon_set.clone()),
+                    icon_set.get_icon_count()
+                ));
+            }
+            false
+        }
+        ConditionalFormatRuleValues::TopBottom {
+            rank, is_percent, ..
+        } => {
+            if *rank == 0 || (*is_percent && *rank > 100) {
+                return Err(anyhow!("Top bottom rank {} is out of range", rank));
+            }
+            true
+        }
+        ConditionalFormatRuleValues::DuplicateValues
+        | ConditionalFormatRuleValues::UniqueValues => true,
+    };
+    if needs_style && rule.style.is_none() {
+        return Err(anyhow!("Conditional format rule needs a style to apply"));
+    }
+    let values = match &rule.rule {
+        ConditionalFormatRuleValues::ColorScale { values, .. }
+        | ConditionalFormatRuleValues::IconSet { values, .. } => values.iter().collect(),
+        ConditionalFormatRuleValues::DataBar {
+            min_value,
+            max_value,
+            ..
+        } => vec![min_value, max_value],
+        _ => Vec::new(),
+    };
+    if values.iter().any(|value| {
+        value.value.is_none()
+            && !matches!(
+                value.value_type,
+                ConditionalFormatValueTypeValues::Min | ConditionalFormatValueTypeValues::Max
+            )
+    }) {
+        return Err(anyhow!(
+            "Conditional format value needs a value except for min and max type"
+        ));
+    }
+    Ok(())
+}
+
+/// Range reference like "A1:C3", single cell range give the cell reference
+fn get_range_ref(
+    ((start_row, start_col), (end_row, end_col)): &CellRange,
+) -> AnyResult<String, AnyError> {
+    if start_row == end_row && start_col == end_col {
+        return Ok(format!(
+            "{}{}",
+            ConverterUtil::get_column_ref(*start_col).context("Failed to get column ref")?,
+            start_row
+        ));
+    }
+    Ok(format!(
+        "{}{}:{}{}",
+        ConverterUtil::get_column_ref(*start_col).context("Failed to get start column ref")?,
+        start_row,
+        ConverterUtil::get_column_ref(*end_col).context("Failed to get end column ref")?,
+        end_row
+    ))
+}
+
+/// Check two cell range share at least one cell
+fn is_range_overlap(cell_range: &CellRange, other_range: &CellRange) -> bool {
+    let ((start_row, start_col), (end_row, end_col)) = *cell_range;
+    let ((other_start_row, other_start_col), (other_end_row, other_end_col)) = *other_range;
+    start_row <= other_end_row
+        && other_start_row <= end_row
+        && start_col <= other_end_col
+        && other_start_col <= end_col
+}
+
+/// Check the inner range is fully covered by the outer range
+fn is_range_inside(inner_range: &CellRange, outer_range: &CellRange) -> bool {
+    let ((start_row, start_col), (end_row, end_col)) = *inner_range;
+    let ((outer_start_row, outer_start_col), (outer_end_row, outer_end_col)) = *outer_range;
+    outer_start_row <= start_row
+        && end_row <= outer_end_row
+        && outer_start_col <= start_col
+        && end_col <= outer_end_col
+}
+
+fn serialize_filter_criteria(
+    xml_doc_mut: &mut XmlDocument,
+    filter_column_id: &usize,
+    criteria: FilterCriteriaValues,
+) -> AnyResult<(), AnyError> {
+    match criteria {
+        FilterCriteriaValues::Values {
+            values,
+            include_blank,
+        } => {
+            let filters_id = xml_doc_mut
+                .append_child_mut("filters", Some(filter_column_id))
+                .context("Failed to insert filters element")?
+                .get_id();
+            if include_blank {
+                xml_doc_mut
+                    .get_element_mut(&filters_id)
+                    .ok_or(anyhow!("Failed to get filters element"))?
+                    .set_attribute_mut(HashMap::from([("blank".to_string(), "1".to_string())]))
+                    .context("Failed to set filters attribute")?;
+            }
+            for value in values {
+                xml_doc_mut
+                    .append_child_mut("filter", Some(&filters_id))
+                    .context("Failed to insert filter element")?
+                    .set_attribute_mut(HashMap::from([("val".to_string(), value)]))
+                    .context("Failed to set filter attribute")?;
+            }
+        }
+        FilterCriteriaValues::Custom { filters, match_all } => {
+            let custom_filters_id = xml_doc_mut
+                .append_child_mut("customFilters", Some(filter_column_id))
+                .context("Failed to insert custom filters element")?
+                .get_id();
+            if match_all {
+                xml_doc_mut
+                    .get_element_mut(&custom_filters_id)
+                    .ok_or(anyhow!("Failed to get custom filters element"))?
+                    .set_attribute_mut(HashMap::from([("and".to_string(), "1".to_string())]))
+                    .context("Failed to set custom filters attribute")?;
+            }
+            for custom_filter in filters {
+                let mut attributes = HashMap::from([("val".to_string(), custom_filter.value)]);
+                if custom_filter.operator != FilterOperatorValues::Equal {
+                    attributes.insert(
+                        "operator".to_string(),
+                        FilterOperatorValues::get_string(custom_filter.operator),
+                    );
+                }
+                xml_doc_mut
+                    .append_child_mut("customFilter", Some(&custom_filters_id))
+                    .context("Failed to insert custom filter element")?
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set custom filter attribute")?;
+            }
+        }
+        FilterCriteriaValues::Top10 {
+            value,
+            is_bottom,
+            is_percent,
+        } => {
+            let mut attributes = HashMap::from([("val".to_string(), value.to_string())]);
+            if is_bottom {
+                attributes.insert("top".to_string(), "0".to_string());
+            }
+            if is_percent {
+                attributes.insert("percent".to_string(), "1".to_string());
+            }
+            xml_doc_mut
+                .append_child_mut("top10", Some(filter_column_id))
+                .context("Failed to insert top 10 element")?
+                .set_attribute_mut(attributes)
+                .context("Failed to set top 10 attribute")?;
+        }
+        FilterCriteriaValues::Dynamic(dynamic_type) => {
+            xml_doc_mut
+                .append_child_mut("dynamicFilter", Some(filter_column_id))
+                .context("Failed to insert dynamic filter element")?
+                .set_attribute_mut(HashMap::from([(
+                    "type".to_string(),
+                    DynamicFilterTypeValues::get_string(dynamic_type),
+                )]))
+                .context("Failed to set dynamic filter attribute")?;
+        }
+    }
+    Ok(())
+}
+
+/// Get or create the child of sheet property like outlinePr, pageSetUpPr
+fn get_sheet_property_child_mut<'a>(
+    xml_doc_mut: &'a mut XmlDocument,
+    tag: &str,
+) -> AnyResult<&'a mut XmlElement, AnyError> {
+    let sheet_property_id = match xml_doc_mut
+        .get_first_element_id(vec!["sheetPr"], None)
+        .context("Failed to find sheet property")?
+    {
+        Some(sheet_property_id) => sheet_property_id,
+        None => xml_doc_mut
+            .append_child_mut("sheetPr", None)
+            .context("Failed to add sheet property")?
+            .get_id(),
+    };
+    let child_id = match xml_doc_mut
+        .get_first_element_id(vec![tag], Some(&sheet_property_id))
+        .context("Failed to find sheet property child")?
+    {
+        Some(child_id) => child_id,
+        None => {
+            let child_id = xml_doc_mut
+                .append_child_mut(tag, Some(&sheet_property_id))
+                .context("Failed to add sheet property child")?
+                .get_id();
+            xml_doc_mut
+                .get_element_mut(&sheet_property_id)
+                .ok_or(anyhow!("Failed to get sheet property"))?
+                .order_child_mut(
+                    EXCEL_ORDER_COLLECTION
+                        .get("sheetPr")
+                        .ok_or(anyhow!("Failed to get sheet property default order"))?,
+                )
+                .context("Failed Reorder the sheet property child's")?;
+            child_id
+        }
+    };
+    xml_doc_mut
+        .get_element_mut(&child_id)
+        .ok_or(anyhow!("Failed to get sheet property child : {}", tag))
+}
+
+/// Check the (start, end) index of the outline group
+fn validate_outline_range(
+    start_index: u32,
+    end_index: u32,
+    max_index: u32,
+) -> AnyResult<(), AnyError> {
+    if start_index == 0 || start_index > end_index || end_index > max_index {
+        Err(anyhow!(
+            "Invalid outline group range : {} to {}",
+            start_index,
+            end_index
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Summary row or column of the group, after the group when summary below or right is set
+fn get_outline_summary_index(
+    start_index: u32,
+    end_index: u32,
+    is_summary_after: bool,
+    max_index: u32,
+) -> Option<u32> {
+    if is_summary_after {
+        Some(end_index + 1).filter(|summary_index| *summary_index <= max_index)
+    } else {
+        Some(start_index - 1).filter(|summary_index| *summary_index > 0)
+    }
+}
+
+/// Hidden state of each member of the group after collapse or expand. On expand the
+/// member stay hidden while it is inside a collapsed inner group
+fn get_outline_hidden_state(
+    mut outline: BTreeMap<u32, (u8, bool)>,
+    start_index: u32,
+    end_index: u32,
+    is_summary_after: bool,
+    max_index: u32,
+    collapsed: bool,
+) -> AnyResult<Vec<(u32, bool)>, AnyError> {
+    let get_level = |outline: &BTreeMap<u32, (u8, bool)>, index: u32| {
+        outline.get(&index).map_or(0, |(level, _)| *level)
+    };
+    let group_level = (start_index..=end_index)
+        .map(|index| get_level(&outline, index))
+        .min()
+        .unwrap_or(0);
+    if group_level == 0 {
+        return Err(anyhow!(
+            "Outline group range is not grouped : {} to {}",
+            start_index,
+            end_index
+        ));
+    }
+    if collapsed {
+        return Ok((start_index..=end_index)
+            .map(|index| (index, true))
+            .collect());
+    }
+    if let Some(summary_index) =
+        get_outline_summary_index(start_index, end_index, is_summary_after, max_index)
+    {
+        if let Some((_, summary_collapsed)) = outline.get_mut(&summary_index) {
+            *summary_collapsed = false;
+        }
+    }
+    Ok((start_index..=end_index)
+        .map(|index| {
+            let is_hidden = (group_level + 1..=get_level(&outline, index)).any(|level| {
+                let mut run_start = index;
+                while run_start > 1 && get_level(&outline, run_start - 1) >= level {
+                    run_start -= 1;
+                }
+                let mut run_end = index;
+                while run_end < max_index && get_level(&outline, run_end + 1) >= level {
+                    run_end += 1;
+                }
+                get_outline_summary_index(run_start, run_end, is_summary_after, max_index)
+                    .and_then(|summary_index| outline.get(&summary_index))
+                    .is_some_and(|(_, summary_collapsed)| *summary_collapsed)
+            });
+            (index, is_hidden)
+        })
+        .collect())
+}
+
+/// Protection attribute of each allow flag with the schema default of the attribute.
+/// Attribute value 1 block the action on the protected sheet
+fn get_sheet_protection_flags_mut(
+    protection: &mut SheetProtection,
+) -> [(&'static str, bool, &mut bool); 15] {
+    [
+        ("objects", false, &mut protection.allow_edit_objects),
+        ("scenarios", false, &mut protection.allow_edit_scenarios),
+        (
+            "selectLockedCells",
+            false,
+            &mut protection.allow_select_locked_cells,
+        ),
+        (
+            "selectUnlockedCells",
+            false,
+            &mut protection.allow_select_unlocked_cells,
+        ),
+        ("formatCells", true, &mut protection.allow_format_cells),
+        ("formatColumns", true, &mut protection.allow_format_columns),
+        ("formatRows", true, &mut protection.allow_format_rows),
+        ("insertColumns", true, &mut protection.allow_insert_columns),
+        ("insertRows", true, &mut protection.allow_insert_rows),
+        (
+            "insertHyperlinks",
+            true,
+            &mut protection.allow_insert_hyperlinks,
+        ),
+        ("deleteColumns", true, &mut protection.allow_delete_columns),
+        ("deleteRows", true, &mut protection.allow_delete_rows),
+        ("sort", true, &mut protection.allow_sort),
+        ("autoFilter", true, &mut protection.allow_auto_filter),
+        ("pivotTables", true, &mut protection.allow_pivot_tables),
+    ]
+}
+
+/// Read sheet protection, password hash is kept as loaded
+fn deserialize_sheet_protection(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<Option<(SheetProtection, PasswordHashAttributes)>, AnyError> {
+    if let Some(mut sheet_protection_element) =
+        xml_doc_mut.pop_elements_by_tag_mut("sheetProtection", None)
+    {
+        if let Some(sheet_protection) = sheet_protection_element.pop() {
+            let attributes = sheet_protection
+                .get_attribute()
+                .cloned()
+                .unwrap_or_default();
+            if !attributes
+                .get("sheet")
+                .is_some_and(|value| ConverterUtil::normalize_bool_property_bool(value))
+            {
+                return Ok(None);
+            }
+            let mut protection = SheetProtection::default();
+            for (key, default_blocked, is_allowed) in
+                get_sheet_protection_flags_mut(&mut protection)
+            {
+                *is_allowed = !attributes
+                    .get(key)
+                    .map(|value| ConverterUtil::normalize_bool_property_bool(value))
+                    .unwrap_or(default_blocked);
+            }
+            return Ok(Some((
+                protection,
+                filter_password_hash_attributes(&attributes, ""),
+            )));
+        }
+    }
+    Ok(None)
+}
+
+fn deserialize_protected_ranges(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<Option<Vec<(ProtectedRange, PasswordHashAttributes)>>, AnyError> {
+    if let Some(mut protected_ranges_element) =
+        xml_doc_mut.pop_elements_by_tag_mut("protectedRanges", None)
+    {
+        if let Some(protected_ranges_element) = protected_ranges_element.pop() {
+            let mut protected_ranges =
+                Vec::with_capacity(protected_ranges_element.get_child_count());
+            while let Some((child_id, _)) = protected_ranges_element.pop_child_mut() {
+                if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                    if let Some(attributes) = child_element.get_attribute() {
+                        protected_ranges.push((
+                            ProtectedRange {
+                                name: attributes.get("name").cloned().unwrap_or_default(),
+                                range_ref: attributes.get("sqref").cloned().unwrap_or_default(),
+                                password: None,
+                            },
+                            filter_password_hash_attributes(attributes, ""),
+                        ));
+                    }
+                }
+            }
+            return Ok(Some(protected_ranges));
+        }
+    }
+    Ok(None)
+}
+
+/// Read auto filter with its sort state, sort state without auto filter stay in document
+fn deserialize_auto_filter(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<Option<AutoFilter>, AnyError> {
+    if xml_doc_mut
+        .get_element_ids_by_tag("autoFilter", None)
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let mut sort_state = None;
+    if let Some(mut sort_state_element) = xml_doc_mut.pop_elements_by_tag_mut("sortState", None) {
+        if let Some(sort_state_element) = sort_state_element.pop() {
+            sort_state = Some(deserialize_sort_state(xml_doc_mut, &sort_state_element));
+        }
+    }
+    if let Some(mut auto_filter_element) = xml_doc_mut.pop_elements_by_tag_mut("autoFilter", None) {
+        if let Some(auto_filter) = auto_filter_element.pop() {
+            let mut filter_columns = Vec::with_capacity(auto_filter.get_child_count());
+            while let Some((child_id, _)) = auto_filter.pop_child_mut() {
+                if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                    match child_element.get_tag() {
+                        "filterColumn" => filter_columns
+                            .push(deserialize_filter_column(xml_doc_mut, &child_element)),
+                        "sortState" if sort_state.is_none() => {
+                            sort_state = Some(deserialize_sort_state(xml_doc_mut, &child_element))
                         }
-                        if let Some(height) = row_attribute.get("ht") {
-                            if let Some(custom_height) = row_attribute.get("customHeight") {
-                                row_record.height = if custom_height == "1" {
-                                    Some(height.parse().context("Failed to parse the row height")?)
-                                } else {
-                                    None
-                                };
-                            }
+                        _ => {}
+                    }
+                }
+            }
+            return Ok(Some(AutoFilter {
+                range_ref: auto_filter
+                    .get_attribute()
+                    .and_then(|attributes| attributes.get("ref").cloned())
+                    .unwrap_or_default(),
+                filter_columns,
+                sort_state,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// Color and icon filter are not supported and get loaded as column without criteria
+fn deserialize_filter_column(
+    xml_doc_mut: &mut XmlDocument,
+    filter_column_element: &XmlElement,
+) -> FilterColumn {
+    let attributes = filter_column_element
+        .get_attribute()
+        .cloned()
+        .unwrap_or_default();
+    let is_enabled = |attributes: &HashMap<String, String>, key: &str| {
+        attributes
+            .get(key)
+            .is_some_and(|value| ConverterUtil::normalize_bool_property_bool(value))
+    };
+    let mut criteria = None;
+    while let Some((criteria_id, _)) = filter_column_element.pop_child_mut() {
+        if let Some(criteria_element) = xml_doc_mut.pop_element_mut(&criteria_id) {
+            let criteria_attributes = criteria_element
+                .get_attribute()
+                .cloned()
+                .unwrap_or_default();
+            let mut child_attributes = Vec::new();
+            while let Some((child_id, _)) = criteria_element.pop_child_mut() {
+                if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                    if let Some(child_attribute) = child_element.get_attribute() {
+                        child_attributes
+                            .push((child_element.get_tag().to_string(), child_attribute.clone()));
+                    }
+                }
+            }
+            criteria = match criteria_element.get_tag() {
+                "filters" => Some(FilterCriteriaValues::Values {
+                    values: child_attributes
+                        .into_iter()
+                        .filter(|(tag, _)| tag == "filter")
+                        .filter_map(|(_, attributes)| attributes.get("val").cloned())
+                        .collect(),
+                    include_blank: is_enabled(&criteria_attributes, "blank"),
+                }),
+                "customFilters" => Some(FilterCriteriaValues::Custom {
+                    filters: child_attributes
+                        .into_iter()
+                        .map(|(_, attributes)| CustomFilter {
+                            operator: FilterOperatorValues::get_enum(
+                                attributes
+                                    .get("operator")
+                                    .map_or("equal", |value| value.as_str()),
+                            ),
+                            value: attributes.get("val").cloned().unwrap_or_default(),
+                        })
+                        .collect(),
+                    match_all: is_enabled(&criteria_attributes, "and"),
+                }),
+                "top10" => Some(FilterCriteriaValues::Top10 {
+                    value: criteria_attributes
+                        .get("val")
+                        .and_then(|value| value.parse().ok())
+                        .unwrap_or(10.0),
+                    is_bottom: criteria_attributes
+                        .get("top")
+                        .is_some_and(|value| !ConverterUtil::normalize_bool_property_bool(value)),
+                    is_percent: is_enabled(&criteria_attributes, "percent"),
+                }),
+                "dynamicFilter" => criteria_attributes.get("type").map(|dynamic_type| {
+                    FilterCriteriaValues::Dynamic(DynamicFilterTypeValues::get_enum(dynamic_type))
+                }),
+                _ => None,
+            };
+        }
+    }
+    FilterColumn {
+        column_index: attributes
+            .get("colId")
+            .and_then(|column_index| column_index.parse().ok())
+            .unwrap_or_default(),
+        criteria,
+        hide_button: is_enabled(&attributes, "hiddenButton"),
+    }
+}
+
+fn deserialize_sort_state(
+    xml_doc_mut: &mut XmlDocument,
+    sort_state_element: &XmlElement,
+) -> SortState {
+    let attributes = sort_state_element
+        .get_attribute()
+        .cloned()
+        .unwrap_or_default();
+    let mut conditions = Vec::with_capacity(sort_state_element.get_child_count());
+    while let Some((sort_condition_id, _)) = sort_state_element.pop_child_mut() {
+        if let Some(sort_condition) = xml_doc_mut.pop_element_mut(&sort_condition_id) {
+            if let Some(attributes) = sort_condition.get_attribute() {
+                conditions.push(SortCondition {
+                    range_ref: attributes.get("ref").cloned().unwrap_or_default(),
+                    descending: attributes
+                        .get("descending")
+                        .is_some_and(|value| ConverterUtil::normalize_bool_property_bool(value)),
+                });
+            }
+        }
+    }
+    SortState {
+        range_ref: attributes.get("ref").cloned().unwrap_or_default(),
+        conditions,
+        case_sensitive: attributes
+            .get("caseSensitive")
+            .is_some_and(|value| ConverterUtil::normalize_bool_property_bool(value)),
+    }
+}
+
+/// Read print options, margins, page setup, header footer and page breaks as one setting.
+/// Printer setting part of the loaded page setup is not kept
+fn deserialize_page_setup(xml_doc_mut: &mut XmlDocument) -> AnyResult<Option<PageSetup>, AnyError> {
+    let mut page_setup = PageSetup::default();
+    let mut is_found = false;
+    // Fit to page flag of sheet property is written back from the page setup
+    if let Some(page_setup_property_id) = xml_doc_mut
+        .get_first_element_id(vec!["sheetPr", "pageSetUpPr"], None)
+        .context("Failed to find page setup property")?
+    {
+        if let Some(attributes) = xml_doc_mut
+            .get_element_mut(&page_setup_property_id)
+            .and_then(|page_setup_property| page_setup_property.get_attribute_mut())
+        {
+            if attributes
+                .remove("fitToPage")
+                .is_some_and(|value| ConverterUtil::normalize_bool_property_bool(&value))
+            {
+                page_setup.fit_to_page = Some((1, 1));
+                is_found = true;
+            }
+        }
+    }
+    let get_flag = |attributes: &HashMap<String, String>, key: &str| {
+        attributes
+            .get(key)
+            .is_some_and(|value| ConverterUtil::normalize_bool_property_bool(value))
+    };
+    if let Some(print_options) = xml_doc_mut
+        .pop_elements_by_tag_mut("printOptions", None)
+        .and_then(|mut elements| elements.pop())
+    {
+        is_found = true;
+        let attributes = print_options.get_attribute().cloned().unwrap_or_default();
+        page_setup.center_horizontally = get_flag(&attributes, "horizontalCentered");
+        page_setup.center_vertically = get_flag(&attributes, "verticalCentered");
+        page_setup.print_headings = get_flag(&attributes, "headings");
+        page_setup.print_grid_lines = get_flag(&attributes, "gridLines");
+    }
+    if let Some(page_margins) = xml_doc_mut
+        .pop_elements_by_tag_mut("pageMargins", None)
+        .and_then(|mut elements| elements.pop())
+    {
+        is_found = true;
+        let attributes = page_margins.get_attribute().cloned().unwrap_or_default();
+        for (key, margin) in [
+            ("left", &mut page_setup.margins.left),
+            ("right", &mut page_setup.margins.right),
+            ("top", &mut page_setup.margins.top),
+            ("bottom", &mut page_setup.margins.bottom),
+            ("header", &mut page_setup.margins.header),
+            ("footer", &mut page_setup.margins.footer),
+        ] {
+            if let Some(value) = attributes.get(key).and_then(|value| value.parse().ok()) {
+                *margin = value;
+            }
+        }
+    }
+    if let Some(page_setup_element) = xml_doc_mut
+        .pop_elements_by_tag_mut("pageSetup", None)
+        .and_then(|mut elements| elements.pop())
+    {
+        is_found = true;
+        let attributes = page_setup_element
+            .get_attribute()
+            .cloned()
+            .unwrap_or_default();
+        if let Some(orientation) = attributes.get("orientation") {
+            page_setup.orientation = PageOrientationValues::get_enum(orientation);
+        }
+        if let Some(paper_size) = attributes.get("paperSize") {
+            page_setup.paper_size = PaperSizeValues::get_enum(paper_size);
+        }
+        if let Some(scale) = attributes.get("scale").and_then(|value| value.parse().ok()) {
+            page_setup.scale = scale;
+        }
+        if let Some(fit_to_page) = page_setup.fit_to_page.as_mut() {
+            if let Some(fit_to_width) = attributes
+                .get("fitToWidth")
+                .and_then(|value| value.parse().ok())
+            {
+                fit_to_page.0 = fit_to_width;
+            }
+            if let Some(fit_to_height) = attributes
+                .get("fitToHeight")
+                .and_then(|value| value.parse().ok())
+            {
+                fit_to_page.1 = fit_to_height;
+            }
+        }
+        if get_flag(&attributes, "useFirstPageNumber") {
+            page_setup.first_page_number = attributes
+                .get("firstPageNumber")
+                .and_then(|value| value.parse().ok());
+        }
+    }
+    if let Some(header_footer_element) = xml_doc_mut
+        .pop_elements_by_tag_mut("headerFooter", None)
+        .and_then(|mut elements| elements.pop())
+    {
+        is_found = true;
+        let attributes = header_footer_element
+            .get_attribute()
+            .cloned()
+            .unwrap_or_default();
+        let header_footer = &mut page_setup.header_footer;
+        header_footer.different_odd_even = get_flag(&attributes, "differentOddEven");
+        header_footer.different_first = get_flag(&attributes, "differentFirst");
+        header_footer.scale_with_doc = attributes
+            .get("scaleWithDoc")
+            .is_none_or(|value| ConverterUtil::normalize_bool_property_bool(value));
+        header_footer.align_with_margins = attributes
+            .get("alignWithMargins")
+            .is_none_or(|value| ConverterUtil::normalize_bool_property_bool(value));
+        while let Some((child_id, _)) = header_footer_element.pop_child_mut() {
+            if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                let text = HeaderFooterText::from_value(
+                    child_element.get_value().as_deref().unwrap_or_default(),
+                );
+                match child_element.get_tag() {
+                    "oddHeader" => header_footer.odd_header = text,
+                    "oddFooter" => header_footer.odd_footer = text,
+                    "evenHeader" => header_footer.even_header = text,
+                    "evenFooter" => header_footer.even_footer = text,
+                    "firstHeader" => header_footer.first_header = text,
+                    "firstFooter" => header_footer.first_footer = text,
+                    _ => {}
+                }
+            }
+        }
+    }
+    for tag in ["rowBreaks", "colBreaks"] {
+        if let Some(breaks_element) = xml_doc_mut
+            .pop_elements_by_tag_mut(tag, None)
+            .and_then(|mut elements| elements.pop())
+        {
+            is_found = true;
+            while let Some((child_id, _)) = breaks_element.pop_child_mut() {
+                if let Some(break_index) = xml_doc_mut
+                    .pop_element_mut(&child_id)
+                    .and_then(|child_element| child_element.get_attribute().cloned())
+                    .and_then(|attributes| attributes.get("id").cloned())
+                {
+                    if tag == "rowBreaks" {
+                        if let Ok(row_index) = break_index.parse() {
+                            page_setup.row_breaks.push(row_index);
                         }
-                        if let Some(row_group_level) = row_attribute.get("outlineLevel") {
-                            let outline_level = row_group_level
-                                .parse()
-                                .context("Failed to parse the row group level")?;
-                            row_record.group_level = if outline_level > 0 {
-                                Some(outline_level)
+                    } else if let Ok(col_index) = break_index.parse() {
+                        page_setup.column_breaks.push(col_index);
+                    }
+                }
+            }
+        }
+    }
+    if is_found {
+        Ok(Some(page_setup))
+    } else {
+        Ok(None)
+    }
+}
+
+fn deserialize_merge_cells(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<Option<Vec<CellRange>>, AnyError> {
+    if let Some(mut merge_cells_element) = xml_doc_mut.pop_elements_by_tag_mut("mergeCells", None) {
+        if let Some(merge_cells) = merge_cells_element.pop() {
+            let mut merge_cell_collection = Vec::new();
+            while let Some((merge_cell_id, _)) = merge_cells.pop_child_mut() {
+                if let Some(merge_cell) = xml_doc_mut.pop_element_mut(&merge_cell_id) {
+                    let range_ref = merge_cell
+                        .get_attribute()
+                        .and_then(|attributes| attributes.get("ref"))
+                        .ok_or(anyhow!("Missing mandatory merge cell ref attribute"))?;
+                    merge_cell_collection.push(
+                        ConverterUtil::get_range_index(range_ref)
+                            .context("Failed to parse merge cell range")?,
+                    );
+                }
+            }
+            return Ok(Some(merge_cell_collection));
+        }
+    }
+    Ok(None)
+}
+
+fn get_mail_to_target(mail_address: &str, email_subject: Option<String>) -> String {
+    if let Some(email_subject) = email_subject {
+        format!(
+            "mailto:{}?subject={}",
+            mail_address,
+            ConverterUtil::percent_encode(&email_subject)
+        )
+    } else {
+        format!("mailto:{}", mail_address)
+    }
+}
+
+fn deserialize_hyperlinks(
+    xml_doc_mut: &mut XmlDocument,
+    sheet_relationship_part: &Rc<RefCell<RelationsPart>>,
+) -> AnyResult<Option<BTreeMap<CellRange, HyperlinkProperties>>, AnyError> {
+    if let Some(mut hyperlinks_element) = xml_doc_mut.pop_elements_by_tag_mut("hyperlinks", None) {
+        if let Some(hyperlinks) = hyperlinks_element.pop() {
+            let mut sheet_relationship_part = sheet_relationship_part
+                .try_borrow_mut()
+                .context("Failed to pull sheet relationship part")?;
+            let mut hyperlink_collection = BTreeMap::new();
+            while let Some((hyperlink_id, _)) = hyperlinks.pop_child_mut() {
+                if let Some(hyperlink_element) = xml_doc_mut.pop_element_mut(&hyperlink_id) {
+                    let attributes = hyperlink_element
+                        .get_attribute()
+                        .ok_or(anyhow!("Failed to pull hyperlink attribute"))?;
+                    let cell_range = ConverterUtil::get_range_index(
+                        attributes
+                            .get("ref")
+                            .ok_or(anyhow!("Missing mandatory hyperlink ref attribute"))?,
+                    )
+                    .context("Failed to parse hyperlink range")?;
+                    let mut hyperlink = HyperlinkProperties {
+                        tool_tip: attributes.get("tooltip").cloned(),
+                        display: attributes.get("display").cloned(),
+                        ..Default::default()
+                    };
+                    let location = attributes.get("location").cloned();
+                    if let Some(relationship_id) = attributes.get("r:id") {
+                        let target = sheet_relationship_part
+                            .get_external_target_by_id(relationship_id)
+                            .ok_or(anyhow!(
+                                "Hyperlink relationship {} not found",
+                                relationship_id
+                            ))?;
+                        // Relationship is created again while saving
+                        sheet_relationship_part.delete_relationship_by_id_mut(relationship_id);
+                        if let Some(mail_to) = target.strip_prefix("mailto:") {
+                            hyperlink.hyperlink_type = HyperlinkPropertyTypeValues::MAIL_TO;
+                            if let Some((mail_address, email_subject)) =
+                                mail_to.split_once("?subject=")
+                            {
+                                hyperlink.value = mail_address.to_string();
+                                hyperlink.email_subject =
+                                    Some(ConverterUtil::percent_decode(email_subject));
                             } else {
-                                None
-                            };
-                        }
-                        if let Some(collapsed) = row_attribute.get("collapsed") {
-                            row_record.collapsed = if collapsed == "1" { Some(true) } else { None };
-                        }
-                        if let Some(thick_top) = row_attribute.get("thickTop") {
-                            row_record.thick_top = if thick_top == "1" { Some(true) } else { None };
-                        }
-                        if let Some(thick_bottom) = row_attribute.get("thickBot") {
-                            row_record.thick_bottom = if thick_bottom == "1" {
-                                Some(true)
+                                hyperlink.value = mail_to.to_string();
+                            }
+                        } else {
+                            hyperlink.hyperlink_type = if target.contains("://") {
+                                HyperlinkPropertyTypeValues::WEB_URL
                             } else {
-                                None
+                                HyperlinkPropertyTypeValues::EXISTING_FILE
                             };
+                            hyperlink.value = target;
                         }
-                        if let Some(place_holder) = row_attribute.get("ph") {
-                            row_record.place_holder = if place_holder == "1" {
-                                Some(true)
-                            } else {
-                                None
-                            };
+                        hyperlink.cell_location = location;
+                    } else if let Some(location) = location {
+                        hyperlink.hyperlink_type = HyperlinkPropertyTypeValues::TARGET_SHEET;
+                        if let Some((sheet_name, cell_location)) = location.rsplit_once("!") {
+                            hyperlink.value = sheet_name
+                                .strip_prefix("'")
+                                .and_then(|sheet_name| sheet_name.strip_suffix("'"))
+                                .map_or(sheet_name.to_string(), |sheet_name| {
+                                    sheet_name.replace("''", "'")
+                                });
+                            hyperlink.cell_location = Some(cell_location.to_string());
+                        } else {
+                            hyperlink.value = location;
                         }
-                        let mut cell_records: BTreeMap<u16, CellProperties> = BTreeMap::new();
-                        // Loop All Columns of row
-                        loop {
-                            let mut cell_record = CellProperties::default();
-                            if let Some((col_element_id, _)) = row_element.pop_child_mut() {
-                                if let Some(col_element) =
-                                    xml_doc_mut.pop_element_mut(&col_element_id)
-                                {
-                                    let cell_attribute = col_element
-                                        .get_attribute()
-                                        .ok_or(anyhow!("Failed to pull attribute for Column"))?;
-                                    // Get Col Id
-                                    let col_index = ConverterUtil::get_column_index(
-                                        cell_attribute
-                                            .get("r")
-                                            .ok_or(anyhow!("Missing mandatory col id attribute"))?,
-                                    )
-                                    .context("Failed to Convert col worksheet initialize")?;
-                                    if let Some(style_id) = cell_attribute.get("s") {
-                                        cell_record.style_id = Some(StyleId::new(
-                                            style_id
-                                                .parse()
-                                                .context("Failed to parse the col style id")?,
-                                        ));
-                                    }
-                                    if let Some(cell_type) = cell_attribute.get("t") {
-                                        cell_record.data_type = CellDataType::get_enum(&cell_type);
-                                    } else {
-                                        cell_record.data_type = CellDataType::Number;
-                                    }
-                                    if let Some(comment_id) = cell_attribute.get("cm") {
-                                        cell_record.comment_id = Some(
-                                            comment_id
-                                                .parse()
-                                                .context("Failed to parse the col comment id")?,
-                                        );
-                                    };
-                                    if let Some(value_meta_id) = cell_attribute.get("vm") {
-                                        cell_record.metadata =
-                                            Some(value_meta_id.parse().context(
-                                                "Failed to parse the col value meta id",
-                                            )?);
-                                    };
-                                    if let Some(place_holder) = cell_attribute.get("ph") {
-                                        cell_record.place_holder = if place_holder == "1" {
-                                            Some(true)
-                                        } else {
-                                            None
-                                        };
-                                    };
-                                    loop {
-                                        if let Some((cell_child_id, _)) =
-                                            col_element.pop_child_mut()
-                                        {
-                                            if let Some(element) =
-                                                xml_doc_mut.pop_element_mut(&cell_child_id)
-                                            {
-                                                match element.get_tag() {
-                                                    "v" => {
-                                                        cell_record.value =
-                                                            element.get_value().clone();
-                                                    }
-                                                    "f" => {
-                                                        cell_record.formula =
-                                                            element.get_value().clone();
-                                                    }
-                                                    "is" => {
-                                                        if let Some((text_id, _)) =
-                                                            element.pop_child_mut()
-                                                        {
-                                                            if let Some(text_element) = xml_doc_mut
-                                                                .pop_element_mut(&text_id)
-                                                            {
-                                                                cell_record.value = text_element
-                                                                    .get_value()
-                                                                    .clone();
-                                                            }
-                                                        }
-                                                    }
-                                                    _ => {
-                                                        return Err(anyhow!(
-                                                            "Found un-know element cell child"
-                                                        ));
-                                                    }
-                                                }
-                                            }
-                                        } else {
-                                            break;
-                                        }
-                                    }
-                                    dimension.start_col = min(dimension.start_col, col_index);
-                                    dimension.end_col = max(dimension.end_col, col_index);
-                                    cell_records.insert(col_index, cell_record);
+                    }
+                    hyperlink_collection.insert(cell_range, hyperlink);
+                }
+            }
+            return Ok(Some(hyperlink_collection));
+        }
+    }
+    Ok(None)
+}
+
+/// Load the conditional formatting blocks the rule model cover, other blocks stay in the sheet as is
+fn deserialize_conditional_formats(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<Vec<ConditionalFormatData>, AnyError> {
+    let mut conditional_formats = Vec::new();
+    for conditional_format_id in xml_doc_mut
+        .get_element_ids_by_tag("conditionalFormatting", None)
+        .unwrap_or_default()
+    {
+        let Some(rule_ids) =
+            xml_doc_mut.get_element_ids_by_tag("cfRule", Some(&conditional_format_id))
+        else {
+            continue;
+        };
+        let rules = rule_ids
+            .iter()
+            .map(|rule_id| get_conditional_format_rule(xml_doc_mut, rule_id))
+            .collect::<Option<Vec<ConditionalFormatRuleData>>>();
+        let is_loadable = xml_doc_mut
+            .get_element(&conditional_format_id)
+            .is_some_and(|element| element.get_child_count() == rule_ids.len());
+        if let (Some(rules), true) = (rules, is_loadable) {
+            if let Some(conditional_format_element) =
+                xml_doc_mut.pop_element_from_parent_mut(&conditional_format_id)
+            {
+                let sq_ref = conditional_format_element
+                    .get_attribute()
+                    .and_then(|attributes| attributes.get("sqref"))
+                    .cloned()
+                    .unwrap_or_default();
+                // Drop the loaded rule elements from the document
+                let mut pending_elements = vec![conditional_format_element];
+                while let Some(element) = pending_elements.pop() {
+                    while let Some((child_id, _)) = element.pop_child_mut() {
+                        pending_elements.extend(xml_doc_mut.pop_element_mut(&child_id));
+                    }
+                }
+                conditional_formats.push(ConditionalFormatData {
+                    sq_ref: get_sq_ref(&sq_ref).unwrap_or(sq_ref),
+                    rules,
+                });
+            }
+        }
+    }
+    Ok(conditional_formats)
+}
+
+/// Read the rule when every element and attribute of it is covered by the rule model
+fn get_conditional_format_rule(
+    xml_doc: &XmlDocument,
+    rule_id: &usize,
+) -> Option<ConditionalFormatRuleData> {
+    fn get_child_ids(xml_doc: &XmlDocument, parent_id: &usize, tag: &str) -> Vec<usize> {
+        xml_doc
+            .get_element_ids_by_tag(tag, Some(parent_id))
+            .unwrap_or_default()
+    }
+    /// Element has only the known attributes and child tags, recursively
+    fn is_known_element(xml_doc: &XmlDocument, element_id: &usize) -> bool {
+        let Some(element) = xml_doc.get_element(element_id) else {
+            return false;
+        };
+        let (attribute_keys, child_tags): (&[&str], &[&str]) = match element.get_tag() {
+            "cfRule" => (
+                &[
+                    "type",
+                    "priority",
+                    "dxfId",
+                    "stopIfTrue",
+                    "operator",
+                    "rank",
+                    "bottom",
+                    "percent",
+                ],
+                &["formula", "colorScale", "dataBar", "iconSet"],
+            ),
+            "colorScale" => (&[], &["cfvo", "color"]),
+            "dataBar" => (&["showValue"], &["cfvo", "color"]),
+            "iconSet" => (&["iconSet", "showValue", "reverse"], &["cfvo"]),
+            "cfvo" => (&["type", "val"], &[]),
+            "color" => (&["theme", "rgb", "indexed"], &[]),
+            "formula" => (&[], &[]),
+            _ => return false,
+        };
+        let child_ids = child_tags
+            .iter()
+            .flat_map(|tag| get_child_ids(xml_doc, element_id, tag))
+            .collect::<Vec<usize>>();
+        element.get_attribute().is_none_or(|attributes| {
+            attributes
+                .keys()
+                .all(|key| attribute_keys.contains(&key.as_str()))
+        }) && child_ids.len() == element.get_child_count()
+            && child_ids
+                .iter()
+                .all(|child_id| is_known_element(xml_doc, child_id))
+    }
+    fn get_values(xml_doc: &XmlDocument, parent_id: &usize) -> Vec<ConditionalFormatValue> {
+        get_child_ids(xml_doc, parent_id, "cfvo")
+            .iter()
+            .filter_map(|value_id| xml_doc.get_element(value_id))
+            .map(|value_element| {
+                let attributes = value_element.get_attribute().cloned().unwrap_or_default();
+                ConditionalFormatValue {
+                    value_type: ConditionalFormatValueTypeValues::get_enum(
+                        attributes.get("type").map_or("num", |value| value.as_str()),
+                    ),
+                    value: attributes.get("val").cloned(),
+                }
+            })
+            .collect()
+    }
+    fn get_colors(xml_doc: &XmlDocument, parent_id: &usize) -> Option<Vec<ColorSetting>> {
+        get_child_ids(xml_doc, parent_id, "color")
+            .iter()
+            .map(|color_id| {
+                xml_doc
+                    .get_element(color_id)
+                    .and_then(|color_element| color_element.get_attribute())
+                    .and_then(StylePart::get_color_setting)
+            })
+            .collect()
+    }
+    if !is_known_element(xml_doc, rule_id) {
+        return None;
+    }
+    let attributes = xml_doc.get_element(rule_id)?.get_attribute()?.clone();
+    let is_enabled = |key: &str| {
+        attributes
+            .get(key)
+            .is_some_and(|value| matches!(value.as_str(), "1" | "true"))
+    };
+    let get_attribute_value = |key: &str| {
+        attributes
+            .get(key)
+            .filter(|value| !value.is_empty())
+            .cloned()
+    };
+    let get_first_child_id = |tag: &str| get_child_ids(xml_doc, rule_id, tag).first().copied();
+    let formulas = get_child_ids(xml_doc, rule_id, "formula")
+        .iter()
+        .filter_map(|formula_id| xml_doc.get_element(formula_id))
+        .map(|formula_element| formula_element.get_value().clone().unwrap_or_default())
+        .collect::<Vec<String>>();
+    let rule = match attributes.get("type")?.as_str() {
+        "cellIs" => {
+            let operator_value = get_attribute_value("operator")?;
+            let operator = ConditionalFormatOperatorValues::get_enum(&operator_value);
+            if ConditionalFormatOperatorValues::get_string(operator.clone()) != operator_value {
+                return None;
+            }
+            ConditionalFormatRuleValues::CellValue { operator, formulas }
+        }
+        "expression" => ConditionalFormatRuleValues::Expression {
+            formula: formulas.into_iter().next()?,
+        },
+        "colorScale" => {
+            let color_scale_id = get_first_child_id("colorScale")?;
+            ConditionalFormatRuleValues::ColorScale {
+                values: get_values(xml_doc, &color_scale_id),
+                colors: get_colors(xml_doc, &color_scale_id)?,
+            }
+        }
+        "dataBar" => {
+            let data_bar_id = get_first_child_id("dataBar")?;
+            let mut values = get_values(xml_doc, &data_bar_id).into_iter();
+            let mut colors = get_colors(xml_doc, &data_bar_id)?.into_iter();
+            let data_bar = ConditionalFormatRuleValues::DataBar {
+                min_value: values.next()?,
+                max_value: values.next()?,
+                color: colors.next()?,
+                show_value: xml_doc
+                    .get_element(&data_bar_id)?
+                    .get_attribute()
+                    .and_then(|data_bar_attributes| data_bar_attributes.get("showValue"))
+                    .is_none_or(|value| !matches!(value.as_str(), "0" | "false")),
+            };
+            if values.next().is_some() || colors.next().is_some() {
+                return None;
+            }
+            data_bar
+        }
+        "iconSet" => {
+            let icon_set_id = get_first_child_id("iconSet")?;
+            let icon_set_attributes = xml_doc
+                .get_element(&icon_set_id)?
+                .get_attribute()
+                .cloned()
+                .unwrap_or_default();
+            let icon_set_value = icon_set_attributes
+                .get("iconSet")
+                .map_or("3TrafficLights1", |value| value.as_str());
+            let icon_set = IconSetValues::get_enum(icon_set_value);
+            if IconSetValues::get_string(icon_set.clone()) != icon_set_value {
+                return None;
+            }
+            ConditionalFormatRuleValues::IconSet {
+                icon_set,
+                values: get_values(xml_doc, &icon_set_id),
+                show_value: icon_set_attributes
+                    .get("showValue")
+                    .is_none_or(|value| !matches!(value.as_str(), "0" | "false")),
+                is_reverse: icon_set_attributes
+                    .get("reverse")
+                    .is_some_and(|value| matches!(value.as_str(), "1" | "true")),
+            }
+        }
+        "top10" => ConditionalFormatRuleValues::TopBottom {
+            rank: get_attribute_value("rank")?.parse().ok()?,
+            is_bottom: is_enabled("bottom"),
+            is_percent: is_enabled("percent"),
+        },
+        "duplicateValues" => ConditionalFormatRuleValues::DuplicateValues,
+        "uniqueValues" => ConditionalFormatRuleValues::UniqueValues,
+        _ => return None,
+    };
+    Some(ConditionalFormatRuleData {
+        rule: ConditionalFormatRule {
+            rule,
+            style: None,
+            stop_if_true: is_enabled("stopIfTrue"),
+        },
+        dxf_id: match attributes.get("dxfId") {
+            Some(dxf_id) => Some(dxf_id.parse().ok()?),
+            None => None,
+        },
+        priority: attributes
+            .get("priority")
+            .and_then(|priority| priority.parse().ok()),
+    })
+}
+
+fn deserialize_data_validations(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<Option<Vec<(String, DataValidation)>>, AnyError> {
+    if let Some(mut data_validations_element) =
+        xml_doc_mut.pop_elements_by_tag_mut("dataValidations", None)
+    {
+        if let Some(data_validations) = data_validations_element.pop() {
+            let mut data_validation_collection =
+                Vec::with_capacity(data_validations.get_child_count());
+            while let Some((data_validation_id, _)) = data_validations.pop_child_mut() {
+                if let Some(data_validation_element) =
+                    xml_doc_mut.pop_element_mut(&data_validation_id)
+                {
+                    let attributes = data_validation_element
+                        .get_attribute()
+                        .cloned()
+                        .unwrap_or_default();
+                    let is_enabled = |key: &str| {
+                        attributes
+                            .get(key)
+                            .is_some_and(|value| matches!(value.as_str(), "1" | "true"))
+                    };
+                    let mut data_validation = DataValidation {
+                        validation_type: DataValidationTypeValues::get_enum(
+                            attributes
+                                .get("type")
+                                .map_or("none", |value| value.as_str()),
+                        ),
+                        operator: DataValidationOperatorValues::get_enum(
+                            attributes
+                                .get("operator")
+                                .map_or("between", |value| value.as_str()),
+                        ),
+                        formula1: None,
+                        formula2: None,
+                        allow_blank: is_enabled("allowBlank"),
+                        hide_dropdown: is_enabled("showDropDown"),
+                        show_input_message: is_enabled("showInputMessage"),
+                        prompt_title: attributes.get("promptTitle").cloned(),
+                        prompt: attributes.get("prompt").cloned(),
+                        show_error_message: is_enabled("showErrorMessage"),
+                        error_style: DataValidationErrorStyleValues::get_enum(
+                            attributes
+                                .get("errorStyle")
+                                .map_or("stop", |value| value.as_str()),
+                        ),
+                        error_title: attributes.get("errorTitle").cloned(),
+                        error: attributes.get("error").cloned(),
+                    };
+                    while let Some((formula_id, _)) = data_validation_element.pop_child_mut() {
+                        if let Some(formula_element) = xml_doc_mut.pop_element_mut(&formula_id) {
+                            match formula_element.get_tag() {
+                                "formula1" => {
+                                    data_validation.formula1 = formula_element.get_value().clone()
                                 }
-                            } else {
-                                break;
+                                "formula2" => {
+                                    data_validation.formula2 = formula_element.get_value().clone()
+                                }
+                                _ => {}
                             }
                         }
-                        sheet_data_collection.insert(
-                            row_index,
-                            RowData {
-                                row_record,
-                                cell_records: if cell_records.len() > 0 {
-                                    Some(cell_records)
-                                } else {
-                                    None
-                                },
-                            },
-                        );
                     }
-                } else {
-                    break;
+                    data_validation_collection.push((
+                        attributes.get("sqref").cloned().unwrap_or_default(),
+                        data_validation,
+                    ));
+                }
+            }
+            return Ok(Some(data_validation_collection));
+        }
+    }
+    Ok(None)
+}
+
+/// Check the image bytes match the format signature
+fn validate_image(image: &[u8], image_format: &ImageFormatValues) -> AnyResult<(), AnyError> {
+    fn is_png(image: &[u8]) -> bool {
+        image.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A])
+    }
+    let is_valid = match image_format {
+        ImageFormatValues::Png => is_png(image),
+        ImageFormatValues::Jpeg => image.starts_with(&[0xFF, 0xD8, 0xFF]),
+        ImageFormatValues::Gif => image.starts_with(b"GIF87a") || image.starts_with(b"GIF89a"),
+        ImageFormatValues::Svg { png_fallback } => {
+            if !is_png(png_fallback) {
+                return Err(anyhow!("SVG fallback image is not PNG"));
+            }
+            String::from_utf8_lossy(image).contains("<svg")
+        }
+    };
+    if is_valid {
+        Ok(())
+    } else {
+        Err(anyhow!("Image content does not match the format"))
+    }
+}
+
+/// Check the row and column index are inside the sheet
+fn validate_cell_index(row_index: u32, col_index: u16) -> AnyResult<(), AnyError> {
+    if !(1..=MAX_ROW_INDEX).contains(&row_index) {
+        return Err(anyhow!("Row index outside the sheet : {}", row_index));
+    }
+    if !(1..=MAX_COL_INDEX).contains(&col_index) {
+        return Err(anyhow!("Column index outside the sheet : {}", col_index));
+    }
+    Ok(())
+}
+
+/// Check the anchor cells are inside the sheet and the size is positive
+fn validate_drawing_anchor(anchor: &DrawingAnchorValues) -> AnyResult<(), AnyError> {
+    fn get_anchor_cell(cell_ref: &str) -> AnyResult<(u32, u16), AnyError> {
+        let (row_index, col_index) = ConverterUtil::get_cell_index(cell_ref)
+            .context(format!("Invalid anchor cell : {}", cell_ref))?;
+        if !(1..=MAX_ROW_INDEX).contains(&row_index) || col_index > MAX_COL_INDEX {
+            return Err(anyhow!("Anchor cell outside the sheet : {}", cell_ref));
+        }
+        Ok((row_index, col_index))
+    }
+    let size = match anchor {
+        DrawingAnchorValues::OneCell { cell_ref, size } => {
+            get_anchor_cell(cell_ref)?;
+            Some(size)
+        }
+        DrawingAnchorValues::TwoCell {
+            from_cell_ref,
+            to_cell_ref,
+        } => {
+            let (from_row, from_col) = get_anchor_cell(from_cell_ref)?;
+            let (to_row, to_col) = get_anchor_cell(to_cell_ref)?;
+            if to_row < from_row || to_col < from_col {
+                return Err(anyhow!("Anchor end cell is before the start cell"));
+            }
+            None
+        }
+        DrawingAnchorValues::Absolute { x, y, size } => {
+            if *x < 0 || *y < 0 {
+                return Err(anyhow!("Anchor position can't be negative"));
+            }
+            Some(size)
+        }
+    };
+    if let Some(size) = size {
+        let (width, height) = size.get_emu();
+        if width <= 0 || height <= 0 {
+            return Err(anyhow!("Drawing size must be greater than 0"));
+        }
+    }
+    Ok(())
+}
+
+/// Cache text of the chart cell, empty cell give empty text
+fn get_chart_text_value(cell_value: Option<&CellValue>) -> AnyResult<String, AnyError> {
+    Ok(match cell_value {
+        Some(CellValue::Number(number)) => number.to_string(),
+        Some(CellValue::Text(text)) | Some(CellValue::Error(text)) => text.to_string(),
+        Some(CellValue::Bool(bool_value)) => bool_value.to_string().to_uppercase(),
+        Some(CellValue::DateTime(date_time)) => ConverterUtil::get_excel_serial_date(date_time)
+            .context("Failed to convert chart date")?
+            .to_string(),
+        Some(CellValue::Rich(rich_text)) => rich_text
+            .iter()
+            .map(|text_run| text_run.text.as_str())
+            .collect(),
+        Some(CellValue::Empty) | None => String::new(),
+    })
+}
+
+/// Cache number of the chart cell, cells without number stay empty
+fn get_chart_number_value(cell_value: Option<&CellValue>) -> AnyResult<Option<f64>, AnyError> {
+    Ok(match cell_value {
+        Some(CellValue::Number(number)) => Some(*number),
+        Some(CellValue::DateTime(date_time)) => Some(
+            ConverterUtil::get_excel_serial_date(date_time)
+                .context("Failed to convert chart date")?,
+        ),
+        _ => None,
+    })
+}
+
+/// Check table name, columns and range size agree with each other
+fn validate_table(table: &Table, cell_range: &CellRange) -> AnyResult<(), AnyError> {
+    if !DefinedName::is_valid_name(&table.name) {
+        return Err(anyhow!("Invalid table name : {}", table.name));
+    }
+    let ((start_row, start_col), (end_row, end_col)) = *cell_range;
+    if table.columns.len() != (end_col - start_col + 1) as usize {
+        return Err(anyhow!(
+            "Table range has {} columns but {} columns given",
+            end_col - start_col + 1,
+            table.columns.len()
+        ));
+    }
+    // Table needs at least one data row
+    let minimum_rows =
+        1 + u32::from(table.setting.show_header_row) + u32::from(table.setting.show_totals_row);
+    if end_row - start_row + 1 < minimum_rows {
+        return Err(anyhow!("Table range needs at least {} rows", minimum_rows));
+    }
+    let mut column_names = HashSet::new();
+    for table_column in &table.columns {
+        if table_column.name.trim().is_empty() {
+            return Err(anyhow!("Table column name can't be empty"));
+        }
+        if !column_names.insert(table_column.name.to_lowercase()) {
+            return Err(anyhow!(
+                "Duplicate table column name : {}",
+                table_column.name
+            ));
+        }
+        if table_column.totals_row_function == TableTotalsRowFunctionValues::Custom
+            && table_column.totals_row_formula.is_none()
+        {
+            return Err(anyhow!(
+                "Custom totals row function of column {} needs formula",
+                table_column.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Escape the special character of column name used in structured reference
+fn get_structured_column_name(column_name: &str) -> String {
+    column_name
+        .chars()
+        .fold(String::new(), |mut column_ref, character| {
+            if matches!(character, '[' | ']' | '#' | '\'') {
+                column_ref.push('\'');
+            }
+            column_ref.push(character);
+            column_ref
+        })
+}
+
+/// Normalize space separated range reference into sqref value
+fn get_sq_ref(range_ref: &str) -> AnyResult<String, AnyError> {
+    let sq_ref = range_ref
+        .split_whitespace()
+        .map(|range_ref| {
+            ConverterUtil::get_range_index(range_ref)
+                .and_then(|cell_range| get_range_ref(&cell_range))
+                .context("Failed to parse range reference")
+        })
+        .collect::<AnyResult<Vec<String>, AnyError>>()?
+        .join(" ");
+    if sq_ref.is_empty() {
+        Err(anyhow!("Range reference is empty"))
+    } else {
+        Ok(sq_ref)
+    }
+}
+
+/// Collect the sheet prefix and defined name the formula refer, names LET and LAMBDA declare are left out
+fn collect_formula_names(
+    expression: &FormulaExpressionValues,
+    sheet_names: &mut Vec<String>,
+    names: &mut Vec<(Option<String>, String)>,
+) {
+    match expression {
+        FormulaExpressionValues::Reference(reference) => {
+            sheet_names.extend(reference.sheet_name.clone());
+        }
+        // External workbook name like [1]!Name is not checked
+        FormulaExpressionValues::Name { sheet_name, name }
+            if !sheet_name
+                .as_ref()
+                .is_some_and(|sheet_name| sheet_name.starts_with('[')) =>
+        {
+            sheet_names.extend(sheet_name.clone());
+            names.push((sheet_name.clone(), name.clone()));
+        }
+        FormulaExpressionValues::Function { name, arguments } => {
+            let name_count = names.len();
+            for argument in arguments {
+                collect_formula_names(argument, sheet_names, names);
+            }
+            // LET declare every other name before the calculation, LAMBDA the parameters before the body
+            let declared_arguments = arguments.iter().take(arguments.len().saturating_sub(1));
+            let declared_names = match name
+                .trim_start_matches("_XLFN.")
+                .trim_start_matches("_XLWS.")
+            {
+                "LET" => declared_arguments.step_by(2).collect(),
+                "LAMBDA" => declared_arguments.collect(),
+                _ => Vec::new(),
+            }
+            .into_iter()
+            .filter_map(|argument| match argument {
+                FormulaExpressionValues::Name {
+                    sheet_name: None,
+                    name,
+                } => Some(name),
+                _ => None,
+            })
+            .collect::<Vec<&String>>();
+            if !declared_names.is_empty() {
+                let function_names = names.split_off(name_count);
+                names.extend(function_names.into_iter().filter(|(sheet_name, name)| {
+                    sheet_name.is_some()
+                        || !declared_names
+                            .iter()
+                            .any(|declared_name| declared_name.eq_ignore_ascii_case(name))
+                }));
+            }
+        }
+        FormulaExpressionValues::Unary { operand, .. } => {
+            collect_formula_names(operand, sheet_names, names);
+        }
+        FormulaExpressionValues::Binary { left, right, .. } => {
+            collect_formula_names(left, sheet_names, names);
+            collect_formula_names(right, sheet_names, names);
+        }
+        _ => {}
+    }
+}
+
+/// Check the validation carry the formula its type needs
+fn validate_data_validation(data_validation: &mut DataValidation) -> AnyResult<(), AnyError> {
+    for formula in [
+        data_validation.formula1.as_mut(),
+        data_validation.formula2.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *formula = formula.trim_start_matches('=').to_string();
+    }
+    let has_formula1 = data_validation
+        .formula1
+        .as_ref()
+        .is_some_and(|formula| !formula.trim().is_empty());
+    match data_validation.validation_type {
+        DataValidationTypeValues::None => {}
+        DataValidationTypeValues::List | DataValidationTypeValues::Custom => {
+            if !has_formula1 {
+                return Err(anyhow!("Data validation needs the formula1 value"));
+            }
+            // Excel limit the literal list to 255 characters including the quotes
+            if data_validation.validation_type == DataValidationTypeValues::List
+                && data_validation
+                    .formula1
+                    .as_ref()
+                    .is_some_and(|formula| formula.starts_with('"') && formula.len() > 257)
+            {
+                return Err(anyhow!("Data validation list values exceed 255 characters"));
+            }
+        }
+        _ => {
+            if !has_formula1 {
+                return Err(anyhow!("Data validation needs the formula1 value"));
+            }
+            let needs_formula2 = matches!(
+                data_validation.operator,
+                DataValidationOperatorValues::Between | DataValidationOperatorValues::NotBetween
+            );
+            if needs_formula2 && data_validation.formula2.is_none() {
+                return Err(anyhow!(
+                    "Data validation with {} operator needs the formula2 value",
+                    DataValidationOperatorValues::get_string(data_validation.operator.clone())
+                ));
+            }
+            if !needs_formula2 {
+                data_validation.formula2 = None;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn deserialize_cols(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<Option<VecDeque<ColumnProperties>>, AnyError> {
+    if let Some(mut cols_element) = xml_doc_mut.pop_elements_by_tag_mut("cols", None) {
+        // Process the columns record if parent node exist
+        if let Some(cols) = cols_element.pop() {
+            let mut column_collection = VecDeque::with_capacity(cols.get_child_count());
+            loop {
+                if let Some((col_elements, _)) = cols.pop_child_mut() {
+                    if let Some(col) = xml_doc_mut.pop_element_mut(&col_elements) {
+                        let mut column_properties = ColumnProperties::default();
+                        let attributes = col
+                            .get_attribute()
+                            .ok_or(anyhow!("Error Getting col attribute"))?;
+                        if let Some(min) = attributes.get("min") {
+                            column_properties.min =
+                                min.parse().context("Failed to parse min value")?;
+                        }
+                        if let Some(max) = attributes.get("max") {
+                            column_properties.max =
+                                max.parse().context("Failed to parse min value")?;
+                        }
+                        if let Some(best_fit) = attributes.get("bestFit") {
+                            column_properties.best_fit =
+                                if best_fit == "1" { Some(true) } else { None }
+                        }
+                        if let Some(hidden) = attributes.get("hidden") {
+                            column_properties.hidden = if hidden == "1" { Some(true) } else { None }
+                        }
+                        if let Some(style) = attributes.get("style") {
+                            column_properties.style_id = Some(StyleId::new(
+                                style.parse().context("Failed to parse style ID")?,
+                            ));
+                        }
+                        if let Some(outline_level) = attributes.get("outlineLevel") {
+                            column_properties.group_level =
+                                outline_level.parse().context("Failed to parse style ID")?;
+                        }
+                        if let Some(custom_width) = attributes.get("customWidth") {
+                            if custom_width == "1" {
+                                column_properties.width = Some(
+                                    attributes
+                                        .get("width")
+                                        .ok_or(anyhow!("Failed to get custom width"))?
+                                        .parse()
+                                        .context("Failed to parse custom width")?,
+                                );
+                            }
+                        }
+                        if let Some(collapsed) = attributes.get("collapsed") {
+                            column_properties.collapsed =
+                                if collapsed == "1" { Some(true) } else { None }
+                        }
+                        column_collection.push_back(column_properties);
+                    }
+                } else {
+                    break;
+                }
+            }
+            return Ok(Some(column_collection));
+        }
+    }
+    Ok(None)
+}
+
+/// DeSerializing Worksheet View
+fn deserialize_worksheet_views(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<WorkSheetViews, AnyError> {
+    let mut worksheet_views = WorkSheetViews {
+        view_collection: Vec::new(),
+    };
+    if let Some(mut sheet_views_elements) = xml_doc_mut.pop_elements_by_tag_mut("sheetViews", None)
+    {
+        if let Some(sheet_views_element) = sheet_views_elements.pop() {
+            loop {
+                if let Some((element_id, element_tag)) = sheet_views_element.pop_child_mut() {
+                    // Validate element that are not accounted
+                    if element_tag != "sheetView" {
+                        return Err(anyhow!("Failed to Process Sheet Views child"));
+                    } else {
+                        let sheet_view_element = xml_doc_mut
+                            .pop_element_mut(&element_id)
+                            .context("Failed to get Sheet View Element")?;
+                        let mut worksheet_view = WorkSheetView::default();
+                        let attributes = sheet_view_element
+                            .get_attribute()
+                            .context("Failed to Get Attributes of Sheet View")?;
+                        worksheet_view.workbook_view_id = attributes
+                            .get("workbookViewId")
+                            .context(
+                                "Mandatory attribute \"workbookViewId\" is missing from sheetView",
+                            )?
+                            .clone();
+                        // Windows protection
+                        if let Some(window_protection) = attributes.get("windowProtection") {
+                            worksheet_view.window_protection = Some(
+                                ConverterUtil::normalize_bool_property_bool(&window_protection),
+                            );
+                        }
+                        // Show formula
+                        if let Some(show_formula_bar) = attributes.get("showFormulas") {
+                            worksheet_view.show_formula_bar = Some(
+                                ConverterUtil::normalize_bool_property_bool(&show_formula_bar),
+                            );
+                        }
+                        // Show Grid Line
+                        if let Some(show_grid_line) = attributes.get("showGridLines") {
+                            worksheet_view.show_grid_line =
+                                Some(ConverterUtil::normalize_bool_property_bool(&show_grid_line));
+                        }
+                        // Show row column header
+                        if let Some(show_row_col_header) = attributes.get("showRowColHeaders") {
+                            worksheet_view.show_row_col_header = Some(
+                                ConverterUtil::normalize_bool_property_bool(&show_row_col_header),
+                            );
+                        }
+                        // Show Zero
+                        if let Some(show_zero) = attributes.get("showZeros") {
+                            worksheet_view.show_zero =
+                                Some(ConverterUtil::normalize_bool_property_bool(&show_zero));
+                        }
+                        // Right to Left
+                        if let Some(view_right_to_left) = attributes.get("rightToLeft") {
+                            worksheet_view.view_right_to_left = Some(
+                                ConverterUtil::normalize_bool_property_bool(&view_right_to_left),
+                            );
+                        }
+                        // Tab Selected
+                        if let Some(tab_selected) = attributes.get("tabSelected") {
+                            worksheet_view.tab_selected =
+                                Some(ConverterUtil::normalize_bool_property_bool(&tab_selected));
+                        }
+                        // show ruler
+                        if let Some(show_ruler) = attributes.get("showRuler") {
+                            worksheet_view.show_ruler =
+                                Some(ConverterUtil::normalize_bool_property_bool(&show_ruler));
+                        }
+                        // show white space
+                        if let Some(show_white_space) = attributes.get("showWhiteSpace") {
+                            worksheet_view.show_white_space = Some(
+                                ConverterUtil::normalize_bool_property_bool(&show_white_space),
+                            );
+                        }
+                        // Show outlined Symbols
+                        if let Some(show_outline_symbol) = attributes.get("showOutlineSymbols") {
+                            worksheet_view.show_outline_symbol = Some(
+                                ConverterUtil::normalize_bool_property_bool(&show_outline_symbol),
+                            );
+                        }
+                        // Default Grid Color
+                        if let Some(default_grid_color) = attributes.get("defaultGridColor") {
+                            worksheet_view.default_grid_color = Some(
+                                ConverterUtil::normalize_bool_property_bool(&default_grid_color),
+                            );
+                        }
+                        // Top Left Cell
+                        if let Some(top_left_cell) = attributes.get("topLeftCell") {
+                            worksheet_view.top_left_cell = Some(top_left_cell.clone());
+                        }
+                        // View Setting
+                        if let Some(view) = attributes.get("view") {
+                            worksheet_view.view = Some(view.clone());
+                        }
+                        // Zoom Scale
+                        if let Some(zoom_scale) = attributes.get("zoomScale") {
+                            worksheet_view.zoom_scale = Some(
+                                zoom_scale
+                                    .parse()
+                                    .context("Failed to Convert Zoom Normal to i16")?,
+                            );
+                        }
+                        // Zoom Scale Normal
+                        if let Some(zoom_scale_normal) = attributes.get("zoomScaleNormal") {
+                            worksheet_view.zoom_scale_normal = Some(
+                                zoom_scale_normal
+                                    .parse()
+                                    .context("Failed to Convert Zoom Normal to i16")?,
+                            );
+                        }
+                        // Zoom Scale Sheet Layout View
+                        if let Some(zoom_scale_sheet_layout) =
+                            attributes.get("zoomScaleSheetLayoutView")
+                        {
+                            worksheet_view.zoom_scale_sheet_layout = Some(
+                                zoom_scale_sheet_layout
+                                    .parse()
+                                    .context("Failed to Convert Zoom Normal to i16")?,
+                            );
+                        }
+                        // Zoom Scale Page Layout View
+                        if let Some(zoom_scale_page_layout) =
+                            attributes.get("zoomScalePageLayoutView")
+                        {
+                            worksheet_view.zoom_scale_page_layout = Some(
+                                zoom_scale_page_layout
+                                    .parse()
+                                    .context("Failed to Convert Zoom Normal to i16")?,
+                            );
+                        }
+                        // Pane and selection
+                        while let Some((child_id, _)) = sheet_view_element.pop_child_mut() {
+                            if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                                let attribute_value = |key: &str| {
+                                    child_element
+                                        .get_attribute()
+                                        .and_then(|attributes| attributes.get(key).cloned())
+                                };
+                                match child_element.get_tag() {
+                                    "pane" => {
+                                        worksheet_view.pane = Some(WorkSheetViewPane {
+                                            x_split: attribute_value("xSplit")
+                                                .map(|x_split| x_split.parse())
+                                                .transpose()
+                                                .context("Failed to parse pane xSplit")?,
+                                            y_split: attribute_value("ySplit")
+                                                .map(|y_split| y_split.parse())
+                                                .transpose()
+                                                .context("Failed to parse pane ySplit")?,
+                                            top_left_cell: attribute_value("topLeftCell"),
+                                            active_pane: attribute_value("activePane"),
+                                            state: attribute_value("state"),
+                                        });
+                                    }
+                                    "selection" => {
+                                        worksheet_view
+                                            .selection_collection
+                                            .get_or_insert_with(Vec::new)
+                                            .push(WorkSheetViewSelection {
+                                                pane: attribute_value("pane"),
+                                                active_cell: attribute_value("activeCell"),
+                                                active_cell_id: attribute_value("activeCellId"),
+                                                sq_ref: attribute_value("sqref"),
+                                            });
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        worksheet_views.view_collection.push(worksheet_view);
+                    }
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+    if worksheet_views.view_collection.is_empty() {
+        worksheet_views = WorkSheetViews::default();
+    }
+    Ok(worksheet_views)
+}
+
+/// Deserialize Sheet Data
+fn deserialize_sheet_data(
+    xml_doc_mut: &mut XmlDocument,
+) -> AnyResult<(Option<BTreeMap<u32, RowData>>, Dimension), AnyError> {
+    let mut dimension = Dimension::default();
+    if let Some(mut sheet_data_element) = xml_doc_mut.pop_elements_by_tag_mut("sheetData", None) {
+        if let Some(sheet_data) = sheet_data_element.pop() {
+            let mut sheet_data_collection: BTreeMap<u32, RowData> = BTreeMap::new();
+            // Loop All rows of sheet data
+            loop {
+                if let Some((row_element_id, _)) = sheet_data.pop_child_mut() {
+                    if let Some(row_element) = xml_doc_mut.pop_element_mut(&row_element_id) {
+                        let mut row_record = RowProperties::default();
+                        let row_attribute = row_element
+                            .get_attribute()
+                            .ok_or(anyhow!("Failed to pull Row Attribute."))?;
+                        // Get Row Id
+                        let row_index = row_attribute
+                            .get("r")
+                            .ok_or(anyhow!("Missing mandatory row id attribute"))?
+                            .parse()
+                            .context("Failed to parse row id")?;
+                        if let Some(row_span) = row_attribute.get("spans") {
+                            row_record.span = Some(row_span.to_string());
+                        }
+                        if let Some(style_id) = row_attribute.get("s") {
+                            if let Some(custom_formant) = row_attribute.get("customFormat") {
+                                row_record.style_id = if custom_formant == "1" {
+                                    Some(StyleId::new(
+                                        style_id
+                                            .parse()
+                                            .context("Failed to parse the row style id")?,
+                                    ))
+                                } else {
+                                    None
+                                };
+                            }
+                        }
+                        if let Some(hidden) = row_attribute.get("hidden") {
+                            row_record.hidden = if hidden == "1" { Some(true) } else { None };
+                        }
+                        if let Some(height) = row_attribute.get("ht") {
+                            if let Some(custom_height) = row_attribute.get("customHeight") {
+                                row_record.height = if custom_height == "1" {
+                                    Some(height.parse().context("Failed to parse the row height")?)
+                                } else {
+                                    None
+                                };
+                            }
+                        }
+                        if let Some(row_group_level) = row_attribute.get("outlineLevel") {
+                            let outline_level = row_group_level
+                                .parse()
+                                .context("Failed to parse the row group level")?;
+                            row_record.group_level = if outline_level > 0 {
+                                Some(outline_level)
+                            } else {
+                                None
+                            };
+                        }
+                        if let Some(collapsed) = row_attribute.get("collapsed") {
+                            row_record.collapsed = if collapsed == "1" { Some(true) } else { None };
+                        }
+                        if let Some(thick_top) = row_attribute.get("thickTop") {
+                            row_record.thick_top = if thick_top == "1" { Some(true) } else { None };
+                        }
+                        if let Some(thick_bottom) = row_attribute.get("thickBot") {
+                            row_record.thick_bottom = if thick_bottom == "1" {
+                                Some(true)
+                            } else {
+                                None
+                            };
+                        }
+                        if let Some(place_holder) = row_attribute.get("ph") {
+                            row_record.place_holder = if place_holder == "1" {
+                                Some(true)
+                            } else {
+                                None
+                            };
+                        }
+                        let mut cell_records: BTreeMap<u16, CellData> = BTreeMap::new();
+                        // Loop All Columns of row
+                        loop {
+                            let mut cell_record = CellData::default();
+                            if let Some((col_element_id, _)) = row_element.pop_child_mut() {
+                                if let Some(col_element) =
+                                    xml_doc_mut.pop_element_mut(&col_element_id)
+                                {
+                                    let cell_attribute = col_element
+                                        .get_attribute()
+                                        .ok_or(anyhow!("Failed to pull attribute for Column"))?;
+                                    // Get Col Id
+                                    let col_index = ConverterUtil::get_column_index(
+                                        cell_attribute
+                                            .get("r")
+                                            .ok_or(anyhow!("Missing mandatory col id attribute"))?,
+                                    )
+                                    .context("Failed to Convert col worksheet initialize")?;
+                                    if let Some(style_id) = cell_attribute.get("s") {
+                                        cell_record.style_id = Some(StyleId::new(
+                                            style_id
+                                                .parse()
+                                                .context("Failed to parse the col style id")?,
+                                        ));
+                                    }
+                                    if let Some(cell_type) = cell_attribute.get("t") {
+                                        cell_record.data_type = CellDataType::get_enum(&cell_type);
+                                    } else {
+                                        cell_record.data_type = CellDataType::Number;
+                                    }
+                                    if let Some(comment_id) = cell_attribute.get("cm") {
+                                        cell_record.comment_id = Some(
+                                            comment_id
+                                                .parse()
+                                                .context("Failed to parse the col comment id")?,
+                                        );
+                                    };
+                                    if let Some(value_meta_id) = cell_attribute.get("vm") {
+                                        cell_record.metadata =
+                                            Some(value_meta_id.parse().context(
+                                                "Failed to parse the col value meta id",
+                                            )?);
+                                    };
+                                    if let Some(place_holder) = cell_attribute.get("ph") {
+                                        cell_record.place_holder = if place_holder == "1" {
+                                            Some(true)
+                                        } else {
+                                            None
+                                        };
+                                    };
+                                    loop {
+                                        if let Some((cell_child_id, _)) =
+                                            col_element.pop_child_mut()
+                                        {
+                                            if let Some(element) =
+                                                xml_doc_mut.pop_element_mut(&cell_child_id)
+                                            {
+                                                match element.get_tag() {
+                                                    "v" => {
+                                                        cell_record.value =
+                                                            element.get_value().clone();
+                                                    }
+                                                    "f" => {
+                                                        cell_record.formula =
+                                                            element.get_value().clone();
+                                                    }
+                                                    "is" => {
+                                                        let string_item =
+                                                            StringItem::deserialize_mut(
+                                                                xml_doc_mut,
+                                                                &element,
+                                                            )
+                                                            .context(
+                                                                "Failed to parse inline string",
+                                                            )?;
+                                                        cell_record.value = Some(
+                                                            string_item.get_text().to_string(),
+                                                        );
+                                                        cell_record.rich_text =
+                                                            string_item.get_rich_text().cloned();
+                                                    }
+                                                    _ => {
+                                                        return Err(anyhow!(
+                                                            "Found un-know element cell child"
+                                                        ));
+                                                    }
+                                                }
+                                            }
+                                        } else {
+                                            break;
+                                        }
+                                    }
+                                    dimension.start_col = min(dimension.start_col, col_index);
+                                    dimension.end_col = max(dimension.end_col, col_index);
+                                    cell_records.insert(col_index, cell_record);
+                                }
+                            } else {
+                                break;
+                            }
+                        }
+                        sheet_data_collection.insert(
+                            row_index,
+                            RowData {
+                                row_record,
+                                cell_records: if cell_records.len() > 0 {
+                                    Some(cell_records)
+                                } else {
+                                    None
+                                },
+                            },
+                        );
+                    }
+                } else {
+                    break;
+                }
+            }
+            return Ok((Some(sheet_data_collection), dimension));
+        }
+    }
+    Ok((None, dimension))
+}
+
+impl WorkSheet {
+    fn deserialize_table_parts(
+        office_document: &Weak<RefCell<OfficeDocument>>,
+        xml_document: &Weak<RefCell<XmlDocument>>,
+        sheet_relationship_part: &Rc<RefCell<RelationsPart>>,
+    ) -> AnyResult<Vec<TablePart>, AnyError> {
+        let mut relationship_ids = Vec::new();
+        if let Some(xml_document) = xml_document.upgrade() {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("Failed to get XML doc handle")?;
+            if let Some(mut table_parts_element) =
+                xml_doc_mut.pop_elements_by_tag_mut("tableParts", None)
+            {
+                if let Some(table_parts) = table_parts_element.pop() {
+                    while let Some((table_part_id, _)) = table_parts.pop_child_mut() {
+                        if let Some(relationship_id) = xml_doc_mut
+                            .pop_element_mut(&table_part_id)
+                            .and_then(|table_part| table_part.get_attribute().cloned())
+                            .and_then(|attributes| attributes.get("r:id").cloned())
+                        {
+                            relationship_ids.push(relationship_id);
+                        }
+                    }
+                }
+            }
+        }
+        let mut tables = Vec::with_capacity(relationship_ids.len());
+        for relationship_id in relationship_ids {
+            let file_path = sheet_relationship_part
+                .try_borrow()
+                .context("Failed to pull sheet relationship part")?
+                .get_target_by_id(&relationship_id)
+                .context("Failed to get table path")?
+                .ok_or(anyhow!("Table relationship missing : {}", relationship_id))?;
+            tables.push(
+                TablePart::new(office_document.clone(), &file_path, &relationship_id)
+                    .context("Failed to open table part")?,
+            );
+        }
+        Ok(tables)
+    }
+
+    /// Load the comments with the vml drawing that renders them. The legacyDrawing element
+    /// is taken off the sheet and added back on close, untouched for sheet without comments
+    fn deserialize_comment_part(
+        office_document: &Weak<RefCell<OfficeDocument>>,
+        xml_document: &Weak<RefCell<XmlDocument>>,
+        sheet_relationship_part: &Rc<RefCell<RelationsPart>>,
+    ) -> AnyResult<Option<CommentPart>, AnyError> {
+        let comments_content = EXCEL_TYPE_COLLECTION.get("comments").unwrap();
+        let vml_content = EXCEL_TYPE_COLLECTION.get("vml_drawing").unwrap();
+        let sheet_relationship = sheet_relationship_part
+            .try_borrow()
+            .context("Failed to pull sheet relationship part")?;
+        let relationship_id = if let Some(relationship_id) =
+            sheet_relationship.get_relationship_id_by_type(comments_content.schemas_type)
+        {
+            relationship_id
+        } else {
+            return Ok(None);
+        };
+        let file_path = sheet_relationship
+            .get_target_by_id(&relationship_id)
+            .context("Failed to get comments path")?
+            .ok_or(anyhow!(
+                "Comments relationship missing : {}",
+                relationship_id
+            ))?;
+        let mut vml_relationship_id = None;
+        if let Some(xml_document) = xml_document.upgrade() {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("Failed to get XML doc handle")?;
+            vml_relationship_id = xml_doc_mut
+                .pop_elements_by_tag_mut("legacyDrawing", None)
+                .and_then(|mut legacy_drawing| legacy_drawing.pop())
+                .and_then(|legacy_drawing| legacy_drawing.get_attribute().cloned())
+                .and_then(|attributes| attributes.get("r:id").cloned());
+        }
+        let vml_relationship_id = vml_relationship_id
+            .or_else(|| sheet_relationship.get_relationship_id_by_type(vml_content.schemas_type))
+            .ok_or(anyhow!("Legacy drawing of the comments missing"))?;
+        let vml_file_path = sheet_relationship
+            .get_target_by_id(&vml_relationship_id)
+            .context("Failed to get legacy drawing path")?
+            .ok_or(anyhow!(
+                "Legacy drawing relationship missing : {}",
+                vml_relationship_id
+            ))?;
+        Ok(Some(
+            CommentPart::new(
+                office_document.clone(),
+                &file_path,
+                &relationship_id,
+                &vml_file_path,
+                &vml_relationship_id,
+            )
+            .context("Failed to open comments part")?,
+        ))
+    }
+
+    fn deserialize_threaded_comment_part(
+        office_document: &Weak<RefCell<OfficeDocument>>,
+        common_service: &Weak<RefCell<CommonServices>>,
+        sheet_relationship_part: &Rc<RefCell<RelationsPart>>,
+    ) -> AnyResult<Option<ThreadedCommentPart>, AnyError> {
+        let threaded_comment_content = EXCEL_TYPE_COLLECTION.get("threaded_comment").unwrap();
+        let sheet_relationship = sheet_relationship_part
+            .try_borrow()
+            .context("Failed to pull sheet relationship part")?;
+        if let Some(relationship_id) =
+            sheet_relationship.get_relationship_id_by_type(threaded_comment_content.schemas_type)
+        {
+            let file_path = sheet_relationship
+                .get_target_by_id(&relationship_id)
+                .context("Failed to get threaded comments path")?
+                .ok_or(anyhow!(
+                    "Threaded comments relationship missing : {}",
+                    relationship_id
+                ))?;
+            Ok(Some(
+                ThreadedCommentPart::new(
+                    office_document.clone(),
+                    common_service.clone(),
+                    &file_path,
+                    &relationship_id,
+                )
+                .context("Failed to open threaded comments part")?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn deserialize_drawing_part(
+        office_document: &Weak<RefCell<OfficeDocument>>,
+        xml_document: &Weak<RefCell<XmlDocument>>,
+        sheet_relationship_part: &Rc<RefCell<RelationsPart>>,
+    ) -> AnyResult<Option<DrawingPart>, AnyError> {
+        let mut relationship_id = None;
+        if let Some(xml_document) = xml_document.upgrade() {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("Failed to get XML doc handle")?;
+            relationship_id = xml_doc_mut
+                .pop_elements_by_tag_mut("drawing", None)
+                .and_then(|mut drawing| drawing.pop())
+                .and_then(|drawing| drawing.get_attribute().cloned())
+                .and_then(|attributes| attributes.get("r:id").cloned());
+        }
+        if let Some(relationship_id) = relationship_id {
+            let file_path = sheet_relationship_part
+                .try_borrow()
+                .context("Failed to pull sheet relationship part")?
+                .get_target_by_id(&relationship_id)
+                .context("Failed to get drawing path")?
+                .ok_or(anyhow!(
+                    "Drawing relationship missing : {}",
+                    relationship_id
+                ))?;
+            Ok(Some(
+                DrawingPart::new(office_document.clone(), &file_path, &relationship_id)
+                    .context("Failed to open drawing part")?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn get_sheet_file_name(
+        sheet_name: Option<String>,
+        office_document: &Weak<RefCell<OfficeDocument>>,
+        sheet_collection: &Weak<RefCell<Vec<(String, String, bool, bool)>>>,
+        workbook_relationship_part: &Weak<RefCell<RelationsPart>>,
+    ) -> AnyResult<(String, String), AnyError> {
+        let worksheet_content = EXCEL_TYPE_COLLECTION.get("worksheet").unwrap();
+        if let Some(sheet_collection) = sheet_collection.upgrade() {
+            if let Some(workbook_relationship_part) = workbook_relationship_part.upgrade() {
+                if let Some(sheet_name) = sheet_name.clone() {
+                    // If the Sheet name already exist get the path of sheet name
+                    if let Some((_, rel_id, _, _)) = sheet_collection
+                        .try_borrow()
+                        .context("Failed to Get Sheet Collection")?
+                        .iter()
+                        .find(|item| item.0 == sheet_name)
+                    {
+                        return Ok((
+                            workbook_relationship_part
+                                .try_borrow()
+                                .context("Failed to Get Workbook relationship")?
+                                .get_target_by_id(&rel_id)
+                                .context("Failed to Get Target Path")?
+                                .ok_or(anyhow!("Failed to Get Relationship path"))?,
+                            sheet_name,
+                        ));
+                    }
+                }
+                let mut sheet_count = sheet_collection
+                    .try_borrow()
+                    .context("Failed to pull Sheet Name Collection")?
+                    .len()
+                    + 1;
+                let relative_path = workbook_relationship_part
+                    .try_borrow_mut()
+                    .context("Failed to pull relationship connection")?
+                    .get_relative_path()
+                    .context("Get Relative Path for Part File")?;
+                if let Some(office_doc) = office_document.upgrade() {
+                    let document = office_doc
+                        .try_borrow()
+                        .context("Failed to Borrow Document")?;
+                    loop {
+                        if document.check_file_exist(format!(
+                            "{}{}/{}{}.{}",
+                            relative_path,
+                            worksheet_content.default_path,
+                            worksheet_content.default_name,
+                            sheet_count,
+                            worksheet_content.extension
+                        )) {
+                            sheet_count += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                let file_path = format!("{}{}", relative_path, worksheet_content.default_path);
+                let sheet_name = format!(
+                    "{}",
+                    sheet_name.clone().unwrap_or(format!(
+                        "{}{}",
+                        worksheet_content.default_name, &sheet_count
+                    ))
+                );
+                let relationship_id = workbook_relationship_part
+                    .try_borrow_mut()
+                    .context("Failed to Get Relationship Handle")?
+                    .set_new_relationship_mut(
+                        worksheet_content,
+                        Some(file_path.clone()),
+                        Some(format!(
+                            "{}{}",
+                            worksheet_content.default_name, &sheet_count
+                        )),
+                    )
+                    .context("Setting New Calculation Chain Relationship Failed.")?;
+                sheet_collection
+                    .try_borrow_mut()
+                    .context("Failed To pull Sheet Collection Handle")?
+                    .push((sheet_name.clone(), relationship_id, false, false));
+                return Ok((
+                    format!(
+                        "{}/{}{}.{}",
+                        file_path,
+                        worksheet_content.default_name,
+                        &sheet_count,
+                        worksheet_content.extension
+                    ),
+                    sheet_name,
+                ));
+            }
+        }
+        Err(anyhow!("Failed to upgrade relation part"))
+    }
+}
+
+// ##################################### Feature Function ################################
+impl WorkSheet {
+    /// Set Active cell of the current sheet along with selected ranges.
+    /// Active cell must be inside one of the selected range, empty selection selects only the active cell
+    pub fn set_active_cell_mut(
+        &mut self,
+        cell_ref: &str,
+        selected_range: Vec<&str>,
+    ) -> AnyResult<(), AnyError> {
+        let (row_index, col_index) =
+            ConverterUtil::get_cell_index(cell_ref).context("Failed to parse active cell")?;
+        let mut active_cell_id = None;
+        for (index, range_ref) in selected_range.iter().enumerate() {
+            let ((start_row, start_col), (end_row, end_col)) =
+                ConverterUtil::get_range_index(range_ref)
+                    .context("Failed to parse selected range")?;
+            if active_cell_id.is_none()
+                && (start_row..=end_row).contains(&row_index)
+                && (start_col..=end_col).contains(&col_index)
+            {
+                active_cell_id = Some(index);
+            }
+        }
+        if !selected_range.is_empty() && active_cell_id.is_none() {
+            return Err(anyhow!(
+                "Active cell {} must be inside the selected range",
+                cell_ref
+            ));
+        }
+        let sheet_view = self.get_sheet_view_mut()?;
+        sheet_view.selection_collection = Some(vec![WorkSheetViewSelection {
+            pane: sheet_view
+                .pane
+                .as_ref()
+                .and_then(|pane| pane.active_pane.clone()),
+            active_cell: Some(cell_ref.replace("$", "")),
+            active_cell_id: active_cell_id
+                .filter(|active_cell_id| *active_cell_id > 0)
+                .map(|active_cell_id| active_cell_id.to_string()),
+            sq_ref: Some(if selected_range.is_empty() {
+                cell_ref.replace("$", "")
+            } else {
+                selected_range.join(" ").replace("$", "")
+            }),
+        }]);
+        Ok(())
+    }
+
+    /// Freeze the rows above and columns left of the cell. "A1" removes the freeze
+    pub fn set_freeze_pane_mut(&mut self, cell_ref: &str) -> AnyResult<(), AnyError> {
+        let cell_ref = cell_ref.replace("$", "");
+        let (row_index, col_index) =
+            ConverterUtil::get_cell_index(&cell_ref).context("Failed to parse freeze cell")?;
+        if !(1..=MAX_ROW_INDEX).contains(&row_index) || !(1..=MAX_COL_INDEX).contains(&col_index) {
+            return Err(anyhow!("Freeze cell outside the sheet : {}", cell_ref));
+        }
+        let (x_split, y_split) = (col_index - 1, row_index - 1);
+        if x_split == 0 && y_split == 0 {
+            return self.remove_pane_mut();
+        }
+        self.set_pane_mut(WorkSheetViewPane {
+            x_split: Some(x_split as f64).filter(|x_split| *x_split > 0.0),
+            y_split: Some(y_split as f64).filter(|y_split| *y_split > 0.0),
+            top_left_cell: Some(cell_ref),
+            active_pane: Some(Self::get_active_pane(x_split > 0, y_split > 0)),
+            state: Some("frozen".to_string()),
+        })
+    }
+
+    /// Split the sheet window at the position given in 1/20 of point.
+    /// Zero on either direction skips the split for that direction
+    pub fn set_split_pane_mut(
+        &mut self,
+        horizontal_position: f64,
+        vertical_position: f64,
+    ) -> AnyResult<(), AnyError> {
+        if horizontal_position < 0.0 || vertical_position < 0.0 {
+            return Err(anyhow!("Split position can't be negative"));
+        }
+        if horizontal_position == 0.0 && vertical_position == 0.0 {
+            return self.remove_pane_mut();
+        }
+        self.set_pane_mut(WorkSheetViewPane {
+            x_split: Some(horizontal_position).filter(|x_split| *x_split > 0.0),
+            y_split: Some(vertical_position).filter(|y_split| *y_split > 0.0),
+            top_left_cell: None,
+            active_pane: Some(Self::get_active_pane(
+                horizontal_position > 0.0,
+                vertical_position > 0.0,
+            )),
+            state: Some("split".to_string()),
+        })
+    }
+
+    /// Remove freeze or split pane from the sheet
+    pub fn remove_pane_mut(&mut self) -> AnyResult<(), AnyError> {
+        let sheet_view = self.get_sheet_view_mut()?;
+        sheet_view.pane = None;
+        for selection in sheet_view.selection_collection.iter_mut().flatten() {
+            selection.pane = None;
+        }
+        Ok(())
+    }
+
+    /// Set zoom percentage of the sheet view, allowed range 10 to 400
+    pub fn set_zoom_mut(&mut self, zoom_scale: u16) -> AnyResult<(), AnyError> {
+        if !(10..=400).contains(&zoom_scale) {
+            return Err(anyhow!(
+                "Zoom scale must be between 10 and 400 : {}",
+                zoom_scale
+            ));
+        }
+        let sheet_view = self.get_sheet_view_mut()?;
+        sheet_view.zoom_scale = Some(zoom_scale as i16);
+        sheet_view.zoom_scale_normal = Some(zoom_scale as i16);
+        Ok(())
+    }
+
+    /// Show or hide the grid lines of the sheet view
+    pub fn set_show_grid_lines_mut(&mut self, show_grid_line: bool) -> AnyResult<(), AnyError> {
+        self.get_sheet_view_mut()?.show_grid_line = Some(show_grid_line);
+        Ok(())
+    }
+
+    /// Display the sheet from right to left
+    pub fn set_right_to_left_mut(&mut self, view_right_to_left: bool) -> AnyResult<(), AnyError> {
+        self.get_sheet_view_mut()?.view_right_to_left = Some(view_right_to_left);
+        Ok(())
+    }
+
+    /// Set Column property
+    pub fn set_column_ref_properties_mut(
+        &mut self,
+        cell_ref: &str,
+        column_properties: Option<ColumnProperties>,
+    ) -> AnyResult<(), AnyError> {
+        let col_index = ConverterUtil::get_column_index(cell_ref)
+            .context("Failed to Get Column index from reference")?;
+        self.set_column_index_properties_mut(&col_index, column_properties)
+    }
+
+    /// Set Column property
+    pub fn set_column_index_properties_mut(
+        &mut self,
+        col_index: &u16,
+        column_properties: Option<ColumnProperties>,
+    ) -> AnyResult<(), AnyError> {
+        let column_collection = self.column_collection.get_or_insert_with(VecDeque::new);
+        let mut new_ranges = VecDeque::new();
+        // Delete Old Record
+        column_collection.retain_mut(|range| {
+            if range.min == *col_index && range.max == *col_index {
+                // Fully matched range, remove it
+                return false;
+            } else if range.min <= *col_index && *col_index <= range.max {
+                // Value lies within the range
+                if range.min == *col_index {
+                    // Trim the start
+                    range.min = col_index + 1;
+                } else if range.max == *col_index {
+                    // Trim the end
+                    range.max = col_index - 1;
+                } else {
+                    // Split the range
+                    new_ranges.push_back(ColumnProperties {
+                        min: col_index + 1,
+                        ..range.clone()
+                    });
+                    range.max = col_index - 1;
+                }
+            }
+            true
+        });
+        if let Some(column_properties) = column_properties {
+            column_collection.push_back(ColumnProperties {
+                max: *col_index,
+                min: *col_index,
+                ..column_properties
+            });
+        }
+        column_collection.append(&mut new_ranges);
+        Ok(())
+    }
+
+    /// Set/Reset Row property
+    pub fn set_row_index_properties_mut(
+        &mut self,
+        row_index: &u32,
+        row_properties: RowProperties,
+    ) -> AnyResult<(), AnyError> {
+        if self.sheet_data_stream.is_some() {
+            return Err(anyhow!(
+                "Worksheet is in streaming mode, use the streaming writer to append rows"
+            ));
+        }
+        if let Some(sheet_data) = self.sheet_data.as_mut() {
+            if let Some(row) = sheet_data.get_mut(row_index) {
+                row.row_record = row_properties;
+            } else {
+                sheet_data.insert(
+                    *row_index,
+                    RowData {
+                        row_record: row_properties,
+                        cell_records: None,
+                    },
+                );
+            }
+        } else {
+            let mut map = BTreeMap::new();
+            map.insert(
+                *row_index,
+                RowData {
+                    row_record: row_properties,
+                    cell_records: None,
+                },
+            );
+            self.sheet_data = Some(map);
+        }
+        Ok(())
+    }
+
+    /// Set data for same row multiple columns along with row property
+    pub fn set_row_value_ref_mut(
+        &mut self,
+        cell_ref: &str,
+        column_cell: Vec<CellProperties>,
+    ) -> AnyResult<(), AnyError> {
+        let (row_index, col_index) =
+            ConverterUtil::get_cell_index(cell_ref).context("Failed to extract cell key")?;
+        self.set_row_value_index_mut(row_index, col_index, column_cell)
+    }
+
+    /// Set data for same row multiple columns along with row property
+    pub fn set_row_value_index_mut(
+        &mut self,
+        row_index: u32,
+        mut col_index: u16,
+        column_cell: Vec<CellProperties>,
+    ) -> AnyResult<(), AnyError> {
+        if self.sheet_data_stream.is_some() {
+            return Err(anyhow!(
+                "Worksheet is in streaming mode, use the streaming writer to append rows"
+            ));
+        }
+        validate_cell_properties(&column_cell).context("Failed to validate cell values")?;
+        // Map Start Normalization
+        col_index -= 1;
+        // Share string cells getting replaced no longer refer the table
+        let replaced_share_string = self
+            .sheet_data
+            .as_ref()
+            .and_then(|sheet_data| sheet_data.get(&row_index))
+            .and_then(|row| row.cell_records.as_ref())
+            .map_or(0, |cell_records| {
+                (col_index + 1..)
+                    .take(column_cell.len())
+                    .filter(|cell_col_index| {
+                        cell_records.get(cell_col_index).is_some_and(|cell_data| {
+                            matches!(cell_data.data_type, CellDataType::ShareString)
+                        })
+                    })
+                    .count()
+            });
+        self.set_cell_hyperlink_mut(row_index, col_index + 1, &column_cell);
+        let mut column_cell = self
+            .normalize_cell_value_mut(column_cell, false)
+            .context("Failed to normalize cell values")?;
+        self.release_share_string_mut(replaced_share_string)
+            .context("Failed to release replaced share string")?;
+        // Load If Sheet Data Exist
+        if let Some(sheet_data) = self.sheet_data.as_mut() {
+            // Load If Row Exits
+            if let Some(row) = sheet_data.get_mut(&row_index) {
+                // Check if the row already has cell data
+                if let Some(cell_records) = row.cell_records.as_mut() {
+                    cell_records.extend(column_cell.iter_mut().map(|item| {
+                        col_index += 1;
+                        self.dimension.start_col = min(self.dimension.start_col, col_index);
+                        self.dimension.end_col = max(self.dimension.end_col, col_index);
+                        (col_index, item.clone())
+                    }));
+                } else {
+                    //Create cells If new
+                    let mut cell_records = BTreeMap::new();
+                    cell_records.extend(column_cell.iter_mut().map(|item| {
+                        col_index += 1;
+                        self.dimension.start_col = min(self.dimension.start_col, col_index);
+                        self.dimension.end_col = max(self.dimension.end_col, col_index);
+                        (col_index, item.clone())
+                    }));
+                    row.cell_records = Some(cell_records);
+                }
+            } else {
+                // Create If new Row
+                let mut cell_records = BTreeMap::new();
+                cell_records.extend(column_cell.iter_mut().map(|item| {
+                    col_index += 1;
+                    self.dimension.start_col = min(self.dimension.start_col, col_index);
+                    self.dimension.end_col = max(self.dimension.end_col, col_index);
+                    (col_index, item.clone())
+                }));
+                sheet_data.insert(
+                    row_index,
+                    RowData {
+                        row_record: RowProperties::default(),
+                        cell_records: Some(cell_records),
+                    },
+                );
+            }
+        } else {
+            // Create Sheet Data
+            let mut sheet_data = BTreeMap::new();
+            let mut cell_records = BTreeMap::new();
+            cell_records.extend(column_cell.iter_mut().map(|item| {
+                col_index += 1;
+                self.dimension.start_col = min(self.dimension.start_col, col_index);
+                self.dimension.end_col = max(self.dimension.end_col, col_index);
+                (col_index, item.clone())
+            }));
+            sheet_data.insert(
+                row_index,
+                RowData {
+                    row_record: RowProperties::default(),
+                    cell_records: Some(cell_records),
+                },
+            );
+            self.sheet_data = Some(sheet_data);
+        }
+        Ok(())
+    }
+
+    /// Replace the cached value of the formula cell keeping the formula and style
+    pub(crate) fn set_formula_value_mut(
+        &mut self,
+        row_index: u32,
+        col_index: u16,
+        cell_value: CellValue,
+    ) -> AnyResult<(), AnyError> {
+        let cell_data = self
+            .sheet_data
+            .as_mut()
+            .and_then(|sheet_data| sheet_data.get_mut(&row_index))
+            .and_then(|row| row.cell_records.as_mut())
+            .and_then(|cell_records| cell_records.get_mut(&col_index))
+            .ok_or(anyhow!(
+                "Formula cell not found at row {} column {}",
+                row_index,
+                col_index
+            ))?;
+        let is_share_string = matches!(cell_data.data_type, CellDataType::ShareString);
+        cell_data.rich_text = None;
+        (cell_data.data_type, cell_data.value) = match cell_value {
+            CellValue::Number(number) => (CellDataType::Number, Some(number.to_string())),
+            CellValue::Text(text) => (CellDataType::String, Some(text)),
+            CellValue::Bool(bool_value) => (
+                CellDataType::Boolean,
+                Some(ConverterUtil::bool_xml_flag(&bool_value)),
+            ),
+            CellValue::Error(error_code) => (CellDataType::Error, Some(error_code)),
+            _ => (CellDataType::Number, None),
+        };
+        if is_share_string {
+            self.release_share_string_mut(1)
+                .context("Failed to release replaced share string")?;
+        }
+        Ok(())
+    }
+
+    /// Convert typed cell value into stored form, text content move into share string table
+    fn normalize_cell_value_mut(
+        &mut self,
+        column_cell: Vec<CellProperties>,
+        inline_string: bool,
+    ) -> AnyResult<Vec<CellData>, AnyError> {
+        let mut cell_collection = Vec::with_capacity(column_cell.len());
+        for cell_properties in column_cell {
+            // Stored formula goes without the leading "="
+            let formula = cell_properties.formula.map(|formula| {
+                let formula = formula.trim();
+                formula.strip_prefix('=').unwrap_or(formula).to_string()
+            });
+            let mut cell_data = CellData {
+                formula,
+                style_id: cell_properties.style_id,
+                ..Default::default()
+            };
+            // Inline mode skips the share string table unless asked explicitly
+            let data_type = match cell_properties.data_type {
+                CellDataType::Auto if inline_string => CellDataType::InlineString,
+                data_type => data_type,
+            };
+            match cell_properties.value {
+                CellValue::Number(number) => cell_data.value = Some(number.to_string()),
+                CellValue::Bool(bool_value) => {
+                    cell_data.data_type = CellDataType::Boolean;
+                    cell_data.value = Some(ConverterUtil::bool_xml_flag(&bool_value));
+                }
+                CellValue::Error(error_code) => {
+                    cell_data.data_type = CellDataType::Error;
+                    cell_data.value = Some(error_code);
+                }
+                CellValue::DateTime(date_time) => {
+                    cell_data.value = Some(
+                        ConverterUtil::get_excel_serial_date(&date_time)
+                            .context("Failed to convert date into serial number")?
+                            .to_string(),
+                    );
+                    if cell_data.style_id.is_none() {
+                        cell_data.style_id = Some(
+                            self.get_date_style_id_mut(date_time.time() != Default::default())
+                                .context("Failed to get date style")?,
+                        );
+                    }
+                }
+                CellValue::Text(text) => match data_type {
+                    CellDataType::Auto | CellDataType::ShareString => {
+                        cell_data.data_type = CellDataType::ShareString;
+                        cell_data.value = Some(self.update_share_string(&text)?);
+                    }
+                    CellDataType::Boolean => {
+                        cell_data.data_type = CellDataType::Boolean;
+                        cell_data.value = match text.to_lowercase().as_str() {
+                            "false" | "0" | "" => Some("0".to_string()),
+                            _ => Some("1".to_string()),
+                        }
+                    }
+                    data_type => {
+                        // Explicit storage type keeps the raw text
+                        cell_data.data_type = data_type;
+                        cell_data.value = Some(text);
+                    }
+                },
+                CellValue::Rich(rich_text) => match data_type {
+                    CellDataType::Auto | CellDataType::ShareString => {
+                        cell_data.data_type = CellDataType::ShareString;
+                        cell_data.value = Some(self.update_rich_share_string(rich_text)?);
+                    }
+                    _ => {
+                        // Other storage type keep the runs inline in the cell
+                        cell_data.data_type = CellDataType::InlineString;
+                        cell_data.rich_text = Some(rich_text);
+                    }
+                },
+                CellValue::Empty => {}
+            }
+            cell_collection.push(cell_data);
+        }
+        Ok(cell_collection)
+    }
+
+    /// Keep the hyperlink of the cells, replaces existing link of the same cell
+    fn set_cell_hyperlink_mut(
+        &mut self,
+        row_index: u32,
+        col_index: u16,
+        column_cell: &[CellProperties],
+    ) {
+        for (cell_col_index, cell_properties) in (col_index..).zip(column_cell.iter()) {
+            if let Some(hyperlink) = cell_properties.hyperlink_properties.as_ref() {
+                self.hyperlinks.get_or_insert_with(BTreeMap::new).insert(
+                    ((row_index, cell_col_index), (row_index, cell_col_index)),
+                    hyperlink.clone(),
+                );
+            }
+        }
+    }
+
+    /// Write column names into header row and the label or function into totals row
+    fn set_table_cells_mut(
+        &mut self,
+        cell_range: &CellRange,
+        table: &Table,
+    ) -> AnyResult<(), AnyError> {
+        let ((start_row, start_col), (end_row, _)) = *cell_range;
+        let get_style_id = |worksheet: &Self, row_index: u32, col_index: u16| {
+            worksheet
+                .get_cell_value_index(row_index, col_index)
+                .map(|cell_record| cell_record.and_then(|cell_record| cell_record.style_id))
+        };
+        if table.setting.show_header_row {
+            let mut header_cells = Vec::with_capacity(table.columns.len());
+            for (index, table_column) in table.columns.iter().enumerate() {
+                header_cells.push(CellProperties {
+                    value: table_column.name.clone().into(),
+                    style_id: get_style_id(self, start_row, start_col + index as u16)?,
+                    ..Default::default()
+                });
+            }
+            self.set_row_value_index_mut(start_row, start_col, header_cells)
+                .context("Failed to set table header row")?;
+        }
+        if table.setting.show_totals_row {
+            let mut totals_cells = Vec::with_capacity(table.columns.len());
+            for (index, table_column) in table.columns.iter().enumerate() {
+                let formula = if let Some(subtotal_number) =
+                    table_column.totals_row_function.get_subtotal_number()
+                {
+                    Some(format!(
+                        "SUBTOTAL({},{}[{}])",
+                        subtotal_number,
+                        table.name,
+                        get_structured_column_name(&table_column.name)
+                    ))
+                } else if table_column.totals_row_function == TableTotalsRowFunctionValues::Custom {
+                    table_column.totals_row_formula.clone()
+                } else {
+                    None
+                };
+                totals_cells.push(CellProperties {
+                    value: if formula.is_none() {
+                        table_column.totals_row_label.clone().into()
+                    } else {
+                        CellValue::Empty
+                    },
+                    formula,
+                    style_id: get_style_id(self, end_row, start_col + index as u16)?,
+                    ..Default::default()
+                });
+            }
+            self.set_row_value_index_mut(end_row, start_col, totals_cells)
+                .context("Failed to set table totals row")?;
+        }
+        Ok(())
+    }
+
+    /// Check the range does not overlap other table or merged cells of the sheet
+    fn check_table_range(
+        &self,
+        cell_range: &CellRange,
+        table_name: Option<&str>,
+    ) -> AnyResult<(), AnyError> {
+        for table_part in &self.tables {
+            let table = table_part.get_table();
+            if table_name.is_some_and(|table_name| table.name.eq_ignore_ascii_case(table_name)) {
+                continue;
+            }
+            if is_range_overlap(
+                cell_range,
+                &ConverterUtil::get_range_index(&table.range_ref)
+                    .context("Failed to parse existing table range")?,
+            ) {
+                return Err(anyhow!("Table range overlaps table {}", table.name));
+            }
+        }
+        if let Some(merge_cell) = self
+            .merge_cells
+            .iter()
+            .flatten()
+            .find(|item| is_range_overlap(cell_range, item))
+        {
+            return Err(anyhow!(
+                "Table range overlaps merged range {}",
+                get_range_ref(merge_cell)?
+            ));
+        }
+        if let Some(auto_filter) = self.auto_filter.as_ref() {
+            if is_range_overlap(
+                cell_range,
+                &ConverterUtil::get_range_index(&auto_filter.range_ref)
+                    .context("Failed to parse auto filter range")?,
+            ) {
+                return Err(anyhow!(
+                    "Table range overlaps auto filter range {}",
+                    auto_filter.range_ref
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep the hidden filter database name of the sheet in sync with auto filter range
+    fn set_filter_database_mut(
+        &mut self,
+        cell_range: Option<&CellRange>,
+    ) -> AnyResult<(), AnyError> {
+        let value = if let Some(cell_range) = cell_range {
+            Some(format!(
+                "'{}'!{}",
+                self.sheet_name.replace("'", "''"),
+                ConverterUtil::get_absolute_range_ref(*cell_range)
+                    .context("Failed to get filter database range")?
+            ))
+        } else {
+            None
+        };
+        self.set_built_in_name_mut(FILTER_DATABASE_NAME, value, true)
+    }
+
+    /// Row properties of the row, row record get created when missing
+    fn get_row_properties_mut(
+        &mut self,
+        row_index: u32,
+    ) -> AnyResult<&mut RowProperties, AnyError> {
+        if self.sheet_data_stream.is_some() {
+            return Err(anyhow!(
+                "Worksheet is in streaming mode, use the streaming writer to append rows"
+            ));
+        }
+        Ok(&mut self
+            .sheet_data
+            .get_or_insert_with(BTreeMap::new)
+            .entry(row_index)
+            .or_insert_with(|| RowData {
+                row_record: RowProperties::default(),
+                cell_records: None,
+            })
+            .row_record)
+    }
+
+    /// Column ranges covering start to end column, ranges crossing the edge get split
+    /// and missing columns get a default range
+    fn get_column_ranges_mut(
+        &mut self,
+        start_col: u16,
+        end_col: u16,
+    ) -> impl Iterator<Item = &mut ColumnProperties> {
+        let column_collection = self.column_collection.get_or_insert_with(VecDeque::new);
+        for split_col in [start_col, end_col + 1] {
+            let mut split_ranges = VecDeque::new();
+            for range in column_collection.iter_mut() {
+                if range.min < split_col && split_col <= range.max {
+                    split_ranges.push_back(ColumnProperties {
+                        min: split_col,
+                        ..range.clone()
+                    });
+                    range.max = split_col - 1;
+                }
+            }
+            column_collection.append(&mut split_ranges);
+        }
+        column_collection
+            .make_contiguous()
+            .sort_by_key(|range| range.min);
+        let mut next_col = start_col;
+        let mut missing_ranges = VecDeque::new();
+        for range in column_collection
+            .iter()
+            .filter(|range| range.min >= start_col && range.max <= end_col)
+        {
+            if range.min > next_col {
+                missing_ranges.push_back(ColumnProperties {
+                    min: next_col,
+                    max: range.min - 1,
+                    ..ColumnProperties::default()
+                });
+            }
+            next_col = range.max + 1;
+        }
+        if next_col <= end_col {
+            missing_ranges.push_back(ColumnProperties {
+                min: next_col,
+                max: end_col,
+                ..ColumnProperties::default()
+            });
+        }
+        column_collection.append(&mut missing_ranges);
+        column_collection
+            .iter_mut()
+            .filter(move |range| range.min >= start_col && range.max <= end_col)
+    }
+
+    /// Outline level and collapsed flag of grouped or collapsed rows
+    fn get_row_outline(&self) -> BTreeMap<u32, (u8, bool)> {
+        self.sheet_data
+            .iter()
+            .flatten()
+            .filter(|(_, row)| {
+                row.row_record.group_level.is_some() || row.row_record.collapsed.is_some()
+            })
+            .map(|(row_index, row)| {
+                (
+                    *row_index,
+                    (
+                        row.row_record.group_level.unwrap_or(0),
+                        row.row_record.collapsed == Some(true),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Outline level and collapsed flag of grouped or collapsed columns
+    fn get_column_outline(&self) -> BTreeMap<u32, (u8, bool)> {
+        let mut column_outline = BTreeMap::new();
+        for range in self.column_collection.iter().flatten() {
+            if range.group_level > 0 || range.collapsed.is_some() {
+                for col_index in range.min..=range.max {
+                    column_outline.insert(
+                        col_index as u32,
+                        (range.group_level as u8, range.collapsed == Some(true)),
+                    );
+                }
+            }
+        }
+        column_outline
+    }
+
+    /// Summary row below and summary column right flag of the outline
+    fn get_outline_summary(&self) -> AnyResult<(bool, bool), AnyError> {
+        let mut outline_summary = (true, true);
+        if let Some(xml_document) = self.xml_document.upgrade() {
+            let xml_doc = xml_document
+                .try_borrow()
+                .context("Failed to Pull XML Handle")?;
+            if let Some(attributes) = xml_doc
+                .get_first_element_id(vec!["sheetPr", "outlinePr"], None)
+                .context("Failed to find outline property")?
+                .and_then(|outline_property_id| xml_doc.get_element(&outline_property_id))
+                .and_then(|outline_property| outline_property.get_attribute())
+            {
+                let is_enabled = |key: &str| {
+                    attributes
+                        .get(key)
+                        .is_none_or(|value| ConverterUtil::normalize_bool_property_bool(value))
+                };
+                outline_summary = (is_enabled("summaryBelow"), is_enabled("summaryRight"));
+            }
+        }
+        Ok(outline_summary)
+    }
+
+    /// Replace the sheet scoped built in name, None value remove the name
+    fn set_built_in_name_mut(
+        &mut self,
+        name: &str,
+        value: Option<String>,
+        hidden: bool,
+    ) -> AnyResult<(), AnyError> {
+        if let Some(defined_name_collection) = self.defined_name_collection.upgrade() {
+            let mut defined_name_collection = defined_name_collection
+                .try_borrow_mut()
+                .context("Failed to pull Defined Name Collection")?;
+            defined_name_collection.retain(|defined_name| {
+                !(defined_name.name.eq_ignore_ascii_case(name)
+                    && defined_name.sheet_name.as_ref() == Some(&self.sheet_name))
+            });
+            if let Some(value) = value {
+                defined_name_collection.push(DefinedName {
+                    sheet_name: Some(self.sheet_name.clone()),
+                    hidden,
+                    ..DefinedName::new(name, &value)
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn delete_table_part_mut(&mut self, table_part: TablePart) -> AnyResult<(), AnyError> {
+        self.sheet_relationship_part
+            .try_borrow_mut()
+            .context("Failed to pull sheet relationship part")?
+            .delete_relationship_by_id_mut(table_part.get_relationship_id());
+        if let Some(table_collection) = self.table_collection.upgrade() {
+            table_collection
+                .try_borrow_mut()
+                .context("Failed to pull table collection")?
+                .retain(|(_, _, file_path)| file_path != table_part.get_file_path());
+        }
+        if let Some(office_document) = self.office_document.upgrade() {
+            office_document
+                .try_borrow_mut()
+                .context("Failed to Pull XML Handle")?
+                .delete_document_mut(table_part.get_file_path());
+        }
+        Ok(())
+    }
+
+    /// Default folder of the content inside the workbook folder
+    fn get_part_folder_path(&self, content: &Content) -> AnyResult<String, AnyError> {
+        Ok(format!(
+            "{}{}",
+            self.workbook_relationship_part
+                .upgrade()
+                .ok_or(anyhow!("Failed to pull workbook relationship part"))?
+                .try_borrow()
+                .context("Failed to pull workbook relationship part")?
+                .get_relative_path()
+                .context("Get Relative Path for Part File")?,
+            content.default_path
+        )
+        .trim_end_matches("/")
+        .to_string())
+    }
+
+    /// Next free part of the content inside the workbook folder. Returns (folder path, file name)
+    fn get_next_part_path(&self, content: &Content) -> AnyResult<(String, String), AnyError> {
+        let folder_path = self
+            .get_part_folder_path(content)
+            .context("Failed to get part folder")?;
+        let office_document = self
+            .office_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to pull office document"))?;
+        let office_doc = office_document
+            .try_borrow()
+            .context("Failed to Borrow Document")?;
+        let mut part_number = 1;
+        loop {
+            let file_name = format!("{}{}", content.default_name, part_number);
+            if office_doc.check_file_exist(format!(
+                "{}/{}.{}",
+                folder_path, file_name, content.extension
+            )) {
+                part_number += 1;
+            } else {
+                return Ok((folder_path, file_name));
+            }
+        }
+    }
+
+    /// Add relationship to the next free part path of the content inside the workbook folder.
+    /// Returns (file path, relationship id)
+    fn add_part_relationship_mut(
+        &mut self,
+        content: &Content,
+    ) -> AnyResult<(String, String), AnyError> {
+        let (folder_path, file_name) = self
+            .get_next_part_path(content)
+            .context("Failed to get next part path")?;
+        let file_path = format!("{}/{}.{}", folder_path, file_name, content.extension);
+        let relationship_id = self
+            .sheet_relationship_part
+            .try_borrow_mut()
+            .context("Failed to pull sheet relationship part")?
+            .set_new_relationship_mut(content, Some(folder_path), Some(file_name))
+            .context("Failed to add part relationship")?;
+        Ok((file_path, relationship_id))
+    }
+
+    fn get_comment_part_mut(&mut self) -> AnyResult<&mut CommentPart, AnyError> {
+        if self.comment_part.is_none() {
+            let (file_path, relationship_id) = self
+                .add_part_relationship_mut(EXCEL_TYPE_COLLECTION.get("comments").unwrap())
+                .context("Failed to add comments relationship")?;
+            let (vml_file_path, vml_relationship_id) = self
+                .add_part_relationship_mut(EXCEL_TYPE_COLLECTION.get("vml_drawing").unwrap())
+                .context("Failed to add legacy drawing relationship")?;
+            self.comment_part = Some(
+                CommentPart::new(
+                    self.office_document.clone(),
+                    &file_path,
+                    &relationship_id,
+                    &vml_file_path,
+                    &vml_relationship_id,
+                )
+                .context("Failed to create comments part")?,
+            );
+        }
+        self.comment_part
+            .as_mut()
+            .ok_or(anyhow!("Failed to get comments part"))
+    }
+
+    fn get_threaded_comment_part_mut(&mut self) -> AnyResult<&mut ThreadedCommentPart, AnyError> {
+        if self.threaded_comment_part.is_none() {
+            let (file_path, relationship_id) = self
+                .add_part_relationship_mut(EXCEL_TYPE_COLLECTION.get("threaded_comment").unwrap())
+                .context("Failed to add threaded comments relationship")?;
+            self.threaded_comment_part = Some(
+                ThreadedCommentPart::new(
+                    self.office_document.clone(),
+                    self.common_service.clone(),
+                    &file_path,
+                    &relationship_id,
+                )
+                .context("Failed to create threaded comments part")?,
+            );
+        }
+        self.threaded_comment_part
+            .as_mut()
+            .ok_or(anyhow!("Failed to get threaded comments part"))
+    }
+
+    /// Note of the cell, placeholder note of the threaded comment is skipped
+    fn get_note(&self, cell_key: &(u32, u16)) -> Option<&Comment> {
+        self.comment_part
+            .as_ref()
+            .and_then(|comment_part| comment_part.get_comments().get(cell_key))
+            .filter(|comment| !comment.author.starts_with(THREADED_COMMENT_AUTHOR_PREFIX))
+    }
+
+    /// Close the comment parts, parts left without comments are removed with their relationship
+    fn close_comment_parts(&mut self) -> AnyResult<(), AnyError> {
+        let mut file_paths = Vec::new();
+        {
+            let mut sheet_relationship_part = self
+                .sheet_relationship_part
+                .try_borrow_mut()
+                .context("Failed to pull sheet relationship part")?;
+            if let Some(comment_part) = self.comment_part.take_if(|part| part.is_empty()) {
+                sheet_relationship_part
+                    .delete_relationship_by_id_mut(comment_part.get_relationship_id());
+                sheet_relationship_part
+                    .delete_relationship_by_id_mut(comment_part.get_vml_relationship_id());
+                file_paths.push(comment_part.get_file_path().to_string());
+                file_paths.push(comment_part.get_vml_file_path().to_string());
+            }
+            if let Some(threaded_comment_part) =
+                self.threaded_comment_part.take_if(|part| part.is_empty())
+            {
+                sheet_relationship_part
+                    .delete_relationship_by_id_mut(threaded_comment_part.get_relationship_id());
+                file_paths.push(threaded_comment_part.get_file_path().to_string());
+            }
+        }
+        if let Some(office_document) = self.office_document.upgrade() {
+            let mut office_doc_mut = office_document
+                .try_borrow_mut()
+                .context("Failed to Pull XML Handle")?;
+            for file_path in file_paths {
+                office_doc_mut.delete_document_mut(&file_path);
+            }
+        }
+        if let Some(threaded_comment_part) = self.threaded_comment_part.as_mut() {
+            threaded_comment_part
+                .close_document()
+                .context("Failed to close worksheet threaded comments")?;
+        }
+        if let Some(comment_part) = self.comment_part.as_mut() {
+            comment_part
+                .close_document()
+                .context("Failed to close worksheet comments")?;
+        }
+        Ok(())
+    }
+
+    /// Delete the notes, drawing and relations of the sheet. Media stay while other drawing refer it
+    fn delete_sheet_parts_mut(&mut self) -> AnyResult<(), AnyError> {
+        let mut file_paths = Vec::new();
+        if let Some(comment_part) = self.comment_part.take() {
+            file_paths.push(comment_part.get_file_path().to_string());
+            file_paths.push(comment_part.get_vml_file_path().to_string());
+        }
+        if let Some(threaded_comment_part) = self.threaded_comment_part.take() {
+            file_paths.push(threaded_comment_part.get_file_path().to_string());
+        }
+        let mut media_file_paths = Vec::new();
+        if let Some(mut drawing_part) = self.drawing_part.take() {
+            let image_type = COMMON_TYPE_COLLECTION
+                .get("image_png")
+                .unwrap()
+                .schemas_type;
+            for (file_path, relationship_type) in drawing_part
+                .take_part_paths_mut()
+                .context("Failed to pull drawing relations")?
+            {
+                if relationship_type == image_type {
+                    media_file_paths.push(file_path);
+                } else {
+                    file_paths.push(get_relationship_file_path(&file_path));
+                    file_paths.push(file_path);
+                }
+            }
+            file_paths.push(drawing_part.get_file_path().to_string());
+        }
+        // Relation part left without relation gets deleted on close
+        self.sheet_relationship_part
+            .try_borrow_mut()
+            .context("Failed to pull sheet relationship part")?
+            .take_relationships_mut()
+            .context("Failed to pull sheet relations")?;
+        let drawing_rels_folder_path = format!(
+            "{}/_rels",
+            self.get_part_folder_path(EXCEL_TYPE_COLLECTION.get("drawing").unwrap())
+                .context("Failed to get drawing folder")?
+        );
+        if let Some(office_document) = self.office_document.upgrade() {
+            let mut office_doc_mut = office_document
+                .try_borrow_mut()
+                .context("Failed to Pull XML Handle")?;
+            for file_path in file_paths {
+                office_doc_mut.delete_document_mut(&file_path);
+            }
+            for media_file_path in media_file_paths {
+                if !is_media_referred(&office_doc_mut, &drawing_rels_folder_path, &media_file_path)
+                    .context("Failed to check media reference")?
+                {
+                    office_doc_mut.delete_document_mut(&media_file_path);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_drawing_part_mut(&mut self) -> AnyResult<&mut DrawingPart, AnyError> {
+        if self.drawing_part.is_none() {
+            let (file_path, relationship_id) = self
+                .add_part_relationship_mut(EXCEL_TYPE_COLLECTION.get("drawing").unwrap())
+                .context("Failed to add drawing relationship")?;
+            self.drawing_part = Some(
+                DrawingPart::new(self.office_document.clone(), &file_path, &relationship_id)
+                    .context("Failed to create drawing part")?,
+            );
+        }
+        self.drawing_part
+            .as_mut()
+            .ok_or(anyhow!("Failed to get drawing part"))
+    }
+
+    /// Store the media in the workbook media folder. Media with the same content is shared
+    /// instead of stored again. Returns the media file path
+    fn add_media_part_mut(
+        &mut self,
+        media: &[u8],
+        content: &Content,
+    ) -> AnyResult<String, AnyError> {
+        let folder_path = self
+            .get_part_folder_path(content)
+            .context("Failed to get media folder")?;
+        let office_document = self
+            .office_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to pull office document"))?;
+        let mut office_doc_mut = office_document
+            .try_borrow_mut()
+            .context("Failed to Borrow Document")?;
+        let extension = format!(".{}", content.extension);
+        for file_path in office_doc_mut.list_file_path_by_folder(&folder_path) {
+            if file_path.ends_with(&extension)
+                && office_doc_mut
+                    .read_binary_part(&file_path)
+                    .context("Failed to read media part")?
+                    .is_some_and(|stored_media| stored_media == media)
+            {
+                return Ok(file_path);
+            }
+        }
+        let mut part_number = 1;
+        let file_path = loop {
+            let file_path = format!(
+                "{}/{}{}{}",
+                folder_path, content.default_name, part_number, extension
+            );
+            if office_doc_mut.check_file_exist(file_path.clone()) {
+                part_number += 1;
+            } else {
+                break file_path;
+            }
+        };
+        office_doc_mut
+            .add_binary_part_mut(&file_path, media, content.extension, content.extension_type)
+            .context("Failed to store media part")?;
+        Ok(file_path)
+    }
+
+    /// Qualify the series ranges with this sheet when the sheet is not given and
+    /// fill the empty caches from the cells of this sheet
+    fn set_chart_series_cache_mut(&self, series: &mut ChartSeries) -> AnyResult<(), AnyError> {
+        let mut text_data_collection = vec![&mut series.name];
+        if let Some(categories) = series.categories.as_mut() {
+            text_data_collection.push(categories);
+        }
+        for text_data in text_data_collection {
+            if let Some(reference) = text_data.reference.as_mut() {
+                if let Some(cell_range) = self.qualify_chart_reference_mut(reference)? {
+                    if text_data.values.is_empty() {
+                        text_data.values = self
+                            .get_chart_range_values(&cell_range)?
+                            .iter()
+                            .map(|cell_value| get_chart_text_value(cell_value.as_ref()))
+                            .collect::<AnyResult<Vec<String>, AnyError>>()?;
+                    }
+                }
+            }
+        }
+        let mut number_data_collection = vec![&mut series.values];
+        if let Some(x_values) = series.x_values.as_mut() {
+            number_data_collection.push(x_values);
+        }
+        if let Some(bubble_sizes) = series.bubble_sizes.as_mut() {
+            number_data_collection.push(bubble_sizes);
+        }
+        for number_data in number_data_collection {
+            if let Some(reference) = number_data.reference.as_mut() {
+                if let Some(cell_range) = self.qualify_chart_reference_mut(reference)? {
+                    if number_data.values.is_empty() {
+                        number_data.values = self
+                            .get_chart_range_values(&cell_range)?
+                            .iter()
+                            .map(|cell_value| get_chart_number_value(cell_value.as_ref()))
+                            .collect::<AnyResult<Vec<Option<f64>>, AnyError>>()?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Prefix the reference with this sheet when the sheet is missing. Returns the range
+    /// when the reference point to this sheet
+    fn qualify_chart_reference_mut(
+        &self,
+        reference: &mut String,
+    ) -> AnyResult<Option<CellRange>, AnyError> {
+        let (sheet_name, range_ref) =
+            if let Some((sheet_name, range_ref)) = reference.rsplit_once("!") {
+                let sheet_name = sheet_name
+                    .strip_prefix("'")
+                    .and_then(|sheet_name| sheet_name.strip_suffix("'"))
+                    .map_or(sheet_name.to_string(), |sheet_name| {
+                        sheet_name.replace("''", "'")
+                    });
+                (sheet_name, range_ref.to_string())
+            } else {
+                let range_ref = reference.to_string();
+                *reference = format!("'{}'!{}", self.sheet_name.replace("'", "''"), range_ref);
+                (self.sheet_name.clone(), range_ref)
+            };
+        let cell_range = ConverterUtil::get_range_index(&range_ref)
+            .context(format!("Invalid chart range : {}", reference))?;
+        Ok(if sheet_name == self.sheet_name {
+            Some(cell_range)
+        } else {
+            None
+        })
+    }
+
+    /// Cell values of the range in row order, a chart range is single row or column
+    fn get_chart_range_values(
+        &self,
+        ((start_row, start_col), (end_row, end_col)): &CellRange,
+    ) -> AnyResult<Vec<Option<CellValue>>, AnyError> {
+        let mut cell_values = Vec::new();
+        for row_index in *start_row..=*end_row {
+            for col_index in *start_col..=*end_col {
+                cell_values.push(
+                    self.get_cell_value_index(row_index, col_index)
+                        .context("Failed to read chart cell")?
+                        .map(|cell_record| cell_record.value),
+                );
+            }
+        }
+        Ok(cell_values)
+    }
+
+    fn get_dxf_id_mut(
+        &mut self,
+        differential_style: DifferentialStyleSetting,
+    ) -> AnyResult<u32, AnyError> {
+        if let Some(common_service) = self.common_service.upgrade() {
+            common_service
+                .try_borrow_mut()
+                .context("Failed to Get Style Handle")?
+                .get_dxf_id_mut(differential_style)
+        } else {
+            Err(anyhow!("Failed to update Style Record"))
+        }
+    }
+
+    fn get_differential_style(&self, dxf_id: u32) -> AnyResult<DifferentialStyleSetting, AnyError> {
+        if let Some(common_service) = self.common_service.upgrade() {
+            common_service
+                .try_borrow()
+                .context("Failed to Get Style Handle")?
+                .get_differential_style(dxf_id)
+        } else {
+            Err(anyhow!("Failed to read Style Record"))
+        }
+    }
+
+    fn get_date_style_id_mut(&mut self, with_time: bool) -> AnyResult<StyleId, AnyError> {
+        if let Some(common_service) = self.common_service.upgrade() {
+            common_service
+                .try_borrow_mut()
+                .context("Failed to Get Style Handle")?
+                .get_date_style_id_mut(with_time)
+        } else {
+            Err(anyhow!("Failed to update Style Record"))
+        }
+    }
+
+    fn update_share_string(&mut self, cell_value: &String) -> AnyResult<String, AnyError> {
+        if let Some(common_service) = self.common_service.upgrade() {
+            common_service
+                .try_borrow_mut()
+                .context("Failed to Get Share String Handle")?
+                .get_string_id_mut(cell_value.to_owned())
+                .context("Failed to get share string id")
+        } else {
+            Err(anyhow!("Failed to update Share String Record"))
+        }
+    }
+
+    fn release_share_string_mut(&mut self, reference_count: usize) -> AnyResult<(), AnyError> {
+        if reference_count == 0 {
+            return Ok(());
+        }
+        if let Some(common_service) = self.common_service.upgrade() {
+            common_service
+                .try_borrow_mut()
+                .context("Failed to Get Share String Handle")?
+                .release_string_reference_mut(reference_count);
+            Ok(())
+        } else {
+            Err(anyhow!("Failed to update Share String Record"))
+        }
+    }
+
+    fn update_rich_share_string(
+        &mut self,
+        rich_text: Vec<RichTextRun>,
+    ) -> AnyResult<String, AnyError> {
+        if let Some(common_service) = self.common_service.upgrade() {
+            common_service
+                .try_borrow_mut()
+                .context("Failed to Get Share String Handle")?
+                .get_rich_string_id_mut(rich_text)
+                .context("Failed to get rich share string id")
+        } else {
+            Err(anyhow!("Failed to update Share String Record"))
+        }
+    }
+
+    /// Add conditional formatting rules to the range like "A1:C10", multiple range separated by space.
+    /// Rules take priority in the order added, after the rules already in the sheet
+    pub fn add_conditional_format_mut(
+        &mut self,
+        range_ref: &str,
+        rules: Vec<ConditionalFormatRule>,
+    ) -> AnyResult<(), AnyError> {
+        let sq_ref = get_sq_ref(range_ref).context("Failed to parse conditional format range")?;
+        if rules.is_empty() {
+            return Err(anyhow!("Conditional format needs at least one rule"));
+        }
+        let mut rule_collection = Vec::with_capacity(rules.len());
+        for rule in rules {
+            validate_conditional_format_rule(&rule)?;
+            let dxf_id = if let Some(style) = rule.style.clone() {
+                Some(
+                    self.get_dxf_id_mut(style)
+                        .context("Failed to add conditional format style")?,
+                )
+            } else {
+                None
+            };
+            rule_collection.push(ConditionalFormatRuleData {
+                rule,
+                dxf_id,
+                priority: None,
+            });
+        }
+        self.conditional_formats.push(ConditionalFormatData {
+            sq_ref,
+            rules: rule_collection,
+        });
+        Ok(())
+    }
+
+    /// List conditional format rules with its sqref, rules of type not modelled
+    /// (like above average or contains text) stay in the sheet and are not listed
+    pub fn list_conditional_format(
+        &self,
+    ) -> AnyResult<Vec<(String, Vec<ConditionalFormatRule>)>, AnyError> {
+        let mut conditional_formats = Vec::with_capacity(self.conditional_formats.len());
+        for conditional_format in &self.conditional_formats {
+            let mut rules = Vec::with_capacity(conditional_format.rules.len());
+            for rule_data in &conditional_format.rules {
+                let mut rule = rule_data.rule.clone();
+                if let (None, Some(dxf_id)) = (&rule.style, rule_data.dxf_id) {
+                    rule.style = Some(
+                        self.get_differential_style(dxf_id)
+                            .context("Failed to read conditional format style")?,
+                    );
+                }
+                rules.push(rule);
+            }
+            conditional_formats.push((conditional_format.sq_ref.clone(), rules));
+        }
+        Ok(conditional_formats)
+    }
+
+    /// Remove all conditional format rules of the sqref
+    pub fn remove_conditional_format_mut(&mut self, range_ref: &str) -> AnyResult<(), AnyError> {
+        let sq_ref = get_sq_ref(range_ref).context("Failed to parse conditional format range")?;
+        let format_count = self.conditional_formats.len();
+        self.conditional_formats
+            .retain(|conditional_format| conditional_format.sq_ref != sq_ref);
+        if self.conditional_formats.len() == format_count {
+            Err(anyhow!("Conditional format not found : {}", range_ref))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Add data validation to the range like "A1:A10", multiple range separated by space.
+    /// Validation on the same sqref is replaced
+    pub fn add_data_validation_mut(
+        &mut self,
+        range_ref: &str,
+        mut data_validation: DataValidation,
+    ) -> AnyResult<(), AnyError> {
+        let sq_ref = get_sq_ref(range_ref).context("Failed to parse data validation range")?;
+        validate_data_validation(&mut data_validation)?;
+        let data_validations = self.data_validations.get_or_insert_with(Vec::new);
+        if let Some(position) = data_validations
+            .iter()
+            .position(|(existing_sq_ref, _)| *existing_sq_ref == sq_ref)
+        {
+            data_validations[position].1 = data_validation;
+        } else {
+            data_validations.push((sq_ref, data_validation));
+        }
+        Ok(())
+    }
+
+    /// List data validation with its sqref
+    pub fn list_data_validation(&self) -> Vec<(String, DataValidation)> {
+        self.data_validations.clone().unwrap_or_default()
+    }
+
+    /// Remove data validation of the sqref
+    pub fn remove_data_validation_mut(&mut self, range_ref: &str) -> AnyResult<(), AnyError> {
+        let sq_ref = get_sq_ref(range_ref).context("Failed to parse data validation range")?;
+        if let Some(data_validations) = self.data_validations.as_mut() {
+            if let Some(position) = data_validations
+                .iter()
+                .position(|(existing_sq_ref, _)| *existing_sq_ref == sq_ref)
+            {
+                data_validations.remove(position);
+                return Ok(());
+            }
+        }
+        Err(anyhow!("Data validation not found : {}", range_ref))
+    }
+
+    /// Add filter buttons to the header row of the range like "A1:D10" with optional
+    /// pre applied criteria and sort state. Replace the existing auto filter of the sheet
+    pub fn set_auto_filter_mut(
+        &mut self,
+        range_ref: &str,
+        filter_columns: Vec<FilterColumn>,
+        sort_state: Option<SortState>,
+    ) -> AnyResult<(), AnyError> {
+        let cell_range = ConverterUtil::get_range_index(range_ref)
+            .context("Failed to parse auto filter range")?;
+        let ((_, start_col), (_, end_col)) = cell_range;
+        let mut column_indexes = HashSet::new();
+        for filter_column in &filter_columns {
+            if filter_column.column_index > end_col - start_col {
+                return Err(anyhow!(
+                    "Filter column {} is outside of auto filter range",
+                    filter_column.column_index
+                ));
+            }
+            if !column_indexes.insert(filter_column.column_index) {
+                return Err(anyhow!(
+                    "Duplicate filter column : {}",
+                    filter_column.column_index
+                ));
+            }
+            match &filter_column.criteria {
+                Some(FilterCriteriaValues::Values {
+                    values,
+                    include_blank,
+                }) if values.is_empty() && !include_blank => {
+                    return Err(anyhow!("Value filter needs at least one value"));
+                }
+                Some(FilterCriteriaValues::Custom { filters, .. })
+                    if filters.is_empty() || filters.len() > 2 =>
+                {
+                    return Err(anyhow!("Custom filter supports one or two comparison"));
+                }
+                Some(FilterCriteriaValues::Top10 {
+                    value, is_percent, ..
+                }) if *value <= 0.0 || *value > if *is_percent { 100.0 } else { 500.0 } => {
+                    return Err(anyhow!("Top 10 filter value out of range : {}", value));
                 }
+                Some(FilterCriteriaValues::Dynamic(DynamicFilterTypeValues::Quarter(quarter)))
+                    if !(1..=4).contains(quarter) =>
+                {
+                    return Err(anyhow!("Invalid quarter of dynamic filter : {}", quarter));
+                }
+                Some(FilterCriteriaValues::Dynamic(DynamicFilterTypeValues::Month(month)))
+                    if !(1..=12).contains(month) =>
+                {
+                    return Err(anyhow!("Invalid month of dynamic filter : {}", month));
+                }
+                _ => {}
+            }
+        }
+        let sort_state = if let Some(sort_state) = sort_state {
+            let sort_range = ConverterUtil::get_range_index(&sort_state.range_ref)
+                .context("Failed to parse sort state range")?;
+            if !is_range_inside(&sort_range, &cell_range) {
+                return Err(anyhow!("Sort range must be inside auto filter range"));
+            }
+            if sort_state.conditions.is_empty() || sort_state.conditions.len() > 64 {
+                return Err(anyhow!("Sort state supports one to 64 conditions"));
+            }
+            let mut conditions = Vec::with_capacity(sort_state.conditions.len());
+            for sort_condition in sort_state.conditions {
+                let condition_range = ConverterUtil::get_range_index(&sort_condition.range_ref)
+                    .context("Failed to parse sort condition range")?;
+                if !is_range_inside(&condition_range, &sort_range) {
+                    return Err(anyhow!("Sort condition range must be inside sort range"));
+                }
+                conditions.push(SortCondition {
+                    range_ref: get_range_ref(&condition_range)?,
+                    ..sort_condition
+                });
+            }
+            Some(SortState {
+                range_ref: get_range_ref(&sort_range)?,
+                conditions,
+                ..sort_state
+            })
+        } else {
+            None
+        };
+        // Table carry its own auto filter
+        for table_part in &self.tables {
+            let table = table_part.get_table();
+            if is_range_overlap(
+                &cell_range,
+                &ConverterUtil::get_range_index(&table.range_ref)
+                    .context("Failed to parse existing table range")?,
+            ) {
+                return Err(anyhow!("Auto filter range overlaps table {}", table.name));
             }
-            return Ok((Some(sheet_data_collection), dimension));
         }
+        self.auto_filter = Some(AutoFilter {
+            range_ref: get_range_ref(&cell_range)?,
+            filter_columns,
+            sort_state,
+        });
+        self.set_filter_database_mut(Some(&cell_range))
     }
-    Ok((None, dimension))
-}
 
-impl WorkSheet {
-    fn get_sheet_file_name(
-        sheet_name: Option<String>,
-        office_document: &Weak<RefCell<OfficeDocument>>,
-        sheet_collection: &Weak<RefCell<Vec<(String, String, bool, bool)>>>,
-        workbook_relationship_part: &Weak<RefCell<RelationsPart>>,
-    ) -> AnyResult<(String, String), AnyError> {
-        let worksheet_content = EXCEL_TYPE_COLLECTION.get("worksheet").unwrap();
-        if let Some(sheet_collection) = sheet_collection.upgrade() {
-            if let Some(workbook_relationship_part) = workbook_relationship_part.upgrade() {
-                if let Some(sheet_name) = sheet_name.clone() {
-                    // If the Sheet name already exist get the path of sheet name
-                    if let Some((_, rel_id, _, _)) = sheet_collection
-                        .try_borrow()
-                        .context("Failed to Get Sheet Collection")?
-                        .iter()
-                        .find(|item| item.0 == sheet_name)
-                    {
-                        return Ok((
-                            workbook_relationship_part
-                                .try_borrow()
-                                .context("Failed to Get Workbook relationship")?
-                                .get_target_by_id(&rel_id)
-                                .context("Failed to Get Target Path")?
-                                .ok_or(anyhow!("Failed to Get Relationship path"))?,
-                            sheet_name,
-                        ));
-                    }
+    /// Get auto filter of the sheet
+    pub fn get_auto_filter(&self) -> Option<AutoFilter> {
+        self.auto_filter.clone()
+    }
+
+    /// Remove auto filter and its sort state from the sheet
+    pub fn remove_auto_filter_mut(&mut self) -> AnyResult<(), AnyError> {
+        self.auto_filter = None;
+        self.set_filter_database_mut(None)
+    }
+
+    /// Protect the sheet with the allowed actions, replace existing protection.
+    /// Only the hash of password get stored
+    pub fn protect_mut(&mut self, protection: SheetProtection) -> AnyResult<(), AnyError> {
+        let hash_attributes = protection
+            .password
+            .as_deref()
+            .filter(|password| !password.is_empty())
+            .map(|password| get_password_hash_attributes(password, ""))
+            .unwrap_or_default();
+        self.protection = Some((
+            SheetProtection {
+                password: None,
+                ..protection
+            },
+            hash_attributes,
+        ));
+        Ok(())
+    }
+
+    /// Get sheet protection, password is never returned
+    pub fn get_protection(&self) -> Option<SheetProtection> {
+        self.protection
+            .as_ref()
+            .map(|(protection, _)| protection.clone())
+    }
+
+    /// Remove the sheet protection, protected ranges are kept
+    pub fn unprotect_mut(&mut self) -> AnyResult<(), AnyError> {
+        self.protection = None;
+        Ok(())
+    }
+
+    /// Add range the user can edit on the protected sheet, name is unique in the sheet
+    pub fn add_protected_range_mut(
+        &mut self,
+        protected_range: ProtectedRange,
+    ) -> AnyResult<(), AnyError> {
+        if protected_range.name.trim().is_empty() {
+            return Err(anyhow!("Protected range name can't be empty"));
+        }
+        let protected_ranges = self.protected_ranges.get_or_insert_with(Vec::new);
+        if protected_ranges.iter().any(|(existing_range, _)| {
+            existing_range
+                .name
+                .eq_ignore_ascii_case(&protected_range.name)
+        }) {
+            return Err(anyhow!(
+                "Protected range already exist : {}",
+                protected_range.name
+            ));
+        }
+        let hash_attributes = protected_range
+            .password
+            .as_deref()
+            .filter(|password| !password.is_empty())
+            .map(|password| get_password_hash_attributes(password, ""))
+            .unwrap_or_default();
+        protected_ranges.push((
+            ProtectedRange {
+                range_ref: get_sq_ref(&protected_range.range_ref)
+                    .context("Failed to parse protected range reference")?,
+                password: None,
+                ..protected_range
+            },
+            hash_attributes,
+        ));
+        Ok(())
+    }
+
+    /// List protected ranges of the sheet, password is never returned
+    pub fn list_protected_ranges(&self) -> Vec<ProtectedRange> {
+        self.protected_ranges
+            .iter()
+            .flatten()
+            .map(|(protected_range, _)| protected_range.clone())
+            .collect()
+    }
+
+    /// Remove protected range by its name
+    pub fn remove_protected_range_mut(&mut self, name: &str) -> AnyResult<(), AnyError> {
+        let protected_ranges = self
+            .protected_ranges
+            .as_mut()
+            .ok_or(anyhow!("Protected range not found : {}", name))?;
+        let position = protected_ranges
+            .iter()
+            .position(|(protected_range, _)| protected_range.name.eq_ignore_ascii_case(name))
+            .ok_or(anyhow!("Protected range not found : {}", name))?;
+        protected_ranges.remove(position);
+        Ok(())
+    }
+
+    /// Set print setting of the sheet, replace existing setting and print titles
+    pub fn set_page_setup_mut(&mut self, mut page_setup: PageSetup) -> AnyResult<(), AnyError> {
+        if !(10..=400).contains(&page_setup.scale) {
+            return Err(anyhow!("Print scale must be between 10 and 400"));
+        }
+        let margins = &page_setup.margins;
+        if [
+            margins.left,
+            margins.right,
+            margins.top,
+            margins.bottom,
+            margins.header,
+            margins.footer,
+        ]
+        .iter()
+        .any(|margin| !margin.is_finite() || *margin < 0.0)
+        {
+            return Err(anyhow!("Page margin must be zero or positive"));
+        }
+        let header_footer = &page_setup.header_footer;
+        if [
+            &header_footer.odd_header,
+            &header_footer.odd_footer,
+            &header_footer.even_header,
+            &header_footer.even_footer,
+            &header_footer.first_header,
+            &header_footer.first_footer,
+        ]
+        .iter()
+        .filter_map(|text| text.get_value())
+        .any(|value| value.chars().count() > 255)
+        {
+            return Err(anyhow!("Header footer text can't exceed 255 characters"));
+        }
+        if page_setup
+            .row_breaks
+            .iter()
+            .any(|row_index| !(1..MAX_ROW_INDEX).contains(row_index))
+        {
+            return Err(anyhow!("Row page break must be between 1 and 1048575"));
+        }
+        if page_setup
+            .column_breaks
+            .iter()
+            .any(|col_index| !(1..MAX_COL_INDEX).contains(col_index))
+        {
+            return Err(anyhow!("Column page break must be between 1 and 16383"));
+        }
+        page_setup.row_breaks.sort();
+        page_setup.row_breaks.dedup();
+        page_setup.column_breaks.sort();
+        page_setup.column_breaks.dedup();
+        let print_titles = DefinedName::get_print_titles_value(
+            &self.sheet_name,
+            page_setup.print_title_rows.take(),
+            page_setup.print_title_columns.take(),
+        )?;
+        self.set_built_in_name_mut(PRINT_TITLES_NAME, print_titles, false)?;
+        self.page_setup = Some(page_setup);
+        Ok(())
+    }
+
+    /// Get print setting of the sheet, default setting when the sheet has none
+    pub fn get_page_setup(&self) -> AnyResult<PageSetup, AnyError> {
+        let mut page_setup = self.page_setup.clone().unwrap_or_default();
+        if let Some(defined_name_collection) = self.defined_name_collection.upgrade() {
+            if let Some(print_titles) = defined_name_collection
+                .try_borrow()
+                .context("Failed to pull Defined Name Collection")?
+                .iter()
+                .find(|defined_name| {
+                    defined_name.name.eq_ignore_ascii_case(PRINT_TITLES_NAME)
+                        && defined_name.sheet_name.as_ref() == Some(&self.sheet_name)
+                })
+            {
+                page_setup.print_title_rows = print_titles.get_print_title_rows();
+                page_setup.print_title_columns = print_titles.get_print_title_columns();
+            }
+        }
+        Ok(page_setup)
+    }
+
+    /// Remove print setting and print titles of the sheet
+    pub fn remove_page_setup_mut(&mut self) -> AnyResult<(), AnyError> {
+        self.page_setup = None;
+        self.set_built_in_name_mut(PRINT_TITLES_NAME, None, false)
+    }
+
+    /// Group the rows one outline level deeper, groups nest up to 7 levels
+    pub fn group_rows_mut(&mut self, start_row: u32, end_row: u32) -> AnyResult<(), AnyError> {
+        validate_outline_range(start_row, end_row, MAX_ROW_INDEX)?;
+        let row_outline = self.get_row_outline();
+        if (start_row..=end_row).any(|row_index| {
+            row_outline
+                .get(&row_index)
+                .is_some_and(|(level, _)| *level >= MAX_OUTLINE_LEVEL)
+        }) {
+            return Err(anyhow!("Outline group can't exceed 7 levels"));
+        }
+        for row_index in start_row..=end_row {
+            let row_properties = self.get_row_properties_mut(row_index)?;
+            row_properties.group_level = Some(row_properties.group_level.unwrap_or(0) + 1);
+        }
+        Ok(())
+    }
+
+    /// Move the rows one outline level up, collapsed group get expanded first
+    pub fn ungroup_rows_mut(&mut self, start_row: u32, end_row: u32) -> AnyResult<(), AnyError> {
+        validate_outline_range(start_row, end_row, MAX_ROW_INDEX)?;
+        let (summary_below, _) = self.get_outline_summary()?;
+        if get_outline_summary_index(start_row, end_row, summary_below, MAX_ROW_INDEX)
+            .and_then(|summary_index| self.get_row_outline().get(&summary_index).copied())
+            .is_some_and(|(_, collapsed)| collapsed)
+        {
+            self.collapse_group_mut(OutlineGroupValues::Rows(start_row, end_row), false)?;
+        }
+        let row_outline = self.get_row_outline();
+        for row_index in start_row..=end_row {
+            if row_outline
+                .get(&row_index)
+                .is_some_and(|(level, _)| *level > 0)
+            {
+                let row_properties = self.get_row_properties_mut(row_index)?;
+                row_properties.group_level = row_properties
+                    .group_level
+                    .map(|level| level - 1)
+                    .filter(|level| *level > 0);
+            }
+        }
+        Ok(())
+    }
+
+    /// Group the columns one outline level deeper, groups nest up to 7 levels
+    pub fn group_columns_mut(&mut self, start_col: u16, end_col: u16) -> AnyResult<(), AnyError> {
+        validate_outline_range(start_col as u32, end_col as u32, MAX_COL_INDEX as u32)?;
+        if self
+            .get_column_outline()
+            .range(start_col as u32..=end_col as u32)
+            .any(|(_, (level, _))| *level >= MAX_OUTLINE_LEVEL)
+        {
+            return Err(anyhow!("Outline group can't exceed 7 levels"));
+        }
+        for range in self.get_column_ranges_mut(start_col, end_col) {
+            range.group_level += 1;
+        }
+        Ok(())
+    }
+
+    /// Move the columns one outline level up, collapsed group get expanded first
+    pub fn ungroup_columns_mut(&mut self, start_col: u16, end_col: u16) -> AnyResult<(), AnyError> {
+        validate_outline_range(start_col as u32, end_col as u32, MAX_COL_INDEX as u32)?;
+        let (_, summary_right) = self.get_outline_summary()?;
+        if get_outline_summary_index(
+            start_col as u32,
+            end_col as u32,
+            summary_right,
+            MAX_COL_INDEX as u32,
+        )
+        .and_then(|summary_index| self.get_column_outline().get(&summary_index).copied())
+        .is_some_and(|(_, collapsed)| collapsed)
+        {
+            self.collapse_group_mut(OutlineGroupValues::Columns(start_col, end_col), false)?;
+        }
+        for range in self.get_column_ranges_mut(start_col, end_col) {
+            range.group_level = range.group_level.saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    /// Collapse or expand the group. Members of collapsed group get hidden, on expand
+    /// members of still collapsed inner group stay hidden
+    pub fn collapse_group_mut(
+        &mut self,
+        outline_group: OutlineGroupValues,
+        collapsed: bool,
+    ) -> AnyResult<(), AnyError> {
+        let (summary_below, summary_right) = self.get_outline_summary()?;
+        match outline_group {
+            OutlineGroupValues::Rows(start_row, end_row) => {
+                validate_outline_range(start_row, end_row, MAX_ROW_INDEX)?;
+                for (row_index, is_hidden) in get_outline_hidden_state(
+                    self.get_row_outline(),
+                    start_row,
+                    end_row,
+                    summary_below,
+                    MAX_ROW_INDEX,
+                    collapsed,
+                )? {
+                    self.get_row_properties_mut(row_index)?.hidden = is_hidden.then_some(true);
                 }
-                let mut sheet_count = sheet_collection
-                    .try_borrow()
-                    .context("Failed to pull Sheet Name Collection")?
-                    .len()
-                    + 1;
-                let relative_path = workbook_relationship_part
-                    .try_borrow_mut()
-                    .context("Failed to pull relationship connection")?
-                    .get_relative_path()
-                    .context("Get Relative Path for Part File")?;
-                if let Some(office_doc) = office_document.upgrade() {
-                    let document = office_doc
-                        .try_borrow()
-                        .context("Failed to Borrow Document")?;
-                    loop {
-                        if document.check_file_exist(format!(
-                            "{}{}/{}{}.{}",
-                            relative_path,
-                            worksheet_content.default_path,
-                            worksheet_content.default_name,
-                            sheet_count,
-                            worksheet_content.extension
-                        )) {
-                            sheet_count += 1;
-                        } else {
-                            break;
+                if let Some(summary_row) =
+                    get_outline_summary_index(start_row, end_row, summary_below, MAX_ROW_INDEX)
+                {
+                    self.get_row_properties_mut(summary_row)?.collapsed = collapsed.then_some(true);
+                }
+            }
+            OutlineGroupValues::Columns(start_col, end_col) => {
+                validate_outline_range(start_col as u32, end_col as u32, MAX_COL_INDEX as u32)?;
+                // Apply the hidden state on runs of columns to keep the ranges merged
+                let mut hidden_runs: Vec<(u16, u16, bool)> = Vec::new();
+                for (col_index, is_hidden) in get_outline_hidden_state(
+                    self.get_column_outline(),
+                    start_col as u32,
+                    end_col as u32,
+                    summary_right,
+                    MAX_COL_INDEX as u32,
+                    collapsed,
+                )? {
+                    match hidden_runs.last_mut() {
+                        Some((_, run_end, run_hidden)) if *run_hidden == is_hidden => {
+                            *run_end = col_index as u16
                         }
+                        _ => hidden_runs.push((col_index as u16, col_index as u16, is_hidden)),
+                    }
+                }
+                for (run_start, run_end, is_hidden) in hidden_runs {
+                    for range in self.get_column_ranges_mut(run_start, run_end) {
+                        range.hidden = is_hidden.then_some(true);
+                    }
+                }
+                if let Some(summary_col) = get_outline_summary_index(
+                    start_col as u32,
+                    end_col as u32,
+                    summary_right,
+                    MAX_COL_INDEX as u32,
+                ) {
+                    for range in self.get_column_ranges_mut(summary_col as u16, summary_col as u16)
+                    {
+                        range.collapsed = collapsed.then_some(true);
                     }
                 }
-                let file_path = format!("{}{}", relative_path, worksheet_content.default_path);
-                let sheet_name = format!(
-                    "{}",
-                    sheet_name.clone().unwrap_or(format!(
-                        "{}{}",
-                        worksheet_content.default_name, &sheet_count
-                    ))
-                );
-                let relationship_id = workbook_relationship_part
-                    .try_borrow_mut()
-                    .context("Failed to Get Relationship Handle")?
-                    .set_new_relationship_mut(
-                        worksheet_content,
-                        Some(file_path.clone()),
-                        Some(format!(
-                            "{}{}",
-                            worksheet_content.default_name, &sheet_count
-                        )),
-                    )
-                    .context("Setting New Calculation Chain Relationship Failed.")?;
-                sheet_collection
-                    .try_borrow_mut()
-                    .context("Failed To pull Sheet Collection Handle")?
-                    .push((sheet_name.clone(), relationship_id, false, false));
-                return Ok((
-                    format!(
-                        "{}/{}{}.{}",
-                        file_path,
-                        worksheet_content.default_name,
-                        &sheet_count,
-                        worksheet_content.extension
-                    ),
-                    sheet_name,
-                ));
             }
         }
-        Err(anyhow!("Failed to upgrade relation part"))
+        Ok(())
     }
-}
-
-// ##################################### Feature Function ################################
-impl WorkSheet {
-    /// Set Active cell of the current sheet
-    pub fn set_active_cell_mut(&mut self, cell_ref: &str, selected_range: Vec<&str>) {}
 
-    /// Set Column property
-    pub fn set_column_ref_properties_mut(
+    /// Place the summary row below the row group and summary column right of the column group.
+    /// Set it before collapsing as collapsed flag is kept on the summary row and column
+    pub fn set_outline_summary_mut(
         &mut self,
-        cell_ref: &str,
-        column_properties: Option<ColumnProperties>,
+        summary_below: bool,
+        summary_right: bool,
     ) -> AnyResult<(), AnyError> {
-        let col_index = ConverterUtil::get_column_index(cell_ref)
-            .context("Failed to Get Column index from reference")?;
-        self.set_column_index_properties_mut(&col_index, column_properties)
+        if let Some(xml_document) = self.xml_document.upgrade() {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("Failed to Pull XML Handle")?;
+            let outline_property = get_sheet_property_child_mut(&mut xml_doc_mut, "outlinePr")?;
+            let mut attributes = outline_property
+                .get_attribute()
+                .cloned()
+                .unwrap_or_default();
+            for (key, is_enabled) in [
+                ("summaryBelow", summary_below),
+                ("summaryRight", summary_right),
+            ] {
+                if is_enabled {
+                    attributes.remove(key);
+                } else {
+                    attributes.insert(key.to_string(), "0".to_string());
+                }
+            }
+            outline_property
+                .set_attribute_mut(attributes)
+                .context("Failed to set outline property attribute")?;
+        }
+        Ok(())
     }
 
-    /// Set Column property
-    pub fn set_column_index_properties_mut(
+    /// Add table to the range like "A1:D10", the first row of range is header row and
+    /// last row is totals row when enabled. Empty columns get named as Column1, Column2...
+    pub fn add_table_mut(
         &mut self,
-        col_index: &u16,
-        column_properties: Option<ColumnProperties>,
+        range_ref: &str,
+        table_name: &str,
+        columns: Vec<TableColumn>,
+        table_setting: TableSetting,
     ) -> AnyResult<(), AnyError> {
-        if let Some(column_collection) = self.column_collection.as_mut() {
-            let mut new_ranges = VecDeque::new();
-            // Delete Old Record
-            column_collection.retain_mut(|range| {
-                if range.min == *col_index && range.max == *col_index {
-                    // Fully matched range, remove it
-                    return false;
-                } else if range.min <= *col_index && *col_index <= range.max {
-                    // Value lies within the range
-                    if range.min == *col_index {
-                        // Trim the start
-                        range.min = col_index + 1;
-                    } else if range.max == *col_index {
-                        // Trim the end
-                        range.max = col_index - 1;
-                    } else {
-                        // Split the range
-                        new_ranges.push_back(ColumnProperties {
-                            min: col_index + 1,
-                            max: range.max,
-                            ..ColumnProperties::default()
-                        });
-                        range.max = col_index - 1;
-                    }
-                }
-                true
-            });
-            if let Some(column_properties) = column_properties {
-                column_collection.push_back(ColumnProperties {
-                    max: *col_index,
-                    min: *col_index,
-                    ..column_properties
-                });
+        let cell_range =
+            ConverterUtil::get_range_index(range_ref).context("Failed to parse table range")?;
+        let ((_, start_col), (_, end_col)) = cell_range;
+        let columns = if columns.is_empty() {
+            (1..=end_col - start_col + 1)
+                .map(|column_number| TableColumn::new(&format!("Column{}", column_number)))
+                .collect()
+        } else {
+            columns
+        };
+        let table = Table {
+            name: table_name.to_string(),
+            range_ref: get_range_ref(&cell_range).context("Failed to get table range")?,
+            columns,
+            setting: table_setting,
+        };
+        validate_table(&table, &cell_range)?;
+        if let Some(style_name) = table.setting.style_name.as_ref() {
+            if !TableSetting::is_built_in_style(style_name) {
+                return Err(anyhow!("Table style {} is not built in style", style_name));
+            }
+        }
+        self.check_table_range(&cell_range, None)?;
+        let table_collection = self
+            .table_collection
+            .upgrade()
+            .ok_or(anyhow!("Failed to pull table collection"))?;
+        if table_collection
+            .try_borrow()
+            .context("Failed to pull table collection")?
+            .iter()
+            .any(|(_, existing_name, _)| existing_name.eq_ignore_ascii_case(table_name))
+        {
+            return Err(anyhow!("Table name already exist : {}", table_name));
+        }
+        // Table name share the name space of defined names
+        if let Some(defined_name_collection) = self.defined_name_collection.upgrade() {
+            if defined_name_collection
+                .try_borrow()
+                .context("Failed to pull Defined Name Collection")?
+                .iter()
+                .any(|defined_name| defined_name.name.eq_ignore_ascii_case(table_name))
+            {
+                return Err(anyhow!("Defined name already exist : {}", table_name));
             }
-            column_collection.append(&mut new_ranges);
         }
+        // Totals row formula is validated against the table collection
+        self.set_table_cells_mut(&cell_range, &table)?;
+        let mut table_collection = table_collection
+            .try_borrow_mut()
+            .context("Failed to pull table collection")?;
+        let table_id = table_collection
+            .iter()
+            .map(|(table_id, _, _)| *table_id)
+            .max()
+            .unwrap_or_default()
+            + 1;
+        let table_content = EXCEL_TYPE_COLLECTION.get("table").unwrap();
+        let table_path = format!(
+            "{}{}",
+            self.workbook_relationship_part
+                .upgrade()
+                .ok_or(anyhow!("Failed to pull workbook relationship part"))?
+                .try_borrow()
+                .context("Failed to pull workbook relationship part")?
+                .get_relative_path()
+                .context("Get Relative Path for Part File")?,
+            table_content.default_path
+        );
+        let mut table_number = 1;
+        let file_path = {
+            let office_document = self
+                .office_document
+                .upgrade()
+                .ok_or(anyhow!("Failed to pull office document"))?;
+            let office_doc = office_document
+                .try_borrow()
+                .context("Failed to Borrow Document")?;
+            loop {
+                let file_path = format!(
+                    "{}/table{}.{}",
+                    table_path, table_number, table_content.extension
+                );
+                if office_doc.check_file_exist(file_path.clone())
+                    || table_collection
+                        .iter()
+                        .any(|(_, _, table_file_path)| *table_file_path == file_path)
+                {
+                    table_number += 1;
+                } else {
+                    break file_path;
+                }
+            }
+        };
+        let relationship_id = self
+            .sheet_relationship_part
+            .try_borrow_mut()
+            .context("Failed to pull sheet relationship part")?
+            .set_new_relationship_mut(
+                table_content,
+                Some(table_path),
+                Some(format!("table{}", table_number)),
+            )
+            .context("Failed to add table relationship")?;
+        let mut table_part =
+            TablePart::new(self.office_document.clone(), &file_path, &relationship_id)
+                .context("Failed to create table part")?;
+        table_collection.push((table_id, table_name.to_string(), file_path));
+        table_part.set_table_mut(table_id, table);
+        self.tables.push(table_part);
         Ok(())
     }
 
-    /// Set/Reset Row property
-    pub fn set_row_index_properties_mut(
+    /// List the tables of the sheet
+    pub fn list_tables(&self) -> Vec<Table> {
+        self.tables
+            .iter()
+            .map(|table_part| table_part.get_table().clone())
+            .collect()
+    }
+
+    /// Update columns and setting of the table, range of the table stays the same
+    pub fn update_table_mut(
         &mut self,
-        row_index: &u32,
-        row_properties: RowProperties,
+        table_name: &str,
+        columns: Vec<TableColumn>,
+        table_setting: TableSetting,
     ) -> AnyResult<(), AnyError> {
-        if let Some(sheet_data) = self.sheet_data.as_mut() {
-            if let Some(row) = sheet_data.get_mut(row_index) {
-                row.row_record = row_properties;
-            } else {
-                sheet_data.insert(
-                    *row_index,
-                    RowData {
-                        row_record: row_properties,
-                        cell_records: None,
-                    },
-                );
+        let position = self
+            .tables
+            .iter()
+            .position(|table_part| table_part.get_table().name.eq_ignore_ascii_case(table_name))
+            .ok_or(anyhow!("Table not found : {}", table_name))?;
+        let current_table = self.tables[position].get_table().clone();
+        let cell_range = ConverterUtil::get_range_index(&current_table.range_ref)
+            .context("Failed to parse table range")?;
+        if let Some(style_name) = table_setting.style_name.as_ref() {
+            // Custom style from loaded file can be kept
+            if !TableSetting::is_built_in_style(style_name)
+                && current_table.setting.style_name.as_ref() != Some(style_name)
+            {
+                return Err(anyhow!("Table style {} is not built in style", style_name));
             }
-        } else {
-            let mut map = BTreeMap::new();
-            map.insert(
-                *row_index,
-                RowData {
-                    row_record: row_properties,
-                    cell_records: None,
-                },
-            );
-            self.sheet_data = Some(map);
+        }
+        let table = Table {
+            columns,
+            setting: table_setting,
+            ..current_table
+        };
+        validate_table(&table, &cell_range)?;
+        self.set_table_cells_mut(&cell_range, &table)?;
+        let table_id = self.tables[position].get_table_id();
+        self.tables[position].set_table_mut(table_id, table);
+        Ok(())
+    }
+
+    /// Remove the table, cell values of the range stay in the sheet
+    pub fn remove_table_mut(&mut self, table_name: &str) -> AnyResult<(), AnyError> {
+        let position = self
+            .tables
+            .iter()
+            .position(|table_part| table_part.get_table().name.eq_ignore_ascii_case(table_name))
+            .ok_or(anyhow!("Table not found : {}", table_name))?;
+        let table_part = self.tables.remove(position);
+        self.delete_table_part_mut(table_part)
+    }
+
+    /// Add note to the cell, replace the existing note of the cell
+    pub fn add_comment_mut(&mut self, cell_ref: &str, comment: Comment) -> AnyResult<(), AnyError> {
+        let cell_key =
+            ConverterUtil::get_cell_index(cell_ref).context("Failed to parse comment cell")?;
+        if comment.author.starts_with(THREADED_COMMENT_AUTHOR_PREFIX) {
+            return Err(anyhow!(
+                "Comment author can't start with {}",
+                THREADED_COMMENT_AUTHOR_PREFIX
+            ));
+        }
+        if self
+            .threaded_comment_part
+            .as_ref()
+            .is_some_and(|part| part.get_threaded_comments().contains_key(&cell_key))
+        {
+            return Err(anyhow!("Cell already has threaded comment : {}", cell_ref));
+        }
+        if comment.width <= 0.0 || comment.height <= 0.0 {
+            return Err(anyhow!("Comment size must be greater than zero"));
+        }
+        self.get_comment_part_mut()?
+            .set_comment_mut(cell_key, comment);
+        Ok(())
+    }
+
+    /// Get note of the cell
+    pub fn get_comment(&self, cell_ref: &str) -> AnyResult<Option<Comment>, AnyError> {
+        let cell_key =
+            ConverterUtil::get_cell_index(cell_ref).context("Failed to parse comment cell")?;
+        Ok(self.get_note(&cell_key).cloned())
+    }
+
+    /// List the notes of the sheet with the cell reference
+    pub fn list_comments(&self) -> AnyResult<Vec<(String, Comment)>, AnyError> {
+        let mut comments = Vec::new();
+        if let Some(comment_part) = self.comment_part.as_ref() {
+            for (cell_key, comment) in comment_part.get_comments() {
+                if !comment.author.starts_with(THREADED_COMMENT_AUTHOR_PREFIX) {
+                    comments.push((get_range_ref(&(*cell_key, *cell_key))?, comment.clone()));
+                }
+            }
+        }
+        Ok(comments)
+    }
+
+    /// Remove note of the cell
+    pub fn remove_comment_mut(&mut self, cell_ref: &str) -> AnyResult<(), AnyError> {
+        let cell_key =
+            ConverterUtil::get_cell_index(cell_ref).context("Failed to parse comment cell")?;
+        if self.get_note(&cell_key).is_none() {
+            return Err(anyhow!("Comment not found : {}", cell_ref));
+        }
+        if let Some(comment_part) = self.comment_part.as_mut() {
+            comment_part.remove_comment_mut(&cell_key);
         }
         Ok(())
     }
 
-    /// Set data for same row multiple columns along with row property
-    pub fn set_row_value_ref_mut(
+    /// Add threaded comment to the cell, replace the existing thread of the cell.
+    /// Read only note of the thread is added for the Excel versions without threaded comments
+    pub fn add_threaded_comment_mut(
         &mut self,
         cell_ref: &str,
-        column_cell: Vec<CellProperties>,
+        threaded_comment: ThreadedComment,
     ) -> AnyResult<(), AnyError> {
-        let (row_index, col_index) =
-            ConverterUtil::get_cell_index(cell_ref).context("Failed to extract cell key")?;
-        self.set_row_value_index_mut(row_index, col_index, column_cell)
+        let cell_key = ConverterUtil::get_cell_index(cell_ref)
+            .context("Failed to parse threaded comment cell")?;
+        if self.get_note(&cell_key).is_some() {
+            return Err(anyhow!("Cell already has comment : {}", cell_ref));
+        }
+        let placeholder_text = get_threaded_comment_placeholder(&threaded_comment);
+        let thread_id = self
+            .get_threaded_comment_part_mut()?
+            .set_threaded_comment_mut(cell_key, threaded_comment);
+        self.get_comment_part_mut()?.set_comment_mut(
+            cell_key,
+            Comment {
+                author: format!("{}{}", THREADED_COMMENT_AUTHOR_PREFIX, thread_id),
+                text: vec![RichTextRun {
+                    text: placeholder_text,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        );
+        Ok(())
     }
 
-    /// Set data for same row multiple columns along with row property
-    pub fn set_row_value_index_mut(
-        &mut self,
-        row_index: u32,
-        mut col_index: u16,
-        mut column_cell: Vec<CellProperties>,
-    ) -> AnyResult<(), AnyError> {
-        // Map Start Normalization
-        col_index -= 1;
-        for cell_data in column_cell.iter_mut() {
-            if let Some(cell_value) = cell_data.value.as_ref() {
-                match cell_data.data_type {
-                    CellDataType::Auto => {
-                        if cell_value.parse::<f64>().is_ok() {
-                            cell_data.data_type = CellDataType::Number;
-                        } else if cell_value.parse::<bool>().is_ok() {
-                            cell_data.data_type = CellDataType::Boolean;
-                            if cell_value.parse::<bool>().context("Parse Fail")? {
-                                cell_data.value = Some("1".to_string());
-                            } else {
-                                cell_data.value = Some("0".to_string());
-                            }
-                        } else {
-                            cell_data.data_type = CellDataType::ShareString;
-                            cell_data.value = Some(self.update_share_string(cell_value)?);
-                        }
-                    }
-                    CellDataType::ShareString => {
-                        cell_data.value = Some(self.update_share_string(cell_value)?);
-                    }
-                    CellDataType::Boolean => {
-                        cell_data.value = match cell_value.to_lowercase().as_str() {
-                            "false" | "0" | "" => Some("0".to_string()),
-                            _ => Some("1".to_string()),
-                        }
-                    }
-                    _ => {}
-                }
-            } else {
-                cell_data.data_type = CellDataType::Number;
+    /// List the threaded comments of the sheet with the cell reference
+    pub fn list_threaded_comments(&self) -> AnyResult<Vec<(String, ThreadedComment)>, AnyError> {
+        let mut threaded_comments = Vec::new();
+        if let Some(threaded_comment_part) = self.threaded_comment_part.as_ref() {
+            for (cell_key, (_, threaded_comment)) in threaded_comment_part.get_threaded_comments() {
+                threaded_comments.push((
+                    get_range_ref(&(*cell_key, *cell_key))?,
+                    threaded_comment.clone(),
+                ));
             }
         }
-        // Load If Sheet Data Exist
-        if let Some(sheet_data) = self.sheet_data.as_mut() {
-            // Load If Row Exits
-            if let Some(row) = sheet_data.get_mut(&row_index) {
-                // Check if the row already has cell data
-                if let Some(cell_records) = row.cell_records.as_mut() {
-                    cell_records.extend(column_cell.iter_mut().map(|item| {
-                        col_index += 1;
-                        self.dimension.start_col = min(self.dimension.start_col, col_index);
-                        self.dimension.end_col = max(self.dimension.end_col, col_index);
-                        (col_index, item.clone())
-                    }));
-                } else {
-                    //Create cells If new
-                    let mut cell_records = BTreeMap::new();
-                    cell_records.extend(column_cell.iter_mut().map(|item| {
-                        col_index += 1;
-                        self.dimension.start_col = min(self.dimension.start_col, col_index);
-                        self.dimension.end_col = max(self.dimension.end_col, col_index);
-                        (col_index, item.clone())
-                    }));
-                    row.cell_records = Some(cell_records);
-                }
-            } else {
-                // Create If new Row
-                let mut cell_records = BTreeMap::new();
-                cell_records.extend(column_cell.iter_mut().map(|item| {
-                    col_index += 1;
-                    self.dimension.start_col = min(self.dimension.start_col, col_index);
-                    self.dimension.end_col = max(self.dimension.end_col, col_index);
-                    (col_index, item.clone())
-                }));
-                sheet_data.insert(
-                    row_index,
-                    RowData {
-                        row_record: RowProperties::default(),
-                        cell_records: Some(cell_records),
-                    },
-                );
-            }
-        } else {
-            // Create Sheet Data
-            let mut sheet_data = BTreeMap::new();
-            let mut cell_records = BTreeMap::new();
-            cell_records.extend(column_cell.iter_mut().map(|item| {
-                col_index += 1;
-                self.dimension.start_col = min(self.dimension.start_col, col_index);
-                self.dimension.end_col = max(self.dimension.end_col, col_index);
-                (col_index, item.clone())
-            }));
-            sheet_data.insert(
-                row_index,
-                RowData {
-                    row_record: RowProperties::default(),
-                    cell_records: Some(cell_records),
-                },
-            );
-            self.sheet_data = Some(sheet_data);
+        Ok(threaded_comments)
+    }
+
+    /// Remove threaded comment of the cell along with its read only note
+    pub fn remove_threaded_comment_mut(&mut self, cell_ref: &str) -> AnyResult<(), AnyError> {
+        let cell_key = ConverterUtil::get_cell_index(cell_ref)
+            .context("Failed to parse threaded comment cell")?;
+        self.threaded_comment_part
+            .as_mut()
+            .and_then(|part| part.remove_threaded_comment_mut(&cell_key))
+            .ok_or(anyhow!("Threaded comment not found : {}", cell_ref))?;
+        if let Some(comment_part) = self.comment_part.as_mut() {
+            comment_part.remove_comment_mut(&cell_key);
         }
         Ok(())
     }
 
-    fn update_share_string(&mut self, cell_value: &String) -> AnyResult<String, AnyError> {
-        if let Some(common_service) = self.common_service.upgrade() {
-            common_service
-                .try_borrow_mut()
-                .context("Failed to Get Share String Handle")?
-                .get_string_id_mut(cell_value.to_owned())
-                .context("Failed to get share string id")
+    /// Add image to the sheet at the anchor. Same image added again share the stored media
+    pub fn add_image_mut(
+        &mut self,
+        image: &[u8],
+        image_format: ImageFormatValues,
+        anchor: DrawingAnchorValues,
+    ) -> AnyResult<(), AnyError> {
+        validate_image(image, &image_format).context("Failed to validate image")?;
+        validate_drawing_anchor(&anchor).context("Failed to validate image anchor")?;
+        let (media, svg_media) = match &image_format {
+            ImageFormatValues::Png => (
+                (image, COMMON_TYPE_COLLECTION.get("image_png").unwrap()),
+                None,
+            ),
+            ImageFormatValues::Jpeg => (
+                (image, COMMON_TYPE_COLLECTION.get("image_jpeg").unwrap()),
+                None,
+            ),
+            ImageFormatValues::Gif => (
+                (image, COMMON_TYPE_COLLECTION.get("image_gif").unwrap()),
+                None,
+            ),
+            ImageFormatValues::Svg { png_fallback } => (
+                (
+                    png_fallback.as_slice(),
+                    COMMON_TYPE_COLLECTION.get("image_png").unwrap(),
+                ),
+                Some((image, COMMON_TYPE_COLLECTION.get("image_svg").unwrap())),
+            ),
+        };
+        let media_file_path = self
+            .add_media_part_mut(media.0, media.1)
+            .context("Failed to add image media")?;
+        let svg_file_path = if let Some((svg_image, svg_content)) = svg_media {
+            Some((
+                self.add_media_part_mut(svg_image, svg_content)
+                    .context("Failed to add svg image media")?,
+                svg_content,
+            ))
         } else {
-            Err(anyhow!("Failed to update Share String Record"))
+            None
+        };
+        self.get_drawing_part_mut()
+            .context("Failed to get sheet drawing")?
+            .add_picture_mut(
+                &anchor,
+                (&media_file_path, media.1),
+                svg_file_path
+                    .as_ref()
+                    .map(|(file_path, content)| (file_path.as_str(), **content)),
+            )
+            .context("Failed to add picture to drawing")
+    }
+
+    /// Add chart to the sheet at the anchor. Series range without the sheet name refer this sheet
+    /// and its cache is filled from the cells of this sheet
+    pub fn add_chart_mut(
+        &mut self,
+        mut chart: ChartTypeValues,
+        anchor: DrawingAnchorValues,
+    ) -> AnyResult<(), AnyError> {
+        chart
+            .get_plot()
+            .validate_chart()
+            .context("Failed to validate chart")?;
+        validate_drawing_anchor(&anchor).context("Failed to validate chart anchor")?;
+        for series in chart.get_plot_mut().get_series_mut() {
+            self.set_chart_series_cache_mut(series)
+                .context("Failed to fill chart series cache")?;
         }
+        let content = COMMON_TYPE_COLLECTION.get("chart").unwrap();
+        let (folder_path, file_name) = self
+            .get_next_part_path(content)
+            .context("Failed to get chart path")?;
+        let chart_file_path = format!("{}/{}.{}", folder_path, file_name, content.extension);
+        let mut chart_part = ChartPart::new(self.office_document.clone(), &chart_file_path)
+            .context("Failed to create chart part")?;
+        chart_part
+            .serialize_chart_mut(&chart)
+            .context("Failed to write chart part")?;
+        chart_part.flush().context("Failed to close chart part")?;
+        self.get_drawing_part_mut()
+            .context("Failed to get sheet drawing")?
+            .add_chart_mut(&anchor, (&chart_file_path, content))
+            .context("Failed to add chart to drawing")
     }
 
-    /// Set Cell Range to merge
-    pub fn set_merge_cell_mut(&mut self) {}
+    /// Set Cell Range to merge like "A1:C2". Range can't overlap existing merged range
+    pub fn set_merge_cell_mut(&mut self, range_ref: &str) -> AnyResult<(), AnyError> {
+        let merge_range =
+            ConverterUtil::get_range_index(range_ref).context("Failed to parse merge range")?;
+        let ((start_row, start_col), (end_row, end_col)) = merge_range;
+        if start_row == end_row && start_col == end_col {
+            return Err(anyhow!(
+                "Merge range must span more than one cell : {}",
+                range_ref
+            ));
+        }
+        let merge_cells = self.merge_cells.get_or_insert_with(Vec::new);
+        if let Some(overlap_range) = merge_cells.iter().find(
+            |((merged_start_row, merged_start_col), (merged_end_row, merged_end_col))| {
+                start_row <= *merged_end_row
+                    && *merged_start_row <= end_row
+                    && start_col <= *merged_end_col
+                    && *merged_start_col <= end_col
+            },
+        ) {
+            return Err(anyhow!(
+                "Merge range {} overlaps existing merged range {}",
+                range_ref,
+                get_range_ref(overlap_range)?
+            ));
+        }
+        merge_cells.push(merge_range);
+        Ok(())
+    }
 
     /// List all Cell Range merged
-    pub fn list_merge_cell_(&mut self) {}
+    pub fn list_merge_cell(&self) -> AnyResult<Vec<String>, AnyError> {
+        self.merge_cells
+            .iter()
+            .flatten()
+            .map(get_range_ref)
+            .collect()
+    }
 
     /// Remove merged cell range
-    pub fn remove_merge_cell_mut(&mut self) {}
+    pub fn remove_merge_cell_mut(&mut self, range_ref: &str) -> AnyResult<(), AnyError> {
+        let merge_range =
+            ConverterUtil::get_range_index(range_ref).context("Failed to parse merge range")?;
+        if let Some(merge_cells) = self.merge_cells.as_mut() {
+            if let Some(position) = merge_cells.iter().position(|item| *item == merge_range) {
+                merge_cells.remove(position);
+                return Ok(());
+            }
+        }
+        Err(anyhow!("Merged range not found : {}", range_ref))
+    }
 
     /// Delete Current sheet and all its components
-    pub fn delete_sheet_mut(self) -> AnyResult<(), AnyError> {
+    pub fn delete_sheet_mut(mut self) -> AnyResult<(), AnyError> {
+        let share_string_count = self.sheet_data.as_ref().map_or(0, |sheet_data| {
+            sheet_data
+                .values()
+                .filter_map(|row| row.cell_records.as_ref())
+                .flat_map(|cell_records| cell_records.values())
+                .filter(|cell_data| matches!(cell_data.data_type, CellDataType::ShareString))
+                .count()
+        });
+        self.release_share_string_mut(share_string_count)
+            .context("Failed to release share string of the sheet")?;
+        while let Some(table_part) = self.tables.pop() {
+            self.delete_table_part_mut(table_part)
+                .context("Failed to delete table of the sheet")?;
+        }
+        // Sheet added later reuse the part path, left over parts would show up in that sheet
+        self.delete_sheet_parts_mut()
+            .context("Failed to delete parts of the sheet")?;
+        if let Some(defined_name_collection) = self.defined_name_collection.upgrade() {
+            let mut defined_name_collection = defined_name_collection
+                .try_borrow_mut()
+                .context("Failed to pull Defined Name Collection")?;
+            // Sheet scoped names go with the sheet, reference from other names turn into #REF!
+            defined_name_collection
+                .retain(|defined_name| defined_name.sheet_name.as_ref() != Some(&self.sheet_name));
+            for defined_name in defined_name_collection.iter_mut() {
+                defined_name.replace_sheet_reference_mut(&self.sheet_name, "#REF!");
+            }
+        }
         if let Some(sheet_collection) = self.sheet_collection.upgrade() {
             sheet_collection
                 .try_borrow_mut()
@@ -1380,3 +6452,423 @@ impl WorkSheet {
         Ok(())
     }
 }
+
+// ############################# Im-Mut Function   ######################################
+impl WorkSheet {
+    /// Get cell content by reference like "B3"
+    pub fn get_cell_value(&self, cell_ref: &str) -> AnyResult<Option<CellRecord>, AnyError> {
+        let (row_index, col_index) =
+            ConverterUtil::get_cell_index(cell_ref).context("Failed to extract cell key")?;
+        self.get_cell_value_index(row_index, col_index)
+    }
+
+    /// Get cell content by row and column index
+    pub fn get_cell_value_index(
+        &self,
+        row_index: u32,
+        col_index: u16,
+    ) -> AnyResult<Option<CellRecord>, AnyError> {
+        validate_cell_index(row_index, col_index)?;
+        if let Some(cell_properties) = self
+            .get_sheet_data()?
+            .and_then(|sheet_data| sheet_data.get(&row_index))
+            .and_then(|row| row.cell_records.as_ref())
+            .and_then(|cell_records| cell_records.get(&col_index))
+        {
+            Ok(Some(
+                self.get_cell_record(row_index, col_index, cell_properties)
+                    .context("Failed to decode cell value")?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get all the cells of the row keyed by column index
+    pub fn get_row(
+        &self,
+        row_index: u32,
+    ) -> AnyResult<Option<BTreeMap<u16, CellRecord>>, AnyError> {
+        validate_cell_index(row_index, 1)?;
+        if let Some(row) = self
+            .get_sheet_data()?
+            .and_then(|sheet_data| sheet_data.get(&row_index))
+        {
+            Ok(Some(
+                self.get_row_record(row_index, row)
+                    .context("Failed to decode row values")?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Iterate all rows in ascending order with cells keyed by column index
+    pub fn iter_rows(
+        &self,
+    ) -> impl Iterator<Item = AnyResult<(u32, BTreeMap<u16, CellRecord>), AnyError>> + '_ {
+        let (sheet_data, sheet_data_error) = match self.get_sheet_data() {
+            Ok(sheet_data) => (sheet_data, None),
+            Err(error) => (None, Some(Err(error))),
+        };
+        sheet_data_error.into_iter().chain(
+            sheet_data
+                .into_iter()
+                .flat_map(|sheet_data| sheet_data.iter())
+                .map(|(row_index, row)| {
+                    Ok((
+                        *row_index,
+                        self.get_row_record(*row_index, row)
+                            .context("Failed to decode row values")?,
+                    ))
+                }),
+        )
+    }
+
+    /// Get the cell grid of range like "A1:D20". Missing cells are returned as empty
+    pub fn get_range(&self, range_ref: &str) -> AnyResult<Vec<Vec<CellRecord>>, AnyError> {
+        let ((start_row, start_col), (end_row, end_col)) =
+            ConverterUtil::get_range_index(range_ref).context("Failed to parse range")?;
+        validate_cell_index(start_row, start_col)?;
+        validate_cell_index(end_row, end_col)?;
+        let sheet_data = self.get_sheet_data()?;
+        let mut range_records = Vec::with_capacity((end_row - start_row + 1) as usize);
+        for row_index in start_row..=end_row {
+            let cell_records = sheet_data
+                .and_then(|sheet_data| sheet_data.get(&row_index))
+                .and_then(|row| row.cell_records.as_ref());
+            let mut row_records = Vec::with_capacity((end_col - start_col + 1) as usize);
+            for col_index in start_col..=end_col {
+                row_records.push(
+                    if let Some(cell_properties) =
+                        cell_records.and_then(|cell_records| cell_records.get(&col_index))
+                    {
+                        self.get_cell_record(row_index, col_index, cell_properties)
+                            .context("Failed to decode cell value")?
+                    } else {
+                        CellRecord {
+                            hyperlink_properties: self.get_cell_hyperlink(row_index, col_index),
+                            ..Default::default()
+                        }
+                    },
+                );
+            }
+            range_records.push(row_records);
+        }
+        Ok(range_records)
+    }
+
+    fn get_sheet_data(&self) -> AnyResult<Option<&BTreeMap<u32, RowData>>, AnyError> {
+        if self.sheet_data_stream.is_some() {
+            Err(anyhow!(
+                "Worksheet is in streaming mode, streamed rows can't be read back"
+            ))
+        } else {
+            Ok(self.sheet_data.as_ref())
+        }
+    }
+
+    fn get_row_record(
+        &self,
+        row_index: u32,
+        row: &RowData,
+    ) -> AnyResult<BTreeMap<u16, CellRecord>, AnyError> {
+        let mut row_record = BTreeMap::new();
+        if let Some(cell_records) = row.cell_records.as_ref() {
+            for (col_index, cell_properties) in cell_records {
+                row_record.insert(
+                    *col_index,
+                    self.get_cell_record(row_index, *col_index, cell_properties)?,
+                );
+            }
+        }
+        Ok(row_record)
+    }
+
+    /// Get hyperlink set on the cell or the range covering the cell
+    fn get_cell_hyperlink(&self, row_index: u32, col_index: u16) -> Option<HyperlinkProperties> {
+        let hyperlinks = self.hyperlinks.as_ref()?;
+        hyperlinks
+            .get(&((row_index, col_index), (row_index, col_index)))
+            .or_else(|| {
+                hyperlinks
+                    .iter()
+                    .find(|(((start_row, start_col), (end_row, end_col)), _)| {
+                        (*start_row..=*end_row).contains(&row_index)
+                            && (*start_col..=*end_col).contains(&col_index)
+                    })
+                    .map(|(_, hyperlink)| hyperlink)
+            })
+            .cloned()
+    }
+
+    /// Decode the stored cell value into typed record
+    fn get_cell_record(
+        &self,
+        row_index: u32,
+        col_index: u16,
+        cell_properties: &CellData,
+    ) -> AnyResult<CellRecord, AnyError> {
+        let value = if let Some(rich_text) = cell_properties.rich_text.as_ref() {
+            CellValue::Rich(rich_text.clone())
+        } else if let Some(value) = cell_properties.value.as_ref() {
+            match cell_properties.data_type {
+                CellDataType::ShareString => {
+                    let string_id = value.parse().context("Failed to parse share string id")?;
+                    self.common_service
+                        .upgrade()
+                        .ok_or(anyhow!("Failed to upgrade common service"))?
+                        .try_borrow()
+                        .context("Failed to Get Share String Handle")?
+                        .get_string_value(string_id)
+                        .ok_or(anyhow!("Share string id {} not found", string_id))?
+                }
+                CellDataType::Boolean => {
+                    CellValue::Bool(ConverterUtil::normalize_bool_property_bool(value))
+                }
+                CellDataType::Error => CellValue::Error(value.to_string()),
+                CellDataType::String | CellDataType::InlineString => {
+                    CellValue::Text(value.to_string())
+                }
+                CellDataType::Number | CellDataType::Auto => {
+                    if value.is_empty() {
+                        CellValue::Empty
+                    } else {
+                        CellValue::Number(value.parse().context("Failed to parse number value")?)
+                    }
+                }
+            }
+        } else {
+            CellValue::Empty
+        };
+        Ok(CellRecord {
+            value,
+            formula: cell_properties.formula.clone(),
+            style_id: cell_properties.style_id.clone(),
+            hyperlink_properties: self.get_cell_hyperlink(row_index, col_index),
+        })
+    }
+
+    /// Check the formula syntax and the sheet, defined name and table it refer exist.
+    /// Formula cells get only the syntax check when set, so the sheets and names can be
+    /// added in any order and checked once the workbook is built
+    pub fn validate_formula_references(&self, formula: &str) -> AnyResult<(), AnyError> {
+        let expression = FormulaParser::parse(formula)?;
+        let mut sheet_names = Vec::new();
+        let mut names = Vec::new();
+        collect_formula_names(&expression, &mut sheet_names, &mut names);
+        if let Some(sheet_collection) = self.sheet_collection.upgrade() {
+            let sheet_collection = sheet_collection
+                .try_borrow()
+                .context("Failed To pull Sheet Collection Handle")?;
+            // External workbook reference like [1]Sheet1 is not checked
+            if let Some(sheet_name) = sheet_names
+                .iter()
+                .filter(|sheet_name| !sheet_name.starts_with('['))
+                .flat_map(|sheet_name| sheet_name.split(':'))
+                .find(|sheet_name| {
+                    !sheet_collection.iter().any(|(existing_name, _, _, _)| {
+                        existing_name.eq_ignore_ascii_case(sheet_name)
+                    })
+                })
+            {
+                return Err(anyhow!("Sheet not found : {}", sheet_name));
+            }
+        }
+        if let (Some(defined_name_collection), Some(table_collection)) = (
+            self.defined_name_collection.upgrade(),
+            self.table_collection.upgrade(),
+        ) {
+            let defined_name_collection = defined_name_collection
+                .try_borrow()
+                .context("Failed to pull Defined Name Collection")?;
+            let table_collection = table_collection
+                .try_borrow()
+                .context("Failed to pull table collection")?;
+            for (sheet_name, name) in names {
+                let scope_sheet_name = sheet_name.as_ref().unwrap_or(&self.sheet_name);
+                let is_defined = name.to_lowercase().starts_with("_xlnm.")
+                    || defined_name_collection.iter().any(|defined_name| {
+                        defined_name.name.eq_ignore_ascii_case(&name)
+                            && defined_name
+                                .sheet_name
+                                .as_ref()
+                                .is_none_or(|defined_sheet| {
+                                    defined_sheet.eq_ignore_ascii_case(scope_sheet_name)
+                                })
+                    })
+                    || (sheet_name.is_none()
+                        && table_collection
+                            .iter()
+                            .any(|(_, table_name, _)| table_name.eq_ignore_ascii_case(&name)));
+                if !is_defined {
+                    return Err(anyhow!("Defined name not found : {}", name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Convert the A1 style formula of the cell like "C3" to R1C1 style
+    pub fn convert_formula_to_r1c1(
+        &self,
+        formula: &str,
+        cell_ref: &str,
+    ) -> AnyResult<String, AnyError> {
+        FormulaParser::convert_to_r1c1(formula, cell_ref)
+    }
+
+    /// Convert the R1C1 style formula of the cell like "C3" to A1 style
+    pub fn convert_formula_to_a1(
+        &self,
+        formula: &str,
+        cell_ref: &str,
+    ) -> AnyResult<String, AnyError> {
+        FormulaParser::convert_to_a1(formula, cell_ref)
+    }
+}
+
+// ############################# Streaming Function ######################################
+impl WorkSheet {
+    /// Switch the sheet into forward only streaming mode.
+    /// Rows are written straight into the document store instead of being held in memory,
+    /// any rows already loaded are written out first. Configure columns and views before streaming.
+    pub fn stream_rows_mut(&mut self) -> AnyResult<StreamingWorksheetWriter<'_>, AnyError> {
+        if self.sheet_data_stream.is_none() {
+            let content_writer = self
+                .office_document
+                .upgrade()
+                .ok_or(anyhow!("Failed to upgrade office document"))?
+                .try_borrow()
+                .context("Failed to pull office document")?
+                .create_content_writer()
+                .context("Failed to create sheet data stream")?;
+            let mut sheet_data_stream = SheetDataStream {
+                content_writer,
+                start_row: None,
+                end_row: None,
+                inline_string: false,
+            };
+            if let Some(sheet_data) = self.sheet_data.take() {
+                for (row_index, db_row) in sheet_data {
+                    Self::serialize_stream_row(&mut sheet_data_stream, row_index, db_row)
+                        .context("Failed to move existing row into stream")?;
+                }
+            }
+            self.sheet_data_stream = Some(sheet_data_stream);
+        }
+        Ok(StreamingWorksheetWriter { worksheet: self })
+    }
+
+    fn append_stream_row_mut(
+        &mut self,
+        row_index: u32,
+        row_properties: RowProperties,
+        mut col_index: u16,
+        column_cell: Vec<CellProperties>,
+    ) -> AnyResult<(), AnyError> {
+        if let Some(end_row) = self
+            .sheet_data_stream
+            .as_ref()
+            .ok_or(anyhow!("Worksheet is not in streaming mode"))?
+            .end_row
+        {
+            if row_index <= end_row {
+                return Err(anyhow!(
+                    "Streaming writer is forward only. Row {} must come after row {}",
+                    row_index,
+                    end_row
+                ));
+            }
+        }
+        validate_cell_properties(&column_cell).context("Failed to validate cell values")?;
+        self.set_cell_hyperlink_mut(row_index, col_index, &column_cell);
+        let inline_string = self
+            .sheet_data_stream
+            .as_ref()
+            .is_some_and(|sheet_data_stream| sheet_data_stream.inline_string);
+        let column_cell = self
+            .normalize_cell_value_mut(column_cell, inline_string)
+            .context("Failed to normalize cell values")?;
+        let cell_records = if !column_cell.is_empty() {
+            let mut cell_records = BTreeMap::new();
+            for cell in column_cell {
+                self.dimension.start_col = min(self.dimension.start_col, col_index);
+                self.dimension.end_col = max(self.dimension.end_col, col_index);
+                cell_records.insert(col_index, cell);
+                col_index += 1;
+            }
+            Some(cell_records)
+        } else {
+            None
+        };
+        Self::serialize_stream_row(
+            self.sheet_data_stream
+                .as_mut()
+                .ok_or(anyhow!("Worksheet is not in streaming mode"))?,
+            row_index,
+            RowData {
+                row_record: row_properties,
+                cell_records,
+            },
+        )
+    }
+}
+
+/// Forward only row writer of the worksheet, created by [`WorkSheet::stream_rows_mut`].
+/// Rows must be appended in ascending row order.
+#[derive(Debug)]
+pub struct StreamingWorksheetWriter<'a> {
+    worksheet: &'a mut WorkSheet,
+}
+
+impl StreamingWorksheetWriter<'_> {
+    /// Write text of the following rows as inline string without share string deduplication.
+    /// Suits write once output with mostly unique text, explicit share string type is kept
+    pub fn set_inline_string_mut(&mut self, inline_string: bool) -> AnyResult<(), AnyError> {
+        self.worksheet
+            .sheet_data_stream
+            .as_mut()
+            .ok_or(anyhow!("Worksheet is not in streaming mode"))?
+            .inline_string = inline_string;
+        Ok(())
+    }
+
+    /// Append data for same row multiple columns
+    pub fn append_row_value_ref_mut(
+        &mut self,
+        cell_ref: &str,
+        column_cell: Vec<CellProperties>,
+    ) -> AnyResult<(), AnyError> {
+        let (row_index, col_index) =
+            ConverterUtil::get_cell_index(cell_ref).context("Failed to extract cell key")?;
+        self.append_row_value_index_mut(row_index, col_index, column_cell)
+    }
+
+    /// Append data for same row multiple columns
+    pub fn append_row_value_index_mut(
+        &mut self,
+        row_index: u32,
+        col_index: u16,
+        column_cell: Vec<CellProperties>,
+    ) -> AnyResult<(), AnyError> {
+        self.worksheet.append_stream_row_mut(
+            row_index,
+            RowProperties::default(),
+            col_index,
+            column_cell,
+        )
+    }
+
+    /// Append data for same row multiple columns along with row property
+    pub fn append_row_mut(
+        &mut self,
+        row_index: u32,
+        row_properties: RowProperties,
+        col_index: u16,
+        column_cell: Vec<CellProperties>,
+    ) -> AnyResult<(), AnyError> {
+        self.worksheet
+            .append_stream_row_mut(row_index, row_properties, col_index, column_cell)
+    }
+}
@@ -1,6 +1,10 @@
 use crate::global_2007::traits::XmlDocumentPartCommon;
-use crate::spreadsheet_2007::models::{StyleId, StyleSetting};
-use crate::spreadsheet_2007::services::{CalculationChainPart, ShareStringPart, StylePart};
+use crate::spreadsheet_2007::models::{
+    CellValue, DifferentialStyleSetting, NumberFormatValues, RichTextRun, StyleId, StyleSetting,
+};
+use crate::spreadsheet_2007::services::{
+    CalculationChainPart, PersonPart, ShareStringPart, StylePart,
+};
 use anyhow::{Context, Error as AnyError, Result as AnyResult};
 
 #[derive(Debug)]
@@ -8,6 +12,7 @@ pub(crate) struct CommonServices {
     calculation_chain: CalculationChainPart,
     share_string: ShareStringPart,
     style: StylePart,
+    person: PersonPart,
 }
 
 impl CommonServices {
@@ -15,11 +20,13 @@ impl CommonServices {
         calculation_chain: CalculationChainPart,
         share_string: ShareStringPart,
         style: StylePart,
+        person: PersonPart,
     ) -> Self {
         Self {
             calculation_chain,
             share_string,
             style,
+            person,
         }
     }
     pub(crate) fn close_service(&mut self) -> AnyResult<(), AnyError> {
@@ -32,6 +39,9 @@ impl CommonServices {
         self.style
             .close_document()
             .context("Common Style Chain Close Failed")?;
+        self.person
+            .close_document()
+            .context("Common Person Close Failed")?;
         Ok(())
     }
 }
@@ -41,6 +51,22 @@ impl CommonServices {
     pub(crate) fn get_string_id_mut(&mut self, value: String) -> AnyResult<String, AnyError> {
         self.share_string.get_string_id_mut(value)
     }
+
+    pub(crate) fn get_rich_string_id_mut(
+        &mut self,
+        rich_text: Vec<RichTextRun>,
+    ) -> AnyResult<String, AnyError> {
+        self.share_string.get_rich_string_id_mut(rich_text)
+    }
+
+    pub(crate) fn release_string_reference_mut(&mut self, reference_count: usize) {
+        self.share_string
+            .release_string_reference_mut(reference_count)
+    }
+
+    pub(crate) fn get_string_value(&self, string_id: usize) -> Option<CellValue> {
+        self.share_string.get_string_value(string_id)
+    }
 }
 
 // ########################### Style ########################
@@ -51,4 +77,44 @@ impl CommonServices {
     ) -> AnyResult<StyleId, AnyError> {
         self.style.get_style_id_mut(style_setting)
     }
+
+    pub(crate) fn get_dxf_id_mut(
+        &mut self,
+        differential_style: DifferentialStyleSetting,
+    ) -> AnyResult<u32, AnyError> {
+        self.style.get_dxf_id_mut(differential_style)
+    }
+
+    pub(crate) fn get_differential_style(
+        &self,
+        dxf_id: u32,
+    ) -> AnyResult<DifferentialStyleSetting, AnyError> {
+        self.style.get_differential_style(dxf_id)
+    }
+
+    /// Style with builtin date format used for date cells without style
+    pub(crate) fn get_date_style_id_mut(
+        &mut self,
+        with_time: bool,
+    ) -> AnyResult<StyleId, AnyError> {
+        self.style.get_style_id_mut(StyleSetting {
+            number_format: if with_time {
+                NumberFormatValues::DateTimeMMDDYY
+            } else {
+                NumberFormatValues::DateMMDDYY
+            },
+            ..Default::default()
+        })
+    }
+}
+
+// ########################### Person ########################
+impl CommonServices {
+    pub(crate) fn get_person_id_mut(&mut self, display_name: &str) -> String {
+        self.person.get_person_id_mut(display_name)
+    }
+
+    pub(crate) fn get_person_name(&self, person_id: &str) -> Option<String> {
+        self.person.get_person_name(person_id)
+    }
 }
@@ -0,0 +1,1016 @@
+use crate::{
+    converters::ConverterUtil,
+    spreadsheet_2007::{
+        models::{CellValue, DefinedName, FormulaCalculationReport},
+        services::formula::{
+            call_function, FormulaExpressionValues, FormulaOperatorValues, FormulaParser,
+            FormulaReference,
+        },
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Display, Formatter},
+};
+
+pub(crate) const ERROR_NULL: &str = "#NULL!";
+pub(crate) const ERROR_DIVIDE_BY_ZERO: &str = "#DIV/0!";
+pub(crate) const ERROR_VALUE: &str = "#VALUE!";
+pub(crate) const ERROR_REFERENCE: &str = "#REF!";
+pub(crate) const ERROR_NAME: &str = "#NAME?";
+pub(crate) const ERROR_NUMBER: &str = "#NUM!";
+pub(crate) const ERROR_NOT_AVAILABLE: &str = "#N/A";
+/// Deepest defined name chain followed while calculating
+const MAX_NAME_DEPTH: usize = 32;
+/// Largest range read into the memory while calculating
+const MAX_RANGE_CELLS: usize = 10_000_000;
+/// Longest text excel keep in the cell
+pub(crate) const MAX_TEXT_LENGTH: usize = 32_767;
+
+/// Sheet index, row and column of the cell
+pub(crate) type FormulaCellKey = (usize, u32, u16);
+/// ((start row, start col), (end row, end col)) of the range
+pub(crate) type FormulaRange = ((u32, u16), (u32, u16));
+
+/// Excel error value raised while calculating like #DIV/0!
+#[derive(Debug)]
+pub(crate) struct FormulaError(String);
+
+impl Display for FormulaError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for FormulaError {}
+
+/// Error value result of the formula, other errors mean the formula can't be calculated
+pub(crate) fn formula_error(error_code: &str) -> AnyError {
+    AnyError::new(FormulaError(error_code.to_string()))
+}
+
+/// Excel error code of the calculation error, None when the formula can't be calculated
+pub(crate) fn get_formula_error(error: &AnyError) -> Option<String> {
+    error
+        .downcast_ref::<FormulaError>()
+        .map(|formula_error| formula_error.0.clone())
+}
+
+/// Value used while calculating, range and array constant are kept as rows
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FormulaValue {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(String),
+    Empty,
+    Array(Vec<Vec<FormulaValue>>),
+}
+
+impl FormulaValue {
+    fn from_cell_value(cell_value: &CellValue) -> Self {
+        match cell_value {
+            CellValue::Number(number) => FormulaValue::Number(*number),
+            CellValue::Text(text) => FormulaValue::Text(text.clone()),
+            CellValue::Bool(value) => FormulaValue::Bool(*value),
+            CellValue::Error(error_code) => FormulaValue::Error(error_code.clone()),
+            CellValue::DateTime(date_time) => ConverterUtil::get_excel_serial_date(date_time)
+                .map_or(
+                    FormulaValue::Error(ERROR_NUMBER.to_string()),
+                    FormulaValue::Number,
+                ),
+            CellValue::Rich(rich_text) => {
+                FormulaValue::Text(rich_text.iter().map(|run| run.text.as_str()).collect())
+            }
+            CellValue::Empty => FormulaValue::Empty,
+        }
+    }
+
+    /// Top left value of the array, value as is otherwise
+    pub(crate) fn get_scalar(self) -> FormulaValue {
+        match self {
+            FormulaValue::Array(rows) => rows
+                .into_iter()
+                .next()
+                .and_then(|row| row.into_iter().next())
+                .unwrap_or(FormulaValue::Empty),
+            value => value,
+        }
+    }
+
+    /// Rows of the array, single value become one cell grid
+    pub(crate) fn into_rows(self) -> Vec<Vec<FormulaValue>> {
+        match self {
+            FormulaValue::Array(rows) => rows,
+            value => vec![vec![value]],
+        }
+    }
+
+    pub(crate) fn to_number(&self) -> AnyResult<f64, AnyError> {
+        match self {
+            FormulaValue::Number(number) => Ok(*number),
+            FormulaValue::Bool(value) => Ok(if *value { 1.0 } else { 0.0 }),
+            FormulaValue::Empty => Ok(0.0),
+            FormulaValue::Text(text) => {
+                parse_number_text(text).ok_or_else(|| formula_error(ERROR_VALUE))
+            }
+            FormulaValue::Error(error_code) => Err(formula_error(error_code)),
+            FormulaValue::Array(_) => self.clone().get_scalar().to_number(),
+        }
+    }
+
+    pub(crate) fn to_text(&self) -> AnyResult<String, AnyError> {
+        match self {
+            FormulaValue::Number(number) => Ok(get_general_text(*number)),
+            FormulaValue::Bool(value) => Ok(if *value { "TRUE" } else { "FALSE" }.to_string()),
+            FormulaValue::Empty => Ok(String::new()),
+            FormulaValue::Text(text) => Ok(text.clone()),
+            FormulaValue::Error(error_code) => Err(formula_error(error_code)),
+            FormulaValue::Array(_) => self.clone().get_scalar().to_text(),
+        }
+    }
+
+    pub(crate) fn to_bool(&self) -> AnyResult<bool, AnyError> {
+        match self {
+            FormulaValue::Number(number) => Ok(*number != 0.0),
+            FormulaValue::Bool(value) => Ok(*value),
+            FormulaValue::Empty => Ok(false),
+            FormulaValue::Text(text) => match text.to_uppercase().as_str() {
+                "TRUE" => Ok(true),
+                "FALSE" => Ok(false),
+                _ => Err(formula_error(ERROR_VALUE)),
+            },
+            FormulaValue::Error(error_code) => Err(formula_error(error_code)),
+            FormulaValue::Array(_) => self.clone().get_scalar().to_bool(),
+        }
+    }
+}
+
+/// Number of the text like " 12.5 " or "50%"
+pub(crate) fn parse_number_text(text: &str) -> Option<f64> {
+    let text = text.trim();
+    if let Some(percent_text) = text.strip_suffix('%') {
+        return percent_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .map(|number| number / 100.0);
+    }
+    text.parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+/// Excel keeps 15 significant digits of the number
+pub(crate) fn round_significant(number: f64) -> f64 {
+    if number == 0.0 || !number.is_finite() {
+        return number;
+    }
+    format!("{:.14e}", number).parse().unwrap_or(number)
+}
+
+/// Text of the number in general format like 0.5, 12 or 1E+20
+pub(crate) fn get_general_text(number: f64) -> String {
+    let number = round_significant(number);
+    if number != 0.0 && (number.abs() >= 1e15 || number.abs() < 1e-9) {
+        let scientific_text = format!("{:e}", number);
+        let (mantissa, exponent) = scientific_text
+            .split_once('e')
+            .unwrap_or((&scientific_text, "0"));
+        let exponent: i32 = exponent.parse().unwrap_or_default();
+        format!(
+            "{}E{}{:02}",
+            mantissa,
+            if exponent < 0 { "-" } else { "+" },
+            exponent.abs()
+        )
+    } else {
+        number.to_string()
+    }
+}
+
+/// Excel sort order, number before text before bool. Empty take the default of the other side
+pub(crate) fn compare_values(left: &FormulaValue, right: &FormulaValue) -> Ordering {
+    fn get_empty_default(other: &FormulaValue) -> FormulaValue {
+        match other {
+            FormulaValue::Text(_) => FormulaValue::Text(String::new()),
+            FormulaValue::Bool(_) => FormulaValue::Bool(false),
+            _ => FormulaValue::Number(0.0),
+        }
+    }
+    fn get_type_rank(value: &FormulaValue) -> u8 {
+        match value {
+            FormulaValue::Number(_) | FormulaValue::Empty => 0,
+            FormulaValue::Text(_) => 1,
+            FormulaValue::Bool(_) => 2,
+            _ => 3,
+        }
+    }
+    let left = match left {
+        FormulaValue::Empty => get_empty_default(right),
+        value => value.clone(),
+    };
+    let right = match right {
+        FormulaValue::Empty => get_empty_default(&left),
+        value => value.clone(),
+    };
+    match (&left, &right) {
+        (FormulaValue::Number(left), FormulaValue::Number(right)) => {
+            left.partial_cmp(right).unwrap_or(Ordering::Equal)
+        }
+        (FormulaValue::Text(left), FormulaValue::Text(right)) => {
+            left.to_lowercase().cmp(&right.to_lowercase())
+        }
+        (FormulaValue::Bool(left), FormulaValue::Bool(right)) => left.cmp(right),
+        _ => get_type_rank(&left).cmp(&get_type_rank(&right)),
+    }
+}
+
+/// Number result, infinite and NaN become #NUM!
+pub(crate) fn get_number_result(number: f64) -> AnyResult<FormulaValue, AnyError> {
+    if number.is_finite() {
+        Ok(FormulaValue::Number(number))
+    } else {
+        Err(formula_error(ERROR_NUMBER))
+    }
+}
+
+/// Text result, text longer than excel keep in the cell become #VALUE!
+pub(crate) fn get_text_result(text: String) -> AnyResult<FormulaValue, AnyError> {
+    if text.chars().count() > MAX_TEXT_LENGTH {
+        Err(formula_error(ERROR_VALUE))
+    } else {
+        Ok(FormulaValue::Text(text))
+    }
+}
+
+/// Error value of the cell in place of the raised error, other errors pass through
+pub(crate) fn catch_formula_error(
+    result: AnyResult<FormulaValue, AnyError>,
+) -> AnyResult<FormulaValue, AnyError> {
+    match result {
+        Err(error) => match get_formula_error(&error) {
+            Some(error_code) => Ok(FormulaValue::Error(error_code)),
+            None => Err(error),
+        },
+        value => value,
+    }
+}
+
+/// Apply the operation on each cell when any side is array, single value is used for all cells
+fn apply_element_wise(
+    left: FormulaValue,
+    right: FormulaValue,
+    operation: impl Fn(FormulaValue, FormulaValue) -> AnyResult<FormulaValue, AnyError>,
+) -> AnyResult<FormulaValue, AnyError> {
+    if !matches!(left, FormulaValue::Array(_)) && !matches!(right, FormulaValue::Array(_)) {
+        return operation(left, right);
+    }
+    fn get_cell(rows: &[Vec<FormulaValue>], row_index: usize, col_index: usize) -> FormulaValue {
+        let row = if rows.len() == 1 {
+            rows.first()
+        } else {
+            rows.get(row_index)
+        };
+        row.and_then(|row| {
+            if row.len() == 1 {
+                row.first()
+            } else {
+                row.get(col_index)
+            }
+        })
+        .cloned()
+        .unwrap_or(FormulaValue::Error(ERROR_NOT_AVAILABLE.to_string()))
+    }
+    let left_rows = left.into_rows();
+    let right_rows = right.into_rows();
+    let row_count = left_rows.len().max(right_rows.len());
+    let col_count = left_rows
+        .iter()
+        .chain(right_rows.iter())
+        .map(|row| row.len())
+        .max()
+        .unwrap_or_default();
+    let mut rows = Vec::with_capacity(row_count);
+    for row_index in 0..row_count {
+        let mut row = Vec::with_capacity(col_count);
+        for col_index in 0..col_count {
+            row.push(catch_formula_error(operation(
+                get_cell(&left_rows, row_index, col_index),
+                get_cell(&right_rows, row_index, col_index),
+            ))?);
+        }
+        rows.push(row);
+    }
+    Ok(FormulaValue::Array(rows))
+}
+
+fn apply_binary_operator(
+    operator: &FormulaOperatorValues,
+    left: FormulaValue,
+    right: FormulaValue,
+) -> AnyResult<FormulaValue, AnyError> {
+    match operator {
+        FormulaOperatorValues::Concatenate => {
+            get_text_result(format!("{}{}", left.to_text()?, right.to_text()?))
+        }
+        FormulaOperatorValues::Equal
+        | FormulaOperatorValues::NotEqual
+        | FormulaOperatorValues::LessThan
+        | FormulaOperatorValues::LessThanOrEqual
+        | FormulaOperatorValues::GreaterThan
+        | FormulaOperatorValues::GreaterThanOrEqual => {
+            for value in [&left, &right] {
+                if let FormulaValue::Error(error_code) = value {
+                    return Err(formula_error(error_code));
+                }
+            }
+            let ordering = compare_values(&left, &right);
+            Ok(FormulaValue::Bool(match operator {
+                FormulaOperatorValues::Equal => ordering == Ordering::Equal,
+                FormulaOperatorValues::NotEqual => ordering != Ordering::Equal,
+                FormulaOperatorValues::LessThan => ordering == Ordering::Less,
+                FormulaOperatorValues::LessThanOrEqual => ordering != Ordering::Greater,
+                FormulaOperatorValues::GreaterThan => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            }))
+        }
+        _ => {
+            let left = left.to_number()?;
+            let right = right.to_number()?;
+            match operator {
+                FormulaOperatorValues::Subtract => get_number_result(left - right),
+                FormulaOperatorValues::Multiply => get_number_result(left * right),
+                FormulaOperatorValues::Divide => {
+                    if right == 0.0 {
+                        Err(formula_error(ERROR_DIVIDE_BY_ZERO))
+                    } else {
+                        get_number_result(left / right)
+                    }
+                }
+                FormulaOperatorValues::Power => {
+                    if left == 0.0 && right < 0.0 {
+                        Err(formula_error(ERROR_DIVIDE_BY_ZERO))
+                    } else {
+                        get_number_result(left.powf(right))
+                    }
+                }
+                _ => get_number_result(left + right),
+            }
+        }
+    }
+}
+
+fn apply_unary_operator(
+    operator: &FormulaOperatorValues,
+    operand: FormulaValue,
+) -> AnyResult<FormulaValue, AnyError> {
+    match operator {
+        FormulaOperatorValues::Subtract => get_number_result(-operand.to_number()?),
+        FormulaOperatorValues::Percent => get_number_result(operand.to_number()? / 100.0),
+        // Plus sign keep the value as is
+        _ => Ok(operand),
+    }
+}
+
+/// Cell being calculated, functions read the workbook through it
+pub(crate) struct FormulaContext<'a> {
+    calculator: &'a FormulaCalculator,
+    pub(crate) sheet_index: usize,
+    pub(crate) row_index: u32,
+    pub(crate) col_index: u16,
+    name_depth: usize,
+}
+
+impl FormulaContext<'_> {
+    pub(crate) fn evaluate(
+        &self,
+        expression: &FormulaExpressionValues,
+    ) -> AnyResult<FormulaValue, AnyError> {
+        match expression {
+            FormulaExpressionValues::Number(number) => Ok(FormulaValue::Number(*number)),
+            FormulaExpressionValues::Text(text) => Ok(FormulaValue::Text(text.clone())),
+            FormulaExpressionValues::Bool(value) => Ok(FormulaValue::Bool(*value)),
+            FormulaExpressionValues::Error(error_code) => {
+                Ok(FormulaValue::Error(error_code.clone()))
+            }
+            FormulaExpressionValues::Empty => Ok(FormulaValue::Empty),
+            FormulaExpressionValues::Reference(reference) => {
+                let (sheet_index, range) = self
+                    .calculator
+                    .resolve_reference(reference, self.sheet_index)?;
+                self.calculator.get_range_value(sheet_index, range)
+            }
+            FormulaExpressionValues::Name { sheet_name, name } => {
+                let (name_context, expression) =
+                    self.get_defined_name(sheet_name.as_deref(), name)?;
+                name_context.evaluate(&expression)
+            }
+            FormulaExpressionValues::StructuredReference(reference) => Err(anyhow!(
+                "Structured reference {} is not supported",
+                reference
+            )),
+            FormulaExpressionValues::Function { name, arguments } => {
+                call_function(self, name, arguments)
+            }
+            FormulaExpressionValues::Unary {
+                operator: FormulaOperatorValues::Spill,
+                ..
+            } => Err(anyhow!("Spilled range reference is not supported")),
+            FormulaExpressionValues::Unary {
+                operator: FormulaOperatorValues::ImplicitIntersection,
+                operand,
+            } => match self.get_reference_range(operand)? {
+                Some((sheet_index, ((start_row, start_col), (end_row, end_col)))) => {
+                    // Multi cell range give the cell on the row and column of the formula cell
+                    let row_index = if start_row == end_row {
+                        start_row
+                    } else if (start_row..=end_row).contains(&self.row_index) {
+                        self.row_index
+                    } else {
+                        return Err(formula_error(ERROR_VALUE));
+                    };
+                    let col_index = if start_col == end_col {
+                        start_col
+                    } else if (start_col..=end_col).contains(&self.col_index) {
+                        self.col_index
+                    } else {
+                        return Err(formula_error(ERROR_VALUE));
+                    };
+                    self.get_range_value(
+                        sheet_index,
+                        ((row_index, col_index), (row_index, col_index)),
+                    )
+                }
+                None => self.evaluate_scalar(operand),
+            },
+            FormulaExpressionValues::Unary { operator, operand } => {
+                match self.evaluate(operand)? {
+                    FormulaValue::Array(rows) => Ok(FormulaValue::Array(
+                        rows.into_iter()
+                            .map(|row| {
+                                row.into_iter()
+                                    .map(|value| {
+                                        catch_formula_error(apply_unary_operator(operator, value))
+                                    })
+                                    .collect::<AnyResult<Vec<_>, AnyError>>()
+                            })
+                            .collect::<AnyResult<Vec<_>, AnyError>>()?,
+                    )),
+                    value => apply_unary_operator(operator, value),
+                }
+            }
+            FormulaExpressionValues::Binary {
+                operator: FormulaOperatorValues::Range | FormulaOperatorValues::Intersection,
+                ..
+            } => {
+                let (sheet_index, range) = self
+                    .get_reference_range(expression)?
+                    .ok_or_else(|| formula_error(ERROR_VALUE))?;
+                self.calculator.get_range_value(sheet_index, range)
+            }
+            FormulaExpressionValues::Binary {
+                operator: FormulaOperatorValues::Union,
+                ..
+            } => Err(anyhow!("Union reference is not supported")),
+            FormulaExpressionValues::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                apply_element_wise(left, right, |left, right| {
+                    apply_binary_operator(operator, left, right)
+                })
+            }
+            FormulaExpressionValues::Array(rows) => Ok(FormulaValue::Array(
+                rows.iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|item| self.evaluate(item))
+                            .collect::<AnyResult<Vec<_>, AnyError>>()
+                    })
+                    .collect::<AnyResult<Vec<_>, AnyError>>()?,
+            )),
+        }
+    }
+
+    /// Single value of the expression, range give the top left cell
+    pub(crate) fn evaluate_scalar(
+        &self,
+        expression: &FormulaExpressionValues,
+    ) -> AnyResult<FormulaValue, AnyError> {
+        Ok(self.evaluate(expression)?.get_scalar())
+    }
+
+    pub(crate) fn get_number(
+        &self,
+        expression: &FormulaExpressionValues,
+    ) -> AnyResult<f64, AnyError> {
+        self.evaluate_scalar(expression)?.to_number()
+    }
+
+    pub(crate) fn get_text(
+        &self,
+        expression: &FormulaExpressionValues,
+    ) -> AnyResult<String, AnyError> {
+        self.evaluate_scalar(expression)?.to_text()
+    }
+
+    pub(crate) fn get_bool(
+        &self,
+        expression: &FormulaExpressionValues,
+    ) -> AnyResult<bool, AnyError> {
+        self.evaluate_scalar(expression)?.to_bool()
+    }
+
+    /// Sheet index and range of the reference expression, None for other expressions
+    pub(crate) fn get_reference_range(
+        &self,
+        expression: &FormulaExpressionValues,
+    ) -> AnyResult<Option<(usize, FormulaRange)>, AnyError> {
+        match expression {
+            FormulaExpressionValues::Reference(reference) => Ok(Some(
+                self.calculator
+                    .resolve_reference(reference, self.sheet_index)?,
+            )),
+            FormulaExpressionValues::Name { sheet_name, name } => {
+                let (name_context, expression) =
+                    self.get_defined_name(sheet_name.as_deref(), name)?;
+                name_context.get_reference_range(&expression)
+            }
+            FormulaExpressionValues::Binary {
+                operator:
+                    operator @ (FormulaOperatorValues::Range | FormulaOperatorValues::Intersection),
+                left,
+                right,
+            } => {
+                let (Some((left_sheet, left_range)), Some((right_sheet, right_range))) = (
+                    self.get_reference_range(left)?,
+                    self.get_reference_range(right)?,
+                ) else {
+                    return Err(anyhow!(
+                        "{} operator of the function result is not supported",
+                        if *operator == FormulaOperatorValues::Range {
+                            "Range"
+                        } else {
+                            "Intersection"
+                        }
+                    ));
+                };
+                if left_sheet != right_sheet {
+                    return Err(formula_error(ERROR_VALUE));
+                }
+                if *operator == FormulaOperatorValues::Range {
+                    return Ok(Some((
+                        left_sheet,
+                        get_bounding_range(left_range, right_range),
+                    )));
+                }
+                let ((left_start_row, left_start_col), (left_end_row, left_end_col)) = left_range;
+                let ((right_start_row, right_start_col), (right_end_row, right_end_col)) =
+                    right_range;
+                let start = (
+                    left_start_row.max(right_start_row),
+                    left_start_col.max(right_start_col),
+                );
+                let end = (
+                    left_end_row.min(right_end_row),
+                    left_end_col.min(right_end_col),
+                );
+                // Ranges without common cell give the null error
+                if start.0 > end.0 || start.1 > end.1 {
+                    Err(formula_error(ERROR_NULL))
+                } else {
+                    Ok(Some((left_sheet, (start, end))))
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) fn get_range_value(
+        &self,
+        sheet_index: usize,
+        range: FormulaRange,
+    ) -> AnyResult<FormulaValue, AnyError> {
+        self.calculator.get_range_value(sheet_index, range)
+    }
+
+    /// Parsed value of the defined name along with the context to evaluate it
+    fn get_defined_name(
+        &self,
+        sheet_name: Option<&str>,
+        name: &str,
+    ) -> AnyResult<(FormulaContext<'_>, FormulaExpressionValues), AnyError> {
+        if self.name_depth >= MAX_NAME_DEPTH {
+            return Err(anyhow!("Defined name {} refer too deep", name));
+        }
+        let defined_name = self
+            .calculator
+            .find_defined_name(sheet_name, name, self.sheet_index)
+            .ok_or_else(|| formula_error(ERROR_NAME))?;
+        let expression = FormulaParser::parse(&defined_name.value)
+            .context(format!("Failed to parse defined name {}", name))?;
+        Ok((
+            FormulaContext {
+                calculator: self.calculator,
+                sheet_index: self.sheet_index,
+                row_index: self.row_index,
+                col_index: self.col_index,
+                name_depth: self.name_depth + 1,
+            },
+            expression,
+        ))
+    }
+}
+
+/// Cell values and formulas of the workbook, calculate the formula cells in dependency order
+#[derive(Debug)]
+pub(crate) struct FormulaCalculator {
+    sheet_names: Vec<String>,
+    defined_names: Vec<DefinedName>,
+    /// Value of the cells per sheet keyed by (row, col)
+    cell_values: Vec<BTreeMap<(u32, u16), FormulaValue>>,
+    /// Last used (row, col) of the sheet, used for full row and column reference
+    sheet_bounds: Vec<(u32, u16)>,
+    formulas: BTreeMap<FormulaCellKey, String>,
+    /// Formula cells without calculated value
+    pending_cells: HashSet<FormulaCellKey>,
+}
+
+impl FormulaCalculator {
+    pub(crate) fn new(sheet_names: Vec<String>, defined_names: Vec<DefinedName>) -> Self {
+        Self {
+            cell_values: vec![BTreeMap::new(); sheet_names.len()],
+            sheet_bounds: vec![(1, 1); sheet_names.len()],
+            sheet_names,
+            defined_names,
+            formulas: BTreeMap::new(),
+            pending_cells: HashSet::new(),
+        }
+    }
+
+    pub(crate) fn add_cell_mut(
+        &mut self,
+        sheet_index: usize,
+        row_index: u32,
+        col_index: u16,
+        cell_value: &CellValue,
+        formula: Option<&str>,
+    ) {
+        if let Some(cell_values) = self.cell_values.get_mut(sheet_index) {
+            cell_values.insert(
+                (row_index, col_index),
+                FormulaValue::from_cell_value(cell_value),
+            );
+            let (max_row, max_col) = &mut self.sheet_bounds[sheet_index];
+            *max_row = (*max_row).max(row_index);
+            *max_col = (*max_col).max(col_index);
+            // Shared formula children have no text of their own and keep the cached value
+            if let Some(formula) = formula.filter(|formula| !formula.trim().is_empty()) {
+                self.formulas
+                    .insert((sheet_index, row_index, col_index), formula.to_string());
+            }
+        }
+    }
+
+    /// Calculate all the formula cells and return the value of the calculated cells
+    pub(crate) fn calculate_mut(
+        &mut self,
+    ) -> AnyResult<(Vec<(FormulaCellKey, CellValue)>, FormulaCalculationReport), AnyError> {
+        let mut report = FormulaCalculationReport::default();
+        self.pending_cells = self.formulas.keys().copied().collect();
+        let mut expressions = BTreeMap::new();
+        for (cell_key, formula) in &self.formulas {
+            match FormulaParser::parse(formula) {
+                Ok(expression) => {
+                    expressions.insert(*cell_key, expression);
+                }
+                Err(error) => report
+                    .skipped_cells
+                    .push((self.get_cell_ref(cell_key)?, format!("{:#}", error))),
+            }
+        }
+        // Formula cell depend on the formula cells inside the ranges it refer
+        let cell_keys: Vec<FormulaCellKey> = expressions.keys().copied().collect();
+        let key_positions: HashMap<FormulaCellKey, usize> = cell_keys
+            .iter()
+            .enumerate()
+            .map(|(position, cell_key)| (*cell_key, position))
+            .collect();
+        let mut dependencies = vec![Vec::new(); cell_keys.len()];
+        for (position, cell_key) in cell_keys.iter().enumerate() {
+            let mut ranges = Vec::new();
+            self.collect_ranges(&expressions[cell_key], cell_key.0, 0, &mut ranges);
+            for (sheet_index, ((start_row, start_col), (end_row, end_col))) in ranges {
+                dependencies[position].extend(
+                    expressions
+                        .range(
+                            (sheet_index, start_row, start_col)..=(sheet_index, end_row, end_col),
+                        )
+                        .filter(|((_, _, col_index), _)| (start_col..=end_col).contains(col_index))
+                        .map(|(dependency_key, _)| key_positions[dependency_key]),
+                );
+            }
+        }
+        let mut calculated_values = Vec::new();
+        for mut component in get_calculation_order(&dependencies) {
+            if component.len() > 1 || dependencies[component[0]].contains(&component[0]) {
+                component.sort();
+                for position in component {
+                    report
+                        .circular_references
+                        .push(self.get_cell_ref(&cell_keys[position])?);
+                }
+                continue;
+            }
+            let cell_key = cell_keys[component[0]];
+            let (sheet_index, row_index, col_index) = cell_key;
+            let context = FormulaContext {
+                calculator: self,
+                sheet_index,
+                row_index,
+                col_index,
+                name_depth: 0,
+            };
+            let cell_value = match catch_formula_error(context.evaluate(&expressions[&cell_key])) {
+                Ok(value) => match value.get_scalar() {
+                    FormulaValue::Number(number) if number.is_finite() => {
+                        CellValue::Number(round_significant(number))
+                    }
+                    FormulaValue::Number(_) => CellValue::Error(ERROR_NUMBER.to_string()),
+                    FormulaValue::Text(text) => CellValue::Text(text),
+                    FormulaValue::Bool(value) => CellValue::Bool(value),
+                    FormulaValue::Error(error_code) => CellValue::Error(error_code),
+                    // Reference to empty cell show zero
+                    _ => CellValue::Number(0.0),
+                },
+                Err(error) => {
+                    report
+                        .skipped_cells
+                        .push((self.get_cell_ref(&cell_key)?, format!("{:#}", error)));
+                    continue;
+                }
+            };
+            self.cell_values[sheet_index].insert(
+                (row_index, col_index),
+                FormulaValue::from_cell_value(&cell_value),
+            );
+            self.pending_cells.remove(&cell_key);
+            calculated_values.push((cell_key, cell_value));
+        }
+        report.calculated_count = calculated_values.len();
+        Ok((calculated_values, report))
+    }
+
+    /// Reference like 'Sheet 1'!B2 of the cell
+    fn get_cell_ref(
+        &self,
+        (sheet_index, row_index, col_index): &FormulaCellKey,
+    ) -> AnyResult<String, AnyError> {
+        Ok(format!(
+            "'{}'!{}{}",
+            self.sheet_names[*sheet_index].replace("'", "''"),
+            ConverterUtil::get_column_ref(*col_index).context("Failed to get column ref")?,
+            row_index
+        ))
+    }
+
+    /// Sheet scoped name take priority over the workbook scoped name
+    fn find_defined_name(
+        &self,
+        sheet_name: Option<&str>,
+        name: &str,
+        sheet_index: usize,
+    ) -> Option<&DefinedName> {
+        let scope_sheet_name = sheet_name.unwrap_or(&self.sheet_names[sheet_index]);
+        self.defined_names
+            .iter()
+            .find(|defined_name| {
+                defined_name.name.eq_ignore_ascii_case(name)
+                    && defined_name
+                        .sheet_name
+                        .as_deref()
+                        .is_some_and(|sheet_name| sheet_name.eq_ignore_ascii_case(scope_sheet_name))
+            })
+            .or_else(|| {
+                self.defined_names.iter().find(|defined_name| {
+                    sheet_name.is_none()
+                        && defined_name.sheet_name.is_none()
+                        && defined_name.name.eq_ignore_ascii_case(name)
+                })
+            })
+    }
+
+    /// Sheet index and range of the reference, full row and column end at the last used cell
+    fn resolve_reference(
+        &self,
+        reference: &FormulaReference,
+        sheet_index: usize,
+    ) -> AnyResult<(usize, FormulaRange), AnyError> {
+        let sheet_index = match reference.sheet_name.as_deref() {
+            Some(sheet_name) if sheet_name.starts_with('[') || sheet_name.contains(':') => {
+                return Err(anyhow!(
+                    "External and multi sheet reference {} is not supported",
+                    sheet_name
+                ))
+            }
+            Some(sheet_name) => self
+                .sheet_names
+                .iter()
+                .position(|current_sheet_name| current_sheet_name.eq_ignore_ascii_case(sheet_name))
+                .ok_or_else(|| formula_error(ERROR_REFERENCE))?,
+            None => sheet_index,
+        };
+        let (max_row, max_col) = self.sheet_bounds[sheet_index];
+        let end = reference.end.as_ref().unwrap_or(&reference.start);
+        let (start_row, end_row) = match (reference.start.row, end.row) {
+            (Some(start_row), Some(end_row)) => (start_row, end_row),
+            _ => (1, max_row),
+        };
+        let (start_col, end_col) = match (reference.start.col, end.col) {
+            (Some(start_col), Some(end_col)) => (start_col, end_col),
+            _ => (1, max_col),
+        };
+        Ok((
+            sheet_index,
+            (
+                (start_row.min(end_row), start_col.min(end_col)),
+                (start_row.max(end_row), start_col.max(end_col)),
+            ),
+        ))
+    }
+
+    /// Single cell give the value, range give the rows
+    fn get_range_value(
+        &self,
+        sheet_index: usize,
+        ((start_row, start_col), (end_row, end_col)): FormulaRange,
+    ) -> AnyResult<FormulaValue, AnyError> {
+        let row_count = (end_row - start_row + 1) as usize;
+        let col_count = (end_col - start_col + 1) as usize;
+        if row_count * col_count > MAX_RANGE_CELLS {
+            return Err(anyhow!(
+                "Range of {} cells is too large to calculate",
+                row_count * col_count
+            ));
+        }
+        let mut rows = vec![vec![FormulaValue::Empty; col_count]; row_count];
+        for ((row_index, col_index), value) in self.cell_values[sheet_index]
+            .range((start_row, start_col)..=(end_row, end_col))
+            .filter(|((_, col_index), _)| (start_col..=end_col).contains(col_index))
+        {
+            let cell_key = (sheet_index, *row_index, *col_index);
+            if self.pending_cells.contains(&cell_key) {
+                return Err(anyhow!(
+                    "Depends on {} which has no calculated value",
+                    self.get_cell_ref(&cell_key)?
+                ));
+            }
+            rows[(row_index - start_row) as usize][(col_index - start_col) as usize] =
+                value.clone();
+        }
+        if row_count == 1 && col_count == 1 {
+            Ok(rows.remove(0).remove(0))
+        } else {
+            Ok(FormulaValue::Array(rows))
+        }
+    }
+
+    /// Ranges the expression refer including the ones through the defined names
+    fn collect_ranges(
+        &self,
+        expression: &FormulaExpressionValues,
+        sheet_index: usize,
+        name_depth: usize,
+        ranges: &mut Vec<(usize, FormulaRange)>,
+    ) {
+        match expression {
+            FormulaExpressionValues::Reference(reference) => {
+                if let Ok(range) = self.resolve_reference(reference, sheet_index) {
+                    ranges.push(range);
+                }
+            }
+            FormulaExpressionValues::Name { sheet_name, name } if name_depth < MAX_NAME_DEPTH => {
+                if let Some(expression) = self
+                    .find_defined_name(sheet_name.as_deref(), name, sheet_index)
+                    .and_then(|defined_name| FormulaParser::parse(&defined_name.value).ok())
+                {
+                    self.collect_ranges(&expression, sheet_index, name_depth + 1, ranges);
+                }
+            }
+            FormulaExpressionValues::Function { arguments, .. } => {
+                for argument in arguments {
+                    self.collect_ranges(argument, sheet_index, name_depth, ranges);
+                }
+            }
+            FormulaExpressionValues::Unary { operand, .. } => {
+                self.collect_ranges(operand, sheet_index, name_depth, ranges);
+            }
+            FormulaExpressionValues::Binary {
+                operator: FormulaOperatorValues::Range,
+                left,
+                right,
+            } => {
+                let mut range_ends = Vec::new();
+                self.collect_ranges(left, sheet_index, name_depth, &mut range_ends);
+                self.collect_ranges(right, sheet_index, name_depth, &mut range_ends);
+                // Range operator cover the cells between its two ends
+                if let [(left_sheet, left_range), (right_sheet, right_range)] =
+                    range_ends.as_slice()
+                {
+                    if left_sheet == right_sheet {
+                        ranges.push((*left_sheet, get_bounding_range(*left_range, *right_range)));
+                    }
+                }
+                ranges.extend(range_ends);
+            }
+            FormulaExpressionValues::Binary { left, right, .. } => {
+                self.collect_ranges(left, sheet_index, name_depth, ranges);
+                self.collect_ranges(right, sheet_index, name_depth, ranges);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Smallest range covering both the ranges
+fn get_bounding_range(
+    ((left_start_row, left_start_col), (left_end_row, left_end_col)): FormulaRange,
+    ((right_start_row, right_start_col), (right_end_row, right_end_col)): FormulaRange,
+) -> FormulaRange {
+    (
+        (
+            left_start_row.min(right_start_row),
+            left_start_col.min(right_start_col),
+        ),
+        (
+            left_end_row.max(right_end_row),
+            left_end_col.max(right_end_col),
+        ),
+    )
+}
+
+/// Strongly connected groups of the dependency graph with dependencies coming first.
+/// Group of more than one cell or cell depending on itself is a circular reference
+fn get_calculation_order(dependencies: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let node_count = dependencies.len();
+    let mut visit_index = vec![usize::MAX; node_count];
+    let mut low_link = vec![0; node_count];
+    let mut on_stack = vec![false; node_count];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+    for root in 0..node_count {
+        if visit_index[root] != usize::MAX {
+            continue;
+        }
+        visit_index[root] = next_index;
+        low_link[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        // Iterative walk keeps long dependency chains off the call stack
+        let mut call_stack = vec![(root, 0)];
+        while let Some((node, edge_position)) = call_stack.last_mut() {
+            let node = *node;
+            let next_node = dependencies[node].get(*edge_position).copied();
+            *edge_position += 1;
+            match next_node {
+                Some(next_node) if visit_index[next_node] == usize::MAX => {
+                    visit_index[next_node] = next_index;
+                    low_link[next_node] = next_index;
+                    next_index += 1;
+                    stack.push(next_node);
+                    on_stack[next_node] = true;
+                    call_stack.push((next_node, 0));
+                }
+                Some(next_node) => {
+                    if on_stack[next_node] {
+                        low_link[node] = low_link[node].min(visit_index[next_node]);
+                    }
+                }
+                None => {
+                    call_stack.pop();
+                    if let Some((parent, _)) = call_stack.last() {
+                        low_link[*parent] = low_link[*parent].min(low_link[node]);
+                    }
+                    if low_link[node] == visit_index[node] {
+                        let mut component = Vec::new();
+                        while let Some(member) = stack.pop() {
+                            on_stack[member] = false;
+                            component.push(member);
+                            if member == node {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+    components
+}
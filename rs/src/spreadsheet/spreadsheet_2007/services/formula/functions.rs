@@ -0,0 +1,1269 @@
+use crate::{
+    converters::ConverterUtil,
+    spreadsheet_2007::services::formula::{
+        catch_formula_error, compare_values, format_number_text, formula_error, get_date_parts,
+        get_number_result, get_text_result, parse_number_text, round_significant, FormulaContext,
+        FormulaExpressionValues, FormulaValue, ERROR_DIVIDE_BY_ZERO, ERROR_NOT_AVAILABLE,
+        ERROR_NUMBER, ERROR_REFERENCE, ERROR_VALUE, MAX_TEXT_LENGTH,
+    },
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use chrono::{Datelike, Local, NaiveDate};
+use std::cmp::Ordering;
+
+/// Rows of the criteria range with its criteria
+type CriteriaRange = (Vec<Vec<FormulaValue>>, FormulaCriteria);
+
+/// Least and most argument count of the supported function, None when not supported
+fn get_argument_range(name: &str) -> Option<(usize, usize)> {
+    const VARIADIC: usize = 255;
+    match name {
+        "PI" | "TODAY" | "NOW" | "NA" | "TRUE" | "FALSE" => Some((0, 0)),
+        "ROW" | "COLUMN" => Some((0, 1)),
+        "ABS" | "INT" | "SQRT" | "SIGN" | "EXP" | "LN" | "LOG10" | "NOT" | "ISBLANK"
+        | "ISNUMBER" | "ISTEXT" | "ISLOGICAL" | "ISERROR" | "ISERR" | "ISNA" | "ROWS"
+        | "COLUMNS" | "LEN" | "UPPER" | "LOWER" | "PROPER" | "TRIM" | "VALUE" | "YEAR"
+        | "MONTH" | "DAY" | "HOUR" | "MINUTE" | "SECOND" | "COUNTBLANK" => Some((1, 1)),
+        "LOG" | "LEFT" | "RIGHT" | "WEEKDAY" => Some((1, 2)),
+        "MOD" | "POWER" | "ROUND" | "ROUNDUP" | "ROUNDDOWN" | "CEILING" | "FLOOR" | "IFERROR"
+        | "IFNA" | "TEXT" | "REPT" | "EXACT" | "EDATE" | "EOMONTH" | "DAYS" => Some((2, 2)),
+        "IF" => Some((1, 3)),
+        "SUMIF" | "AVERAGEIF" | "INDEX" | "MATCH" | "FIND" | "SEARCH" => Some((2, 3)),
+        "COUNTIF" => Some((2, 2)),
+        "MID" | "DATE" | "TIME" => Some((3, 3)),
+        "SUBSTITUTE" | "VLOOKUP" | "HLOOKUP" => Some((3, 4)),
+        "REPLACE" => Some((4, 4)),
+        "XLOOKUP" => Some((3, 6)),
+        "SUMIFS" | "AVERAGEIFS" => Some((3, VARIADIC)),
+        "COUNTIFS" | "IFS" | "CHOOSE" => Some((2, VARIADIC)),
+        "SWITCH" => Some((3, VARIADIC)),
+        "TEXTJOIN" => Some((3, VARIADIC)),
+        "SUM" | "PRODUCT" | "AVERAGE" | "MIN" | "MAX" | "COUNT" | "COUNTA" | "MEDIAN"
+        | "SUMPRODUCT" | "AND" | "OR" | "XOR" | "CONCATENATE" | "CONCAT" => Some((1, VARIADIC)),
+        _ => None,
+    }
+}
+
+/// Newer functions are saved with the future function prefix
+fn get_function_name(name: &str) -> &str {
+    name.trim_start_matches("_XLFN.")
+        .trim_start_matches("_XLWS.")
+}
+
+/// Check the argument count of the supported function, unsupported function is left to the calculation
+pub(crate) fn check_argument_count(name: &str, count: usize) -> AnyResult<(), AnyError> {
+    if let Some((min_count, max_count)) = get_argument_range(get_function_name(name)) {
+        if count < min_count {
+            return Err(anyhow!(
+                "Function {} needs at least {} arguments",
+                name,
+                min_count
+            ));
+        }
+        if count > max_count {
+            return Err(anyhow!(
+                "Function {} takes at most {} arguments",
+                name,
+                max_count
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Calculate the function, unsupported function return error in place of the error value
+pub(crate) fn call_function(
+    context: &FormulaContext,
+    name: &str,
+    arguments: &[FormulaExpressionValues],
+) -> AnyResult<FormulaValue, AnyError> {
+    let name = get_function_name(name);
+    if get_argument_range(name).is_none() {
+        return Err(anyhow!("Function {} is not supported", name));
+    }
+    check_argument_count(name, arguments.len())?;
+    let get_number = |index: usize| context.get_number(&arguments[index]);
+    let get_optional_number = |index: usize, default: f64| {
+        arguments
+            .get(index)
+            .map_or(Ok(default), |argument| context.get_number(argument))
+    };
+    let get_text = |index: usize| context.get_text(&arguments[index]);
+    match name {
+        // Math and aggregate
+        "SUM" => get_number_result(get_aggregate_numbers(context, arguments)?.iter().sum()),
+        "PRODUCT" => {
+            let numbers = get_aggregate_numbers(context, arguments)?;
+            if numbers.is_empty() {
+                Ok(FormulaValue::Number(0.0))
+            } else {
+                get_number_result(numbers.iter().product())
+            }
+        }
+        "AVERAGE" => get_average(&get_aggregate_numbers(context, arguments)?),
+        "MIN" => Ok(FormulaValue::Number(
+            get_aggregate_numbers(context, arguments)?
+                .into_iter()
+                .reduce(f64::min)
+                .unwrap_or_default(),
+        )),
+        "MAX" => Ok(FormulaValue::Number(
+            get_aggregate_numbers(context, arguments)?
+                .into_iter()
+                .reduce(f64::max)
+                .unwrap_or_default(),
+        )),
+        "MEDIAN" => {
+            let mut numbers = get_aggregate_numbers(context, arguments)?;
+            if numbers.is_empty() {
+                return Err(formula_error(ERROR_NUMBER));
+            }
+            numbers.sort_by(|left, right| left.partial_cmp(right).unwrap_or(Ordering::Equal));
+            let middle = numbers.len() / 2;
+            Ok(FormulaValue::Number(if numbers.len() % 2 == 0 {
+                (numbers[middle - 1] + numbers[middle]) / 2.0
+            } else {
+                numbers[middle]
+            }))
+        }
+        "COUNT" => {
+            let mut count = 0;
+            for argument in arguments {
+                let (values, is_range) = get_argument_values(context, argument)?;
+                count += values
+                    .iter()
+                    .filter(|value| match value {
+                        FormulaValue::Number(_) => true,
+                        FormulaValue::Text(_) | FormulaValue::Bool(_) => {
+                            !is_range && value.to_number().is_ok()
+                        }
+                        _ => false,
+                    })
+                    .count();
+            }
+            Ok(FormulaValue::Number(count as f64))
+        }
+        "COUNTA" => {
+            let mut count = 0;
+            for argument in arguments {
+                let (values, _) = get_argument_values(context, argument)?;
+                count += values
+                    .iter()
+                    .filter(|value| **value != FormulaValue::Empty)
+                    .count();
+            }
+            Ok(FormulaValue::Number(count as f64))
+        }
+        "COUNTBLANK" => Ok(FormulaValue::Number(
+            get_argument_values(context, &arguments[0])?
+                .0
+                .iter()
+                .filter(|value| match value {
+                    FormulaValue::Empty => true,
+                    FormulaValue::Text(text) => text.is_empty(),
+                    _ => false,
+                })
+                .count() as f64,
+        )),
+        "SUMPRODUCT" => {
+            let mut arrays = Vec::with_capacity(arguments.len());
+            for argument in arguments {
+                arrays.push(context.evaluate(argument)?.into_rows());
+            }
+            let size = (
+                arrays[0].len(),
+                arrays[0].first().map_or(0, |row| row.len()),
+            );
+            let mut total = 0.0;
+            for row_index in 0..size.0 {
+                for col_index in 0..size.1 {
+                    let mut product = 1.0;
+                    for rows in &arrays {
+                        if rows.len() != size.0 || rows[row_index].len() != size.1 {
+                            return Err(formula_error(ERROR_VALUE));
+                        }
+                        product *= match &rows[row_index][col_index] {
+                            FormulaValue::Number(number) => *number,
+                            FormulaValue::Error(error_code) => {
+                                return Err(formula_error(error_code))
+                            }
+                            _ => 0.0,
+                        };
+                    }
+                    total += product;
+                }
+            }
+            get_number_result(total)
+        }
+        "ABS" => Ok(FormulaValue::Number(get_number(0)?.abs())),
+        "INT" => Ok(FormulaValue::Number(get_number(0)?.floor())),
+        "SIGN" => {
+            let number = get_number(0)?;
+            Ok(FormulaValue::Number(if number == 0.0 {
+                0.0
+            } else {
+                number.signum()
+            }))
+        }
+        "MOD" => {
+            let (number, divisor) = (get_number(0)?, get_number(1)?);
+            if divisor == 0.0 {
+                return Err(formula_error(ERROR_DIVIDE_BY_ZERO));
+            }
+            get_number_result(number - divisor * (number / divisor).floor())
+        }
+        "POWER" => {
+            let (number, power) = (get_number(0)?, get_number(1)?);
+            if number == 0.0 && power < 0.0 {
+                return Err(formula_error(ERROR_DIVIDE_BY_ZERO));
+            }
+            get_number_result(number.powf(power))
+        }
+        "SQRT" => get_number_result(get_number(0)?.sqrt()),
+        "EXP" => get_number_result(get_number(0)?.exp()),
+        "LN" | "LOG10" | "LOG" => {
+            let number = get_number(0)?;
+            let base = match name {
+                "LN" => std::f64::consts::E,
+                "LOG10" => 10.0,
+                _ => get_optional_number(1, 10.0)?,
+            };
+            if number <= 0.0 || base <= 0.0 {
+                return Err(formula_error(ERROR_NUMBER));
+            }
+            if base == 1.0 {
+                return Err(formula_error(ERROR_DIVIDE_BY_ZERO));
+            }
+            get_number_result(number.log(base))
+        }
+        "PI" => Ok(FormulaValue::Number(std::f64::consts::PI)),
+        "ROUND" | "ROUNDUP" | "ROUNDDOWN" => {
+            let (number, digits) = (get_number(0)?, get_number(1)?.trunc() as i32);
+            let factor = 10f64.powi(digits);
+            // Remove the binary noise like 2.675 * 100 = 267.49999999999997
+            let scaled = round_significant(number * factor);
+            let rounded = match name {
+                "ROUNDUP" => scaled.abs().ceil() * scaled.signum(),
+                "ROUNDDOWN" => scaled.trunc(),
+                _ => scaled.round(),
+            };
+            get_number_result(rounded / factor)
+        }
+        "CEILING" | "FLOOR" => {
+            let (number, significance) = (get_number(0)?, get_number(1)?);
+            if significance == 0.0 {
+                return if name == "CEILING" {
+                    Ok(FormulaValue::Number(0.0))
+                } else {
+                    Err(formula_error(ERROR_DIVIDE_BY_ZERO))
+                };
+            }
+            if number > 0.0 && significance < 0.0 {
+                return Err(formula_error(ERROR_NUMBER));
+            }
+            let quotient = round_significant(number / significance);
+            get_number_result(
+                if name == "CEILING" {
+                    quotient.ceil()
+                } else {
+                    quotient.floor()
+                } * significance,
+            )
+        }
+        // Criteria
+        "SUMIF" | "AVERAGEIF" => {
+            let criteria_rows = context.evaluate(&arguments[0])?.into_rows();
+            let criteria = FormulaCriteria::new(context.evaluate_scalar(&arguments[1])?);
+            let value_rows = match arguments.get(2) {
+                Some(argument) => get_resized_rows(context, argument, &criteria_rows)?,
+                None => criteria_rows.clone(),
+            };
+            let numbers = get_criteria_numbers(&value_rows, &[(criteria_rows, criteria)])?;
+            if name == "SUMIF" {
+                get_number_result(numbers.iter().sum())
+            } else {
+                get_average(&numbers)
+            }
+        }
+        "SUMIFS" | "AVERAGEIFS" => {
+            if arguments.len().is_multiple_of(2) {
+                return Err(anyhow!("Function {} need criteria pairs", name));
+            }
+            let value_rows = context.evaluate(&arguments[0])?.into_rows();
+            let criteria_list = get_criteria_list(context, &arguments[1..])?;
+            let numbers = get_criteria_numbers(&value_rows, &criteria_list)?;
+            if name == "SUMIFS" {
+                get_number_result(numbers.iter().sum())
+            } else {
+                get_average(&numbers)
+            }
+        }
+        "COUNTIF" | "COUNTIFS" => {
+            if !arguments.len().is_multiple_of(2) {
+                return Err(anyhow!("Function {} need criteria pairs", name));
+            }
+            let criteria_list = get_criteria_list(context, arguments)?;
+            let count_rows = criteria_list[0]
+                .0
+                .iter()
+                .map(|row| vec![FormulaValue::Number(1.0); row.len()])
+                .collect::<Vec<_>>();
+            Ok(FormulaValue::Number(
+                get_criteria_numbers(&count_rows, &criteria_list)?.len() as f64,
+            ))
+        }
+        // Logical
+        "IF" => {
+            if context.get_bool(&arguments[0])? {
+                arguments
+                    .get(1)
+                    .map_or(Ok(FormulaValue::Bool(true)), |argument| {
+                        context.evaluate(argument)
+                    })
+            } else {
+                arguments
+                    .get(2)
+                    .map_or(Ok(FormulaValue::Bool(false)), |argument| {
+                        context.evaluate(argument)
+                    })
+            }
+        }
+        "IFS" => {
+            if !arguments.len().is_multiple_of(2) {
+                return Err(anyhow!("Function {} need condition pairs", name));
+            }
+            for pair in arguments.chunks(2) {
+                if context.get_bool(&pair[0])? {
+                    return context.evaluate(&pair[1]);
+                }
+            }
+            Err(formula_error(ERROR_NOT_AVAILABLE))
+        }
+        "SWITCH" => {
+            let value = context.evaluate_scalar(&arguments[0])?;
+            if let FormulaValue::Error(error_code) = &value {
+                return Err(formula_error(error_code));
+            }
+            let cases = &arguments[1..];
+            for pair in cases.chunks(2) {
+                if pair.len() == 1 {
+                    return context.evaluate(&pair[0]);
+                }
+                let case_value = context.evaluate_scalar(&pair[0])?;
+                if is_same_type(&value, &case_value)
+                    && compare_values(&value, &case_value) == Ordering::Equal
+                {
+                    return context.evaluate(&pair[1]);
+                }
+            }
+            Err(formula_error(ERROR_NOT_AVAILABLE))
+        }
+        "AND" | "OR" | "XOR" => {
+            let mut values = Vec::new();
+            for argument in arguments {
+                let (argument_values, is_range) = get_argument_values(context, argument)?;
+                for value in argument_values {
+                    match value {
+                        FormulaValue::Error(error_code) => return Err(formula_error(&error_code)),
+                        FormulaValue::Empty => {}
+                        FormulaValue::Text(_) if is_range => {}
+                        value => values.push(value.to_bool()?),
+                    }
+                }
+            }
+            if values.is_empty() {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            Ok(FormulaValue::Bool(match name {
+                "AND" => values.iter().all(|value| *value),
+                "OR" => values.iter().any(|value| *value),
+                _ => values.iter().filter(|value| **value).count() % 2 == 1,
+            }))
+        }
+        "NOT" => Ok(FormulaValue::Bool(!context.get_bool(&arguments[0])?)),
+        "TRUE" => Ok(FormulaValue::Bool(true)),
+        "FALSE" => Ok(FormulaValue::Bool(false)),
+        "IFERROR" | "IFNA" => {
+            let value = catch_formula_error(context.evaluate(&arguments[0]))?;
+            let is_caught = |value: &FormulaValue| match value {
+                FormulaValue::Error(error_code) => {
+                    name == "IFERROR" || error_code == ERROR_NOT_AVAILABLE
+                }
+                _ => false,
+            };
+            match value {
+                FormulaValue::Array(rows) => {
+                    let fallback = context.evaluate_scalar(&arguments[1])?;
+                    Ok(FormulaValue::Array(
+                        rows.into_iter()
+                            .map(|row| {
+                                row.into_iter()
+                                    .map(|value| {
+                                        if is_caught(&value) {
+                                            fallback.clone()
+                                        } else {
+                                            value
+                                        }
+                                    })
+                                    .collect()
+                            })
+                            .collect(),
+                    ))
+                }
+                value if is_caught(&value) => context.evaluate(&arguments[1]),
+                value => Ok(value),
+            }
+        }
+        // Information
+        "ISBLANK" | "ISNUMBER" | "ISTEXT" | "ISLOGICAL" | "ISERROR" | "ISERR" | "ISNA" => {
+            let value = catch_formula_error(context.evaluate(&arguments[0]))?.get_scalar();
+            Ok(FormulaValue::Bool(match (name, &value) {
+                ("ISBLANK", FormulaValue::Empty)
+                | ("ISNUMBER", FormulaValue::Number(_))
+                | ("ISTEXT", FormulaValue::Text(_))
+                | ("ISLOGICAL", FormulaValue::Bool(_))
+                | ("ISERROR", FormulaValue::Error(_)) => true,
+                ("ISERR", FormulaValue::Error(error_code)) => error_code != ERROR_NOT_AVAILABLE,
+                ("ISNA", FormulaValue::Error(error_code)) => error_code == ERROR_NOT_AVAILABLE,
+                _ => false,
+            }))
+        }
+        "NA" => Err(formula_error(ERROR_NOT_AVAILABLE)),
+        // Lookup and reference
+        "VLOOKUP" | "HLOOKUP" => {
+            let lookup_value = get_lookup_value(context, &arguments[0])?;
+            let mut table_rows = context.evaluate(&arguments[1])?.into_rows();
+            if name == "HLOOKUP" {
+                table_rows = transpose_rows(table_rows);
+            }
+            let index = get_number(2)?.trunc();
+            if index < 1.0 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            let index = index as usize - 1;
+            let is_approximate = match arguments.get(3) {
+                Some(argument) => context.get_bool(argument)?,
+                None => true,
+            };
+            let keys: Vec<FormulaValue> = table_rows
+                .iter()
+                .map(|row| row.first().cloned().unwrap_or(FormulaValue::Empty))
+                .collect();
+            let position =
+                find_match_position(&lookup_value, &keys, if is_approximate { 1 } else { 0 })
+                    .ok_or_else(|| formula_error(ERROR_NOT_AVAILABLE))?;
+            table_rows[position]
+                .get(index)
+                .cloned()
+                .ok_or_else(|| formula_error(ERROR_REFERENCE))
+        }
+        "XLOOKUP" => {
+            let lookup_value = get_lookup_value(context, &arguments[0])?;
+            let lookup_rows = context.evaluate(&arguments[1])?.into_rows();
+            let return_rows = context.evaluate(&arguments[2])?.into_rows();
+            let is_vertical = lookup_rows.len() > 1 || lookup_rows[0].len() == 1;
+            let keys: Vec<FormulaValue> = if is_vertical {
+                lookup_rows
+                    .into_iter()
+                    .map(|row| row.into_iter().next().unwrap_or(FormulaValue::Empty))
+                    .collect()
+            } else {
+                lookup_rows.into_iter().next().unwrap_or_default()
+            };
+            let match_mode = get_optional_number(4, 0.0)? as i32;
+            let search_mode = get_optional_number(5, 1.0)? as i32;
+            if !(-1..=2).contains(&match_mode) || ![-2, -1, 1, 2].contains(&search_mode) {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            match find_xlookup_position(&lookup_value, &keys, match_mode, search_mode < 0) {
+                Some(position) => {
+                    if is_vertical {
+                        let row = return_rows
+                            .get(position)
+                            .ok_or_else(|| formula_error(ERROR_VALUE))?;
+                        Ok(get_line_value(row.clone()))
+                    } else {
+                        let column = return_rows
+                            .into_iter()
+                            .map(|row| row.get(position).cloned())
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or_else(|| formula_error(ERROR_VALUE))?;
+                        Ok(get_line_value(column))
+                    }
+                }
+                None => match arguments.get(3) {
+                    Some(argument) if *argument != FormulaExpressionValues::Empty => {
+                        context.evaluate(argument)
+                    }
+                    _ => Err(formula_error(ERROR_NOT_AVAILABLE)),
+                },
+            }
+        }
+        "INDEX" => {
+            let rows = context.evaluate(&arguments[0])?.into_rows();
+            let mut row_number = get_number(1)?.trunc() as i64;
+            let mut col_number = get_optional_number(2, 0.0)?.trunc() as i64;
+            // Single row array take the only index as column
+            if arguments.len() == 2 && rows.len() == 1 {
+                col_number = row_number;
+                row_number = 1;
+            }
+            let col_count = rows.first().map_or(0, |row| row.len()) as i64;
+            if row_number < 0 || col_number < 0 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            if row_number > rows.len() as i64 || col_number > col_count {
+                return Err(formula_error(ERROR_REFERENCE));
+            }
+            match (row_number, col_number) {
+                (0, 0) => Ok(FormulaValue::Array(rows)),
+                (0, col_number) => Ok(FormulaValue::Array(
+                    rows.into_iter()
+                        .map(|row| vec![row[col_number as usize - 1].clone()])
+                        .collect(),
+                )),
+                (row_number, 0) => Ok(FormulaValue::Array(vec![
+                    rows[row_number as usize - 1].clone()
+                ])),
+                (row_number, col_number) => {
+                    Ok(rows[row_number as usize - 1][col_number as usize - 1].clone())
+                }
+            }
+        }
+        "MATCH" => {
+            let lookup_value = get_lookup_value(context, &arguments[0])?;
+            let rows = context.evaluate(&arguments[1])?.into_rows();
+            let keys: Vec<FormulaValue> = if rows.len() == 1 {
+                rows.into_iter().next().unwrap_or_default()
+            } else if rows.iter().all(|row| row.len() == 1) {
+                rows.into_iter().flatten().collect()
+            } else {
+                return Err(formula_error(ERROR_NOT_AVAILABLE));
+            };
+            let match_type = match get_optional_number(2, 1.0)? {
+                match_type if match_type > 0.0 => 1,
+                match_type if match_type < 0.0 => -1,
+                _ => 0,
+            };
+            find_match_position(&lookup_value, &keys, match_type)
+                .map(|position| FormulaValue::Number(position as f64 + 1.0))
+                .ok_or_else(|| formula_error(ERROR_NOT_AVAILABLE))
+        }
+        "CHOOSE" => {
+            let index = get_number(0)?.trunc();
+            if index < 1.0 || index >= arguments.len() as f64 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            context.evaluate(&arguments[index as usize])
+        }
+        "ROW" | "COLUMN" => {
+            let (row_index, col_index) = match arguments.first() {
+                Some(argument) => {
+                    let (_, (start, _)) = context
+                        .get_reference_range(argument)?
+                        .ok_or_else(|| formula_error(ERROR_VALUE))?;
+                    start
+                }
+                None => (context.row_index, context.col_index),
+            };
+            Ok(FormulaValue::Number(if name == "ROW" {
+                row_index as f64
+            } else {
+                col_index as f64
+            }))
+        }
+        "ROWS" | "COLUMNS" => {
+            let (row_count, col_count) = match context.get_reference_range(&arguments[0])? {
+                Some((_, ((start_row, start_col), (end_row, end_col)))) => (
+                    (end_row - start_row + 1) as usize,
+                    (end_col - start_col + 1) as usize,
+                ),
+                None => {
+                    let rows = context.evaluate(&arguments[0])?.into_rows();
+                    (rows.len(), rows.first().map_or(0, |row| row.len()))
+                }
+            };
+            Ok(FormulaValue::Number(if name == "ROWS" {
+                row_count as f64
+            } else {
+                col_count as f64
+            }))
+        }
+        // Text
+        "CONCATENATE" => {
+            let mut text = String::new();
+            for index in 0..arguments.len() {
+                text.push_str(&get_text(index)?);
+            }
+            get_text_result(text)
+        }
+        "CONCAT" => {
+            let mut text = String::new();
+            for argument in arguments {
+                for value in get_argument_values(context, argument)?.0 {
+                    text.push_str(&value.to_text()?);
+                }
+            }
+            get_text_result(text)
+        }
+        "TEXTJOIN" => {
+            let delimiter = get_text(0)?;
+            let ignore_empty = context.get_bool(&arguments[1])?;
+            let mut texts = Vec::new();
+            for argument in &arguments[2..] {
+                for value in get_argument_values(context, argument)?.0 {
+                    let text = value.to_text()?;
+                    if !ignore_empty || !text.is_empty() {
+                        texts.push(text);
+                    }
+                }
+            }
+            get_text_result(texts.join(&delimiter))
+        }
+        "LEFT" | "RIGHT" => {
+            let text: Vec<char> = get_text(0)?.chars().collect();
+            let count = get_optional_number(1, 1.0)?.trunc();
+            if count < 0.0 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            let count = (count as usize).min(text.len());
+            Ok(FormulaValue::Text(if name == "LEFT" {
+                text[..count].iter().collect()
+            } else {
+                text[text.len() - count..].iter().collect()
+            }))
+        }
+        "MID" => {
+            let text: Vec<char> = get_text(0)?.chars().collect();
+            let (start, count) = (get_number(1)?.trunc(), get_number(2)?.trunc());
+            if start < 1.0 || count < 0.0 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            Ok(FormulaValue::Text(
+                text.iter()
+                    .skip(start as usize - 1)
+                    .take(count as usize)
+                    .collect(),
+            ))
+        }
+        "LEN" => Ok(FormulaValue::Number(get_text(0)?.chars().count() as f64)),
+        "UPPER" => Ok(FormulaValue::Text(get_text(0)?.to_uppercase())),
+        "LOWER" => Ok(FormulaValue::Text(get_text(0)?.to_lowercase())),
+        "PROPER" => {
+            let mut is_word_start = true;
+            let mut text = String::new();
+            for character in get_text(0)?.chars() {
+                if character.is_alphabetic() {
+                    if is_word_start {
+                        text.extend(character.to_uppercase());
+                    } else {
+                        text.extend(character.to_lowercase());
+                    }
+                    is_word_start = false;
+                } else {
+                    text.push(character);
+                    is_word_start = true;
+                }
+            }
+            Ok(FormulaValue::Text(text))
+        }
+        "TRIM" => Ok(FormulaValue::Text(
+            get_text(0)?
+                .split(' ')
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )),
+        "SUBSTITUTE" => {
+            let (text, old_text, new_text) = (get_text(0)?, get_text(1)?, get_text(2)?);
+            if old_text.is_empty() {
+                return Ok(FormulaValue::Text(text));
+            }
+            match arguments.get(3) {
+                Some(argument) => {
+                    let instance = context.get_number(argument)?.trunc();
+                    if instance < 1.0 {
+                        return Err(formula_error(ERROR_VALUE));
+                    }
+                    get_text_result(
+                        match text.match_indices(&old_text).nth(instance as usize - 1) {
+                            Some((position, _)) => format!(
+                                "{}{}{}",
+                                &text[..position],
+                                new_text,
+                                &text[position + old_text.len()..]
+                            ),
+                            None => text,
+                        },
+                    )
+                }
+                None => {
+                    // Length is checked before the replaced text is allocated
+                    let match_count = text.matches(&old_text).count();
+                    if text.chars().count() - match_count * old_text.chars().count()
+                        + match_count * new_text.chars().count()
+                        > MAX_TEXT_LENGTH
+                    {
+                        return Err(formula_error(ERROR_VALUE));
+                    }
+                    Ok(FormulaValue::Text(text.replace(&old_text, &new_text)))
+                }
+            }
+        }
+        "REPLACE" => {
+            let text: Vec<char> = get_text(0)?.chars().collect();
+            let (start, count) = (get_number(1)?.trunc(), get_number(2)?.trunc());
+            if start < 1.0 || count < 0.0 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            let start = (start as usize - 1).min(text.len());
+            let end = (start + count as usize).min(text.len());
+            get_text_result(format!(
+                "{}{}{}",
+                text[..start].iter().collect::<String>(),
+                get_text(3)?,
+                text[end..].iter().collect::<String>()
+            ))
+        }
+        "FIND" | "SEARCH" => {
+            let find_text = get_text(0)?;
+            let text: Vec<char> = get_text(1)?.chars().collect();
+            let start = get_optional_number(2, 1.0)?.trunc();
+            if start < 1.0 || start as usize > text.len() + 1 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            let start = start as usize - 1;
+            let position = if name == "FIND" {
+                let find_text: Vec<char> = find_text.chars().collect();
+                (start..=text.len().saturating_sub(find_text.len()))
+                    .find(|position| text[*position..].starts_with(&find_text))
+            } else {
+                // Search pattern match the start of the remaining text
+                let mut pattern = get_wildcard_pattern(&find_text);
+                pattern.push(WildcardValues::AnyText);
+                let text: Vec<char> = text.iter().flat_map(|c| c.to_lowercase()).collect();
+                (start..=text.len())
+                    .find(|position| is_wildcard_match(&pattern, &text[*position..]))
+            };
+            position
+                .map(|position| FormulaValue::Number(position as f64 + 1.0))
+                .ok_or_else(|| formula_error(ERROR_VALUE))
+        }
+        "VALUE" => match context.evaluate_scalar(&arguments[0])? {
+            FormulaValue::Text(text) => parse_number_text(&text)
+                .map(FormulaValue::Number)
+                .ok_or_else(|| formula_error(ERROR_VALUE)),
+            value => Ok(FormulaValue::Number(value.to_number()?)),
+        },
+        "TEXT" => {
+            let value = context.evaluate_scalar(&arguments[0])?;
+            let format_code = get_text(1)?;
+            match value.to_number() {
+                Ok(number) => Ok(FormulaValue::Text(
+                    format_number_text(number, &format_code)
+                        .context(format!("Failed to apply the format {}", format_code))?,
+                )),
+                // Text that isn't a number is returned as is
+                Err(_) if matches!(value, FormulaValue::Text(_)) => Ok(value),
+                Err(error) => Err(error),
+            }
+        }
+        "REPT" => {
+            let text = get_text(0)?;
+            let count = get_number(1)?.trunc();
+            // Length is checked before the repeated text is allocated
+            if count < 0.0 || text.chars().count() as f64 * count > MAX_TEXT_LENGTH as f64 {
+                return Err(formula_error(ERROR_VALUE));
+            }
+            Ok(FormulaValue::Text(text.repeat(count as usize)))
+        }
+        "EXACT" => Ok(FormulaValue::Bool(get_text(0)? == get_text(1)?)),
+        // Date and time
+        "DATE" => {
+            let mut year = get_number(0)?.trunc() as i64;
+            if (0..1900).contains(&year) {
+                year += 1900;
+            }
+            get_serial_date(
+                year,
+                get_number(1)?.trunc() as i64,
+                get_number(2)?.trunc() as i64,
+            )
+        }
+        "TIME" => {
+            let seconds = get_number(0)?.trunc() * 3600.0
+                + get_number(1)?.trunc() * 60.0
+                + get_number(2)?.trunc();
+            if seconds < 0.0 {
+                return Err(formula_error(ERROR_NUMBER));
+            }
+            Ok(FormulaValue::Number((seconds % 86_400.0) / 86_400.0))
+        }
+        "YEAR" | "MONTH" | "DAY" => {
+            let (year, month, day) =
+                get_date_parts(get_number(0)?).map_err(|_| formula_error(ERROR_NUMBER))?;
+            Ok(FormulaValue::Number(match name {
+                "YEAR" => year as f64,
+                "MONTH" => month as f64,
+                _ => day as f64,
+            }))
+        }
+        "HOUR" | "MINUTE" | "SECOND" => {
+            let serial_date = get_number(0)?;
+            if serial_date < 0.0 {
+                return Err(formula_error(ERROR_NUMBER));
+            }
+            let seconds = (serial_date.fract() * 86_400.0).round() as u32 % 86_400;
+            Ok(FormulaValue::Number(match name {
+                "HOUR" => seconds / 3600,
+                "MINUTE" => seconds / 60 % 60,
+                _ => seconds % 60,
+            } as f64))
+        }
+        "WEEKDAY" => {
+            let serial_date = get_number(0)?.floor();
+            if serial_date < 0.0 {
+                return Err(formula_error(ERROR_NUMBER));
+            }
+            // Excel take serial 1 as sunday
+            let day_index = (serial_date as i64 - 1).rem_euclid(7);
+            Ok(FormulaValue::Number(
+                match get_optional_number(1, 1.0)? as i64 {
+                    1 => day_index + 1,
+                    2 => (day_index + 6) % 7 + 1,
+                    3 => (day_index + 6) % 7,
+                    _ => return Err(formula_error(ERROR_NUMBER)),
+                } as f64,
+            ))
+        }
+        "TODAY" | "NOW" => {
+            let now = Local::now().naive_local();
+            let serial_date = ConverterUtil::get_excel_serial_date(&now)
+                .context("Failed to get serial date of now")?;
+            Ok(FormulaValue::Number(if name == "TODAY" {
+                serial_date.floor()
+            } else {
+                serial_date
+            }))
+        }
+        "EDATE" | "EOMONTH" => {
+            let (year, month, day) =
+                get_date_parts(get_number(0)?).map_err(|_| formula_error(ERROR_NUMBER))?;
+            let month_index = year as i64 * 12 + month as i64 - 1 + get_number(1)?.trunc() as i64;
+            let last_day = get_last_day(month_index)?;
+            let day = if name == "EDATE" {
+                (day as i64).min(last_day)
+            } else {
+                last_day
+            };
+            get_serial_date(
+                month_index.div_euclid(12),
+                month_index.rem_euclid(12) + 1,
+                day,
+            )
+        }
+        "DAYS" => Ok(FormulaValue::Number(
+            get_number(0)?.floor() - get_number(1)?.floor(),
+        )),
+        _ => Err(anyhow!("Function {} is not supported", name)),
+    }
+}
+
+/// Values of the argument and whether it came from range or array
+fn get_argument_values(
+    context: &FormulaContext,
+    argument: &FormulaExpressionValues,
+) -> AnyResult<(Vec<FormulaValue>, bool), AnyError> {
+    let value = context.evaluate(argument)?;
+    let is_range =
+        matches!(value, FormulaValue::Array(_)) || context.get_reference_range(argument)?.is_some();
+    Ok((value.into_rows().into_iter().flatten().collect(), is_range))
+}
+
+/// Numbers of the arguments, text and bool inside the range are skipped
+fn get_aggregate_numbers(
+    context: &FormulaContext,
+    arguments: &[FormulaExpressionValues],
+) -> AnyResult<Vec<f64>, AnyError> {
+    let mut numbers = Vec::new();
+    for argument in arguments {
+        let (values, is_range) = get_argument_values(context, argument)?;
+        for value in values {
+            match value {
+                FormulaValue::Number(number) => numbers.push(number),
+                FormulaValue::Error(error_code) => return Err(formula_error(&error_code)),
+                FormulaValue::Empty => {}
+                value if !is_range => numbers.push(value.to_number()?),
+                _ => {}
+            }
+        }
+    }
+    Ok(numbers)
+}
+
+fn get_average(numbers: &[f64]) -> AnyResult<FormulaValue, AnyError> {
+    if numbers.is_empty() {
+        return Err(formula_error(ERROR_DIVIDE_BY_ZERO));
+    }
+    get_number_result(numbers.iter().sum::<f64>() / numbers.len() as f64)
+}
+
+/// Rows of the range resized to the shape of the other range from its top left cell
+fn get_resized_rows(
+    context: &FormulaContext,
+    argument: &FormulaExpressionValues,
+    shape_rows: &[Vec<FormulaValue>],
+) -> AnyResult<Vec<Vec<FormulaValue>>, AnyError> {
+    match context.get_reference_range(argument)? {
+        Some((sheet_index, ((start_row, start_col), _))) => {
+            let row_count = shape_rows.len() as u32;
+            let col_count = shape_rows.first().map_or(1, |row| row.len()) as u16;
+            Ok(context
+                .get_range_value(
+                    sheet_index,
+                    (
+                        (start_row, start_col),
+                        (start_row + row_count - 1, start_col + col_count - 1),
+                    ),
+                )?
+                .into_rows())
+        }
+        None => Ok(context.evaluate(argument)?.into_rows()),
+    }
+}
+
+/// Criteria ranges with the parsed criteria of the range and criteria argument pairs
+fn get_criteria_list(
+    context: &FormulaContext,
+    arguments: &[FormulaExpressionValues],
+) -> AnyResult<Vec<CriteriaRange>, AnyError> {
+    let mut criteria_list = Vec::new();
+    for pair in arguments.chunks(2) {
+        criteria_list.push((
+            context.evaluate(&pair[0])?.into_rows(),
+            FormulaCriteria::new(context.evaluate_scalar(&pair[1])?),
+        ));
+    }
+    Ok(criteria_list)
+}
+
+/// Numbers of the value cells whose position match all the criteria
+fn get_criteria_numbers(
+    value_rows: &[Vec<FormulaValue>],
+    criteria_list: &[CriteriaRange],
+) -> AnyResult<Vec<f64>, AnyError> {
+    let mut numbers = Vec::new();
+    for (row_index, row) in value_rows.iter().enumerate() {
+        for (col_index, value) in row.iter().enumerate() {
+            let mut is_match = true;
+            for (criteria_rows, criteria) in criteria_list {
+                let criteria_value = criteria_rows
+                    .get(row_index)
+                    .and_then(|row| row.get(col_index))
+                    .ok_or_else(|| formula_error(ERROR_VALUE))?;
+                if !criteria.is_match(criteria_value) {
+                    is_match = false;
+                    break;
+                }
+            }
+            if is_match {
+                match value {
+                    FormulaValue::Number(number) => numbers.push(*number),
+                    FormulaValue::Error(error_code) => return Err(formula_error(error_code)),
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(numbers)
+}
+
+/// Single lookup value, error value is raised
+fn get_lookup_value(
+    context: &FormulaContext,
+    argument: &FormulaExpressionValues,
+) -> AnyResult<FormulaValue, AnyError> {
+    match context.evaluate_scalar(argument)? {
+        FormulaValue::Error(error_code) => Err(formula_error(&error_code)),
+        value => Ok(value),
+    }
+}
+
+/// Single value of the line, array for the longer ones
+fn get_line_value(values: Vec<FormulaValue>) -> FormulaValue {
+    if values.len() == 1 {
+        values.into_iter().next().unwrap_or(FormulaValue::Empty)
+    } else {
+        FormulaValue::Array(vec![values])
+    }
+}
+
+fn transpose_rows(rows: Vec<Vec<FormulaValue>>) -> Vec<Vec<FormulaValue>> {
+    let col_count = rows.first().map_or(0, |row| row.len());
+    (0..col_count)
+        .map(|col_index| {
+            rows.iter()
+                .map(|row| row.get(col_index).cloned().unwrap_or(FormulaValue::Empty))
+                .collect()
+        })
+        .collect()
+}
+
+fn is_same_type(left: &FormulaValue, right: &FormulaValue) -> bool {
+    matches!(
+        (left, right),
+        (FormulaValue::Number(_), FormulaValue::Number(_))
+            | (FormulaValue::Text(_), FormulaValue::Text(_))
+            | (FormulaValue::Bool(_), FormulaValue::Bool(_))
+            | (FormulaValue::Empty, _)
+            | (_, FormulaValue::Empty)
+    )
+}
+
+/// Exact match with wildcard text
+fn is_exact_match(lookup_value: &FormulaValue, value: &FormulaValue) -> bool {
+    match (lookup_value, value) {
+        (FormulaValue::Text(pattern), FormulaValue::Text(text)) => is_wildcard_match(
+            &get_wildcard_pattern(pattern),
+            &text.to_lowercase().chars().collect::<Vec<_>>(),
+        ),
+        _ => {
+            is_same_type(lookup_value, value)
+                && !matches!(value, FormulaValue::Empty)
+                && compare_values(lookup_value, value) == Ordering::Equal
+        }
+    }
+}
+
+/// Position of the lookup value. Match type 0 is exact, 1 is largest value not above
+/// the lookup value in ascending values and -1 is smallest value not below in descending values
+fn find_match_position(
+    lookup_value: &FormulaValue,
+    values: &[FormulaValue],
+    match_type: i32,
+) -> Option<usize> {
+    if match_type == 0 {
+        return values
+            .iter()
+            .position(|value| is_exact_match(lookup_value, value));
+    }
+    let mut found_position = None;
+    for (position, value) in values.iter().enumerate() {
+        if matches!(value, FormulaValue::Empty) || !is_same_type(lookup_value, value) {
+            continue;
+        }
+        let ordering = compare_values(value, lookup_value);
+        if ordering == Ordering::Equal
+            || (match_type > 0 && ordering == Ordering::Less)
+            || (match_type < 0 && ordering == Ordering::Greater)
+        {
+            found_position = Some(position);
+        } else {
+            break;
+        }
+    }
+    found_position
+}
+
+/// Position of the lookup value. Match mode 0 is exact, -1 and 1 take the next smaller
+/// or larger value when there is no exact match and 2 is wildcard match
+fn find_xlookup_position(
+    lookup_value: &FormulaValue,
+    values: &[FormulaValue],
+    match_mode: i32,
+    is_reverse: bool,
+) -> Option<usize> {
+    let positions: Box<dyn Iterator<Item = usize>> = if is_reverse {
+        Box::new((0..values.len()).rev())
+    } else {
+        Box::new(0..values.len())
+    };
+    let mut closest_position: Option<usize> = None;
+    for position in positions {
+        let value = &values[position];
+        if matches!(value, FormulaValue::Empty) || !is_same_type(lookup_value, value) {
+            continue;
+        }
+        let ordering = compare_values(value, lookup_value);
+        if match_mode == 2 {
+            if is_exact_match(lookup_value, value) {
+                return Some(position);
+            }
+        } else if ordering == Ordering::Equal {
+            return Some(position);
+        }
+        let is_candidate = (match_mode == -1 && ordering == Ordering::Less)
+            || (match_mode == 1 && ordering == Ordering::Greater);
+        if is_candidate
+            && closest_position.is_none_or(|closest_position| {
+                compare_values(value, &values[closest_position])
+                    == if match_mode == -1 {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Less
+                    }
+            })
+        {
+            closest_position = Some(position);
+        }
+    }
+    closest_position
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum WildcardValues {
+    AnyText,
+    AnyCharacter,
+    Character(char),
+}
+
+/// Lowercase pattern of the text with * ? wildcards and ~ escape
+fn get_wildcard_pattern(text: &str) -> Vec<WildcardValues> {
+    let mut pattern = Vec::new();
+    let mut characters = text.chars().flat_map(|c| c.to_lowercase());
+    while let Some(character) = characters.next() {
+        pattern.push(match character {
+            '*' => WildcardValues::AnyText,
+            '?' => WildcardValues::AnyCharacter,
+            '~' => WildcardValues::Character(characters.next().unwrap_or('~')),
+            character => WildcardValues::Character(character),
+        });
+    }
+    pattern
+}
+
+fn is_wildcard_match(pattern: &[WildcardValues], text: &[char]) -> bool {
+    // matches[text_index] tell whether the pattern so far match the text up to the index
+    let mut matches = vec![false; text.len() + 1];
+    matches[0] = true;
+    for item in pattern {
+        let mut next_matches = vec![false; text.len() + 1];
+        for text_index in 0..=text.len() {
+            next_matches[text_index] = match item {
+                WildcardValues::AnyText => {
+                    matches[text_index] || (text_index > 0 && next_matches[text_index - 1])
+                }
+                WildcardValues::AnyCharacter => text_index > 0 && matches[text_index - 1],
+                WildcardValues::Character(character) => {
+                    text_index > 0 && matches[text_index - 1] && text[text_index - 1] == *character
+                }
+            };
+        }
+        matches = next_matches;
+    }
+    matches[text.len()]
+}
+
+/// Criteria of the *IF functions like ">10", "<>apple" or "a*"
+#[derive(Debug)]
+struct FormulaCriteria {
+    /// Orderings of the cell value against the criteria value that match
+    orderings: Vec<Ordering>,
+    is_not_equal: bool,
+    value: FormulaValue,
+}
+
+impl FormulaCriteria {
+    fn new(criteria: FormulaValue) -> Self {
+        let criteria_text = match criteria {
+            FormulaValue::Text(text) => text,
+            value => {
+                return Self {
+                    orderings: vec![Ordering::Equal],
+                    is_not_equal: false,
+                    value,
+                }
+            }
+        };
+        let (orderings, is_not_equal, value_text) = [
+            ("<=", vec![Ordering::Less, Ordering::Equal], false),
+            (">=", vec![Ordering::Greater, Ordering::Equal], false),
+            ("<>", vec![Ordering::Equal], true),
+            ("<", vec![Ordering::Less], false),
+            (">", vec![Ordering::Greater], false),
+            ("=", vec![Ordering::Equal], false),
+        ]
+        .into_iter()
+        .find_map(|(prefix, orderings, is_not_equal)| {
+            criteria_text
+                .strip_prefix(prefix)
+                .map(|value_text| (orderings, is_not_equal, value_text))
+        })
+        .unwrap_or((vec![Ordering::Equal], false, criteria_text.as_str()));
+        let value = if value_text.is_empty() {
+            FormulaValue::Empty
+        } else if let Some(number) = parse_number_text(value_text) {
+            FormulaValue::Number(number)
+        } else {
+            match value_text.to_uppercase().as_str() {
+                "TRUE" => FormulaValue::Bool(true),
+                "FALSE" => FormulaValue::Bool(false),
+                _ => FormulaValue::Text(value_text.to_string()),
+            }
+        };
+        Self {
+            orderings,
+            is_not_equal,
+            value,
+        }
+    }
+
+    fn is_match(&self, cell_value: &FormulaValue) -> bool {
+        let is_equal_only = self.orderings == [Ordering::Equal];
+        let is_match = match &self.value {
+            // Empty criteria match the blank cells
+            FormulaValue::Empty => match cell_value {
+                FormulaValue::Empty => true,
+                FormulaValue::Text(text) => text.is_empty() && !self.is_not_equal,
+                _ => false,
+            },
+            FormulaValue::Number(number) if is_equal_only => match cell_value {
+                FormulaValue::Number(cell_number) => cell_number == number,
+                FormulaValue::Text(text) => parse_number_text(text) == Some(*number),
+                _ => false,
+            },
+            FormulaValue::Text(_) if is_equal_only => is_exact_match(&self.value, cell_value),
+            value => {
+                is_same_type(value, cell_value)
+                    && !matches!(cell_value, FormulaValue::Empty)
+                    && self.orderings.contains(&compare_values(cell_value, value))
+            }
+        };
+        is_match != self.is_not_equal
+    }
+}
+
+/// Serial date of the year, month and day, overflowing month and day roll over
+fn get_serial_date(year: i64, month: i64, day: i64) -> AnyResult<FormulaValue, AnyError> {
+    let month_index = year * 12 + month - 1;
+    let first_date = i32::try_from(month_index.div_euclid(12))
+        .ok()
+        .and_then(|year| NaiveDate::from_ymd_opt(year, month_index.rem_euclid(12) as u32 + 1, 1))
+        .ok_or_else(|| formula_error(ERROR_NUMBER))?;
+    // Days are added to the serial so excel's phantom 1900-02-29 stays in the calendar
+    let serial_date =
+        ConverterUtil::get_excel_serial_date(&first_date.and_time(Default::default()))
+            .map_err(|_| formula_error(ERROR_NUMBER))?
+            + (day - 1) as f64;
+    if !(0.0..2_958_466.0).contains(&serial_date) {
+        return Err(formula_error(ERROR_NUMBER));
+    }
+    Ok(FormulaValue::Number(serial_date))
+}
+
+/// Last day of the month counted from year zero
+fn get_last_day(month_index: i64) -> AnyResult<i64, AnyError> {
+    // Excel treat 1900 as leap year
+    if month_index == 1900 * 12 + 1 {
+        return Ok(29);
+    }
+    let next_month_index = month_index + 1;
+    i32::try_from(next_month_index.div_euclid(12))
+        .ok()
+        .and_then(|year| {
+            NaiveDate::from_ymd_opt(year, next_month_index.rem_euclid(12) as u32 + 1, 1)
+        })
+        .and_then(|date| date.pred_opt())
+        .map(|date| date.day() as i64)
+        .ok_or_else(|| formula_error(ERROR_NUMBER))
+}
@@ -0,0 +1,9 @@
+pub(crate) mod evaluator;
+pub(crate) mod functions;
+pub(crate) mod parser;
+pub(crate) mod text_format;
+
+pub(crate) use evaluator::*;
+pub(crate) use functions::*;
+pub(crate) use parser::*;
+pub(crate) use text_format::*;
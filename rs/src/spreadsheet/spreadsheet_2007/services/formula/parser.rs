@@ -0,0 +1,1168 @@
+use crate::{
+    converters::ConverterUtil, global_2007::traits::Enum,
+    spreadsheet_2007::services::formula::check_argument_count,
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+
+/// Last row of the worksheet
+pub(crate) const MAX_ROW_INDEX: u32 = 1_048_576;
+/// Last column (XFD) of the worksheet
+pub(crate) const MAX_COL_INDEX: u16 = 16_384;
+/// Error values excel accept in the formula
+const ERROR_VALUES: [&str; 10] = [
+    "#NULL!",
+    "#DIV/0!",
+    "#VALUE!",
+    "#REF!",
+    "#NAME?",
+    "#NUM!",
+    "#N/A",
+    "#GETTING_DATA",
+    "#SPILL!",
+    "#CALC!",
+];
+
+/// Operator of the formula expression
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FormulaOperatorValues {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+    Concatenate,
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    Percent,
+    /// Range between two references like A1:INDEX(B1:B3,2)
+    Range,
+    /// Union of the references inside the bracket like (A1,B1)
+    Union,
+    /// Intersection of the references separated by space like A1:A3 A2:A4
+    Intersection,
+    /// Spilled range of the dynamic array formula like A1#
+    Spill,
+    /// Implicit intersection like @A1:A10
+    ImplicitIntersection,
+}
+
+impl Enum<FormulaOperatorValues> for FormulaOperatorValues {
+    fn get_string(input_enum: FormulaOperatorValues) -> String {
+        match input_enum {
+            FormulaOperatorValues::Add => "+".to_string(),
+            FormulaOperatorValues::Subtract => "-".to_string(),
+            FormulaOperatorValues::Multiply => "*".to_string(),
+            FormulaOperatorValues::Divide => "/".to_string(),
+            FormulaOperatorValues::Power => "^".to_string(),
+            FormulaOperatorValues::Concatenate => "&".to_string(),
+            FormulaOperatorValues::Equal => "=".to_string(),
+            FormulaOperatorValues::NotEqual => "<>".to_string(),
+            FormulaOperatorValues::LessThan => "<".to_string(),
+            FormulaOperatorValues::LessThanOrEqual => "<=".to_string(),
+            FormulaOperatorValues::GreaterThan => ">".to_string(),
+            FormulaOperatorValues::GreaterThanOrEqual => ">=".to_string(),
+            FormulaOperatorValues::Percent => "%".to_string(),
+            FormulaOperatorValues::Range => ":".to_string(),
+            FormulaOperatorValues::Union => ",".to_string(),
+            FormulaOperatorValues::Intersection => " ".to_string(),
+            FormulaOperatorValues::Spill => "#".to_string(),
+            FormulaOperatorValues::ImplicitIntersection => "@".to_string(),
+        }
+    }
+    fn get_enum(input_string: &str) -> FormulaOperatorValues {
+        match input_string {
+            "-" => FormulaOperatorValues::Subtract,
+            "*" => FormulaOperatorValues::Multiply,
+            "/" => FormulaOperatorValues::Divide,
+            "^" => FormulaOperatorValues::Power,
+            "&" => FormulaOperatorValues::Concatenate,
+            "=" => FormulaOperatorValues::Equal,
+            "<>" => FormulaOperatorValues::NotEqual,
+            "<" => FormulaOperatorValues::LessThan,
+            "<=" => FormulaOperatorValues::LessThanOrEqual,
+            ">" => FormulaOperatorValues::GreaterThan,
+            ">=" => FormulaOperatorValues::GreaterThanOrEqual,
+            "%" => FormulaOperatorValues::Percent,
+            ":" => FormulaOperatorValues::Range,
+            "," => FormulaOperatorValues::Union,
+            " " => FormulaOperatorValues::Intersection,
+            "#" => FormulaOperatorValues::Spill,
+            "@" => FormulaOperatorValues::ImplicitIntersection,
+            _ => FormulaOperatorValues::Add,
+        }
+    }
+}
+
+impl FormulaOperatorValues {
+    /// Binding strength of the binary operator, higher bind first
+    fn get_precedence(&self) -> u8 {
+        match self {
+            FormulaOperatorValues::Equal
+            | FormulaOperatorValues::NotEqual
+            | FormulaOperatorValues::LessThan
+            | FormulaOperatorValues::LessThanOrEqual
+            | FormulaOperatorValues::GreaterThan
+            | FormulaOperatorValues::GreaterThanOrEqual => 1,
+            FormulaOperatorValues::Concatenate => 2,
+            FormulaOperatorValues::Add | FormulaOperatorValues::Subtract => 3,
+            FormulaOperatorValues::Multiply | FormulaOperatorValues::Divide => 4,
+            FormulaOperatorValues::Power => 5,
+            FormulaOperatorValues::Percent => 6,
+            FormulaOperatorValues::Union => 8,
+            FormulaOperatorValues::Intersection => 9,
+            FormulaOperatorValues::Range => 10,
+            FormulaOperatorValues::Spill | FormulaOperatorValues::ImplicitIntersection => 11,
+        }
+    }
+
+    /// Operator written after the operand
+    fn is_postfix(&self) -> bool {
+        matches!(
+            self,
+            FormulaOperatorValues::Percent | FormulaOperatorValues::Spill
+        )
+    }
+}
+
+/// Binding strength of the sign operator, excel apply it before the power
+const SIGN_PRECEDENCE: u8 = 7;
+/// Reference operators bind before any other operator
+const REFERENCE_PRECEDENCE: u8 = 8;
+/// Deepest nesting of brackets and functions excel accept
+const MAX_NESTING_LEVEL: usize = 64;
+
+/// Row and column of the reference, None row or column for the full column or full row reference
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FormulaCellReference {
+    pub(crate) row: Option<u32>,
+    pub(crate) col: Option<u16>,
+    pub(crate) is_row_absolute: bool,
+    pub(crate) is_col_absolute: bool,
+}
+
+/// Cell, range, full row or full column reference with the optional sheet prefix
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FormulaReference {
+    /// Sheet name as written without the quotes, like Sheet1:Sheet3 or [1]Sheet1 for the 3D and external reference
+    pub(crate) sheet_name: Option<String>,
+    pub(crate) start: FormulaCellReference,
+    pub(crate) end: Option<FormulaCellReference>,
+}
+
+/// Token of the formula text
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FormulaTokenValues {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(String),
+    Reference(FormulaReference),
+    /// Defined name with the optional sheet prefix
+    Name {
+        sheet_name: Option<String>,
+        name: String,
+    },
+    /// Table reference like Table1[Column] kept as written
+    StructuredReference(String),
+    /// Function name, the opening bracket is part of the token
+    Function(String),
+    Operator(FormulaOperatorValues),
+    OpenBracket,
+    CloseBracket,
+    /// Argument or array column separator
+    Separator,
+    /// Array row separator
+    RowSeparator,
+    OpenBrace,
+    CloseBrace,
+}
+
+/// Parsed formula expression
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FormulaExpressionValues {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(String),
+    Reference(FormulaReference),
+    Name {
+        sheet_name: Option<String>,
+        name: String,
+    },
+    StructuredReference(String),
+    Function {
+        name: String,
+        arguments: Vec<FormulaExpressionValues>,
+    },
+    /// Sign, percent, spill or implicit intersection operator
+    Unary {
+        operator: FormulaOperatorValues,
+        operand: Box<FormulaExpressionValues>,
+    },
+    Binary {
+        operator: FormulaOperatorValues,
+        left: Box<FormulaExpressionValues>,
+        right: Box<FormulaExpressionValues>,
+    },
+    /// Array constant like {1,2;3,4} as rows
+    Array(Vec<Vec<FormulaExpressionValues>>),
+    /// Skipped function argument like the second one of IF(A1,,1)
+    Empty,
+}
+
+/// Token with its start and end position in the formula text
+type TokenSpan = (FormulaTokenValues, usize, usize);
+
+pub(crate) struct FormulaParser;
+
+impl FormulaParser {
+    /// Split the formula text with or without the leading "=" into tokens
+    pub(crate) fn tokenize(formula: &str) -> AnyResult<Vec<FormulaTokenValues>, AnyError> {
+        let (_, characters) = get_formula_characters(formula);
+        Ok(read_tokens(&characters, None)?
+            .into_iter()
+            .map(|(token, _, _)| token)
+            .collect())
+    }
+
+    /// Parse the A1 style formula text with or without the leading "=" into expression
+    pub(crate) fn parse(formula: &str) -> AnyResult<FormulaExpressionValues, AnyError> {
+        parse_tokens(Self::tokenize(formula).context("Failed to tokenize the formula")?)
+    }
+
+    /// Convert the A1 style formula of the given cell to R1C1 style, rest of the text is kept as written
+    pub(crate) fn convert_to_r1c1(formula: &str, cell_ref: &str) -> AnyResult<String, AnyError> {
+        let base_cell = ConverterUtil::get_cell_index(cell_ref).context("Invalid formula cell")?;
+        replace_references(formula, None, |reference| {
+            Ok(get_r1c1_text(reference, base_cell))
+        })
+    }
+
+    /// Convert the R1C1 style formula of the given cell to A1 style, rest of the text is kept as written
+    pub(crate) fn convert_to_a1(formula: &str, cell_ref: &str) -> AnyResult<String, AnyError> {
+        let base_cell = ConverterUtil::get_cell_index(cell_ref).context("Invalid formula cell")?;
+        replace_references(formula, Some(base_cell), get_a1_text)
+    }
+}
+
+/// Leading "=" if present along with the rest of the formula characters
+fn get_formula_characters(formula: &str) -> (&str, Vec<char>) {
+    let formula = formula.trim();
+    match formula.strip_prefix('=') {
+        Some(formula) => ("=", formula.chars().collect()),
+        None => ("", formula.chars().collect()),
+    }
+}
+
+fn parse_tokens(tokens: Vec<FormulaTokenValues>) -> AnyResult<FormulaExpressionValues, AnyError> {
+    if tokens.is_empty() {
+        return Err(anyhow!("Formula is empty"));
+    }
+    let mut token_reader = TokenReader {
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let expression = token_reader.parse_expression(0)?;
+    if let Some(token) = token_reader.peek() {
+        return Err(anyhow!("Unexpected token {:?}", token));
+    }
+    Ok(expression)
+}
+
+/// Rewrite the reference tokens of the formula keeping the sheet prefix as written
+fn replace_references(
+    formula: &str,
+    base_cell: Option<(u32, u16)>,
+    get_text: impl Fn(&FormulaReference) -> AnyResult<String, AnyError>,
+) -> AnyResult<String, AnyError> {
+    let (prefix, characters) = get_formula_characters(formula);
+    let tokens = read_tokens(&characters, base_cell).context("Failed to tokenize the formula")?;
+    let mut formula_text = prefix.to_string();
+    let mut last_position = 0;
+    for (token, start_position, end_position) in tokens {
+        if let FormulaTokenValues::Reference(reference) = token {
+            formula_text.extend(&characters[last_position..start_position]);
+            let reference_text: String = characters[start_position..end_position].iter().collect();
+            if let Some(sheet_end) = reference_text.rfind('!') {
+                formula_text.push_str(&reference_text[..=sheet_end]);
+            }
+            formula_text.push_str(&get_text(&reference)?);
+            last_position = end_position;
+        }
+    }
+    formula_text.extend(&characters[last_position..]);
+    Ok(formula_text)
+}
+
+/// A1 text of the reference without the sheet prefix
+fn get_a1_text(reference: &FormulaReference) -> AnyResult<String, AnyError> {
+    let start_text = get_a1_cell_text(&reference.start)?;
+    match &reference.end {
+        Some(end) => Ok(format!("{}:{}", start_text, get_a1_cell_text(end)?)),
+        None => Ok(start_text),
+    }
+}
+
+fn get_a1_cell_text(cell_reference: &FormulaCellReference) -> AnyResult<String, AnyError> {
+    let mut text = String::new();
+    if let Some(col) = cell_reference.col {
+        if cell_reference.is_col_absolute {
+            text.push('$');
+        }
+        text.push_str(&ConverterUtil::get_column_ref(col)?);
+    }
+    if let Some(row) = cell_reference.row {
+        if cell_reference.is_row_absolute {
+            text.push('$');
+        }
+        text.push_str(&row.to_string());
+    }
+    Ok(text)
+}
+
+/// R1C1 text of the reference without the sheet prefix, single full row or column is written as R2 or C3
+fn get_r1c1_text(reference: &FormulaReference, base_cell: (u32, u16)) -> String {
+    let start_text = get_r1c1_cell_text(&reference.start, base_cell);
+    match &reference.end {
+        Some(end)
+            if *end == reference.start
+                && (reference.start.row.is_none() || reference.start.col.is_none()) =>
+        {
+            start_text
+        }
+        Some(end) => format!("{}:{}", start_text, get_r1c1_cell_text(end, base_cell)),
+        None => start_text,
+    }
+}
+
+fn get_r1c1_cell_text(
+    cell_reference: &FormulaCellReference,
+    (base_row, base_col): (u32, u16),
+) -> String {
+    let mut text = String::new();
+    if let Some(row) = cell_reference.row {
+        text.push_str(&get_r1c1_part_text(
+            'R',
+            row as i64,
+            base_row as i64,
+            cell_reference.is_row_absolute,
+        ));
+    }
+    if let Some(col) = cell_reference.col {
+        text.push_str(&get_r1c1_part_text(
+            'C',
+            col as i64,
+            base_col as i64,
+            cell_reference.is_col_absolute,
+        ));
+    }
+    text
+}
+
+fn get_r1c1_part_text(marker: char, index: i64, base_index: i64, is_absolute: bool) -> String {
+    match index - base_index {
+        _ if is_absolute => format!("{}{}", marker, index),
+        0 => marker.to_string(),
+        offset => format!("{}[{}]", marker, offset),
+    }
+}
+
+/// Split the formula characters into tokens, R1C1 style reference is read when the base cell is given
+fn read_tokens(
+    characters: &[char],
+    base_cell: Option<(u32, u16)>,
+) -> AnyResult<Vec<TokenSpan>, AnyError> {
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    while let Some(character) = characters.get(position).copied() {
+        if character.is_whitespace() {
+            let next_position = characters[position..]
+                .iter()
+                .position(|character| !character.is_whitespace())
+                .map_or(characters.len(), |offset| position + offset);
+            // Space between two references is the intersection operator
+            if is_reference_end(tokens.last())
+                && characters.get(next_position).is_some_and(|character| {
+                    is_word_character(*character) || matches!(character, '\'' | '(' | '[')
+                })
+            {
+                tokens.push((
+                    FormulaTokenValues::Operator(FormulaOperatorValues::Intersection),
+                    position,
+                    next_position,
+                ));
+            }
+            position = next_position;
+            continue;
+        }
+        let (token, next_position) = match character {
+            '"' => {
+                let (text, next_position) = read_quoted(characters, position, '"')?;
+                (FormulaTokenValues::Text(text), next_position)
+            }
+            '#' if tokens.last().is_some_and(|(token, _, end_position)| {
+                *end_position == position
+                    && matches!(
+                        token,
+                        FormulaTokenValues::Reference(_) | FormulaTokenValues::Name { .. }
+                    )
+            }) =>
+            {
+                (
+                    FormulaTokenValues::Operator(FormulaOperatorValues::Spill),
+                    position + 1,
+                )
+            }
+            '#' => {
+                let remaining: String = characters[position..].iter().collect();
+                let error_value = ERROR_VALUES
+                    .iter()
+                    .find(|error_value| {
+                        remaining
+                            .to_uppercase()
+                            .starts_with(&error_value.to_string())
+                    })
+                    .ok_or(anyhow!("Unknown error value at position {}", position))?;
+                (
+                    FormulaTokenValues::Error(error_value.to_string()),
+                    position + error_value.chars().count(),
+                )
+            }
+            '(' => (FormulaTokenValues::OpenBracket, position + 1),
+            ')' => (FormulaTokenValues::CloseBracket, position + 1),
+            ',' => (FormulaTokenValues::Separator, position + 1),
+            ';' => (FormulaTokenValues::RowSeparator, position + 1),
+            '{' => (FormulaTokenValues::OpenBrace, position + 1),
+            '}' => (FormulaTokenValues::CloseBrace, position + 1),
+            '+' | '-' | '*' | '/' | '^' | '&' | '%' | '=' | ':' | '@' => (
+                FormulaTokenValues::Operator(FormulaOperatorValues::get_enum(
+                    &character.to_string(),
+                )),
+                position + 1,
+            ),
+            '<' | '>' => {
+                let operator = match characters.get(position + 1) {
+                    Some('=') => format!("{}=", character),
+                    Some('>') if character == '<' => "<>".to_string(),
+                    _ => character.to_string(),
+                };
+                (
+                    FormulaTokenValues::Operator(FormulaOperatorValues::get_enum(&operator)),
+                    position + operator.len(),
+                )
+            }
+            '\'' => {
+                let (sheet_name, next_position) = read_quoted(characters, position, '\'')?;
+                if characters.get(next_position) != Some(&'!') {
+                    return Err(anyhow!(
+                        "Quoted sheet name need the reference at position {}",
+                        position
+                    ));
+                }
+                read_sheet_item(characters, next_position + 1, sheet_name, base_cell)?
+            }
+            _ if character.is_ascii_digit() || character == '.' => {
+                let (word, next_position) = read_word(characters, position);
+                // Full row range like 1:3, R1C1 style write it as R1:R3
+                if base_cell.is_none() && characters.get(next_position) == Some(&':') {
+                    read_reference(characters, position, None)?
+                } else {
+                    let (number, next_position) = read_number(characters, position)
+                        .context(format!("Invalid number : {}", word))?;
+                    (FormulaTokenValues::Number(number), next_position)
+                }
+            }
+            _ if is_word_character(character) || character == '[' => {
+                read_word_item(characters, position, base_cell)?
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Unexpected character {} at position {}",
+                    character,
+                    position
+                ))
+            }
+        };
+        tokens.push((token, position, next_position));
+        position = next_position;
+    }
+    Ok(tokens)
+}
+
+/// Token the reference operator can follow
+fn is_reference_end(token: Option<&TokenSpan>) -> bool {
+    matches!(
+        token,
+        Some((
+            FormulaTokenValues::Reference(_)
+                | FormulaTokenValues::Name { .. }
+                | FormulaTokenValues::StructuredReference(_)
+                | FormulaTokenValues::CloseBracket,
+            _,
+            _
+        ))
+    )
+}
+
+struct TokenReader {
+    tokens: Vec<FormulaTokenValues>,
+    position: usize,
+    /// Nesting level of the operand being read
+    depth: usize,
+}
+
+impl TokenReader {
+    fn peek(&self) -> Option<&FormulaTokenValues> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<FormulaTokenValues> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: FormulaTokenValues) -> AnyResult<(), AnyError> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(anyhow!("Expected {:?} found {:?}", expected, token)),
+            None => Err(anyhow!("Expected {:?} found end of formula", expected)),
+        }
+    }
+
+    fn parse_expression(
+        &mut self,
+        min_precedence: u8,
+    ) -> AnyResult<FormulaExpressionValues, AnyError> {
+        let mut expression = self.parse_operand()?;
+        while let Some(FormulaTokenValues::Operator(operator)) = self.peek().cloned() {
+            let precedence = operator.get_precedence();
+            // Implicit intersection is written only before the operand
+            if precedence < min_precedence
+                || operator == FormulaOperatorValues::ImplicitIntersection
+            {
+                break;
+            }
+            self.position += 1;
+            expression = if operator.is_postfix() {
+                FormulaExpressionValues::Unary {
+                    operator,
+                    operand: Box::new(expression),
+                }
+            } else {
+                // Same precedence operators evaluate left to right
+                FormulaExpressionValues::Binary {
+                    operator,
+                    left: Box::new(expression),
+                    right: Box::new(self.parse_expression(precedence + 1)?),
+                }
+            };
+        }
+        Ok(expression)
+    }
+
+    /// Operand inside the bracket, function or sign goes one level deeper
+    fn parse_operand(&mut self) -> AnyResult<FormulaExpressionValues, AnyError> {
+        if self.depth > MAX_NESTING_LEVEL {
+            return Err(anyhow!(
+                "Formula is nested deeper than {} levels",
+                MAX_NESTING_LEVEL
+            ));
+        }
+        self.depth += 1;
+        let operand = self.read_operand();
+        self.depth -= 1;
+        operand
+    }
+
+    fn read_operand(&mut self) -> AnyResult<FormulaExpressionValues, AnyError> {
+        match self.next() {
+            Some(FormulaTokenValues::Operator(
+                operator @ (FormulaOperatorValues::Add | FormulaOperatorValues::Subtract),
+            )) => Ok(FormulaExpressionValues::Unary {
+                operator,
+                operand: Box::new(self.parse_expression(SIGN_PRECEDENCE)?),
+            }),
+            Some(FormulaTokenValues::Operator(
+                operator @ FormulaOperatorValues::ImplicitIntersection,
+            )) => Ok(FormulaExpressionValues::Unary {
+                operator,
+                operand: Box::new(self.parse_expression(REFERENCE_PRECEDENCE)?),
+            }),
+            Some(FormulaTokenValues::Number(number)) => Ok(FormulaExpressionValues::Number(number)),
+            Some(FormulaTokenValues::Text(text)) => Ok(FormulaExpressionValues::Text(text)),
+            Some(FormulaTokenValues::Bool(value)) => Ok(FormulaExpressionValues::Bool(value)),
+            Some(FormulaTokenValues::Error(error)) => Ok(FormulaExpressionValues::Error(error)),
+            Some(FormulaTokenValues::Reference(reference)) => {
+                Ok(FormulaExpressionValues::Reference(reference))
+            }
+            Some(FormulaTokenValues::Name { sheet_name, name }) => {
+                Ok(FormulaExpressionValues::Name { sheet_name, name })
+            }
+            Some(FormulaTokenValues::StructuredReference(reference)) => {
+                Ok(FormulaExpressionValues::StructuredReference(reference))
+            }
+            Some(FormulaTokenValues::Function(name)) => {
+                let mut arguments = Vec::new();
+                if self.peek() == Some(&FormulaTokenValues::CloseBracket) {
+                    self.position += 1;
+                    check_argument_count(&name, arguments.len())?;
+                    return Ok(FormulaExpressionValues::Function { name, arguments });
+                }
+                loop {
+                    arguments.push(match self.peek() {
+                        Some(FormulaTokenValues::Separator | FormulaTokenValues::CloseBracket) => {
+                            FormulaExpressionValues::Empty
+                        }
+                        _ => self.parse_expression(0)?,
+                    });
+                    match self.next() {
+                        Some(FormulaTokenValues::Separator) => continue,
+                        Some(FormulaTokenValues::CloseBracket) => break,
+                        _ => return Err(anyhow!("Function {} is not closed", name)),
+                    }
+                }
+                check_argument_count(&name, arguments.len())?;
+                Ok(FormulaExpressionValues::Function { name, arguments })
+            }
+            Some(FormulaTokenValues::OpenBracket) => {
+                let mut expression = self.parse_expression(0)?;
+                // Comma inside the plain bracket is the union operator like (A1,B1)
+                while self.peek() == Some(&FormulaTokenValues::Separator) {
+                    self.position += 1;
+                    expression = FormulaExpressionValues::Binary {
+                        operator: FormulaOperatorValues::Union,
+                        left: Box::new(expression),
+                        right: Box::new(self.parse_expression(0)?),
+                    };
+                }
+                self.expect(FormulaTokenValues::CloseBracket)?;
+                Ok(expression)
+            }
+            Some(FormulaTokenValues::OpenBrace) => {
+                let mut rows = vec![Vec::new()];
+                loop {
+                    rows.last_mut()
+                        .ok_or(anyhow!("Failed to get array row"))?
+                        .push(self.parse_array_item()?);
+                    match self.next() {
+                        Some(FormulaTokenValues::Separator) => {}
+                        Some(FormulaTokenValues::RowSeparator) => rows.push(Vec::new()),
+                        Some(FormulaTokenValues::CloseBrace) => break,
+                        _ => return Err(anyhow!("Array constant is not closed")),
+                    }
+                }
+                if rows.iter().any(|row| row.len() != rows[0].len()) {
+                    return Err(anyhow!("Array constant rows must have the same length"));
+                }
+                Ok(FormulaExpressionValues::Array(rows))
+            }
+            Some(token) => Err(anyhow!("Unexpected token {:?}", token)),
+            None => Err(anyhow!("Formula ended before the operand")),
+        }
+    }
+
+    /// Array constant accept only literal value
+    fn parse_array_item(&mut self) -> AnyResult<FormulaExpressionValues, AnyError> {
+        match self.next() {
+            Some(FormulaTokenValues::Operator(FormulaOperatorValues::Subtract)) => {
+                match self.next() {
+                    Some(FormulaTokenValues::Number(number)) => {
+                        Ok(FormulaExpressionValues::Number(-number))
+                    }
+                    _ => Err(anyhow!("Array constant sign need a number")),
+                }
+            }
+            Some(FormulaTokenValues::Number(number)) => Ok(FormulaExpressionValues::Number(number)),
+            Some(FormulaTokenValues::Text(text)) => Ok(FormulaExpressionValues::Text(text)),
+            Some(FormulaTokenValues::Bool(value)) => Ok(FormulaExpressionValues::Bool(value)),
+            Some(FormulaTokenValues::Error(error)) => Ok(FormulaExpressionValues::Error(error)),
+            _ => Err(anyhow!("Array constant accept only literal values")),
+        }
+    }
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || matches!(character, '_' | '.' | '\\' | '$' | '?')
+}
+
+/// Read name like text along with the external workbook prefix like [1]
+fn read_word(characters: &[char], mut position: usize) -> (String, usize) {
+    let start_position = position;
+    if characters.get(position) == Some(&'[') {
+        while let Some(character) = characters.get(position) {
+            position += 1;
+            if *character == ']' {
+                break;
+            }
+        }
+    }
+    while characters
+        .get(position)
+        .is_some_and(|character| is_word_character(*character))
+    {
+        position += 1;
+    }
+    (
+        characters[start_position..position].iter().collect(),
+        position,
+    )
+}
+
+/// Read text between the quote, doubled quote is the escaped quote
+fn read_quoted(
+    characters: &[char],
+    position: usize,
+    quote: char,
+) -> AnyResult<(String, usize), AnyError> {
+    let mut text = String::new();
+    let mut position = position + 1;
+    loop {
+        match characters.get(position) {
+            Some(character) if *character == quote => {
+                if characters.get(position + 1) == Some(&quote) {
+                    text.push(quote);
+                    position += 2;
+                } else {
+                    return Ok((text, position + 1));
+                }
+            }
+            Some(character) => {
+                text.push(*character);
+                position += 1;
+            }
+            None => return Err(anyhow!("Missing closing {}", quote)),
+        }
+    }
+}
+
+/// Position after the matching closing bracket of the structured reference
+fn read_bracket_end(characters: &[char], mut position: usize) -> AnyResult<usize, AnyError> {
+    let mut depth = 0;
+    while let Some(character) = characters.get(position) {
+        position += 1;
+        match character {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(position);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(anyhow!("Structured reference is not closed"))
+}
+
+fn read_number(characters: &[char], mut position: usize) -> AnyResult<(f64, usize), AnyError> {
+    let start_position = position;
+    while characters
+        .get(position)
+        .is_some_and(|character| character.is_ascii_digit() || *character == '.')
+    {
+        position += 1;
+    }
+    if characters
+        .get(position)
+        .is_some_and(|character| matches!(character, 'e' | 'E'))
+    {
+        let mut exponent_position = position + 1;
+        if characters
+            .get(exponent_position)
+            .is_some_and(|character| matches!(character, '+' | '-'))
+        {
+            exponent_position += 1;
+        }
+        if characters
+            .get(exponent_position)
+            .is_some_and(|character| character.is_ascii_digit())
+        {
+            position = exponent_position;
+            while characters
+                .get(position)
+                .is_some_and(|character| character.is_ascii_digit())
+            {
+                position += 1;
+            }
+        }
+    }
+    let number_text: String = characters[start_position..position].iter().collect();
+    Ok((number_text.parse()?, position))
+}
+
+/// Read the reference, name, function or structured reference starting at the word character
+fn read_word_item(
+    characters: &[char],
+    position: usize,
+    base_cell: Option<(u32, u16)>,
+) -> AnyResult<(FormulaTokenValues, usize), AnyError> {
+    if let Some(base_cell) = base_cell {
+        if let Some(item) = read_r1c1_reference(characters, position, None, base_cell)? {
+            return Ok(item);
+        }
+    }
+    let (word, next_position) = read_word(characters, position);
+    if characters[position] == '[' && characters.get(next_position) != Some(&'!') {
+        // Table relative reference like [@Column]
+        let next_position = read_bracket_end(characters, position)?;
+        return Ok((
+            FormulaTokenValues::StructuredReference(
+                characters[position..next_position].iter().collect(),
+            ),
+            next_position,
+        ));
+    }
+    match characters.get(next_position) {
+        Some('!') => read_sheet_item(characters, next_position + 1, word, base_cell),
+        Some('(') => Ok((
+            FormulaTokenValues::Function(word.to_uppercase()),
+            next_position + 1,
+        )),
+        Some('[') => {
+            let next_position = read_bracket_end(characters, next_position)?;
+            Ok((
+                FormulaTokenValues::StructuredReference(
+                    characters[position..next_position].iter().collect(),
+                ),
+                next_position,
+            ))
+        }
+        Some(':') => {
+            let (after_word, after_position) = read_word(characters, next_position + 1);
+            if characters.get(after_position) == Some(&'!') {
+                // Sheet range like Sheet1:Sheet3!A1
+                read_sheet_item(
+                    characters,
+                    after_position + 1,
+                    format!("{}:{}", word, after_word),
+                    base_cell,
+                )
+            } else if is_range_text(characters, position, base_cell) {
+                read_reference(characters, position, None)
+            } else {
+                // Range end like INDEX(B1:B3,2) is read after the range operator
+                Ok((
+                    get_word_token(word, None, base_cell.is_some())?,
+                    next_position,
+                ))
+            }
+        }
+        _ => Ok((
+            get_word_token(word, None, base_cell.is_some())?,
+            next_position,
+        )),
+    }
+}
+
+/// Both side of the colon are A1 style cell, row or column reference
+fn is_range_text(characters: &[char], position: usize, base_cell: Option<(u32, u16)>) -> bool {
+    let (start_word, next_position) = read_word(characters, position);
+    let (end_word, _) = read_word(characters, next_position + 1);
+    base_cell.is_none()
+        && parse_cell_reference(&start_word).is_some()
+        && parse_cell_reference(&end_word).is_some()
+}
+
+/// Read the reference or defined name following the sheet prefix
+fn read_sheet_item(
+    characters: &[char],
+    position: usize,
+    sheet_name: String,
+    base_cell: Option<(u32, u16)>,
+) -> AnyResult<(FormulaTokenValues, usize), AnyError> {
+    if let Some(base_cell) = base_cell {
+        if let Some(item) =
+            read_r1c1_reference(characters, position, Some(sheet_name.clone()), base_cell)?
+        {
+            return Ok(item);
+        }
+    }
+    let (word, next_position) = read_word(characters, position);
+    if word.is_empty() {
+        return Err(anyhow!("Sheet {} need the reference", sheet_name));
+    }
+    if characters.get(next_position) == Some(&':') && is_range_text(characters, position, base_cell)
+    {
+        read_reference(characters, position, Some(sheet_name))
+    } else {
+        match get_word_token(word, Some(sheet_name), base_cell.is_some())? {
+            token @ (FormulaTokenValues::Reference(_) | FormulaTokenValues::Name { .. }) => {
+                Ok((token, next_position))
+            }
+            token => Err(anyhow!("Unexpected token {:?} after the sheet", token)),
+        }
+    }
+}
+
+/// Read R1C1 style cell, range, full row or full column, None when the text is not a reference
+fn read_r1c1_reference(
+    characters: &[char],
+    position: usize,
+    sheet_name: Option<String>,
+    base_cell: (u32, u16),
+) -> AnyResult<Option<(FormulaTokenValues, usize)>, AnyError> {
+    let Some((start, next_position)) = read_r1c1_cell(characters, position, base_cell)? else {
+        return Ok(None);
+    };
+    if characters.get(next_position) != Some(&':') {
+        // Single R2 or C3 is the full row or full column
+        let end = (start.row.is_none() || start.col.is_none()).then(|| start.clone());
+        return Ok(Some((
+            FormulaTokenValues::Reference(FormulaReference {
+                sheet_name,
+                start,
+                end,
+            }),
+            next_position,
+        )));
+    }
+    match read_r1c1_cell(characters, next_position + 1, base_cell)? {
+        Some((end, next_position))
+            if start.row.is_some() == end.row.is_some()
+                && start.col.is_some() == end.col.is_some() =>
+        {
+            Ok(Some((
+                FormulaTokenValues::Reference(FormulaReference {
+                    sheet_name,
+                    start,
+                    end: Some(end),
+                }),
+                next_position,
+            )))
+        }
+        Some((end, _)) => Err(anyhow!(
+            "Invalid R1C1 range at position {}, {:?} and {:?} are not the same kind",
+            position,
+            start,
+            end
+        )),
+        // Range end like INDEX(R1C1:R3C1,2) is read after the range operator
+        None => {
+            let end = (start.row.is_none() || start.col.is_none()).then(|| start.clone());
+            Ok(Some((
+                FormulaTokenValues::Reference(FormulaReference {
+                    sheet_name,
+                    start,
+                    end,
+                }),
+                next_position,
+            )))
+        }
+    }
+}
+
+/// Read R1C1 style part like R2C3, R[-1]C or C2 resolved against the base cell
+fn read_r1c1_cell(
+    characters: &[char],
+    position: usize,
+    (base_row, base_col): (u32, u16),
+) -> AnyResult<Option<(FormulaCellReference, usize)>, AnyError> {
+    let row_part = read_r1c1_part(characters, position, 'R');
+    let next_position = row_part.map_or(position, |(_, _, next_position)| next_position);
+    let col_part = read_r1c1_part(characters, next_position, 'C');
+    let next_position = col_part.map_or(next_position, |(_, _, next_position)| next_position);
+    // Name or function starting with R or C like ROUND or Cost
+    if (row_part.is_none() && col_part.is_none())
+        || characters.get(next_position).is_some_and(|character| {
+            is_word_character(*character) || matches!(character, '(' | '[' | '!')
+        })
+    {
+        return Ok(None);
+    }
+    let row = match row_part {
+        Some((index, is_absolute, _)) => {
+            let row = if is_absolute {
+                index
+            } else {
+                base_row as i64 + index
+            };
+            if !(1..=MAX_ROW_INDEX as i64).contains(&row) {
+                return Err(anyhow!("Row out of range at position {}", position));
+            }
+            Some(row as u32)
+        }
+        None => None,
+    };
+    let col = match col_part {
+        Some((index, is_absolute, _)) => {
+            let col = if is_absolute {
+                index
+            } else {
+                base_col as i64 + index
+            };
+            if !(1..=MAX_COL_INDEX as i64).contains(&col) {
+                return Err(anyhow!("Column out of range at position {}", position));
+            }
+            Some(col as u16)
+        }
+        None => None,
+    };
+    Ok(Some((
+        FormulaCellReference {
+            row,
+            col,
+            is_row_absolute: row_part.is_some_and(|(_, is_absolute, _)| is_absolute),
+            is_col_absolute: col_part.is_some_and(|(_, is_absolute, _)| is_absolute),
+        },
+        next_position,
+    )))
+}
+
+/// Read R or C with the absolute index, bracket offset or nothing for the same row or column
+fn read_r1c1_part(
+    characters: &[char],
+    position: usize,
+    marker: char,
+) -> Option<(i64, bool, usize)> {
+    if !characters
+        .get(position)
+        .is_some_and(|character| character.eq_ignore_ascii_case(&marker))
+    {
+        return None;
+    }
+    let start_position = position + 1;
+    if characters.get(start_position) == Some(&'[') {
+        let end_position = characters[start_position..]
+            .iter()
+            .position(|character| *character == ']')?
+            + start_position;
+        let offset: String = characters[start_position + 1..end_position]
+            .iter()
+            .collect();
+        return Some((offset.parse().ok()?, false, end_position + 1));
+    }
+    let mut end_position = start_position;
+    while characters
+        .get(end_position)
+        .is_some_and(|character| character.is_ascii_digit())
+    {
+        end_position += 1;
+    }
+    if end_position == start_position {
+        return Some((0, false, end_position));
+    }
+    let index: String = characters[start_position..end_position].iter().collect();
+    Some((index.parse().ok()?, true, end_position))
+}
+
+/// Read range like A1:B2, A:C or 1:3 starting at the position
+fn read_reference(
+    characters: &[char],
+    position: usize,
+    sheet_name: Option<String>,
+) -> AnyResult<(FormulaTokenValues, usize), AnyError> {
+    let (start_word, next_position) = read_word(characters, position);
+    let (end_word, next_position) = read_word(characters, next_position + 1);
+    let start =
+        parse_cell_reference(&start_word).ok_or(anyhow!("Invalid range start : {}", start_word))?;
+    let end = parse_cell_reference(&end_word).ok_or(anyhow!("Invalid range end : {}", end_word))?;
+    // Both side must be same kind, cell with cell, row with row and column with column
+    if start.row.is_some() != end.row.is_some() || start.col.is_some() != end.col.is_some() {
+        return Err(anyhow!("Invalid range : {}:{}", start_word, end_word));
+    }
+    Ok((
+        FormulaTokenValues::Reference(FormulaReference {
+            sheet_name,
+            start,
+            end: Some(end),
+        }),
+        next_position,
+    ))
+}
+
+/// Cell like reference is read as reference, rest of the word as bool or defined name
+fn get_word_token(
+    word: String,
+    sheet_name: Option<String>,
+    is_r1c1: bool,
+) -> AnyResult<FormulaTokenValues, AnyError> {
+    match parse_cell_reference(&word).filter(|_| !is_r1c1) {
+        Some(cell_reference) if cell_reference.row.is_some() && cell_reference.col.is_some() => {
+            Ok(FormulaTokenValues::Reference(FormulaReference {
+                sheet_name,
+                start: cell_reference,
+                end: None,
+            }))
+        }
+        _ if !is_r1c1 && is_cell_like(&word) => {
+            Err(anyhow!("Reference outside of the sheet : {}", word))
+        }
+        _ => Ok(match word.to_uppercase().as_str() {
+            "TRUE" if sheet_name.is_none() => FormulaTokenValues::Bool(true),
+            "FALSE" if sheet_name.is_none() => FormulaTokenValues::Bool(false),
+            _ => FormulaTokenValues::Name {
+                sheet_name,
+                name: word,
+            },
+        }),
+    }
+}
+
+/// Word written like the A1 cell reference, column letters followed by the row number
+fn is_cell_like(word: &str) -> bool {
+    let word = word.strip_prefix('$').unwrap_or(word);
+    let letter_count = word
+        .chars()
+        .take_while(|character| character.is_ascii_alphabetic())
+        .count();
+    let row_part = &word[letter_count..];
+    let row_part = row_part.strip_prefix('$').unwrap_or(row_part);
+    (1..=3).contains(&letter_count)
+        && !row_part.is_empty()
+        && row_part.chars().all(|character| character.is_ascii_digit())
+}
+
+/// Parse A1, $A$1, A or 1 like part of the reference
+pub(crate) fn parse_cell_reference(reference: &str) -> Option<FormulaCellReference> {
+    let (is_col_absolute, reference) = match reference.strip_prefix('$') {
+        Some(reference) => (true, reference),
+        None => (false, reference),
+    };
+    let letter_count = reference
+        .chars()
+        .take_while(|character| character.is_ascii_alphabetic())
+        .count();
+    let (column_part, row_part) = reference.split_at(letter_count);
+    let (is_row_absolute, row_part) = match row_part.strip_prefix('$') {
+        Some(row_part) if letter_count > 0 => (true, row_part),
+        _ => (false, row_part),
+    };
+    if letter_count > 3
+        || !row_part.chars().all(|character| character.is_ascii_digit())
+        || (column_part.is_empty() && row_part.is_empty())
+    {
+        return None;
+    }
+    let col = if column_part.is_empty() {
+        None
+    } else {
+        Some(
+            ConverterUtil::get_column_index(column_part)
+                .ok()
+                .filter(|col| *col <= MAX_COL_INDEX)?,
+        )
+    };
+    let row = if row_part.is_empty() {
+        None
+    } else {
+        Some(
+            row_part
+                .parse::<u32>()
+                .ok()
+                .filter(|row| (1..=MAX_ROW_INDEX).contains(row))?,
+        )
+    };
+    Some(FormulaCellReference {
+        row,
+        col,
+        // Full row reference like $1 keep the flag on the row
+        is_row_absolute: is_row_absolute || (col.is_none() && is_col_absolute),
+        is_col_absolute: col.is_some() && is_col_absolute,
+    })
+}
@@ -0,0 +1,555 @@
+use crate::{
+    converters::ConverterUtil,
+    spreadsheet_2007::services::formula::{get_general_text, round_significant},
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use chrono::{Datelike, Timelike};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum TextFormatTokenValues {
+    Literal(String),
+    /// Digit placeholder 0, # or ?
+    Digit(char),
+    DecimalPoint,
+    Comma,
+    Percent,
+    /// Exponent with the sign shown for positive exponent
+    Exponent(bool),
+    /// @ text placeholder
+    Text,
+    General,
+    /// Date part like yyyy, mmm, d, hh, ss, AM/PM or elapsed [h]
+    DatePart(String),
+}
+
+/// Text of the number with the number format code like "#,##0.00", "0%" or "dd-mmm-yyyy"
+pub(crate) fn format_number_text(number: f64, format_code: &str) -> AnyResult<String, AnyError> {
+    let sections = split_sections(format_code);
+    // Negative section carry its own sign
+    let (section, number, is_sign_shown) = if number < 0.0 && sections.len() >= 2 {
+        (sections[1].as_str(), -number, false)
+    } else if number == 0.0 && sections.len() >= 3 {
+        (sections[2].as_str(), number, false)
+    } else {
+        (sections[0].as_str(), number, true)
+    };
+    let tokens = tokenize_section(section)?;
+    if tokens
+        .iter()
+        .any(|token| matches!(token, TextFormatTokenValues::DatePart(_)))
+    {
+        return format_date_text(number, &tokens);
+    }
+    format_numeric_text(number, &tokens, is_sign_shown)
+}
+
+/// Split the format code into positive, negative and zero sections
+fn split_sections(format_code: &str) -> Vec<String> {
+    let mut sections = vec![String::new()];
+    let mut characters = format_code.chars();
+    let mut is_quoted = false;
+    while let Some(character) = characters.next() {
+        let section = sections.last_mut().expect("Section exist");
+        match character {
+            '"' => {
+                is_quoted = !is_quoted;
+                section.push(character);
+            }
+            '\\' if !is_quoted => {
+                section.push(character);
+                if let Some(next_character) = characters.next() {
+                    section.push(next_character);
+                }
+            }
+            ';' if !is_quoted => sections.push(String::new()),
+            _ => section.push(character),
+        }
+    }
+    sections
+}
+
+fn tokenize_section(section: &str) -> AnyResult<Vec<TextFormatTokenValues>, AnyError> {
+    let characters: Vec<char> = section.chars().collect();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    let push_literal = |tokens: &mut Vec<TextFormatTokenValues>, text: &str| {
+        if let Some(TextFormatTokenValues::Literal(literal)) = tokens.last_mut() {
+            literal.push_str(text);
+        } else {
+            tokens.push(TextFormatTokenValues::Literal(text.to_string()));
+        }
+    };
+    while position < characters.len() {
+        let character = characters[position];
+        let remaining: String = characters[position..].iter().collect();
+        let remaining_upper = remaining.to_uppercase();
+        position += 1;
+        match character {
+            '"' => {
+                let end = characters[position..]
+                    .iter()
+                    .position(|character| *character == '"')
+                    .ok_or(anyhow!("Quoted text of the format is not closed"))?;
+                let text: String = characters[position..position + end].iter().collect();
+                push_literal(&mut tokens, &text);
+                position += end + 1;
+            }
+            '\\' | '!' => {
+                if let Some(next_character) = characters.get(position) {
+                    push_literal(&mut tokens, &next_character.to_string());
+                    position += 1;
+                }
+            }
+            // Space of the next character width
+            '_' => {
+                push_literal(&mut tokens, " ");
+                position += 1;
+            }
+            // Repeat fill has no column width to fill
+            '*' => position += 1,
+            '[' => {
+                let end = characters[position..]
+                    .iter()
+                    .position(|character| *character == ']')
+                    .ok_or(anyhow!("Bracket of the format is not closed"))?;
+                let content: String = characters[position..position + end]
+                    .iter()
+                    .collect::<String>()
+                    .to_lowercase();
+                position += end + 1;
+                // Color and condition have no text to show
+                let first_character = content.chars().next();
+                if matches!(first_character, Some('h' | 'm' | 's'))
+                    && content
+                        .chars()
+                        .all(|character| Some(character) == first_character)
+                {
+                    tokens.push(TextFormatTokenValues::DatePart(format!("[{}]", content)));
+                }
+            }
+            '0' | '#' | '?' => tokens.push(TextFormatTokenValues::Digit(character)),
+            '.' => tokens.push(TextFormatTokenValues::DecimalPoint),
+            ',' => tokens.push(TextFormatTokenValues::Comma),
+            '%' => tokens.push(TextFormatTokenValues::Percent),
+            '@' => tokens.push(TextFormatTokenValues::Text),
+            'E' | 'e' if matches!(characters.get(position), Some('+') | Some('-')) => {
+                tokens.push(TextFormatTokenValues::Exponent(characters[position] == '+'));
+                position += 1;
+            }
+            _ if remaining_upper.starts_with("GENERAL") => {
+                tokens.push(TextFormatTokenValues::General);
+                position += 6;
+            }
+            _ if remaining_upper.starts_with("AM/PM") => {
+                tokens.push(TextFormatTokenValues::DatePart("AM/PM".to_string()));
+                position += 4;
+            }
+            _ if remaining_upper.starts_with("A/P") => {
+                tokens.push(TextFormatTokenValues::DatePart("A/P".to_string()));
+                position += 2;
+            }
+            'y' | 'Y' | 'm' | 'M' | 'd' | 'D' | 'h' | 'H' | 's' | 'S' => {
+                let lower_character = character.to_ascii_lowercase();
+                let mut part = lower_character.to_string();
+                while characters.get(position).is_some_and(|next_character| {
+                    next_character.to_ascii_lowercase() == lower_character
+                }) {
+                    part.push(lower_character);
+                    position += 1;
+                }
+                tokens.push(TextFormatTokenValues::DatePart(part));
+            }
+            _ => push_literal(&mut tokens, &character.to_string()),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Year, month and day of the serial date as excel show them. Serial 0 is 1900-01-00 and
+/// serial 60 is the 1900-02-29 excel keep for the 1900 leap year
+pub(crate) fn get_date_parts(serial_date: f64) -> AnyResult<(i32, u32, u32), AnyError> {
+    match serial_date.floor() as i64 {
+        0 => Ok((1900, 1, 0)),
+        60 => Ok((1900, 2, 29)),
+        _ => {
+            let date_time = ConverterUtil::get_date_from_excel_serial(serial_date)
+                .context("Number is out of the date range")?;
+            Ok((date_time.year(), date_time.month(), date_time.day()))
+        }
+    }
+}
+
+fn format_date_text(
+    serial_date: f64,
+    tokens: &[TextFormatTokenValues],
+) -> AnyResult<String, AnyError> {
+    let is_twelve_hour = tokens.iter().any(|token| {
+        matches!(token, TextFormatTokenValues::DatePart(part) if part == "AM/PM" || part == "A/P")
+    });
+    let second_decimals = tokens
+        .windows(2)
+        .position(|pair| {
+            matches!(&pair[0], TextFormatTokenValues::DatePart(part) if part.starts_with('s'))
+                && pair[1] == TextFormatTokenValues::DecimalPoint
+        })
+        .map(|position| {
+            tokens[position + 2..]
+                .iter()
+                .take_while(|token| **token == TextFormatTokenValues::Digit('0'))
+                .count()
+        })
+        .unwrap_or_default();
+    // Round to the shown fraction of the second
+    let second_scale = 86_400.0 * 10f64.powi(second_decimals as i32);
+    let serial_date = (serial_date * second_scale).round() / second_scale;
+    let date_time = ConverterUtil::get_date_from_excel_serial(serial_date)
+        .context("Number is out of the date range")?;
+    let (year, month, day) = get_date_parts(serial_date)?;
+    let month_name = MONTH_NAMES[month as usize - 1];
+    // Excel take serial 1 as sunday
+    let day_name = DAY_NAMES[(serial_date.floor() as i64 + 6).rem_euclid(7) as usize];
+    let mut text = String::new();
+    let mut position = 0;
+    while position < tokens.len() {
+        match &tokens[position] {
+            TextFormatTokenValues::DatePart(part) => {
+                let is_minute = part.starts_with('m')
+                    && part.len() <= 2
+                    && (is_previous_hour(tokens, position) || is_next_second(tokens, position));
+                text.push_str(&match part.as_str() {
+                    "yy" | "y" => format!("{:02}", year % 100),
+                    part if part.starts_with('y') => format!("{:04}", year),
+                    "m" if is_minute => date_time.minute().to_string(),
+                    "mm" if is_minute => format!("{:02}", date_time.minute()),
+                    "m" => month.to_string(),
+                    "mm" => format!("{:02}", month),
+                    "mmm" => month_name[..3].to_string(),
+                    "mmmmm" => month_name[..1].to_string(),
+                    part if part.starts_with('m') => month_name.to_string(),
+                    "d" => day.to_string(),
+                    "dd" => format!("{:02}", day),
+                    "ddd" => day_name[..3].to_string(),
+                    part if part.starts_with('d') => day_name.to_string(),
+                    "h" | "hh" => {
+                        let hour = if is_twelve_hour {
+                            (date_time.hour() + 11) % 12 + 1
+                        } else {
+                            date_time.hour()
+                        };
+                        if part == "h" {
+                            hour.to_string()
+                        } else {
+                            format!("{:02}", hour)
+                        }
+                    }
+                    "s" => date_time.second().to_string(),
+                    part if part.starts_with('s') => format!("{:02}", date_time.second()),
+                    "AM/PM" => if date_time.hour() < 12 { "AM" } else { "PM" }.to_string(),
+                    "A/P" => if date_time.hour() < 12 { "A" } else { "P" }.to_string(),
+                    part => {
+                        // Elapsed [h], [m] or [s] count from the serial zero
+                        let elapsed_seconds = (serial_date * 86_400.0).round() as i64;
+                        let elapsed = match part.as_bytes()[1] {
+                            b'h' => elapsed_seconds / 3600,
+                            b'm' => elapsed_seconds / 60,
+                            _ => elapsed_seconds,
+                        };
+                        format!("{:0width$}", elapsed, width = part.len() - 2)
+                    }
+                });
+            }
+            TextFormatTokenValues::DecimalPoint if second_decimals > 0 => {
+                let fraction = ((serial_date * 86_400.0).fract()
+                    * 10f64.powi(second_decimals as i32))
+                .round() as u64;
+                text.push_str(&format!(".{:0width$}", fraction, width = second_decimals));
+                position += second_decimals;
+            }
+            TextFormatTokenValues::Literal(literal) => text.push_str(literal),
+            TextFormatTokenValues::DecimalPoint => text.push('.'),
+            TextFormatTokenValues::Comma => text.push(','),
+            TextFormatTokenValues::Percent => text.push('%'),
+            TextFormatTokenValues::Digit(digit) => text.push(*digit),
+            TextFormatTokenValues::General | TextFormatTokenValues::Text => {
+                text.push_str(&get_general_text(serial_date))
+            }
+            TextFormatTokenValues::Exponent(_) => text.push('E'),
+        }
+        position += 1;
+    }
+    Ok(text)
+}
+
+/// Month part after hour is minute like hh:mm
+fn is_previous_hour(tokens: &[TextFormatTokenValues], position: usize) -> bool {
+    tokens[..position]
+        .iter()
+        .rev()
+        .find_map(|token| match token {
+            TextFormatTokenValues::DatePart(part) => Some(part.starts_with('h') || part == "[h]"),
+            _ => None,
+        })
+        .unwrap_or(false)
+}
+
+/// Month part before second is minute like mm:ss
+fn is_next_second(tokens: &[TextFormatTokenValues], position: usize) -> bool {
+    tokens[position + 1..]
+        .iter()
+        .find_map(|token| match token {
+            TextFormatTokenValues::DatePart(part) => Some(part.starts_with('s') || part == "[s]"),
+            _ => None,
+        })
+        .unwrap_or(false)
+}
+
+fn format_numeric_text(
+    number: f64,
+    tokens: &[TextFormatTokenValues],
+    is_sign_shown: bool,
+) -> AnyResult<String, AnyError> {
+    let is_general = tokens.contains(&TextFormatTokenValues::General);
+    let digit_positions: Vec<usize> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| matches!(token, TextFormatTokenValues::Digit(_)))
+        .map(|(position, _)| position)
+        .collect();
+    if digit_positions.is_empty() {
+        // Literal only format like "Total" or General with the text around it
+        let mut text = String::new();
+        for token in tokens {
+            match token {
+                TextFormatTokenValues::Literal(literal) => text.push_str(literal),
+                TextFormatTokenValues::General | TextFormatTokenValues::Text => {
+                    text.push_str(&get_general_text(number.abs()))
+                }
+                TextFormatTokenValues::Percent => text.push('%'),
+                TextFormatTokenValues::DecimalPoint => text.push('.'),
+                TextFormatTokenValues::Comma => text.push(','),
+                _ => {}
+            }
+        }
+        let is_negative = number < 0.0 && is_sign_shown && is_general;
+        return Ok(format!("{}{}", if is_negative { "-" } else { "" }, text));
+    }
+    let exponent_position = tokens
+        .iter()
+        .position(|token| matches!(token, TextFormatTokenValues::Exponent(_)));
+    let mantissa_end = exponent_position.unwrap_or(tokens.len());
+    let decimal_position = tokens[..mantissa_end]
+        .iter()
+        .position(|token| *token == TextFormatTokenValues::DecimalPoint);
+    let integer_end = decimal_position.unwrap_or(mantissa_end);
+    let integer_digits: Vec<usize> = digit_positions
+        .iter()
+        .copied()
+        .filter(|position| *position < integer_end)
+        .collect();
+    let decimal_digits: Vec<usize> = digit_positions
+        .iter()
+        .copied()
+        .filter(|position| *position > integer_end && *position < mantissa_end)
+        .collect();
+    let exponent_digits: Vec<usize> = digit_positions
+        .iter()
+        .copied()
+        .filter(|position| *position > mantissa_end)
+        .collect();
+    // Comma between digits group the thousands, comma after the last digit scale by thousand
+    let is_grouped = match (integer_digits.first(), integer_digits.last()) {
+        (Some(first_digit), Some(last_digit)) => {
+            tokens[*first_digit..*last_digit].contains(&TextFormatTokenValues::Comma)
+        }
+        _ => false,
+    };
+    let scale_count = digit_positions
+        .iter()
+        .rev()
+        .find(|position| **position < mantissa_end)
+        .map_or(0, |last_digit| {
+            tokens[last_digit + 1..]
+                .iter()
+                .take_while(|token| **token == TextFormatTokenValues::Comma)
+                .count()
+        });
+    let percent_count = tokens
+        .iter()
+        .filter(|token| **token == TextFormatTokenValues::Percent)
+        .count();
+    let mut value =
+        number.abs() * 100f64.powi(percent_count as i32) / 1000f64.powi(scale_count as i32);
+    let mut exponent = 0;
+    if exponent_position.is_some() && value != 0.0 {
+        let integer_count = integer_digits.len().max(1) as i32;
+        exponent = value.log10().floor() as i32 - (integer_count - 1);
+        value /= 10f64.powi(exponent);
+        // Rounding can carry the mantissa to the next power like 9.99 to 10.0
+        let rounded = round_decimals(value, decimal_digits.len());
+        if rounded >= 10f64.powi(integer_count) {
+            exponent += 1;
+            value /= 10.0;
+        }
+    }
+    let value = round_decimals(value, decimal_digits.len());
+    let value_text = format!("{:.*}", decimal_digits.len(), value);
+    let (integer_text, decimal_text) = value_text
+        .split_once('.')
+        .unwrap_or((value_text.as_str(), ""));
+    let integer_text = if integer_text == "0" {
+        ""
+    } else {
+        integer_text
+    };
+    let mut placeholder_texts = vec![String::new(); tokens.len()];
+    fill_integer_digits(
+        tokens,
+        &integer_digits,
+        integer_text,
+        is_grouped,
+        &mut placeholder_texts,
+    );
+    fill_decimal_digits(
+        tokens,
+        &decimal_digits,
+        decimal_text,
+        &mut placeholder_texts,
+    );
+    if let Some(exponent_position) = exponent_position {
+        let exponent_text = exponent.abs().to_string();
+        fill_integer_digits(
+            tokens,
+            &exponent_digits,
+            &exponent_text,
+            false,
+            &mut placeholder_texts,
+        );
+        placeholder_texts[exponent_position] = format!(
+            "E{}",
+            match (exponent < 0, tokens[exponent_position].clone()) {
+                (true, _) => "-",
+                (false, TextFormatTokenValues::Exponent(true)) => "+",
+                _ => "",
+            }
+        );
+    }
+    let mut text = String::new();
+    for (position, token) in tokens.iter().enumerate() {
+        match token {
+            TextFormatTokenValues::Literal(literal) => text.push_str(literal),
+            TextFormatTokenValues::DecimalPoint if Some(position) == decimal_position => {
+                text.push('.')
+            }
+            TextFormatTokenValues::Percent => text.push('%'),
+            TextFormatTokenValues::General | TextFormatTokenValues::Text => {
+                text.push_str(&get_general_text(number.abs()))
+            }
+            _ => text.push_str(&placeholder_texts[position]),
+        }
+    }
+    let is_negative = number < 0.0 && is_sign_shown && value != 0.0;
+    Ok(format!("{}{}", if is_negative { "-" } else { "" }, text))
+}
+
+/// Round half away from zero at the decimal count
+fn round_decimals(value: f64, decimal_count: usize) -> f64 {
+    let factor = 10f64.powi(decimal_count as i32);
+    round_significant(value * factor).round() / factor
+}
+
+/// Place the digits right to left, extra digits go to the first placeholder
+fn fill_integer_digits(
+    tokens: &[TextFormatTokenValues],
+    digit_positions: &[usize],
+    digits_text: &str,
+    is_grouped: bool,
+    placeholder_texts: &mut [String],
+) {
+    let Some(first_position) = digit_positions.first() else {
+        return;
+    };
+    let get_padding = |position: usize| match tokens[position] {
+        TextFormatTokenValues::Digit('0') => "0",
+        TextFormatTokenValues::Digit('?') => " ",
+        _ => "",
+    };
+    if is_grouped {
+        // Group the digits as a whole, zero padded to the 0 placeholder count
+        let zero_count = digit_positions
+            .iter()
+            .filter(|position| tokens[**position] == TextFormatTokenValues::Digit('0'))
+            .count();
+        let digits_text = format!("{:0>width$}", digits_text, width = zero_count);
+        let mut grouped_text = String::new();
+        for (index, digit) in digits_text.chars().enumerate() {
+            if index > 0 && (digits_text.len() - index) % 3 == 0 {
+                grouped_text.push(',');
+            }
+            grouped_text.push(digit);
+        }
+        placeholder_texts[*first_position] = grouped_text;
+        return;
+    }
+    let digits: Vec<char> = digits_text.chars().collect();
+    let mut digit_index = digits.len();
+    for (index, position) in digit_positions.iter().enumerate().rev() {
+        if index == 0 {
+            let mut text: String = digits[..digit_index].iter().collect();
+            if text.is_empty() {
+                text = get_padding(*position).to_string();
+            }
+            placeholder_texts[*position] = text;
+        } else if digit_index > 0 {
+            digit_index -= 1;
+            placeholder_texts[*position] = digits[digit_index].to_string();
+        } else {
+            placeholder_texts[*position] = get_padding(*position).to_string();
+        }
+    }
+}
+
+/// Place the digits left to right, trailing zero of optional placeholder is dropped
+fn fill_decimal_digits(
+    tokens: &[TextFormatTokenValues],
+    digit_positions: &[usize],
+    digits_text: &str,
+    placeholder_texts: &mut [String],
+) {
+    let digits: Vec<char> = digits_text.chars().collect();
+    let mut is_trailing = true;
+    for (index, position) in digit_positions.iter().enumerate().rev() {
+        let digit = digits.get(index).copied().unwrap_or('0');
+        placeholder_texts[*position] = match tokens[*position] {
+            TextFormatTokenValues::Digit('#') if is_trailing && digit == '0' => String::new(),
+            TextFormatTokenValues::Digit('?') if is_trailing && digit == '0' => " ".to_string(),
+            _ => {
+                is_trailing = false;
+                digit.to_string()
+            }
+        };
+    }
+}
@@ -1,9 +1,15 @@
 pub(crate) mod calculation_chain;
 pub(crate) mod common_service;
+pub(crate) mod formula;
+pub(crate) mod person;
+pub(crate) mod rich_text;
 pub(crate) mod share_string;
 pub(crate) mod style;
 
 pub(crate) use calculation_chain::*;
 pub(crate) use common_service::*;
+pub(crate) use formula::*;
+pub(crate) use person::*;
+pub(crate) use rich_text::*;
 pub(crate) use share_string::*;
 pub(crate) use style::*;
@@ -0,0 +1,187 @@
+use crate::element_dictionary::EXCEL_TYPE_COLLECTION;
+use crate::files::XmlSerializer;
+use crate::global_2007::parts::RelationsPart;
+use crate::global_2007::traits::XmlDocumentPartCommon;
+use crate::log_elapsed;
+use crate::{
+    files::{OfficeDocument, XmlDocument},
+    global_2007::traits::XmlDocumentPart,
+};
+use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
+use std::{cell::RefCell, collections::HashMap, rc::Weak};
+
+/// Person list of the workbook, threaded comments refer the author by the person id
+#[derive(Debug)]
+pub struct PersonPart {
+    office_document: Weak<RefCell<OfficeDocument>>,
+    parent_relationship_part: Weak<RefCell<RelationsPart>>,
+    xml_document: Weak<RefCell<XmlDocument>>,
+    /// Attributes of the person like id, displayName, userId
+    person_collection: Vec<HashMap<String, String>>,
+    file_path: String,
+}
+
+impl Drop for PersonPart {
+    fn drop(&mut self) {
+        let _ = self.close_document();
+    }
+}
+
+impl XmlDocumentPartCommon for PersonPart {
+    fn close_document(&mut self) -> AnyResult<(), AnyError>
+    where
+        Self: Sized,
+    {
+        log_elapsed!(
+            || {
+                if let Some(office_doc_ref) = self.office_document.upgrade() {
+                    if !self.person_collection.is_empty() {
+                        if let Some(xml_document) = self.xml_document.upgrade() {
+                            let mut xml_doc_mut = xml_document
+                                .try_borrow_mut()
+                                .context("Failed to pull document handle")?;
+                            for attributes in self.person_collection.iter() {
+                                xml_doc_mut
+                                    .append_child_mut("person", None)
+                                    .context("Failed To Add Person Item")?
+                                    .set_attribute_mut(attributes.clone())
+                                    .context("Failed To Set Person Attributes")?;
+                            }
+                        }
+                        office_doc_ref
+                            .try_borrow_mut()
+                            .context("Failed to Borrow Person Tree")?
+                            .close_xml_document(&self.file_path)?;
+                    } else if let Some(relationship_part) = self.parent_relationship_part.upgrade()
+                    {
+                        relationship_part
+                            .try_borrow_mut()
+                            .context("Failed To pull parent relation ship part of Person")?
+                            .delete_relationship_mut(&self.file_path);
+                        office_doc_ref
+                            .try_borrow_mut()
+                            .context("Failed to Borrow Person Tree")?
+                            .delete_document_mut(&self.file_path);
+                    }
+                }
+                Ok(())
+            },
+            "Close Person"
+        )
+    }
+
+    /// Initialize xml content for this part from base template
+    fn initialize_content_xml() -> AnyResult<(XmlDocument, Option<String>, String, String), AnyError>
+    {
+        let content = EXCEL_TYPE_COLLECTION.get("person").unwrap();
+        let template_core_properties = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<personList xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments"
+    xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main"></personList>"#;
+        Ok((
+            XmlSerializer::vec_to_xml_doc_tree(
+                template_core_properties.as_bytes().to_vec(),
+                "Default Person",
+            )
+            .context("Initializing Person Failed")?,
+            Some(content.content_type.to_string()),
+            content.extension.to_string(),
+            content.extension_type.to_string(),
+        ))
+    }
+}
+
+impl XmlDocumentPart for PersonPart {
+    fn new(
+        office_document: Weak<RefCell<OfficeDocument>>,
+        parent_relationship_part: Weak<RefCell<RelationsPart>>,
+    ) -> AnyResult<Self, AnyError> {
+        let file_name = Self::get_person_file_name(&parent_relationship_part)
+            .context("Failed to pull person file name")?
+            .to_string();
+        let mut xml_document = Self::get_xml_document(&office_document, &file_name)?;
+        let person_collection = Self::load_content_to_database(&mut xml_document)
+            .context("Load Person To DB Failed")?;
+        Ok(Self {
+            office_document,
+            parent_relationship_part,
+            xml_document,
+            person_collection,
+            file_path: file_name,
+        })
+    }
+}
+
+impl PersonPart {
+    fn get_person_file_name(
+        relations_part: &Weak<RefCell<RelationsPart>>,
+    ) -> AnyResult<String, AnyError> {
+        let person_content = EXCEL_TYPE_COLLECTION.get("person").unwrap();
+        if let Some(relations_part) = relations_part.upgrade() {
+            Ok(relations_part
+                .try_borrow_mut()
+                .context("Failed to pull relationship connection")?
+                .get_relationship_target_by_type_mut(
+                    person_content.schemas_type,
+                    person_content,
+                    None,
+                    None,
+                )
+                .context("Pull Path From Existing File Failed")?)
+        } else {
+            Err(anyhow!("Failed to upgrade relation part"))
+        }
+    }
+
+    fn load_content_to_database(
+        xml_document: &mut Weak<RefCell<XmlDocument>>,
+    ) -> AnyResult<Vec<HashMap<String, String>>, AnyError> {
+        let mut person_collection = Vec::new();
+        if let Some(xml_document) = xml_document.upgrade() {
+            let mut xml_doc_mut = xml_document
+                .try_borrow_mut()
+                .context("xml doc borrow failed")?;
+            if let Some(elements) = xml_doc_mut.pop_elements_by_tag_mut("person", None) {
+                for element in elements {
+                    if let Some(attributes) = element.get_attribute() {
+                        person_collection.push(attributes.clone());
+                    }
+                }
+            }
+        }
+        Ok(person_collection)
+    }
+
+    /// Get the person id of the display name, new person is added when not exist
+    pub(crate) fn get_person_id_mut(&mut self, display_name: &str) -> String {
+        if let Some(person_id) = self
+            .person_collection
+            .iter()
+            .find(|attributes| {
+                attributes.get("displayName").map(|name| name.as_str()) == Some(display_name)
+            })
+            .and_then(|attributes| attributes.get("id"))
+        {
+            return person_id.to_string();
+        }
+        let person_id = get_new_guid();
+        self.person_collection.push(HashMap::from([
+            ("displayName".to_string(), display_name.to_string()),
+            ("id".to_string(), person_id.clone()),
+            ("userId".to_string(), display_name.to_string()),
+            ("providerId".to_string(), "None".to_string()),
+        ]));
+        person_id
+    }
+
+    pub(crate) fn get_person_name(&self, person_id: &str) -> Option<String> {
+        self.person_collection
+            .iter()
+            .find(|attributes| attributes.get("id").map(|id| id.as_str()) == Some(person_id))
+            .and_then(|attributes| attributes.get("displayName").cloned())
+    }
+}
+
+/// Braced upper case GUID used as id of person and threaded comment
+pub(crate) fn get_new_guid() -> String {
+    format!("{{{}}}", uuid::Uuid::new_v4().to_string().to_uppercase())
+}
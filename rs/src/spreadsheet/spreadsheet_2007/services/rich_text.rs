@@ -0,0 +1,351 @@
+use crate::{
+    files::{XmlDeSerializer, XmlDocument, XmlElement},
+    global_2007::traits::Enum,
+    spreadsheet_2007::models::{CellValue, ColorSetting, ColorSettingTypeValues, RichTextRun},
+};
+use anyhow::{Context, Error as AnyError, Result as AnyResult};
+use std::collections::{BTreeMap, HashMap};
+
+/// Phonetic reading run attached to the base text of string item
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct PhoneticRun {
+    start_base: String,
+    end_base: String,
+    text: String,
+}
+
+/// Content of share string item or inline string as plain text or rich text runs
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub(crate) struct StringItem {
+    text: String,
+    rich_text: Option<Vec<RichTextRun>>,
+    phonetic_runs: Vec<PhoneticRun>,
+    phonetic_property: Option<BTreeMap<String, String>>,
+}
+
+impl StringItem {
+    pub(crate) fn from_text(text: String) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn from_rich_text(rich_text: Vec<RichTextRun>) -> Self {
+        Self {
+            text: rich_text
+                .iter()
+                .map(|rich_text_run| rich_text_run.text.as_str())
+                .collect(),
+            rich_text: Some(rich_text),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    pub(crate) fn get_rich_text(&self) -> Option<&Vec<RichTextRun>> {
+        self.rich_text.as_ref()
+    }
+
+    pub(crate) fn get_cell_value(&self) -> CellValue {
+        if let Some(rich_text) = self.rich_text.as_ref() {
+            CellValue::Rich(rich_text.clone())
+        } else {
+            CellValue::Text(self.text.clone())
+        }
+    }
+
+    /// Read the children of si/is element, consumes the child elements from the tree
+    pub(crate) fn deserialize_mut(
+        xml_doc_mut: &mut XmlDocument,
+        string_element: &XmlElement,
+    ) -> AnyResult<Self, AnyError> {
+        let mut string_item = Self::default();
+        let mut rich_text = Vec::new();
+        while let Some((child_id, _)) = string_element.pop_child_mut() {
+            if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                match child_element.get_tag() {
+                    "t" => {
+                        string_item.text = child_element.get_value().clone().unwrap_or_default();
+                    }
+                    "r" => {
+                        rich_text.push(
+                            deserialize_rich_text_run(xml_doc_mut, &child_element)
+                                .context("Failed to parse rich text run")?,
+                        );
+                    }
+                    "rPh" => {
+                        let attribute_value = |key: &str| {
+                            child_element
+                                .get_attribute()
+                                .and_then(|attributes| attributes.get(key).cloned())
+                                .unwrap_or_default()
+                        };
+                        let mut phonetic_run = PhoneticRun {
+                            start_base: attribute_value("sb"),
+                            end_base: attribute_value("eb"),
+                            text: String::new(),
+                        };
+                        while let Some((text_id, _)) = child_element.pop_child_mut() {
+                            if let Some(text_element) = xml_doc_mut.pop_element_mut(&text_id) {
+                                phonetic_run.text =
+                                    text_element.get_value().clone().unwrap_or_default();
+                            }
+                        }
+                        string_item.phonetic_runs.push(phonetic_run);
+                    }
+                    "phoneticPr" => {
+                        string_item.phonetic_property = child_element
+                            .get_attribute()
+                            .map(|attributes| attributes.clone().into_iter().collect());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !rich_text.is_empty() {
+            string_item.text = rich_text
+                .iter()
+                .map(|rich_text_run| rich_text_run.text.as_str())
+                .collect();
+            string_item.rich_text = Some(rich_text);
+        }
+        Ok(string_item)
+    }
+
+    /// Write the string item content under the si/is element
+    pub(crate) fn serialize_mut(
+        &self,
+        xml_doc_mut: &mut XmlDocument,
+        parent_id: &usize,
+    ) -> AnyResult<(), AnyError> {
+        if let Some(rich_text) = self.rich_text.as_ref() {
+            for rich_text_run in rich_text {
+                append_rich_text_run_mut(xml_doc_mut, parent_id, rich_text_run)
+                    .context("Failed to insert rich text run")?;
+            }
+        } else {
+            let text_element = xml_doc_mut
+                .append_child_mut("t", Some(parent_id))
+                .context("Failed to insert text element")?;
+            if let Some(attributes) = get_text_attribute(&self.text) {
+                text_element
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set text attribute")?;
+            }
+            text_element.set_value_mut(self.text.clone());
+        }
+        for phonetic_run in &self.phonetic_runs {
+            let phonetic_run_id = xml_doc_mut
+                .append_child_mut("rPh", Some(parent_id))
+                .context("Failed to insert phonetic run element")?
+                .set_attribute_mut(HashMap::from([
+                    ("sb".to_string(), phonetic_run.start_base.clone()),
+                    ("eb".to_string(), phonetic_run.end_base.clone()),
+                ]))
+                .context("Failed to set phonetic run attribute")?
+                .get_id();
+            xml_doc_mut
+                .append_child_mut("t", Some(&phonetic_run_id))
+                .context("Failed to insert phonetic text element")?
+                .set_value_mut(phonetic_run.text.clone());
+        }
+        if let Some(phonetic_property) = self.phonetic_property.as_ref() {
+            xml_doc_mut
+                .append_child_mut("phoneticPr", Some(parent_id))
+                .context("Failed to insert phonetic property element")?
+                .set_attribute_mut(phonetic_property.clone().into_iter().collect())
+                .context("Failed to set phonetic property attribute")?;
+        }
+        Ok(())
+    }
+
+    /// Generate string item content written directly without xml tree
+    pub(crate) fn generate_xml(&self) -> String {
+        if let Some(rich_text) = self.rich_text.as_ref() {
+            rich_text.iter().map(generate_rich_text_run).collect()
+        } else {
+            generate_text(&self.text, get_text_attribute(&self.text))
+        }
+    }
+}
+
+/// Leading or trailing space of text is dropped by reader unless preserved
+fn get_text_attribute(text: &str) -> Option<HashMap<String, String>> {
+    if text.trim() != text {
+        Some(HashMap::from([(
+            "xml:space".to_string(),
+            "preserve".to_string(),
+        )]))
+    } else {
+        None
+    }
+}
+
+fn generate_text(text: &str, attributes: Option<HashMap<String, String>>) -> String {
+    if let Some(attributes) = attributes {
+        format!(
+            "{}{}</t>",
+            XmlDeSerializer::generate_xml_tag("t", &attributes, false),
+            quick_xml::escape::escape(text)
+        )
+    } else {
+        XmlDeSerializer::generate_xml_value_tag("t", text)
+    }
+}
+
+/// Run property elements in schema order with their attribute
+fn get_rich_text_property(rich_text_run: &RichTextRun) -> Vec<(&str, HashMap<String, String>)> {
+    let value_attribute = |value: String| HashMap::from([("val".to_string(), value)]);
+    let mut run_property = Vec::new();
+    if let Some(font_family) = rich_text_run.font_family.as_ref() {
+        run_property.push(("rFont", value_attribute(font_family.to_string())));
+    }
+    if rich_text_run.is_bold {
+        run_property.push(("b", HashMap::new()));
+    }
+    if rich_text_run.is_italic {
+        run_property.push(("i", HashMap::new()));
+    }
+    if rich_text_run.is_strike {
+        run_property.push(("strike", HashMap::new()));
+    }
+    if let Some(text_color) = rich_text_run.text_color.as_ref() {
+        run_property.push((
+            "color",
+            HashMap::from([(
+                ColorSettingTypeValues::get_string(text_color.color_setting_type.clone()),
+                text_color.value.clone(),
+            )]),
+        ));
+    }
+    if let Some(font_size) = rich_text_run.font_size {
+        run_property.push(("sz", value_attribute(font_size.to_string())));
+    }
+    if rich_text_run.is_underline {
+        run_property.push(("u", HashMap::new()));
+    }
+    run_property
+}
+
+/// Add rich text run element into xml tree
+fn append_rich_text_run_mut(
+    xml_doc_mut: &mut XmlDocument,
+    parent_id: &usize,
+    rich_text_run: &RichTextRun,
+) -> AnyResult<(), AnyError> {
+    let run_id = xml_doc_mut
+        .append_child_mut("r", Some(parent_id))
+        .context("Failed to insert rich text run element")?
+        .get_id();
+    let run_property = get_rich_text_property(rich_text_run);
+    if !run_property.is_empty() {
+        let run_property_id = xml_doc_mut
+            .append_child_mut("rPr", Some(&run_id))
+            .context("Failed to insert run property element")?
+            .get_id();
+        for (tag, attributes) in run_property {
+            let property_element = xml_doc_mut
+                .append_child_mut(tag, Some(&run_property_id))
+                .context("Failed to insert run property")?;
+            if !attributes.is_empty() {
+                property_element
+                    .set_attribute_mut(attributes)
+                    .context("Failed to set run property attribute")?;
+            }
+        }
+    }
+    xml_doc_mut
+        .append_child_mut("t", Some(&run_id))
+        .context("Failed to insert run text element")?
+        .set_attribute_mut(HashMap::from([(
+            "xml:space".to_string(),
+            "preserve".to_string(),
+        )]))
+        .context("Failed to set run text attribute")?
+        .set_value_mut(rich_text_run.text.clone());
+    Ok(())
+}
+
+/// Generate rich text run content written directly without xml tree
+fn generate_rich_text_run(rich_text_run: &RichTextRun) -> String {
+    let mut run_content = "<r>".to_string();
+    let run_property = get_rich_text_property(rich_text_run);
+    if !run_property.is_empty() {
+        run_content.push_str("<rPr>");
+        for (tag, attributes) in run_property {
+            run_content.push_str(&XmlDeSerializer::generate_xml_tag(tag, &attributes, true));
+        }
+        run_content.push_str("</rPr>");
+    }
+    run_content.push_str(&generate_text(
+        &rich_text_run.text,
+        Some(HashMap::from([(
+            "xml:space".to_string(),
+            "preserve".to_string(),
+        )])),
+    ));
+    run_content.push_str("</r>");
+    run_content
+}
+
+fn deserialize_rich_text_run(
+    xml_doc_mut: &mut XmlDocument,
+    run_element: &XmlElement,
+) -> AnyResult<RichTextRun, AnyError> {
+    let mut rich_text_run = RichTextRun::default();
+    while let Some((child_id, _)) = run_element.pop_child_mut() {
+        if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+            match child_element.get_tag() {
+                "t" => {
+                    rich_text_run.text = child_element.get_value().clone().unwrap_or_default();
+                }
+                "rPr" => {
+                    while let Some((property_id, _)) = child_element.pop_child_mut() {
+                        if let Some(property_element) = xml_doc_mut.pop_element_mut(&property_id) {
+                            let attribute_value = |key: &str| {
+                                property_element
+                                    .get_attribute()
+                                    .and_then(|attributes| attributes.get(key).cloned())
+                            };
+                            let is_enabled = attribute_value("val").is_none_or(|value| {
+                                !matches!(value.as_str(), "0" | "false" | "none")
+                            });
+                            match property_element.get_tag() {
+                                "b" => rich_text_run.is_bold = is_enabled,
+                                "i" => rich_text_run.is_italic = is_enabled,
+                                "u" => rich_text_run.is_underline = is_enabled,
+                                "strike" => rich_text_run.is_strike = is_enabled,
+                                "sz" => {
+                                    if let Some(font_size) = attribute_value("val") {
+                                        rich_text_run.font_size = font_size
+                                            .parse::<f32>()
+                                            .ok()
+                                            .map(|font_size| font_size as u8);
+                                    }
+                                }
+                                "color" => {
+                                    rich_text_run.text_color =
+                                        ["rgb", "theme", "indexed"].into_iter().find_map(|key| {
+                                            attribute_value(key).map(|value| ColorSetting {
+                                                color_setting_type:
+                                                    ColorSettingTypeValues::get_enum(key),
+                                                value,
+                                            })
+                                        });
+                                }
+                                "rFont" => rich_text_run.font_family = attribute_value("val"),
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(rich_text_run)
+}
@@ -2,6 +2,10 @@ use crate::element_dictionary::EXCEL_TYPE_COLLECTION;
 use crate::global_2007::parts::RelationsPart;
 use crate::global_2007::traits::XmlDocumentPartCommon;
 use crate::log_elapsed;
+use crate::spreadsheet_2007::{
+    models::{CellValue, RichTextRun},
+    services::StringItem,
+};
 use crate::{
     files::{OfficeDocument, XmlDocument},
     global_2007::traits::XmlDocumentPart,
@@ -9,8 +13,8 @@ use crate::{
 use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
 use std::{
     cell::RefCell,
-    collections::{HashMap, HashSet},
-    rc::Weak,
+    collections::HashMap,
+    rc::{Rc, Weak},
 };
 
 #[derive(Debug)]
@@ -18,7 +22,12 @@ pub struct ShareStringPart {
     office_document: Weak<RefCell<OfficeDocument>>,
     parent_relationship_part: Weak<RefCell<RelationsPart>>,
     xml_document: Weak<RefCell<XmlDocument>>,
-    share_string_collection: Vec<String>,
+    /// Item position is the string id referred by the cells
+    share_string_collection: Vec<Rc<StringItem>>,
+    /// Lookup of string id by item content, keeps the first id of duplicate items
+    share_string_index: HashMap<Rc<StringItem>, usize>,
+    /// Number of cells referring the table
+    reference_count: usize,
     file_path: String,
 }
 
@@ -46,28 +55,22 @@ impl XmlDocumentPartCommon for ShareStringPart {
                                 if let Some(attributes) = root.get_attribute_mut() {
                                     attributes.insert(
                                         "count".to_string(),
-                                        self.share_string_collection.len().to_string(),
+                                        self.reference_count.to_string(),
                                     );
                                     attributes.insert(
                                         "uniqueCount".to_string(),
-                                        self.share_string_collection
-                                            .iter()
-                                            .map(|s| s.to_string())
-                                            .collect::<HashSet<String>>()
-                                            .len()
-                                            .to_string(),
+                                        self.share_string_collection.len().to_string(),
                                     );
                                 }
                             }
-                            for string in self.share_string_collection.to_owned() {
+                            for string_item in self.share_string_collection.iter() {
                                 let parent_id = xml_doc_mut
                                     .append_child_mut("si", None)
                                     .context("Failed to Add Child")?
                                     .get_id();
-                                xml_doc_mut
-                                    .append_child_mut("t", Some(&parent_id))
-                                    .context("Creating Share String Child Failed")?
-                                    .set_value_mut(string);
+                                string_item
+                                    .serialize_mut(&mut xml_doc_mut, &parent_id)
+                                    .context("Creating Share String Child Failed")?;
                             }
                         }
                         office_doc_ref
@@ -132,13 +135,22 @@ impl XmlDocumentPart for ShareStringPart {
             .context("Failed to pull share string file name")?
             .to_string();
         let mut xml_document = Self::get_xml_document(&office_document, &file_name)?;
-        let share_string_collection = Self::load_content_to_database(&mut xml_document)
-            .context("Load Share String To DB Failed")?;
+        let (share_string_collection, reference_count) =
+            Self::load_content_to_database(&mut xml_document)
+                .context("Load Share String To DB Failed")?;
+        let mut share_string_index = HashMap::with_capacity(share_string_collection.len());
+        for (string_id, string_item) in share_string_collection.iter().enumerate() {
+            share_string_index
+                .entry(string_item.clone())
+                .or_insert(string_id);
+        }
         Ok(Self {
             office_document,
             parent_relationship_part,
             xml_document,
             share_string_collection,
+            share_string_index,
+            reference_count,
             file_path: file_name,
         })
     }
@@ -167,40 +179,66 @@ impl ShareStringPart {
 
     fn load_content_to_database(
         xml_document: &mut Weak<RefCell<XmlDocument>>,
-    ) -> AnyResult<Vec<String>, AnyError> {
+    ) -> AnyResult<(Vec<Rc<StringItem>>, usize), AnyError> {
         let mut share_string_collection = Vec::new();
+        let mut reference_count = None;
         if let Some(xml_document) = xml_document.upgrade() {
             let mut xml_doc_mut = xml_document
                 .try_borrow_mut()
                 .context("xml doc borrow failed")?;
+            if let Some(root) = xml_doc_mut.get_root() {
+                reference_count = root
+                    .get_attribute()
+                    .and_then(|attributes| attributes.get("count"))
+                    .and_then(|count| count.parse::<usize>().ok());
+            }
             if let Some(elements) = xml_doc_mut.pop_elements_by_tag_mut("si", None) {
                 for element in elements {
-                    if let Some((child_id, _)) = element.pop_child_mut() {
-                        if let Some(text_element) = xml_doc_mut.pop_element_mut(&child_id) {
-                            let value = text_element.get_value().clone().unwrap_or("".to_string());
-                            share_string_collection.push(value);
-                        }
-                    }
+                    share_string_collection.push(Rc::new(
+                        StringItem::deserialize_mut(&mut xml_doc_mut, &element)
+                            .context("Failed to parse share string item")?,
+                    ));
                 }
             }
         }
-        Ok(share_string_collection)
+        let reference_count = reference_count.unwrap_or(share_string_collection.len());
+        Ok((share_string_collection, reference_count))
     }
 }
 
 impl ShareStringPart {
     pub(crate) fn get_string_id_mut(&mut self, value: String) -> AnyResult<String, AnyError> {
-        Ok(
-            if let Some(position) = self
-                .share_string_collection
-                .iter()
-                .position(|predicate| predicate == &value)
-            {
-                position.to_string()
-            } else {
-                self.share_string_collection.push(value);
-                (self.share_string_collection.len() - 1).to_string()
-            },
-        )
+        self.get_string_item_id_mut(StringItem::from_text(value))
+    }
+
+    pub(crate) fn get_rich_string_id_mut(
+        &mut self,
+        rich_text: Vec<RichTextRun>,
+    ) -> AnyResult<String, AnyError> {
+        self.get_string_item_id_mut(StringItem::from_rich_text(rich_text))
+    }
+
+    fn get_string_item_id_mut(&mut self, string_item: StringItem) -> AnyResult<String, AnyError> {
+        self.reference_count += 1;
+        if let Some(string_id) = self.share_string_index.get(&string_item) {
+            return Ok(string_id.to_string());
+        }
+        let string_id = self.share_string_collection.len();
+        let string_item = Rc::new(string_item);
+        self.share_string_collection.push(string_item.clone());
+        self.share_string_index.insert(string_item, string_id);
+        Ok(string_id.to_string())
+    }
+
+    /// Reduce the reference count for the cells no longer pointing the table
+    pub(crate) fn release_string_reference_mut(&mut self, reference_count: usize) {
+        self.reference_count = self.reference_count.saturating_sub(reference_count);
+    }
+
+    /// Text or rich text value of the share string item
+    pub(crate) fn get_string_value(&self, string_id: usize) -> Option<CellValue> {
+        self.share_string_collection
+            .get(string_id)
+            .map(|string_item| string_item.get_cell_value())
     }
 }
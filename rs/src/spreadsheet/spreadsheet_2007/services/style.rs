@@ -9,9 +9,9 @@ use crate::{
     log_elapsed,
     spreadsheet_2007::models::{
         BorderSetting, BorderStyle, BorderStyleValues, CellXfs, ColorSetting,
-        ColorSettingTypeValues, FillStyle, FontSchemeValues, FontStyle, HorizontalAlignmentValues,
-        NumberFormat, NumberFormatValues, PatternTypeValues, StyleId, StyleSetting,
-        VerticalAlignmentValues,
+        ColorSettingTypeValues, DifferentialStyleSetting, FillStyle, FontSchemeValues, FontStyle,
+        HorizontalAlignmentValues, NumberFormat, NumberFormatValues, PatternTypeValues, StyleId,
+        StyleSetting, VerticalAlignmentValues,
     },
 };
 use anyhow::{anyhow, Context, Error as AnyError, Result as AnyResult};
@@ -36,6 +36,9 @@ pub struct StylePart {
     border_collection: Vec<(u64, BorderStyle)>,
     cell_style_xfs_collection: Vec<(u64, CellXfs)>,
     cell_xfs_collection: Vec<(u64, CellXfs)>,
+    /// Dxf id by hash of the differential styles added in this session, dxfs loaded from
+    /// the document are not hashed so an equal new style get its own dxf
+    dxf_cache_id: HashMap<u64, u32>,
 }
 
 impl Drop for StylePart {
@@ -123,6 +126,7 @@ impl XmlDocumentPart for StylePart {
             border_collection,
             cell_style_xfs_collection: cell_style_collection,
             cell_xfs_collection: cell_collection,
+            dxf_cache_id: HashMap::new(),
         })
     }
 }
@@ -832,41 +836,65 @@ impl StylePart {
                                 ConverterUtil::normalize_bool_property_u8(apply_border);
                         }
                         if let Some(apply_fill) = attributes.get("applyFill") {
-                            cell_xf.apply_fill = ConverterUtil::normalize_bool_property_u8(apply_fill);
+                            cell_xf.apply_fill =
+                                ConverterUtil::normalize_bool_property_u8(apply_fill);
                         }
                         if let Some(apply_font) = attributes.get("applyFont") {
-                            cell_xf.apply_font = ConverterUtil::normalize_bool_property_u8(apply_font);
+                            cell_xf.apply_font =
+                                ConverterUtil::normalize_bool_property_u8(apply_font);
                         }
                         if let Some(apply_number_format) = attributes.get("applyNumberFormat") {
                             cell_xf.apply_number_format =
                                 ConverterUtil::normalize_bool_property_u8(apply_number_format);
                         }
-                        // Load Alignment Values if exist
-                        if let Some((alignment_id, _)) = current_element.pop_child_mut() {
-                            if let Some(alignment_element) =
-                                xml_doc_mut.pop_element_mut(&alignment_id)
-                            {
-                                if let Some(alignment_attributes) =
-                                    alignment_element.get_attribute()
-                                {
-                                    if let Some(is_wrap_text) = alignment_attributes.get("wrapText")
-                                    {
-                                        cell_xf.is_wrap_text =
-                                            ConverterUtil::normalize_bool_property_u8(is_wrap_text);
-                                    }
-                                    if let Some(vertical_alignment) =
-                                        alignment_attributes.get("vertical")
-                                    {
-                                        cell_xf.vertical_alignment =
-                                            VerticalAlignmentValues::get_enum(vertical_alignment);
-                                    }
-                                    if let Some(horizontal_alignment) =
-                                        alignment_attributes.get("horizontal")
-                                    {
-                                        cell_xf.horizontal_alignment =
-                                            HorizontalAlignmentValues::get_enum(
-                                                horizontal_alignment,
-                                            );
+                        // Load Alignment and Protection Values if exist
+                        while let Some((child_id, _)) = current_element.pop_child_mut() {
+                            if let Some(child_element) = xml_doc_mut.pop_element_mut(&child_id) {
+                                if let Some(child_attributes) = child_element.get_attribute() {
+                                    match child_element.get_tag() {
+                                        "alignment" => {
+                                            if let Some(is_wrap_text) =
+                                                child_attributes.get("wrapText")
+                                            {
+                                                cell_xf.is_wrap_text =
+                                                    ConverterUtil::normalize_bool_property_u8(
+                                                        is_wrap_text,
+                                                    );
+                                            }
+                                            if let Some(vertical_alignment) =
+                                                child_attributes.get("vertical")
+                                            {
+                                                cell_xf.vertical_alignment =
+                                                    VerticalAlignmentValues::get_enum(
+                                                        vertical_alignment,
+                                                    );
+                                            }
+                                            if let Some(horizontal_alignment) =
+                                                child_attributes.get("horizontal")
+                                            {
+                                                cell_xf.horizontal_alignment =
+                                                    HorizontalAlignmentValues::get_enum(
+                                                        horizontal_alignment,
+                                                    );
+                                            }
+                                        }
+                                        "protection" => {
+                                            if let Some(is_locked) = child_attributes.get("locked")
+                                            {
+                                                cell_xf.is_locked =
+                                                    ConverterUtil::normalize_bool_property_u8(
+                                                        is_locked,
+                                                    );
+                                            }
+                                            if let Some(is_hidden) = child_attributes.get("hidden")
+                                            {
+                                                cell_xf.is_hidden =
+                                                    ConverterUtil::normalize_bool_property_u8(
+                                                        is_hidden,
+                                                    );
+                                            }
+                                        }
+                                        _ => {}
                                     }
                                 }
                             }
@@ -1060,6 +1088,20 @@ impl StylePart {
                     .set_attribute_mut(alignment_attributes)
                     .context("Setting Alignment Attribute Failed")?;
             }
+            if xfs.is_locked == 0 || xfs.is_hidden > 0 {
+                let mut protection_attributes: HashMap<String, String> = HashMap::new();
+                if xfs.is_locked == 0 {
+                    protection_attributes.insert("locked".to_string(), "0".to_string());
+                }
+                if xfs.is_hidden > 0 {
+                    protection_attributes.insert("hidden".to_string(), "1".to_string());
+                }
+                xml_doc_mut
+                    .append_child_mut("protection", Some(&xf_id))
+                    .context("Create Cell Protection Style Config Failed")?
+                    .set_attribute_mut(protection_attributes)
+                    .context("Setting Protection Attribute Failed")?;
+            }
         }
         Ok(())
     }
@@ -1073,6 +1115,256 @@ impl StylePart {
         hasher.finish()
     }
 
+    fn get_custom_number_format_id_mut(&mut self, custom_format: String) -> usize {
+        if let Some((_, number_format)) = self
+            .number_format_collection
+            .iter()
+            .find(|(_, number_format)| number_format.format_code == custom_format)
+        {
+            number_format.format_id
+        } else {
+            // Custom format id start after the builtin range
+            let format_id = self
+                .number_format_collection
+                .iter()
+                .map(|(_, number_format)| number_format.format_id + 1)
+                .max()
+                .unwrap_or_default()
+                .max(164);
+            let number_format = NumberFormat {
+                format_id,
+                format_code: custom_format,
+                format_type: NumberFormatValues::Custom,
+            };
+            let current_hash = self.generate_setting_hash(&number_format);
+            self.number_format_collection
+                .push((current_hash, number_format));
+            format_id
+        }
+    }
+
+    /// Add differential style used by conditional formatting and return the dxf id
+    pub(crate) fn get_dxf_id_mut(
+        &mut self,
+        differential_style: DifferentialStyleSetting,
+    ) -> AnyResult<u32, AnyError> {
+        let style_hash = self.generate_setting_hash(&differential_style);
+        if let Some(dxf_id) = self.dxf_cache_id.get(&style_hash) {
+            return Ok(*dxf_id);
+        }
+        let number_format_id = differential_style
+            .custom_number_format
+            .clone()
+            .map(|custom_format| self.get_custom_number_format_id_mut(custom_format));
+        let xml_document = self
+            .xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade style document"))?;
+        let mut xml_doc_mut = xml_document
+            .try_borrow_mut()
+            .context("xml doc borrow failed")?;
+        let dxfs_id = if let Some(dxfs_id) = xml_doc_mut
+            .get_first_element_id(vec!["dxfs"], None)
+            .context("Failed to find dxfs element")?
+        {
+            dxfs_id
+        } else {
+            xml_doc_mut
+                .insert_children_after_tag_mut("dxfs", "cellStyles", None)
+                .context("Create dxfs parent Failed")?
+                .get_id()
+        };
+        let dxf_id = xml_doc_mut
+            .get_element(&dxfs_id)
+            .ok_or(anyhow!("dxfs element not found"))?
+            .get_child_count() as u32;
+        let dxf_element_id = xml_doc_mut
+            .append_child_mut("dxf", Some(&dxfs_id))
+            .context("Create dxf Element Failed")?
+            .get_id();
+        if differential_style.is_bold
+            || differential_style.is_italic
+            || differential_style.is_strike
+            || differential_style.is_underline
+            || differential_style.text_color.is_some()
+        {
+            let font_id = xml_doc_mut
+                .append_child_mut("font", Some(&dxf_element_id))
+                .context("Create dxf Font Failed")?
+                .get_id();
+            for (tag, is_enabled) in [
+                ("b", differential_style.is_bold),
+                ("i", differential_style.is_italic),
+                ("strike", differential_style.is_strike),
+                ("u", differential_style.is_underline),
+            ] {
+                if is_enabled {
+                    xml_doc_mut
+                        .append_child_mut(tag, Some(&font_id))
+                        .context("Create dxf Font Property Failed")?;
+                }
+            }
+            StylePart::add_color_element(
+                differential_style.text_color.clone(),
+                &mut xml_doc_mut,
+                font_id,
+            )?;
+        }
+        if let (Some(format_id), Some(format_code)) = (
+            number_format_id,
+            differential_style.custom_number_format.clone(),
+        ) {
+            xml_doc_mut
+                .append_child_mut("numFmt", Some(&dxf_element_id))
+                .context("Create dxf Number Format Failed")?
+                .set_attribute_mut(HashMap::from([
+                    ("numFmtId".to_string(), format_id.to_string()),
+                    ("formatCode".to_string(), format_code),
+                ]))
+                .context("Set dxf Number Format Attribute Failed")?;
+        }
+        if let Some(background_color) = differential_style.background_color.clone() {
+            let fill_id = xml_doc_mut
+                .append_child_mut("fill", Some(&dxf_element_id))
+                .context("Create dxf Fill Failed")?
+                .get_id();
+            let pattern_fill_id = xml_doc_mut
+                .append_child_mut("patternFill", Some(&fill_id))
+                .context("Create dxf Pattern Fill Failed")?
+                .get_id();
+            // Solid fill of differential style takes the background color
+            xml_doc_mut
+                .append_child_mut("bgColor", Some(&pattern_fill_id))
+                .context("Create dxf Background Color Failed")?
+                .set_attribute_mut(HashMap::from([(
+                    ColorSettingTypeValues::get_string(background_color.color_setting_type),
+                    background_color.value,
+                )]))
+                .context("Set dxf Background Color Failed")?;
+        }
+        if let Some(border) = differential_style.border.clone() {
+            let border_id = xml_doc_mut
+                .append_child_mut("border", Some(&dxf_element_id))
+                .context("Create dxf Border Failed")?
+                .get_id();
+            for border_side in ["left", "right", "top", "bottom"] {
+                StylePart::add_border_element(
+                    border_side,
+                    &mut xml_doc_mut,
+                    &border_id,
+                    border.clone(),
+                )?;
+            }
+        }
+        if let Some(attributes) = xml_doc_mut
+            .get_element_mut(&dxfs_id)
+            .ok_or(anyhow!("dxfs element not found"))?
+            .get_attribute_mut()
+        {
+            attributes.insert("count".to_string(), (dxf_id + 1).to_string());
+        } else {
+            xml_doc_mut
+                .get_element_mut(&dxfs_id)
+                .ok_or(anyhow!("dxfs element not found"))?
+                .set_attribute_mut(HashMap::from([(
+                    "count".to_string(),
+                    (dxf_id + 1).to_string(),
+                )]))
+                .context("Set dxfs count Failed")?;
+        }
+        self.dxf_cache_id.insert(style_hash, dxf_id);
+        Ok(dxf_id)
+    }
+
+    /// Read the differential style of the dxf id already in the style part
+    pub(crate) fn get_differential_style(
+        &self,
+        dxf_id: u32,
+    ) -> AnyResult<DifferentialStyleSetting, AnyError> {
+        let xml_document = self
+            .xml_document
+            .upgrade()
+            .ok_or(anyhow!("Failed to upgrade style document"))?;
+        let xml_doc = xml_document.try_borrow().context("xml doc borrow failed")?;
+        let dxf_element_id = xml_doc
+            .get_first_element_id(vec!["dxfs"], None)
+            .context("Failed to find dxfs element")?
+            .and_then(|dxfs_id| xml_doc.get_element_ids_by_tag("dxf", Some(&dxfs_id)))
+            .and_then(|dxf_ids| dxf_ids.get(dxf_id as usize).copied())
+            .ok_or(anyhow!("Differential style not found : {}", dxf_id))?;
+        let get_child = |parent_id: &usize, tag: &str| {
+            xml_doc
+                .get_element_ids_by_tag(tag, Some(parent_id))
+                .and_then(|element_ids| element_ids.first().copied())
+                .and_then(|element_id| xml_doc.get_element(&element_id))
+        };
+        let get_color = |parent_id: &usize, tag: &str| {
+            get_child(parent_id, tag)
+                .and_then(|color_element| color_element.get_attribute())
+                .and_then(StylePart::get_color_setting)
+        };
+        let mut differential_style = DifferentialStyleSetting::default();
+        if let Some(font_element) = get_child(&dxf_element_id, "font") {
+            let font_id = font_element.get_id();
+            // Font property without val is enabled, val turn it off with 0, false or none
+            let is_enabled = |tag: &str| {
+                get_child(&font_id, tag).is_some_and(|property_element| {
+                    property_element
+                        .get_attribute()
+                        .and_then(|attributes| attributes.get("val"))
+                        .is_none_or(|value| !matches!(value.as_str(), "0" | "false" | "none"))
+                })
+            };
+            differential_style.is_bold = is_enabled("b");
+            differential_style.is_italic = is_enabled("i");
+            differential_style.is_strike = is_enabled("strike");
+            differential_style.is_underline = is_enabled("u");
+            differential_style.text_color = get_color(&font_id, "color");
+        }
+        differential_style.custom_number_format = get_child(&dxf_element_id, "numFmt")
+            .and_then(|number_format_element| number_format_element.get_attribute())
+            .and_then(|attributes| attributes.get("formatCode").cloned());
+        if let Some(pattern_fill_element) = get_child(&dxf_element_id, "fill")
+            .and_then(|fill_element| get_child(&fill_element.get_id(), "patternFill"))
+        {
+            let pattern_fill_id = pattern_fill_element.get_id();
+            differential_style.background_color = get_color(&pattern_fill_id, "bgColor")
+                .or_else(|| get_color(&pattern_fill_id, "fgColor"));
+        }
+        if let Some(border_element) = get_child(&dxf_element_id, "border") {
+            let border_id = border_element.get_id();
+            differential_style.border = ["left", "right", "top", "bottom"]
+                .iter()
+                .filter_map(|border_side| get_child(&border_id, border_side))
+                .find_map(|side_element| {
+                    side_element
+                        .get_attribute()
+                        .and_then(|attributes| attributes.get("style"))
+                        .map(|style| BorderSetting {
+                            border_color: get_color(&side_element.get_id(), "color"),
+                            style: BorderStyleValues::get_enum(style),
+                        })
+                });
+        }
+        Ok(differential_style)
+    }
+
+    /// Color setting from the theme, rgb or indexed attribute of color element
+    pub(crate) fn get_color_setting(attributes: &HashMap<String, String>) -> Option<ColorSetting> {
+        [
+            ("theme", ColorSettingTypeValues::Theme),
+            ("rgb", ColorSettingTypeValues::Rgb),
+            ("indexed", ColorSettingTypeValues::Indexed),
+        ]
+        .into_iter()
+        .find_map(|(key, color_setting_type)| {
+            attributes.get(key).map(|value| ColorSetting {
+                color_setting_type,
+                value: value.to_string(),
+            })
+        })
+    }
+
     pub(crate) fn get_style_id_mut(
         &mut self,
         style_setting: StyleSetting,
@@ -1086,31 +1378,19 @@ impl StylePart {
                 cell_style.apply_number_format = 1;
                 // Get Number Format ID
                 if let Some(custom_format) = style_setting.custom_number_format {
-                    let mut hasher = DefaultHasher::new();
-                    let number_format = NumberFormat {
-                        format_code: custom_format,
-                        format_type: NumberFormatValues::Custom,
-                        ..Default::default()
-                    };
-                    number_format.hash(&mut hasher);
-                    let current_hash = hasher.finish();
-                    if let Some(position) = self
-                        .number_format_collection
-                        .iter()
-                        .position(|(hash, _)| *hash == current_hash)
-                    {
-                        cell_style.number_format_id = position as u16 + 163;
-                    } else {
-                        self.number_format_collection
-                            .push((current_hash, number_format));
-                        cell_style.number_format_id =
-                            (self.number_format_collection.len() - 1) as u16;
-                    }
+                    cell_style.number_format_id =
+                        self.get_custom_number_format_id_mut(custom_format) as u16;
                 } else {
                     return Err(anyhow!(
                         "Custom Format Type is used without providing custom number format."
                     ));
                 }
+            } else if style_setting.number_format != NumberFormatValues::General {
+                cell_style.apply_number_format = 1;
+                cell_style.number_format_id =
+                    NumberFormatValues::get_string(style_setting.number_format)
+                        .parse()
+                        .context("Failed to parse builtin number format id")?;
             }
             // Get Font Style ID
             {
@@ -1226,6 +1506,11 @@ impl StylePart {
                 if style_setting.is_wrap_text {
                     cell_style.is_wrap_text = 1;
                 }
+                if !style_setting.is_locked || style_setting.is_formula_hidden {
+                    cell_style.apply_protection = 1;
+                }
+                cell_style.is_locked = u8::from(style_setting.is_locked);
+                cell_style.is_hidden = u8::from(style_setting.is_formula_hidden);
                 let cell_style_xfs = cell_style.clone();
                 cell_style_xfs.hash(&mut hasher);
                 let current_hash = hasher.finish();
@@ -1,3 +1,4 @@
 pub(crate) mod test_document;
 pub(crate) mod test_excel;
 pub(crate) mod test_power_point;
+pub(crate) mod test_xml;
@@ -7,6 +7,17 @@ fn blank_document() {
     assert_eq!(true, true);
 }
 
+#[test]
+fn memory_document() {
+    let file = crate::document_2007::Word::new(None, crate::document_2007::Word::default())
+        .expect("Blank File Open Failed");
+    let file_content = file.save_to_vec().expect("Save Result Failed");
+    crate::document_2007::Word::from_bytes(&file_content, crate::document_2007::Word::default())
+        .expect("Open Memory File Failed")
+        .save_to_vec()
+        .expect("Save Result Failed");
+}
+
 #[test]
 fn edit_document() {
     let file = crate::document_2007::Word::new(
@@ -36,6 +36,108 @@ fn blank_excel() {
     assert_eq!(true, true);
 }
 
+#[test]
+fn excel_memory_round_trip() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    file.add_sheet_mut(Some("Memory".to_string()))
+        .expect("Failed to add static Sheet");
+    let file_content = file.save_to_vec().expect("Memory Save Failed");
+    let file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Memory File Failed");
+    assert!(file
+        .list_sheet_names()
+        .expect("Failed to list sheet names")
+        .contains(&"Memory".to_string()));
+    file.save_to_writer(
+        std::fs::File::create(get_save_file(Some("memory"))).expect("Create File Failed"),
+    )
+    .expect("Writer Save Failed");
+    let file = crate::spreadsheet_2007::Excel::from_reader(
+        std::fs::File::open("src/tests/TestFiles/basic_test.xlsx").expect("Open Test File Failed"),
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Reader File Failed");
+    file.save_to_vec().expect("Memory Save Failed");
+}
+
+#[test]
+fn excel_temp_file_store() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        Some("src/tests/TestFiles/basic_test.xlsx".to_string()),
+        crate::spreadsheet_2007::ExcelPropertiesModel {
+            is_in_memory: false,
+            is_editable: true,
+        },
+    )
+    .expect("Open Existing File Failed");
+    file.add_sheet_mut(Some("TempStore".to_string()))
+        .expect("Failed to add static Sheet");
+    let file_content = file.save_to_vec().expect("Memory Save Failed");
+    let file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Memory File Failed");
+    assert!(file
+        .list_sheet_names()
+        .expect("Failed to list sheet names")
+        .contains(&"TempStore".to_string()));
+}
+
+#[test]
+fn excel_save_replace() {
+    let save_directory = "test_results/save_replace";
+    std::fs::create_dir_all(save_directory).expect("Failed to Create");
+    let save_path = format!("{}/replace.xlsx", save_directory);
+    for sheet_name in ["First", "Second"] {
+        let mut file = crate::spreadsheet_2007::Excel::new(
+            None,
+            crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+        )
+        .expect("Create New File Failed");
+        file.add_sheet_mut(Some(sheet_name.to_string()))
+            .expect("Failed to add static Sheet");
+        file.save_as(&save_path).expect("File Save Failed");
+    }
+    // Existing file is replaced in place without leaving the save buffer behind
+    assert_eq!(
+        std::fs::read_dir(save_directory)
+            .expect("Failed to read dir")
+            .count(),
+        1
+    );
+    let file = crate::spreadsheet_2007::Excel::new(
+        Some(save_path.clone()),
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    assert!(file
+        .list_sheet_names()
+        .expect("Failed to list sheet names")
+        .contains(&"Second".to_string()));
+    // Failed save keep the existing file untouched
+    let file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    assert!(file
+        .save_as(&format!("{}/missing/replace.xlsx", save_directory))
+        .is_err());
+    assert!(crate::spreadsheet_2007::Excel::new(
+        Some(save_path),
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .is_ok());
+}
+
 #[test]
 fn excel_handling() {
     let mut file = crate::spreadsheet_2007::Excel::new(
@@ -272,17 +374,17 @@ fn set_cell_style() {
                 "V3",
                 vec![
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Dravia".to_string()),
+                        value: "Dravia".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Vemal".to_string()),
+                        value: "Vemal".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("style".to_string()),
+                        value: "style".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         style_id: Some(style_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
@@ -346,38 +448,36 @@ fn blank_style_excel() {
                 "V3",
                 vec![
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Dravia".to_string()),
+                        value: "Dravia".into(),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Vemal".to_string()),
+                        value: "Vemal".into(),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Bold".to_string()),
+                        value: "Bold".into(),
                         style_id: Some(bold_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Italic".to_string()),
+                        value: "Italic".into(),
                         style_id: Some(italic_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("underline".to_string()),
+                        value: "underline".into(),
                         style_id: Some(underline_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("double underline".to_string()),
+                        value: "double underline".into(),
                         style_id: Some(double_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some(
-                            "This is a very long line to wrap the column. Test the wrap string"
-                                .to_string(),
-                        ),
+                        value: "This is a very long line to wrap the column. Test the wrap string"
+                            .into(),
                         style_id: Some(wrap_text_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
@@ -438,44 +538,42 @@ fn edit_excel() {
                 "V3",
                 vec![
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Dravia".to_string()),
+                        value: "Dravia".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Vemal".to_string()),
+                        value: "Vemal".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Bold".to_string()),
+                        value: "Bold".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         style_id: Some(bold_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Italic".to_string()),
+                        value: "Italic".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         style_id: Some(italic_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("underline".to_string()),
+                        value: "underline".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         style_id: Some(underline_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("double underline".to_string()),
+                        value: "double underline".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         style_id: Some(double_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some(
-                            "This is a very long line to wrap the column. Test the wrap string"
-                                .to_string(),
-                        ),
+                        value: "This is a very long line to wrap the column. Test the wrap string"
+                            .into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         style_id: Some(wrap_text_id),
                         ..crate::spreadsheet_2007::models::CellProperties::default()
@@ -512,12 +610,12 @@ fn edit_large_excel() {
                 "V3",
                 vec![
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Dravia".to_string()),
+                        value: "Dravia".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
                     crate::spreadsheet_2007::models::CellProperties {
-                        value: Some("Vemal".to_string()),
+                        value: "Vemal".into(),
                         data_type: crate::spreadsheet_2007::models::CellDataType::Auto,
                         ..crate::spreadsheet_2007::models::CellProperties::default()
                     },
@@ -558,7 +656,7 @@ fn large_excel() {
                             1,
                             (1..10)
                                 .map(|_| crate::spreadsheet_2007::models::CellProperties {
-                                    value: Some("Test".to_string()),
+                                    value: "Test".into(),
                                     ..Default::default()
                                 })
                                 .collect(),
@@ -573,3 +671,3699 @@ fn large_excel() {
         .expect("Save File Failed");
     assert_eq!(true, true);
 }
+
+#[test]
+fn stream_large_excel() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel {
+            is_in_memory: false,
+            is_editable: true,
+        },
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Stream".to_string()))
+            .expect("Failed to add the worksheet");
+        let mut stream = sheet.stream_rows_mut().expect("Failed to start stream");
+        for row in 1..10_000 {
+            stream
+                .append_row_value_index_mut(
+                    row,
+                    1,
+                    (1..10)
+                        .map(|col| crate::spreadsheet_2007::models::CellProperties {
+                            value: if col % 2 == 0 {
+                                "Test".into()
+                            } else {
+                                (row * col).into()
+                            },
+                            ..Default::default()
+                        })
+                        .collect(),
+                )
+                .expect("Failed to Stream Row Value");
+        }
+        assert!(stream.append_row_value_index_mut(10, 1, vec![]).is_err());
+        assert!(sheet.set_row_value_index_mut(10_001, 1, vec![]).is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Streamed File Failed");
+    file.get_worksheet_mut("Stream".to_string())
+        .expect("Failed to open streamed worksheet");
+    file.save_as(&get_save_file(Some("stream")))
+        .expect("Save File Failed");
+}
+
+#[test]
+fn share_string_count() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Count".to_string()))
+            .expect("Failed to add sheet");
+        sheet
+            .set_row_value_ref_mut(
+                "A1",
+                vec!["Apple".into(), "Berry".into(), "Apple".into()]
+                    .into_iter()
+                    .map(|value| crate::spreadsheet_2007::models::CellProperties {
+                        value,
+                        ..Default::default()
+                    })
+                    .collect(),
+            )
+            .expect("Failed To Set Row Value");
+        // Replaced share string cell is no longer counted
+        sheet
+            .set_row_value_ref_mut(
+                "C1",
+                vec![crate::spreadsheet_2007::models::CellProperties {
+                    value: 10.into(),
+                    ..Default::default()
+                }],
+            )
+            .expect("Failed To Set Row Value");
+        // Rejected write keep the replaced cell, its count and the link untouched
+        for _ in 0..3 {
+            assert!(sheet
+                .set_row_value_ref_mut(
+                    "A1",
+                    vec![
+                        crate::spreadsheet_2007::models::CellProperties {
+                            value: "Cherry".into(),
+                            hyperlink_properties: Some(
+                                crate::global_2007::models::HyperlinkProperties {
+                                    value: "https://example.com/".to_string(),
+                                    ..Default::default()
+                                }
+                            ),
+                            ..Default::default()
+                        },
+                        crate::spreadsheet_2007::models::CellProperties {
+                            formula: Some("SUM(".to_string()),
+                            ..Default::default()
+                        },
+                    ],
+                )
+                .is_err());
+        }
+        assert_eq!(
+            sheet
+                .get_cell_value("A1")
+                .expect("Failed to read the cell")
+                .expect("Cell missing")
+                .value,
+            crate::spreadsheet_2007::models::CellValue::Text("Apple".to_string())
+        );
+    }
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Inline".to_string()))
+            .expect("Failed to add sheet");
+        let mut stream = sheet.stream_rows_mut().expect("Failed to start stream");
+        stream
+            .set_inline_string_mut(true)
+            .expect("Failed to set inline string");
+        for row in 1..=3 {
+            stream
+                .append_row_value_index_mut(
+                    row,
+                    1,
+                    vec![crate::spreadsheet_2007::models::CellProperties {
+                        value: format!("Unique {}", row).into(),
+                        ..Default::default()
+                    }],
+                )
+                .expect("Failed to Stream Row Value");
+        }
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read saved file");
+    let mut share_string = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/sharedStrings.xml")
+            .expect("Share string part missing"),
+        &mut share_string,
+    )
+    .expect("Failed to read share string");
+    assert!(share_string.contains(" count=\"2\""));
+    assert!(share_string.contains(" uniqueCount=\"2\""));
+    assert!(!share_string.contains("Unique"));
+    assert!(!share_string.contains("Cherry"));
+    let mut count_sheet = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet part missing"),
+        &mut count_sheet,
+    )
+    .expect("Failed to read sheet");
+    assert!(count_sheet.contains("r=\"C1\"") && !count_sheet.contains("Unique"));
+    assert!(!count_sheet.contains("hyperlink"));
+    let mut inline_sheet = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet3.xml")
+            .expect("Inline sheet part missing"),
+        &mut inline_sheet,
+    )
+    .expect("Failed to read inline sheet");
+    assert!(inline_sheet.contains("<is><t>Unique 1</t></is>"));
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let sheet = file
+        .get_worksheet_mut("Count".to_string())
+        .expect("Failed to find the worksheet");
+    let row = sheet
+        .get_row(1)
+        .expect("Failed to read row")
+        .expect("Row missing");
+    assert_eq!(
+        row[&2].value,
+        crate::spreadsheet_2007::models::CellValue::Text("Berry".to_string())
+    );
+    assert_eq!(
+        row[&3].value,
+        crate::spreadsheet_2007::models::CellValue::Number(10.0)
+    );
+}
+
+#[test]
+fn read_cell_values() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        Some("src/tests/TestFiles/basic_test.xlsx".to_string()),
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Existing File Failed");
+    let formula = file
+        .get_worksheet_mut("formula".to_string())
+        .expect("Failed to find the worksheet");
+    let share_string = formula
+        .get_cell_value("A1")
+        .expect("Failed to read cell")
+        .expect("Cell missing");
+    assert_eq!(
+        share_string.value,
+        crate::spreadsheet_2007::models::CellValue::Text("val 1 & test".to_string())
+    );
+    let formula_cell = formula
+        .get_cell_value("C2")
+        .expect("Failed to read cell")
+        .expect("Cell missing");
+    assert_eq!(formula_cell.formula, Some("A2+B2".to_string()));
+    assert_eq!(
+        formula_cell.value,
+        crate::spreadsheet_2007::models::CellValue::Number(100.0)
+    );
+    assert!(formula
+        .get_cell_value("Z100")
+        .expect("Failed to read cell")
+        .is_none());
+    // References outside the sheet are rejected instead of read as missing
+    for cell_ref in ["ZZZZ1", "XFE1", "A0", "A1048577"] {
+        assert!(formula.get_cell_value(cell_ref).is_err());
+    }
+    assert!(formula.get_range("A1:XFE2").is_err());
+    assert!(formula.get_row(0).is_err());
+    assert!(formula.get_row(1_048_577).is_err());
+    let range = formula.get_range("A2:D3").expect("Failed to read range");
+    assert_eq!(range.len(), 2);
+    assert_eq!(range[0].len(), 4);
+    assert_eq!(
+        range[1][3].value,
+        crate::spreadsheet_2007::models::CellValue::Empty
+    );
+    let row = formula
+        .get_row(8)
+        .expect("Failed to read row")
+        .expect("Row missing");
+    assert!(row.get(&13).expect("Cell missing").style_id.is_some());
+    assert!(formula
+        .iter_rows()
+        .all(|row| row.expect("Failed to read row").0 > 0));
+}
+
+#[test]
+fn typed_cell_values() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 15).expect("Invalid date");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Typed".to_string()))
+            .expect("Failed to add the worksheet");
+        sheet
+            .set_row_value_ref_mut(
+                "A1",
+                vec![
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: "00123".into(),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: "true".into(),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: 42.5.into(),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: true.into(),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: date.into(),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: crate::spreadsheet_2007::models::CellValue::Error(
+                            "#N/A".to_string(),
+                        ),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: vec![
+                            crate::spreadsheet_2007::models::RichTextRun {
+                                text: "Bold ".to_string(),
+                                is_bold: true,
+                                ..Default::default()
+                            },
+                            crate::spreadsheet_2007::models::RichTextRun {
+                                text: "Red".to_string(),
+                                text_color: Some(crate::spreadsheet_2007::models::ColorSetting {
+                                    color_setting_type:
+                                        crate::spreadsheet_2007::models::ColorSettingTypeValues::Rgb,
+                                    value: "FFFF0000".to_string(),
+                                }),
+                                ..Default::default()
+                            },
+                        ]
+                        .into(),
+                        ..Default::default()
+                    },
+                ],
+            )
+            .expect("Failed To Set Row Value");
+        assert!(sheet
+            .set_row_value_ref_mut(
+                "A2",
+                vec![crate::spreadsheet_2007::models::CellProperties {
+                    value: chrono::NaiveDate::from_ymd_opt(1899, 1, 1).into(),
+                    ..Default::default()
+                }],
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let sheet = file
+        .get_worksheet_mut("Typed".to_string())
+        .expect("Failed to find the worksheet");
+    let row = sheet
+        .get_row(1)
+        .expect("Failed to read row")
+        .expect("Row missing");
+    assert_eq!(
+        row[&1].value,
+        crate::spreadsheet_2007::models::CellValue::Text("00123".to_string())
+    );
+    assert_eq!(
+        row[&2].value,
+        crate::spreadsheet_2007::models::CellValue::Text("true".to_string())
+    );
+    assert_eq!(
+        row[&3].value,
+        crate::spreadsheet_2007::models::CellValue::Number(42.5)
+    );
+    assert_eq!(
+        row[&4].value,
+        crate::spreadsheet_2007::models::CellValue::Bool(true)
+    );
+    assert_eq!(
+        row[&5].value,
+        crate::spreadsheet_2007::models::CellValue::Number(45306.0)
+    );
+    assert!(row[&5].style_id.is_some());
+    assert_eq!(
+        row[&6].value,
+        crate::spreadsheet_2007::models::CellValue::Error("#N/A".to_string())
+    );
+    if let crate::spreadsheet_2007::models::CellValue::Rich(rich_text) = &row[&7].value {
+        assert_eq!(rich_text.len(), 2);
+        assert!(rich_text[0].is_bold);
+        assert_eq!(rich_text[0].text, "Bold ");
+        assert_eq!(
+            rich_text[1]
+                .text_color
+                .as_ref()
+                .map(|color| color.value.as_str()),
+            Some("FFFF0000")
+        );
+    } else {
+        panic!("Rich text value expected");
+    }
+}
+
+#[test]
+fn rich_text_share_string() {
+    let rich_text = vec![
+        crate::spreadsheet_2007::models::RichTextRun {
+            text: "Total ".to_string(),
+            font_family: Some("Arial".to_string()),
+            font_size: Some(12),
+            is_bold: true,
+            ..Default::default()
+        },
+        crate::spreadsheet_2007::models::RichTextRun {
+            text: "Due".to_string(),
+            text_color: Some(crate::spreadsheet_2007::models::ColorSetting {
+                color_setting_type: crate::spreadsheet_2007::models::ColorSettingTypeValues::Theme,
+                value: "4".to_string(),
+            }),
+            is_italic: true,
+            is_underline: true,
+            is_strike: true,
+            ..Default::default()
+        },
+    ];
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Rich".to_string()))
+            .expect("Failed to add sheet");
+        sheet
+            .set_row_value_ref_mut(
+                "A1",
+                vec![
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: rich_text.clone().into(),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: rich_text.clone().into(),
+                        data_type: crate::spreadsheet_2007::models::CellDataType::InlineString,
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: " padded ".into(),
+                        ..Default::default()
+                    },
+                ],
+            )
+            .expect("Failed To Set Row Value");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read saved file");
+    let mut share_string = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/sharedStrings.xml")
+            .expect("Share string part missing"),
+        &mut share_string,
+    )
+    .expect("Failed to read share string");
+    assert!(share_string.contains(
+        "<si ><r ><rPr ><rFont val=\"Arial\" /><b /><sz val=\"12\" /></rPr><t xml:space=\"preserve\" >Total </t></r>"
+    ));
+    assert!(share_string.contains("<i /><strike /><color theme=\"4\" /><u />"));
+    assert!(share_string.contains("<t xml:space=\"preserve\" > padded </t>"));
+    // Editing the loaded file keeps the runs of existing share string items
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut sheet = file
+            .get_worksheet_mut("Rich".to_string())
+            .expect("Failed to find the worksheet");
+        sheet
+            .set_row_value_ref_mut(
+                "A2",
+                vec![crate::spreadsheet_2007::models::CellProperties {
+                    value: "Plain".into(),
+                    ..Default::default()
+                }],
+            )
+            .expect("Failed To Set Row Value");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let sheet = file
+        .get_worksheet_mut("Rich".to_string())
+        .expect("Failed to find the worksheet");
+    let row = sheet
+        .get_row(1)
+        .expect("Failed to read row")
+        .expect("Row missing");
+    assert_eq!(
+        row[&1].value,
+        crate::spreadsheet_2007::models::CellValue::Rich(rich_text.clone())
+    );
+    assert_eq!(
+        row[&2].value,
+        crate::spreadsheet_2007::models::CellValue::Rich(rich_text)
+    );
+    assert_eq!(
+        row[&3].value,
+        crate::spreadsheet_2007::models::CellValue::Text(" padded ".to_string())
+    );
+}
+
+#[test]
+fn merge_cells() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Merge".to_string()))
+            .expect("Failed to add the worksheet");
+        sheet
+            .set_merge_cell_mut("A1:C1")
+            .expect("Failed to merge cells");
+        sheet
+            .set_merge_cell_mut("$E$4:D2")
+            .expect("Failed to merge cells");
+        assert!(sheet.set_merge_cell_mut("B1:D1").is_err());
+        assert!(sheet.set_merge_cell_mut("A5").is_err());
+        assert_eq!(
+            sheet.list_merge_cell().expect("Failed to list merge cells"),
+            vec!["A1:C1".to_string(), "D2:E4".to_string()]
+        );
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let mut sheet = file
+        .get_worksheet_mut("Merge".to_string())
+        .expect("Failed to find the worksheet");
+    assert_eq!(
+        sheet.list_merge_cell().expect("Failed to list merge cells"),
+        vec!["A1:C1".to_string(), "D2:E4".to_string()]
+    );
+    sheet
+        .remove_merge_cell_mut("A1:C1")
+        .expect("Failed to remove merge cells");
+    assert!(sheet.remove_merge_cell_mut("A1:C1").is_err());
+    assert_eq!(
+        sheet.list_merge_cell().expect("Failed to list merge cells"),
+        vec!["D2:E4".to_string()]
+    );
+}
+
+#[test]
+fn cell_hyperlinks() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Links".to_string()))
+            .expect("Failed to add the worksheet");
+        sheet
+            .set_row_value_ref_mut(
+                "A1",
+                vec![
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: "Site".into(),
+                        hyperlink_properties: Some(crate::global_2007::models::HyperlinkProperties {
+                            value: "https://openxml-office.draviavemal.com/".to_string(),
+                            tool_tip: Some("Docs & <Guide> \"v4\"".to_string()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: "Mail".into(),
+                        hyperlink_properties: Some(crate::global_2007::models::HyperlinkProperties {
+                            hyperlink_type:
+                                crate::global_2007::models::HyperlinkPropertyTypeValues::MAIL_TO,
+                            value: "support@example.com".to_string(),
+                            email_subject: Some("Open Xml Office 100% & Ünïcode ✓".to_string()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: "Jump".into(),
+                        hyperlink_properties: Some(crate::global_2007::models::HyperlinkProperties {
+                            hyperlink_type:
+                                crate::global_2007::models::HyperlinkPropertyTypeValues::TARGET_SHEET,
+                            value: "Dravia's Sheet".to_string(),
+                            cell_location: Some("B2".to_string()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                ],
+            )
+            .expect("Failed To Set Row Value");
+        assert!(sheet
+            .set_row_value_ref_mut(
+                "A2",
+                vec![crate::spreadsheet_2007::models::CellProperties {
+                    hyperlink_properties: Some(crate::global_2007::models::HyperlinkProperties {
+                        hyperlink_type:
+                            crate::global_2007::models::HyperlinkPropertyTypeValues::NEXT_SLIDE,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+            )
+            .is_err());
+        // Link of a row that fail on a later cell is not stored either
+        assert!(sheet
+            .set_row_value_ref_mut(
+                "A2",
+                vec![
+                    crate::spreadsheet_2007::models::CellProperties {
+                        value: "Rejected".into(),
+                        hyperlink_properties: Some(
+                            crate::global_2007::models::HyperlinkProperties {
+                                value: "https://example.com/".to_string(),
+                                ..Default::default()
+                            }
+                        ),
+                        ..Default::default()
+                    },
+                    crate::spreadsheet_2007::models::CellProperties {
+                        formula: Some("SUM(".to_string()),
+                        ..Default::default()
+                    },
+                ],
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    // Reopen twice to make sure relationships are not duplicated
+    let file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut relationship_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/_rels/sheet2.xml.rels")
+            .expect("Sheet relationship missing"),
+        &mut relationship_content,
+    )
+    .expect("Failed to read relationship");
+    assert_eq!(
+        relationship_content
+            .matches("TargetMode=\"External\"")
+            .count(),
+        2
+    );
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let sheet = file
+        .get_worksheet_mut("Links".to_string())
+        .expect("Failed to find the worksheet");
+    let row = sheet
+        .get_row(1)
+        .expect("Failed to read row")
+        .expect("Row missing");
+    let web_link = row[&1].hyperlink_properties.as_ref().expect("Link missing");
+    assert_eq!(
+        web_link.hyperlink_type,
+        crate::global_2007::models::HyperlinkPropertyTypeValues::WEB_URL
+    );
+    assert_eq!(web_link.value, "https://openxml-office.draviavemal.com/");
+    // Escaped attribute survive the two reopen without double escaping
+    assert_eq!(web_link.tool_tip, Some("Docs & <Guide> \"v4\"".to_string()));
+    let mail_link = row[&2].hyperlink_properties.as_ref().expect("Link missing");
+    assert_eq!(mail_link.value, "support@example.com");
+    assert_eq!(
+        mail_link.email_subject,
+        Some("Open Xml Office 100% & Ünïcode ✓".to_string())
+    );
+    let sheet_link = row[&3].hyperlink_properties.as_ref().expect("Link missing");
+    assert_eq!(
+        sheet_link.hyperlink_type,
+        crate::global_2007::models::HyperlinkPropertyTypeValues::TARGET_SHEET
+    );
+    assert_eq!(sheet_link.value, "Dravia's Sheet");
+    assert_eq!(sheet_link.cell_location, Some("B2".to_string()));
+    assert!(sheet.get_row(2).expect("Failed to read row").is_none());
+}
+
+#[test]
+fn sheet_view_setting() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("View".to_string()))
+            .expect("Failed to add the worksheet");
+        // Absolute reference freeze the same as the relative one
+        sheet
+            .set_freeze_pane_mut("$B$3")
+            .expect("Failed to freeze pane");
+        assert!(sheet.set_freeze_pane_mut("A0").is_err());
+        sheet
+            .set_active_cell_mut("D6", vec!["A1:B2", "C5:D6"])
+            .expect("Failed to set active cell");
+        assert!(sheet.set_active_cell_mut("F1", vec!["A1:B2"]).is_err());
+        sheet.set_zoom_mut(150).expect("Failed to set zoom");
+        assert!(sheet.set_zoom_mut(500).is_err());
+        sheet
+            .set_show_grid_lines_mut(false)
+            .expect("Failed to hide grid lines");
+        sheet
+            .set_right_to_left_mut(true)
+            .expect("Failed to set right to left");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    // Reopen to make sure the view is loaded back without duplicates
+    let file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert_eq!(sheet_content.matches("<sheetView ").count(), 1);
+    assert!(sheet_content.contains("showGridLines=\"0\""));
+    assert!(sheet_content.contains("rightToLeft=\"1\""));
+    assert!(sheet_content.contains("zoomScale=\"150\""));
+    assert!(sheet_content.contains(
+        "<pane activePane=\"bottomRight\" state=\"frozen\" topLeftCell=\"B3\" xSplit=\"1\" ySplit=\"2\""
+    ));
+    assert!(sheet_content.contains(
+        "<selection activeCell=\"D6\" activeCellId=\"1\" pane=\"bottomRight\" sqref=\"A1:B2 C5:D6\""
+    ));
+}
+
+#[test]
+fn conditional_formatting() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Rules".to_string()))
+            .expect("Failed to add the worksheet");
+        let red_style = crate::spreadsheet_2007::models::DifferentialStyleSetting {
+            text_color: Some(crate::spreadsheet_2007::models::ColorSetting {
+                color_setting_type: crate::spreadsheet_2007::models::ColorSettingTypeValues::Rgb,
+                value: "FF9C0006".to_string(),
+            }),
+            background_color: Some(crate::spreadsheet_2007::models::ColorSetting {
+                color_setting_type: crate::spreadsheet_2007::models::ColorSettingTypeValues::Rgb,
+                value: "FFFFC7CE".to_string(),
+            }),
+            is_bold: true,
+            ..Default::default()
+        };
+        sheet
+            .add_conditional_format_mut(
+                "A1:A10 C1:C10",
+                vec![
+                    crate::spreadsheet_2007::models::ConditionalFormatRule::new(
+                        crate::spreadsheet_2007::models::ConditionalFormatRuleValues::CellValue {
+                            operator: crate::spreadsheet_2007::models::ConditionalFormatOperatorValues::Between,
+                            formulas: vec!["=10".to_string(), "20".to_string()],
+                        },
+                        Some(red_style.clone()),
+                    ),
+                    crate::spreadsheet_2007::models::ConditionalFormatRule::new(
+                        crate::spreadsheet_2007::models::ConditionalFormatRuleValues::TopBottom {
+                            rank: 3,
+                            is_bottom: true,
+                            is_percent: false,
+                        },
+                        Some(red_style.clone()),
+                    ),
+                ],
+            )
+            .expect("Failed to add cell value rule");
+        sheet
+            .add_conditional_format_mut(
+                "B1:B10",
+                vec![crate::spreadsheet_2007::models::ConditionalFormatRule::new(
+                    crate::spreadsheet_2007::models::ConditionalFormatRuleValues::IconSet {
+                        icon_set: crate::spreadsheet_2007::models::IconSetValues::ThreeFlags,
+                        values: vec![
+                            crate::spreadsheet_2007::models::ConditionalFormatValue::new(
+                                crate::spreadsheet_2007::models::ConditionalFormatValueTypeValues::Percent,
+                                "0",
+                            ),
+                            crate::spreadsheet_2007::models::ConditionalFormatValue::new(
+                                crate::spreadsheet_2007::models::ConditionalFormatValueTypeValues::Percent,
+                                "33",
+                            ),
+                            crate::spreadsheet_2007::models::ConditionalFormatValue::new(
+                                crate::spreadsheet_2007::models::ConditionalFormatValueTypeValues::Percent,
+                                "67",
+                            ),
+                        ],
+                        show_value: false,
+                        is_reverse: false,
+                    },
+                    None,
+                )],
+            )
+            .expect("Failed to add icon set rule");
+        // Rule needing style or values are rejected
+        assert!(sheet
+            .add_conditional_format_mut(
+                "D1",
+                vec![crate::spreadsheet_2007::models::ConditionalFormatRule::new(
+                    crate::spreadsheet_2007::models::ConditionalFormatRuleValues::DuplicateValues,
+                    None,
+                )],
+            )
+            .is_err());
+        assert!(sheet
+            .add_conditional_format_mut(
+                "D1",
+                vec![crate::spreadsheet_2007::models::ConditionalFormatRule::new(
+                    crate::spreadsheet_2007::models::ConditionalFormatRuleValues::ColorScale {
+                        values: vec![
+                            crate::spreadsheet_2007::models::ConditionalFormatValue::min()
+                        ],
+                        colors: vec![crate::spreadsheet_2007::models::ColorSetting::default()],
+                    },
+                    None,
+                )],
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    // Reopen, existing rules are loaded with the style, remove one and add one more rule
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut sheet = file
+            .get_worksheet_mut("Rules".to_string())
+            .expect("Failed to find the worksheet");
+        let conditional_formats = sheet
+            .list_conditional_format()
+            .expect("Failed to list conditional format");
+        assert_eq!(conditional_formats.len(), 2);
+        let (sq_ref, rules) = &conditional_formats[0];
+        assert_eq!(sq_ref, "A1:A10 C1:C10");
+        assert_eq!(rules.len(), 2);
+        assert!(matches!(
+            &rules[0].rule,
+            crate::spreadsheet_2007::models::ConditionalFormatRuleValues::CellValue {
+                operator: crate::spreadsheet_2007::models::ConditionalFormatOperatorValues::Between,
+                formulas,
+            } if formulas == &vec!["10".to_string(), "20".to_string()]
+        ));
+        let loaded_style = rules[1].style.clone().expect("Rule style missing");
+        assert!(loaded_style.is_bold && !loaded_style.is_italic);
+        assert_eq!(
+            loaded_style.background_color.map(|color| color.value),
+            Some("FFFFC7CE".to_string())
+        );
+        assert!(matches!(
+            &conditional_formats[1].1[0].rule,
+            crate::spreadsheet_2007::models::ConditionalFormatRuleValues::IconSet {
+                icon_set: crate::spreadsheet_2007::models::IconSetValues::ThreeFlags,
+                values,
+                show_value: false,
+                ..
+            } if values.len() == 3
+        ));
+        sheet
+            .remove_conditional_format_mut("B1:B10")
+            .expect("Failed to remove conditional format");
+        assert!(sheet.remove_conditional_format_mut("B1:B10").is_err());
+        sheet
+            .add_conditional_format_mut(
+                "D1:D10",
+                vec![crate::spreadsheet_2007::models::ConditionalFormatRule::new(
+                    crate::spreadsheet_2007::models::ConditionalFormatRuleValues::DataBar {
+                        min_value: crate::spreadsheet_2007::models::ConditionalFormatValue::min(),
+                        max_value: crate::spreadsheet_2007::models::ConditionalFormatValue::max(),
+                        color: crate::spreadsheet_2007::models::ColorSetting {
+                            color_setting_type:
+                                crate::spreadsheet_2007::models::ColorSettingTypeValues::Rgb,
+                            value: "FF638EC6".to_string(),
+                        },
+                        show_value: true,
+                    },
+                    None,
+                )],
+            )
+            .expect("Failed to add data bar rule");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut style_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("xl/styles.xml").expect("Style missing"),
+        &mut style_content,
+    )
+    .expect("Failed to read style");
+    // Same differential style is registered once
+    assert!(style_content.contains("<dxfs count=\"1\""));
+    assert!(style_content.contains("<bgColor rgb=\"FFFFC7CE\""));
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert_eq!(sheet_content.matches("<conditionalFormatting ").count(), 2);
+    assert!(!sheet_content.contains("<iconSet "));
+    assert!(sheet_content.contains("<conditionalFormatting sqref=\"A1:A10 C1:C10\""));
+    assert!(sheet_content
+        .contains("<cfRule dxfId=\"0\" operator=\"between\" priority=\"1\" type=\"cellIs\" >"));
+    assert!(sheet_content.contains(">10</formula>"));
+    assert!(sheet_content
+        .contains("<cfRule bottom=\"1\" dxfId=\"0\" priority=\"2\" rank=\"3\" type=\"top10\""));
+    assert!(sheet_content.contains("<cfRule priority=\"3\" type=\"dataBar\""));
+    assert!(sheet_content.contains("<color rgb=\"FF638EC6\""));
+}
+
+#[test]
+fn data_validation() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Inputs".to_string()))
+            .expect("Failed to add the worksheet");
+        let mut status_list =
+            crate::spreadsheet_2007::models::DataValidation::list_values(vec!["Open", "Closed"]);
+        status_list.prompt_title = Some("Status".to_string());
+        status_list.prompt = Some("Pick a status".to_string());
+        sheet
+            .add_data_validation_mut("A2:A100", status_list)
+            .expect("Failed to add list validation");
+        let mut quantity = crate::spreadsheet_2007::models::DataValidation::bounded(
+            crate::spreadsheet_2007::models::DataValidationTypeValues::Whole,
+            crate::spreadsheet_2007::models::DataValidationOperatorValues::Between,
+            "=1",
+            Some("10"),
+        );
+        quantity.error_style =
+            crate::spreadsheet_2007::models::DataValidationErrorStyleValues::Warning;
+        quantity.error = Some("Quantity between 1 and 10".to_string());
+        sheet
+            .add_data_validation_mut("B2:B100 D2", quantity)
+            .expect("Failed to add whole validation");
+        sheet
+            .add_data_validation_mut(
+                "C2:C100",
+                crate::spreadsheet_2007::models::DataValidation::custom("=LEN(C2)<=5"),
+            )
+            .expect("Failed to add custom validation");
+        // Between operator needs upper bound
+        assert!(sheet
+            .add_data_validation_mut(
+                "E2",
+                crate::spreadsheet_2007::models::DataValidation::bounded(
+                    crate::spreadsheet_2007::models::DataValidationTypeValues::Date,
+                    crate::spreadsheet_2007::models::DataValidationOperatorValues::Between,
+                    "45292",
+                    None,
+                ),
+            )
+            .is_err());
+        assert!(sheet
+            .add_data_validation_mut(
+                "E2",
+                crate::spreadsheet_2007::models::DataValidation::list_range(""),
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    // Reopen, existing validation are loaded and written back
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut sheet = file
+            .get_worksheet_mut("Inputs".to_string())
+            .expect("Failed to find the worksheet");
+        let data_validations = sheet.list_data_validation();
+        assert_eq!(data_validations.len(), 3);
+        assert_eq!(data_validations[1].0, "B2:B100 D2");
+        assert_eq!(data_validations[1].1.formula1, Some("1".to_string()));
+        assert_eq!(
+            data_validations[1].1.error_style,
+            crate::spreadsheet_2007::models::DataValidationErrorStyleValues::Warning
+        );
+        sheet
+            .remove_data_validation_mut("C2:C100")
+            .expect("Failed to remove validation");
+        sheet
+            .add_data_validation_mut(
+                "E2:E10",
+                crate::spreadsheet_2007::models::DataValidation::list_range("$A$1:$A$5"),
+            )
+            .expect("Failed to add range list validation");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(sheet_content.contains("<dataValidations count=\"3\""));
+    assert!(sheet_content.contains(
+        "<dataValidation allowBlank=\"1\" prompt=\"Pick a status\" promptTitle=\"Status\" showErrorMessage=\"1\" showInputMessage=\"1\" sqref=\"A2:A100\" type=\"list\" >"
+    ));
+    assert!(sheet_content.contains("&quot;Open,Closed&quot;</formula1>"));
+    assert!(sheet_content.contains("errorStyle=\"warning\""));
+    assert!(sheet_content.contains(">10</formula2>"));
+    assert!(sheet_content.contains(">$A$1:$A$5</formula1>"));
+    assert!(!sheet_content.contains("LEN(C2)"));
+}
+
+#[test]
+fn excel_table() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Sales".to_string()))
+            .expect("Failed to add the worksheet");
+        let mut region = crate::spreadsheet_2007::models::TableColumn::new("Region");
+        region.totals_row_label = Some("Total".to_string());
+        let mut amount = crate::spreadsheet_2007::models::TableColumn::new("Amount");
+        amount.totals_row_function =
+            crate::spreadsheet_2007::models::TableTotalsRowFunctionValues::Sum;
+        sheet
+            .add_table_mut(
+                "A1:B5",
+                "SalesTable",
+                vec![region, amount],
+                crate::spreadsheet_2007::models::TableSetting {
+                    show_totals_row: true,
+                    ..Default::default()
+                },
+            )
+            .expect("Failed to add table");
+        // Columns get default name
+        sheet
+            .add_table_mut(
+                "D1:F3",
+                "Lookup",
+                vec![],
+                crate::spreadsheet_2007::models::TableSetting::default(),
+            )
+            .expect("Failed to add default column table");
+        // Overlapping range, reference like name and duplicate name are rejected
+        assert!(sheet
+            .add_table_mut(
+                "B4:C6",
+                "Other",
+                vec![],
+                crate::spreadsheet_2007::models::TableSetting::default(),
+            )
+            .is_err());
+        assert!(sheet
+            .add_table_mut(
+                "H1:H3",
+                "AB12",
+                vec![],
+                crate::spreadsheet_2007::models::TableSetting::default(),
+            )
+            .is_err());
+        assert!(sheet
+            .add_table_mut(
+                "H1:H3",
+                "salestable",
+                vec![],
+                crate::spreadsheet_2007::models::TableSetting::default(),
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut table_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/tables/table1.xml")
+            .expect("Table missing"),
+        &mut table_content,
+    )
+    .expect("Failed to read table");
+    assert!(table_content.contains("displayName=\"SalesTable\""));
+    assert!(table_content.contains("ref=\"A1:B5\""));
+    assert!(table_content.contains("totalsRowCount=\"1\""));
+    assert!(table_content.contains("<autoFilter ref=\"A1:B4\""));
+    assert!(table_content.contains("totalsRowFunction=\"sum\""));
+    assert!(table_content.contains("name=\"TableStyleMedium2\""));
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(sheet_content.contains("<tableParts count=\"2\""));
+    assert!(sheet_content.contains("SUBTOTAL(109,SalesTable[Amount])"));
+    let mut relation_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/_rels/sheet2.xml.rels")
+            .expect("Sheet relation missing"),
+        &mut relation_content,
+    )
+    .expect("Failed to read sheet relation");
+    assert!(relation_content.contains("Target=\"/xl/tables/table2.xml\""));
+    // Reopen, update and remove existing tables
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut sheet = file
+            .get_worksheet_mut("Sales".to_string())
+            .expect("Failed to find the worksheet");
+        let tables = sheet.list_tables();
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[1].columns[2].name, "Column3");
+        let mut columns = tables[0].columns.clone();
+        columns[1].totals_row_function =
+            crate::spreadsheet_2007::models::TableTotalsRowFunctionValues::Average;
+        sheet
+            .update_table_mut(
+                "SalesTable",
+                columns,
+                crate::spreadsheet_2007::models::TableSetting {
+                    style_name: Some("TableStyleLight9".to_string()),
+                    ..tables[0].setting.clone()
+                },
+            )
+            .expect("Failed to update table");
+        sheet
+            .remove_table_mut("Lookup")
+            .expect("Failed to remove table");
+    }
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Other".to_string()))
+            .expect("Failed to add the worksheet");
+        // Table name is unique across the workbook
+        assert!(sheet
+            .add_table_mut(
+                "A1:A3",
+                "SalesTable",
+                vec![],
+                crate::spreadsheet_2007::models::TableSetting::default(),
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    assert!(archive.by_name("xl/tables/table2.xml").is_err());
+    let mut table_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/tables/table1.xml")
+            .expect("Table missing"),
+        &mut table_content,
+    )
+    .expect("Failed to read table");
+    assert!(table_content.contains("totalsRowFunction=\"average\""));
+    assert!(table_content.contains("name=\"TableStyleLight9\""));
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(sheet_content.contains("<tableParts count=\"1\""));
+    assert!(sheet_content.contains("SUBTOTAL(101,SalesTable[Amount])"));
+}
+
+#[test]
+fn auto_filter() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut sheet = file
+            .add_sheet_mut(Some("Orders List".to_string()))
+            .expect("Failed to add the worksheet");
+        sheet
+            .set_auto_filter_mut(
+                "A1:D20",
+                vec![
+                    crate::spreadsheet_2007::models::FilterColumn::new(
+                        0,
+                        crate::spreadsheet_2007::models::FilterCriteriaValues::Values {
+                            values: vec!["East".to_string(), "West".to_string()],
+                            include_blank: true,
+                        },
+                    ),
+                    crate::spreadsheet_2007::models::FilterColumn::new(
+                        1,
+                        crate::spreadsheet_2007::models::FilterCriteriaValues::Custom {
+                            filters: vec![
+                                crate::spreadsheet_2007::models::CustomFilter::new(
+                                    crate::spreadsheet_2007::models::FilterOperatorValues::GreaterThan,
+                                    "10",
+                                ),
+                                crate::spreadsheet_2007::models::CustomFilter::new(
+                                    crate::spreadsheet_2007::models::FilterOperatorValues::LessThanOrEqual,
+                                    "100",
+                                ),
+                            ],
+                            match_all: true,
+                        },
+                    ),
+                    crate::spreadsheet_2007::models::FilterColumn::new(
+                        2,
+                        crate::spreadsheet_2007::models::FilterCriteriaValues::Top10 {
+                            value: 10.0,
+                            is_bottom: false,
+                            is_percent: true,
+                        },
+                    ),
+                    crate::spreadsheet_2007::models::FilterColumn::new(
+                        3,
+                        crate::spreadsheet_2007::models::FilterCriteriaValues::Dynamic(
+                            crate::spreadsheet_2007::models::DynamicFilterTypeValues::Quarter(2),
+                        ),
+                    ),
+                ],
+                Some(crate::spreadsheet_2007::models::SortState {
+                    range_ref: "A2:D20".to_string(),
+                    conditions: vec![crate::spreadsheet_2007::models::SortCondition {
+                        range_ref: "B2:B20".to_string(),
+                        descending: true,
+                    }],
+                    case_sensitive: false,
+                }),
+            )
+            .expect("Failed to set auto filter");
+        // Column outside of range and sort outside of filter range are rejected
+        assert!(sheet
+            .set_auto_filter_mut(
+                "A1:B5",
+                vec![crate::spreadsheet_2007::models::FilterColumn::new(
+                    2,
+                    crate::spreadsheet_2007::models::FilterCriteriaValues::Dynamic(
+                        crate::spreadsheet_2007::models::DynamicFilterTypeValues::Today,
+                    ),
+                )],
+                None,
+            )
+            .is_err());
+        assert!(sheet
+            .set_auto_filter_mut(
+                "A1:B5",
+                vec![],
+                Some(crate::spreadsheet_2007::models::SortState {
+                    range_ref: "A2:B9".to_string(),
+                    conditions: vec![],
+                    case_sensitive: false,
+                }),
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(sheet_content.contains("<autoFilter ref=\"A1:D20\" >"));
+    assert!(sheet_content.contains("<filters blank=\"1\" >"));
+    assert!(sheet_content.contains("<filter val=\"East\" />"));
+    assert!(sheet_content.contains("<customFilters and=\"1\" >"));
+    assert!(sheet_content.contains("<customFilter operator=\"greaterThan\" val=\"10\" />"));
+    assert!(sheet_content.contains("<top10 percent=\"1\" val=\"10\" />"));
+    assert!(sheet_content.contains("<dynamicFilter type=\"Q2\" />"));
+    assert!(sheet_content.contains("<sortState ref=\"A2:D20\" >"));
+    assert!(sheet_content.contains("<sortCondition descending=\"1\" ref=\"B2:B20\" />"));
+    let mut workbook_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/workbook.xml")
+            .expect("Workbook missing"),
+        &mut workbook_content,
+    )
+    .expect("Failed to read workbook");
+    assert!(workbook_content.contains(
+        "<definedName hidden=\"1\" localSheetId=\"1\" name=\"_xlnm._FilterDatabase\" >&apos;Orders List&apos;!$A$1:$D$20</definedName>"
+    ));
+    // Reopen, loaded filter can be read and removed
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut sheet = file
+            .get_worksheet_mut("Orders List".to_string())
+            .expect("Failed to find the worksheet");
+        let auto_filter = sheet.get_auto_filter().expect("Auto filter missing");
+        assert_eq!(auto_filter.range_ref, "A1:D20");
+        assert_eq!(auto_filter.filter_columns.len(), 4);
+        assert!(matches!(
+            &auto_filter.filter_columns[1].criteria,
+            Some(crate::spreadsheet_2007::models::FilterCriteriaValues::Custom {
+                filters,
+                match_all: true,
+            }) if filters.len() == 2
+        ));
+        assert_eq!(
+            auto_filter
+                .sort_state
+                .expect("Sort state missing")
+                .conditions[0]
+                .range_ref,
+            "B2:B20"
+        );
+        sheet
+            .remove_auto_filter_mut()
+            .expect("Failed to remove auto filter");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut workbook_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/workbook.xml")
+            .expect("Workbook missing"),
+        &mut workbook_content,
+    )
+    .expect("Failed to read workbook");
+    assert!(!workbook_content.contains("_xlnm._FilterDatabase"));
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(!sheet_content.contains("autoFilter"));
+}
+
+#[test]
+fn defined_names() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    file.add_sheet_mut(Some("Data".to_string()))
+        .expect("Failed to add the worksheet");
+    file.add_sheet_mut(Some("Summary Sheet".to_string()))
+        .expect("Failed to add the worksheet");
+    file.add_defined_name_mut(crate::spreadsheet_2007::models::DefinedName::new(
+        "SalesRange",
+        "=Data!$A$1:$B$10",
+    ))
+    .expect("Failed to add workbook name");
+    file.add_defined_name_mut(crate::spreadsheet_2007::models::DefinedName {
+        sheet_name: Some("Summary Sheet".to_string()),
+        hidden: true,
+        comment: Some("Tax rate".to_string()),
+        ..crate::spreadsheet_2007::models::DefinedName::new("Rate", "0.2")
+    })
+    .expect("Failed to add sheet name");
+    file.add_defined_name_mut(crate::spreadsheet_2007::models::DefinedName::new(
+        "TotalRef",
+        "'Summary Sheet'!$A$1+SUM(Data!A1:A5)&\"Data!\"",
+    ))
+    .expect("Failed to add formula name");
+    // Same name in other scope is allowed
+    file.add_defined_name_mut(crate::spreadsheet_2007::models::DefinedName::new(
+        "Rate", "0.1",
+    ))
+    .expect("Failed to add workbook scope name");
+    for invalid_name in [
+        crate::spreadsheet_2007::models::DefinedName::new("A1", "1"),
+        crate::spreadsheet_2007::models::DefinedName::new("R2C3", "1"),
+        crate::spreadsheet_2007::models::DefinedName::new("salesrange", "1"),
+        crate::spreadsheet_2007::models::DefinedName::new("_xlnm.Print_Area", "Data!$A$1"),
+        crate::spreadsheet_2007::models::DefinedName {
+            sheet_name: Some("Missing".to_string()),
+            ..crate::spreadsheet_2007::models::DefinedName::new("Other", "1")
+        },
+    ] {
+        assert!(file.add_defined_name_mut(invalid_name).is_err());
+    }
+    file.set_print_area_mut("Data".to_string(), Some("A1:D20 F1:G5".to_string()))
+        .expect("Failed to set print area");
+    file.set_print_titles_mut("Data".to_string(), Some((1, 2)), Some((1, 1)))
+        .expect("Failed to set print titles");
+    file.rename_sheet_name_mut("Data".to_string(), "Raw Data".to_string())
+        .expect("Failed to rename sheet");
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut workbook_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/workbook.xml")
+            .expect("Workbook missing"),
+        &mut workbook_content,
+    )
+    .expect("Failed to read workbook");
+    assert!(workbook_content.contains(
+        "<definedName name=\"SalesRange\" >&apos;Raw Data&apos;!$A$1:$B$10</definedName>"
+    ));
+    assert!(workbook_content.contains(
+        "<definedName comment=\"Tax rate\" hidden=\"1\" localSheetId=\"2\" name=\"Rate\" >0.2</definedName>"
+    ));
+    assert!(workbook_content.contains(
+        "&apos;Summary Sheet&apos;!$A$1+SUM(&apos;Raw Data&apos;!A1:A5)&amp;&quot;Data!&quot;"
+    ));
+    assert!(workbook_content.contains(
+        "<definedName localSheetId=\"1\" name=\"_xlnm.Print_Area\" >&apos;Raw Data&apos;!$A$1:$D$20,&apos;Raw Data&apos;!$F$1:$G$5</definedName>"
+    ));
+    assert!(workbook_content
+        .contains(">&apos;Raw Data&apos;!$A:$A,&apos;Raw Data&apos;!$1:$2</definedName>"));
+    // Reopen, edit loaded names and drop names of deleted sheet
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let defined_names = file.list_defined_names().expect("Failed to list names");
+    assert_eq!(defined_names.len(), 6);
+    let rate = defined_names
+        .iter()
+        .find(|defined_name| defined_name.sheet_name.is_some() && defined_name.name == "Rate")
+        .expect("Sheet scoped name missing");
+    assert_eq!(rate.sheet_name, Some("Summary Sheet".to_string()));
+    assert!(rate.hidden);
+    file.update_defined_name_mut(
+        "Rate".to_string(),
+        None,
+        crate::spreadsheet_2007::models::DefinedName::new("BaseRate", "0.15"),
+    )
+    .expect("Failed to update name");
+    file.remove_defined_name_mut("SalesRange".to_string(), None)
+        .expect("Failed to remove name");
+    file.set_print_titles_mut("Raw Data".to_string(), None, None)
+        .expect("Failed to remove print titles");
+    file.get_worksheet_mut("Summary Sheet".to_string())
+        .expect("Failed to find the worksheet")
+        .delete_sheet_mut()
+        .expect("Failed to delete the worksheet");
+    let defined_names = file.list_defined_names().expect("Failed to list names");
+    assert_eq!(
+        defined_names
+            .iter()
+            .map(|defined_name| defined_name.name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["TotalRef", "BaseRate", "_xlnm.Print_Area"]
+    );
+    assert_eq!(
+        defined_names[0].value,
+        "#REF!$A$1+SUM('Raw Data'!A1:A5)&\"Data!\""
+    );
+    file.save_to_vec().expect("Save File Failed");
+}
+
+#[test]
+fn password_hash() {
+    assert_eq!(
+        crate::utils::password_hash::hash_password(
+            "Secret1",
+            &(0..16).collect::<Vec<u8>>(),
+            100_000
+        ),
+        "A5/DGG2yRNJKVZSVgt4KbLetHxCMseyalSmU01nIFs4fnVIcy1YVYniahyacT/gB0zEoGnIPmhMJLBs2EXnbcg=="
+    );
+}
+
+#[test]
+fn protection() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    file.get_style_id_mut(crate::spreadsheet_2007::models::StyleSetting {
+        is_locked: false,
+        is_formula_hidden: true,
+        ..Default::default()
+    })
+    .expect("Failed to get Style Id");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Locked".to_string()))
+            .expect("Failed to add the worksheet");
+        worksheet
+            .protect_mut(crate::spreadsheet_2007::models::SheetProtection {
+                password: Some("Secret1".to_string()),
+                allow_select_locked_cells: false,
+                allow_format_cells: true,
+                allow_sort: true,
+                ..Default::default()
+            })
+            .expect("Failed to protect the worksheet");
+        worksheet
+            .add_protected_range_mut(crate::spreadsheet_2007::models::ProtectedRange {
+                name: "Input".to_string(),
+                range_ref: "B2:C5 E1".to_string(),
+                password: None,
+            })
+            .expect("Failed to add protected range");
+        worksheet
+            .add_protected_range_mut(crate::spreadsheet_2007::models::ProtectedRange {
+                name: "Admin".to_string(),
+                range_ref: "$F$1:$F$3".to_string(),
+                password: Some("Admin1".to_string()),
+            })
+            .expect("Failed to add protected range");
+        assert!(worksheet
+            .add_protected_range_mut(crate::spreadsheet_2007::models::ProtectedRange {
+                name: "input".to_string(),
+                range_ref: "A1".to_string(),
+                password: None,
+            })
+            .is_err());
+        let protection = worksheet.get_protection().expect("Protection missing");
+        assert!(protection.password.is_none());
+        assert!(protection.allow_format_cells);
+    }
+    file.protect_workbook_mut(crate::spreadsheet_2007::models::WorkbookProtection {
+        password: Some("Book1".to_string()),
+        lock_structure: true,
+        lock_windows: false,
+    })
+    .expect("Failed to protect the workbook");
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let style_content = read_part("xl/styles.xml");
+    assert!(style_content.contains("applyProtection=\"1\""));
+    assert!(style_content.contains("<protection hidden=\"1\" locked=\"0\" />"));
+    let sheet_content = read_part("xl/worksheets/sheet2.xml");
+    assert!(sheet_content.contains("<sheetProtection algorithmName=\"SHA-512\" "));
+    assert!(sheet_content.contains("formatCells=\"0\" hashValue=\""));
+    assert!(sheet_content.contains("objects=\"1\" saltValue=\""));
+    assert!(sheet_content.contains(
+        "scenarios=\"1\" selectLockedCells=\"1\" sheet=\"1\" sort=\"0\" spinCount=\"100000\" />"
+    ));
+    assert!(!sheet_content.contains("Secret1"));
+    assert!(sheet_content
+        .contains("<protectedRanges ><protectedRange name=\"Input\" sqref=\"B2:C5 E1\" />"));
+    assert!(sheet_content.contains("<protectedRange algorithmName=\"SHA-512\" "));
+    assert!(
+        sheet_content.find("<sheetProtection").unwrap()
+            < sheet_content.find("<protectedRanges").unwrap()
+    );
+    let workbook_content = read_part("xl/workbook.xml");
+    assert!(workbook_content.contains("lockStructure=\"1\""));
+    assert!(workbook_content.contains("workbookAlgorithmName=\"SHA-512\""));
+    assert!(workbook_content.contains("workbookSpinCount=\"100000\""));
+    // Reopen keep the hash and flags of the loaded protection
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    let workbook_protection = file
+        .get_workbook_protection()
+        .expect("Workbook protection missing");
+    assert!(workbook_protection.lock_structure);
+    assert!(!workbook_protection.lock_windows);
+    {
+        let mut worksheet = file
+            .get_worksheet_mut("Locked".to_string())
+            .expect("Failed to find the worksheet");
+        let protection = worksheet.get_protection().expect("Protection missing");
+        assert!(!protection.allow_select_locked_cells);
+        assert!(protection.allow_select_unlocked_cells);
+        assert!(protection.allow_format_cells);
+        assert!(protection.allow_sort);
+        assert!(!protection.allow_edit_objects);
+        assert_eq!(worksheet.list_protected_ranges().len(), 2);
+        worksheet
+            .remove_protected_range_mut("Input")
+            .expect("Failed to remove protected range");
+    }
+    let reopened_content = file.save_to_vec().expect("Save File Failed");
+    let get_hash_value = |content: &str| {
+        let start = content
+            .find("<sheetProtection")
+            .expect("Protection missing");
+        let start = start + content[start..].find("hashValue=\"").unwrap() + 11;
+        content[start..start + content[start..].find('"').unwrap()].to_string()
+    };
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(reopened_content))
+        .expect("Failed to read archive");
+    let mut reopened_sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut reopened_sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert_eq!(
+        get_hash_value(&sheet_content),
+        get_hash_value(&reopened_sheet_content)
+    );
+    assert!(!reopened_sheet_content.contains("name=\"Input\""));
+    assert!(reopened_sheet_content.contains("name=\"Admin\""));
+}
+
+#[test]
+fn page_setup() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Report".to_string()))
+            .expect("Failed to add the worksheet");
+        assert!(worksheet
+            .set_page_setup_mut(crate::spreadsheet_2007::models::PageSetup {
+                scale: 500,
+                ..Default::default()
+            })
+            .is_err());
+        worksheet
+            .set_page_setup_mut(crate::spreadsheet_2007::models::PageSetup {
+                orientation: crate::spreadsheet_2007::models::PageOrientationValues::Landscape,
+                paper_size: crate::spreadsheet_2007::models::PaperSizeValues::A4,
+                fit_to_page: Some((1, 0)),
+                first_page_number: Some(3),
+                margins: crate::spreadsheet_2007::models::PageMargins {
+                    left: 0.25,
+                    right: 0.25,
+                    ..Default::default()
+                },
+                center_horizontally: true,
+                print_grid_lines: true,
+                print_title_rows: Some((1, 2)),
+                row_breaks: vec![40, 20, 40],
+                column_breaks: vec![8],
+                header_footer: crate::spreadsheet_2007::models::HeaderFooter {
+                    odd_header: crate::spreadsheet_2007::models::HeaderFooterText {
+                        left: Some("Sales && Cost".to_string()),
+                        right: Some(
+                            crate::spreadsheet_2007::models::HeaderFooterText::DATE.to_string(),
+                        ),
+                        ..Default::default()
+                    },
+                    odd_footer: crate::spreadsheet_2007::models::HeaderFooterText::new(&format!(
+                        "Page {} of {}",
+                        crate::spreadsheet_2007::models::HeaderFooterText::PAGE_NUMBER,
+                        crate::spreadsheet_2007::models::HeaderFooterText::PAGE_COUNT
+                    )),
+                    first_footer: crate::spreadsheet_2007::models::HeaderFooterText::new("Cover"),
+                    different_first: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .expect("Failed to set page setup");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let sheet_content = read_part("xl/worksheets/sheet2.xml");
+    assert!(sheet_content.contains("<sheetPr ><pageSetUpPr fitToPage=\"1\" /></sheetPr>"));
+    assert!(sheet_content.contains("<printOptions gridLines=\"1\" horizontalCentered=\"1\" />"));
+    assert!(sheet_content.contains(
+        "<pageMargins bottom=\"0.75\" footer=\"0.3\" header=\"0.3\" left=\"0.25\" right=\"0.25\" top=\"0.75\" />"
+    ));
+    assert!(sheet_content.contains(
+        "<pageSetup firstPageNumber=\"3\" fitToHeight=\"0\" orientation=\"landscape\" paperSize=\"9\" useFirstPageNumber=\"1\" />"
+    ));
+    assert!(sheet_content.contains(
+        "<headerFooter differentFirst=\"1\" ><oddHeader >&amp;LSales &amp;&amp; Cost&amp;R&amp;D</oddHeader><oddFooter >&amp;CPage &amp;P of &amp;N</oddFooter><firstFooter >&amp;CCover</firstFooter></headerFooter>"
+    ));
+    assert!(sheet_content.contains(
+        "<rowBreaks count=\"2\" manualBreakCount=\"2\" ><brk id=\"20\" man=\"1\" max=\"16383\" /><brk id=\"40\" man=\"1\" max=\"16383\" /></rowBreaks><colBreaks count=\"1\" manualBreakCount=\"1\" ><brk id=\"8\" man=\"1\" max=\"1048575\" /></colBreaks>"
+    ));
+    let workbook_content = read_part("xl/workbook.xml");
+    assert!(workbook_content.contains(
+        "<definedName localSheetId=\"1\" name=\"_xlnm.Print_Titles\" >&apos;Report&apos;!$1:$2</definedName>"
+    ));
+    // Reopen read back the same setting
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut worksheet = file
+            .get_worksheet_mut("Report".to_string())
+            .expect("Failed to find the worksheet");
+        let page_setup = worksheet
+            .get_page_setup()
+            .expect("Failed to get page setup");
+        assert_eq!(
+            page_setup.orientation,
+            crate::spreadsheet_2007::models::PageOrientationValues::Landscape
+        );
+        assert_eq!(
+            page_setup.paper_size,
+            crate::spreadsheet_2007::models::PaperSizeValues::A4
+        );
+        assert_eq!(page_setup.fit_to_page, Some((1, 0)));
+        assert_eq!(page_setup.first_page_number, Some(3));
+        assert_eq!(page_setup.margins.left, 0.25);
+        assert_eq!(page_setup.print_title_rows, Some((1, 2)));
+        assert_eq!(page_setup.print_title_columns, None);
+        assert_eq!(page_setup.row_breaks, vec![20, 40]);
+        assert_eq!(page_setup.column_breaks, vec![8]);
+        assert_eq!(
+            page_setup.header_footer.odd_header.left,
+            Some("Sales && Cost".to_string())
+        );
+        assert_eq!(
+            page_setup.header_footer.odd_footer.center,
+            Some("Page &P of &N".to_string())
+        );
+        assert!(page_setup.header_footer.different_first);
+        worksheet
+            .remove_page_setup_mut()
+            .expect("Failed to remove page setup");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(!sheet_content.contains("fitToPage"));
+    assert!(!sheet_content.contains("<pageSetup "));
+    assert!(!sheet_content.contains("<headerFooter"));
+}
+
+#[test]
+fn outline_group() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Outline".to_string()))
+            .expect("Failed to add the worksheet");
+        worksheet
+            .set_column_index_properties_mut(
+                &6,
+                Some(crate::spreadsheet_2007::models::ColumnProperties {
+                    width: Some(20.0),
+                    ..Default::default()
+                }),
+            )
+            .expect("Failed to set column property");
+        worksheet
+            .group_rows_mut(2, 5)
+            .expect("Failed to group rows");
+        worksheet
+            .group_rows_mut(3, 4)
+            .expect("Failed to group inner rows");
+        for _ in 0..7 {
+            worksheet
+                .group_rows_mut(20, 21)
+                .expect("Failed to group rows");
+        }
+        assert!(worksheet.group_rows_mut(20, 20).is_err());
+        assert!(worksheet.group_rows_mut(5, 2).is_err());
+        assert!(worksheet
+            .collapse_group_mut(
+                crate::spreadsheet_2007::models::OutlineGroupValues::Rows(8, 9),
+                true
+            )
+            .is_err());
+        worksheet
+            .collapse_group_mut(
+                crate::spreadsheet_2007::models::OutlineGroupValues::Rows(3, 4),
+                true,
+            )
+            .expect("Failed to collapse inner group");
+        worksheet
+            .collapse_group_mut(
+                crate::spreadsheet_2007::models::OutlineGroupValues::Rows(2, 5),
+                true,
+            )
+            .expect("Failed to collapse outer group");
+        // Expand outer group keep the collapsed inner group hidden
+        worksheet
+            .collapse_group_mut(
+                crate::spreadsheet_2007::models::OutlineGroupValues::Rows(2, 5),
+                false,
+            )
+            .expect("Failed to expand outer group");
+        worksheet
+            .group_columns_mut(2, 7)
+            .expect("Failed to group columns");
+        worksheet
+            .collapse_group_mut(
+                crate::spreadsheet_2007::models::OutlineGroupValues::Columns(2, 7),
+                true,
+            )
+            .expect("Failed to collapse column group");
+        worksheet
+            .group_columns_mut(10, 11)
+            .expect("Failed to group columns");
+        worksheet
+            .ungroup_columns_mut(10, 11)
+            .expect("Failed to ungroup columns");
+        worksheet
+            .set_outline_summary_mut(true, false)
+            .expect("Failed to set outline summary");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(sheet_content.contains("<sheetPr ><outlinePr summaryRight=\"0\" /></sheetPr>"));
+    assert!(sheet_content.contains(
+        "<sheetFormatPr defaultRowHeight=\"15\" outlineLevelCol=\"1\" outlineLevelRow=\"7\" />"
+    ));
+    assert!(sheet_content.contains(
+        "<cols ><col hidden=\"1\" max=\"5\" min=\"2\" outlineLevel=\"1\" /><col customWidth=\"1\" hidden=\"1\" max=\"6\" min=\"6\" outlineLevel=\"1\" width=\"20\" /><col hidden=\"1\" max=\"7\" min=\"7\" outlineLevel=\"1\" /><col collapsed=\"1\" max=\"8\" min=\"8\" />"
+    ));
+    assert!(!sheet_content.contains("min=\"10\" outlineLevel"));
+    assert!(sheet_content.contains("<row outlineLevel=\"1\" r=\"2\" />"));
+    assert!(sheet_content.contains("<row hidden=\"1\" outlineLevel=\"2\" r=\"3\" />"));
+    assert!(sheet_content.contains("<row hidden=\"1\" outlineLevel=\"2\" r=\"4\" />"));
+    assert!(sheet_content.contains("<row collapsed=\"1\" outlineLevel=\"1\" r=\"5\" />"));
+    assert!(sheet_content.contains("<row r=\"6\" />"));
+    assert!(sheet_content.contains("<row outlineLevel=\"7\" r=\"20\" />"));
+    // Reopen and ungroup the collapsed inner group
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    file.get_worksheet_mut("Outline".to_string())
+        .expect("Failed to find the worksheet")
+        .ungroup_rows_mut(3, 4)
+        .expect("Failed to ungroup rows");
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(sheet_content.contains("<row outlineLevel=\"1\" r=\"3\" />"));
+    assert!(sheet_content.contains("<row outlineLevel=\"1\" r=\"5\" />"));
+}
+
+#[test]
+fn cell_comment() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Comments".to_string()))
+            .expect("Failed to add the worksheet");
+        worksheet
+            .add_comment_mut(
+                "B2",
+                crate::spreadsheet_2007::models::Comment::new("Reviewer", "Check the total"),
+            )
+            .expect("Failed to add comment");
+        worksheet
+            .add_comment_mut(
+                "D5",
+                crate::spreadsheet_2007::models::Comment {
+                    author: "Owner".to_string(),
+                    text: vec![
+                        crate::spreadsheet_2007::models::RichTextRun {
+                            text: "Owner:".to_string(),
+                            is_bold: true,
+                            ..Default::default()
+                        },
+                        crate::spreadsheet_2007::models::RichTextRun {
+                            text: " updated daily".to_string(),
+                            ..Default::default()
+                        },
+                    ],
+                    width: 150.0,
+                    height: 80.0,
+                    visible: true,
+                },
+            )
+            .expect("Failed to add rich comment");
+        worksheet
+            .add_comment_mut(
+                "F1",
+                crate::spreadsheet_2007::models::Comment::new("Reviewer", "Removed"),
+            )
+            .expect("Failed to add comment");
+        worksheet
+            .remove_comment_mut("F1")
+            .expect("Failed to remove comment");
+        assert!(worksheet.remove_comment_mut("F1").is_err());
+        let mut threaded_comment =
+            crate::spreadsheet_2007::models::ThreadedComment::new("Reviewer", "Is this final?");
+        threaded_comment
+            .replies
+            .push(crate::spreadsheet_2007::models::ThreadedCommentReply {
+                author: "Owner".to_string(),
+                text: "Yes".to_string(),
+            });
+        worksheet
+            .add_threaded_comment_mut("C3", threaded_comment)
+            .expect("Failed to add threaded comment");
+        assert!(worksheet
+            .add_threaded_comment_mut(
+                "B2",
+                crate::spreadsheet_2007::models::ThreadedComment::new("Reviewer", "Clash")
+            )
+            .is_err());
+        assert!(worksheet
+            .add_comment_mut(
+                "C3",
+                crate::spreadsheet_2007::models::Comment::new("Reviewer", "Clash")
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let sheet_content = read_part("xl/worksheets/sheet2.xml");
+    let sheet_relationship = read_part("xl/worksheets/_rels/sheet2.xml.rels");
+    let comments_content = read_part("xl/comments1.xml");
+    let vml_content = read_part("xl/drawings/vmlDrawing1.vml");
+    let threaded_content = read_part("xl/threadedComments/threadedComment1.xml");
+    let person_content = read_part("xl/persons/person.xml");
+    let workbook_relationship = read_part("xl/_rels/workbook.xml.rels");
+    let content_types = read_part("[Content_Types].xml");
+    assert!(sheet_content.contains("<legacyDrawing r:id=\"rId2\" />"));
+    assert!(sheet_relationship.contains("Target=\"/xl/comments1.xml\""));
+    assert!(sheet_relationship.contains("Target=\"/xl/drawings/vmlDrawing1.vml\""));
+    assert!(sheet_relationship.contains("Target=\"/xl/threadedComments/threadedComment1.xml\""));
+    assert!(comments_content.contains("<authors ><author >Reviewer</author><author >tc={"));
+    assert!(comments_content.contains("<author >Owner</author></authors>"));
+    assert!(comments_content.contains("<r ><t xml:space=\"preserve\" >Check the total</t></r>"));
+    assert!(
+        comments_content.contains("<r ><rPr ><b /></rPr><t xml:space=\"preserve\" >Owner:</t></r>")
+    );
+    assert!(comments_content.contains("[Threaded comment]"));
+    assert!(!comments_content.contains("ref=\"F1\""));
+    assert!(vml_content.contains("<x:Row >1</x:Row><x:Column >1</x:Column>"));
+    assert!(vml_content.contains("width:150pt;height:80pt;z-index:3;visibility:visible"));
+    assert!(vml_content.contains("<x:Visible />"));
+    assert!(threaded_content.contains("<text >Is this final?</text>"));
+    assert!(threaded_content.contains("parentId=\"{"));
+    assert!(person_content.contains("displayName=\"Owner\""));
+    assert!(workbook_relationship.contains("Target=\"/xl/persons/person.xml\""));
+    assert!(content_types.contains(
+        "ContentType=\"application/vnd.openxmlformats-officedocument.vmlDrawing\" Extension=\"vml\""
+    ));
+    // Reopen, read back and remove the comments
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut worksheet = file
+            .get_worksheet_mut("Comments".to_string())
+            .expect("Failed to find the worksheet");
+        let comments = worksheet.list_comments().expect("Failed to list comments");
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].0, "B2");
+        assert_eq!(comments[0].1.get_text(), "Check the total");
+        let rich_comment = worksheet
+            .get_comment("D5")
+            .expect("Failed to get comment")
+            .expect("Comment missing");
+        assert_eq!(rich_comment.author, "Owner");
+        assert!(rich_comment.text[0].is_bold);
+        assert_eq!(rich_comment.width, 150.0);
+        assert!(rich_comment.visible);
+        assert!(worksheet
+            .get_comment("C3")
+            .expect("Failed to get comment")
+            .is_none());
+        let threaded_comments = worksheet
+            .list_threaded_comments()
+            .expect("Failed to list threaded comments");
+        assert_eq!(threaded_comments.len(), 1);
+        assert_eq!(threaded_comments[0].0, "C3");
+        assert_eq!(threaded_comments[0].1.author, "Reviewer");
+        assert_eq!(threaded_comments[0].1.replies[0].author, "Owner");
+        worksheet
+            .remove_threaded_comment_mut("C3")
+            .expect("Failed to remove threaded comment");
+        worksheet
+            .remove_comment_mut("B2")
+            .expect("Failed to remove comment");
+        worksheet
+            .remove_comment_mut("D5")
+            .expect("Failed to remove comment");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    assert!(archive.by_name("xl/comments1.xml").is_err());
+    assert!(archive.by_name("xl/drawings/vmlDrawing1.vml").is_err());
+    assert!(archive
+        .by_name("xl/threadedComments/threadedComment1.xml")
+        .is_err());
+    let mut sheet_content = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .expect("Sheet missing"),
+        &mut sheet_content,
+    )
+    .expect("Failed to read sheet");
+    assert!(!sheet_content.contains("legacyDrawing"));
+}
+
+#[test]
+fn worksheet_image() {
+    let png_image = [
+        vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A],
+        b"png image content".to_vec(),
+    ]
+    .concat();
+    let jpeg_image = [vec![0xFF, 0xD8, 0xFF, 0xE0], b"jpeg image content".to_vec()].concat();
+    let svg_image = br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"></svg>"#;
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Images".to_string()))
+            .expect("Failed to add the worksheet");
+        worksheet
+            .add_image_mut(
+                &png_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Png,
+                crate::spreadsheet_2007::models::DrawingAnchorValues::OneCell {
+                    cell_ref: "B2".to_string(),
+                    size: crate::spreadsheet_2007::models::DrawingSizeValues::Pixel {
+                        width: 100,
+                        height: 50,
+                    },
+                },
+            )
+            .expect("Failed to add one cell image");
+        worksheet
+            .add_image_mut(
+                &png_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Png,
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "D2".to_string(),
+                    to_cell_ref: "F6".to_string(),
+                },
+            )
+            .expect("Failed to add two cell image");
+        worksheet
+            .add_image_mut(
+                &jpeg_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Jpeg,
+                crate::spreadsheet_2007::models::DrawingAnchorValues::Absolute {
+                    x: 9525,
+                    y: 19050,
+                    size: crate::spreadsheet_2007::models::DrawingSizeValues::Emu {
+                        width: 952500,
+                        height: 476250,
+                    },
+                },
+            )
+            .expect("Failed to add absolute image");
+        worksheet
+            .add_image_mut(
+                svg_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Svg {
+                    png_fallback: png_image.clone(),
+                },
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "H2".to_string(),
+                    to_cell_ref: "H2".to_string(),
+                },
+            )
+            .expect("Failed to add svg image");
+        assert!(worksheet
+            .add_image_mut(
+                &jpeg_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Png,
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "A1".to_string(),
+                    to_cell_ref: "B2".to_string(),
+                },
+            )
+            .is_err());
+        assert!(worksheet
+            .add_image_mut(
+                &png_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Png,
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "C3".to_string(),
+                    to_cell_ref: "B2".to_string(),
+                },
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let sheet_content = read_part("xl/worksheets/sheet2.xml");
+    let sheet_relationship = read_part("xl/worksheets/_rels/sheet2.xml.rels");
+    let drawing_content = read_part("xl/drawings/drawing1.xml");
+    let drawing_relationship = read_part("xl/drawings/_rels/drawing1.xml.rels");
+    let content_types = read_part("[Content_Types].xml");
+    assert!(sheet_content.contains("<drawing r:id=\"rId1\" />"));
+    assert!(sheet_relationship.contains("Target=\"/xl/drawings/drawing1.xml\""));
+    assert!(drawing_content.contains(
+        "<xdr:oneCellAnchor ><xdr:from ><xdr:col >1</xdr:col><xdr:colOff >0</xdr:colOff><xdr:row >1</xdr:row>"
+    ));
+    assert!(drawing_content.contains("<xdr:ext cx=\"952500\" cy=\"476250\" />"));
+    assert!(drawing_content.contains(
+        "<xdr:to ><xdr:col >6</xdr:col><xdr:colOff >0</xdr:colOff><xdr:row >6</xdr:row>"
+    ));
+    assert!(drawing_content.contains("<xdr:pos x=\"9525\" y=\"19050\" />"));
+    assert!(drawing_content.contains("<xdr:cNvPr id=\"5\" name=\"Picture 4\" />"));
+    assert!(drawing_content.contains("<asvg:svgBlip r:embed=\"rId3\" />"));
+    assert_eq!(drawing_content.matches("<xdr:clientData />").count(), 4);
+    // Same png is stored once and shared by the pictures
+    assert_eq!(
+        drawing_relationship.matches("/xl/media/image1.png").count(),
+        1
+    );
+    assert!(drawing_relationship.contains("Target=\"/xl/media/image1.jpeg\""));
+    assert!(drawing_relationship.contains("Target=\"/xl/media/image1.svg\""));
+    assert!(archive.by_name("xl/media/image2.png").is_err());
+    assert!(content_types.contains("ContentType=\"image/png\" Extension=\"png\""));
+    assert!(content_types.contains("ContentType=\"image/jpeg\" Extension=\"jpeg\""));
+    assert!(content_types.contains("ContentType=\"image/svg+xml\" Extension=\"svg\""));
+    assert!(content_types.contains(
+        "ContentType=\"application/vnd.openxmlformats-officedocument.drawing+xml\" PartName=\"/xl/drawings/drawing1.xml\""
+    ));
+    // Reopen and add to the existing drawing and to the new sheet
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Saved File Failed");
+    {
+        let mut worksheet = file
+            .get_worksheet_mut("Images".to_string())
+            .expect("Failed to find the worksheet");
+        worksheet
+            .add_image_mut(
+                &png_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Png,
+                crate::spreadsheet_2007::models::DrawingAnchorValues::OneCell {
+                    cell_ref: "J2".to_string(),
+                    size: crate::spreadsheet_2007::models::DrawingSizeValues::Pixel {
+                        width: 20,
+                        height: 20,
+                    },
+                },
+            )
+            .expect("Failed to add image to existing drawing");
+    }
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Logo".to_string()))
+            .expect("Failed to add the worksheet");
+        worksheet
+            .add_image_mut(
+                &png_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Png,
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "A1".to_string(),
+                    to_cell_ref: "B2".to_string(),
+                },
+            )
+            .expect("Failed to add image to new sheet");
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let drawing_content = read_part("xl/drawings/drawing1.xml");
+    let other_drawing_relationship = read_part("xl/drawings/_rels/drawing2.xml.rels");
+    assert_eq!(drawing_content.matches("<xdr:clientData").count(), 5);
+    assert!(drawing_content.contains("<xdr:cNvPr id=\"6\" name=\"Picture 5\" />"));
+    assert!(other_drawing_relationship.contains("Target=\"/xl/media/image1.png\""));
+    assert!(archive.by_name("xl/media/image2.png").is_err());
+}
+
+#[test]
+fn delete_sheet_parts() {
+    let png_image = [
+        vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A],
+        b"png image content".to_vec(),
+    ]
+    .concat();
+    let jpeg_image = [vec![0xFF, 0xD8, 0xFF, 0xE0], b"jpeg image content".to_vec()].concat();
+    let get_anchor =
+        |cell_ref: &str| crate::spreadsheet_2007::models::DrawingAnchorValues::OneCell {
+            cell_ref: cell_ref.to_string(),
+            size: crate::spreadsheet_2007::models::DrawingSizeValues::Pixel {
+                width: 100,
+                height: 50,
+            },
+        };
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    file.add_sheet_mut(Some("Shared".to_string()))
+        .expect("Failed to add the worksheet")
+        .add_image_mut(
+            &png_image,
+            crate::spreadsheet_2007::models::ImageFormatValues::Png,
+            get_anchor("B2"),
+        )
+        .expect("Failed to add shared image");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Gone".to_string()))
+            .expect("Failed to add the worksheet");
+        worksheet
+            .add_comment_mut(
+                "B2",
+                crate::spreadsheet_2007::models::Comment::new("Reviewer", "Old note"),
+            )
+            .expect("Failed to add comment");
+        worksheet
+            .add_threaded_comment_mut(
+                "C3",
+                crate::spreadsheet_2007::models::ThreadedComment::new("Reviewer", "Old thread"),
+            )
+            .expect("Failed to add threaded comment");
+        worksheet
+            .add_image_mut(
+                &png_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Png,
+                get_anchor("D2"),
+            )
+            .expect("Failed to add shared image");
+        worksheet
+            .add_image_mut(
+                &jpeg_image,
+                crate::spreadsheet_2007::models::ImageFormatValues::Jpeg,
+                get_anchor("F2"),
+            )
+            .expect("Failed to add own image");
+    }
+    // Reopen so the deleted parts come from the store as well
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Memory File Failed");
+    file.get_worksheet_mut("Gone".to_string())
+        .expect("Failed to get the worksheet")
+        .delete_sheet_mut()
+        .expect("Failed to delete the worksheet");
+    file.add_sheet_mut(Some("Keep".to_string()))
+        .expect("Failed to add the worksheet");
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let file_names = archive
+        .file_names()
+        .map(|file_name| file_name.to_string())
+        .collect::<Vec<_>>();
+    for file_name in &file_names {
+        assert!(
+            !file_name.contains("comments")
+                && !file_name.contains("vmlDrawing")
+                && !file_name.contains("threadedComment")
+                && !file_name.ends_with(".jpeg"),
+            "{} should be deleted",
+            file_name
+        );
+    }
+    assert_eq!(
+        file_names
+            .iter()
+            .filter(|file_name| file_name.starts_with("xl/drawings/"))
+            .count(),
+        2
+    );
+    assert!(file_names.contains(&"xl/media/image1.png".to_string()));
+    assert_eq!(
+        file_names
+            .iter()
+            .filter(|file_name| file_name.starts_with("xl/worksheets/_rels/"))
+            .count(),
+        1
+    );
+    let mut content_types = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("[Content_Types].xml")
+            .expect("Content types missing"),
+        &mut content_types,
+    )
+    .expect("Failed to read content types");
+    assert!(!content_types.contains("jpeg"));
+    assert!(!content_types.contains("comments"));
+    for file_name in file_names
+        .iter()
+        .filter(|file_name| file_name.starts_with("xl/worksheets/sheet"))
+    {
+        let mut sheet_content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_name).expect("Sheet missing"),
+            &mut sheet_content,
+        )
+        .expect("Failed to read sheet");
+        assert!(!sheet_content.contains("legacyDrawing"), "{}", file_name);
+    }
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Memory File Failed");
+    let worksheet = file
+        .get_worksheet_mut("Keep".to_string())
+        .expect("Failed to get the worksheet");
+    assert!(worksheet
+        .list_comments()
+        .expect("Failed to list comments")
+        .is_empty());
+    assert!(worksheet
+        .list_threaded_comments()
+        .expect("Failed to list threaded comments")
+        .is_empty());
+}
+
+#[test]
+fn worksheet_chart() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Sales Data".to_string()))
+            .expect("Failed to add the worksheet");
+        for (row_index, (label, first, second)) in [
+            ("Region", None, None),
+            ("North", Some(10), Some(4)),
+            ("South", Some(20), None),
+            ("East", Some(15), Some(8)),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut row = vec![crate::spreadsheet_2007::models::CellProperties {
+                value: label.into(),
+                ..Default::default()
+            }];
+            for value in [first, second] {
+                row.push(crate::spreadsheet_2007::models::CellProperties {
+                    value: value.map_or(
+                        if row_index == 0 {
+                            "Quarter".into()
+                        } else {
+                            crate::spreadsheet_2007::models::CellValue::Empty
+                        },
+                        |value| value.into(),
+                    ),
+                    ..Default::default()
+                });
+            }
+            worksheet
+                .set_row_value_index_mut(row_index as u32 + 1, 1, row)
+                .expect("Failed to set chart data");
+        }
+        let mut second_series = crate::global_2007::charts::ChartSeries::from_reference(
+            "$C$1",
+            "$A$2:$A$4",
+            "$C$2:$C$4",
+        );
+        second_series.color = Some("ed7d31".to_string());
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::BarColumn(
+                    crate::global_2007::charts::BarColumnChart {
+                        base_chart: crate::global_2007::charts::BaseChart {
+                            title: Some("Sales".to_string()),
+                            legend_position: Some(
+                                crate::global_2007::charts::ChartLegendPositionValues::Bottom,
+                            ),
+                            ..Default::default()
+                        },
+                        series: vec![
+                            crate::global_2007::charts::ChartSeries::from_reference(
+                                "$B$1",
+                                "$A$2:$A$4",
+                                "$B$2:$B$4",
+                            ),
+                            second_series,
+                        ],
+                        ..Default::default()
+                    },
+                ),
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "E2".to_string(),
+                    to_cell_ref: "L16".to_string(),
+                },
+            )
+            .expect("Failed to add column chart");
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::BarColumn(
+                    crate::global_2007::charts::BarColumnChart {
+                        direction: crate::global_2007::charts::BarDirectionValues::Bar,
+                        grouping: crate::global_2007::charts::BarGroupingValues::PercentStacked,
+                        series: vec![crate::global_2007::charts::ChartSeries {
+                            name: crate::global_2007::charts::ChartTextData::from_values(vec![
+                                "Literal",
+                            ]),
+                            categories: Some(
+                                crate::global_2007::charts::ChartTextData::from_values(vec![
+                                    "A", "B",
+                                ]),
+                            ),
+                            values: crate::global_2007::charts::ChartNumberData::from_values(vec![
+                                1.5, 2.0,
+                            ]),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    },
+                ),
+                crate::spreadsheet_2007::models::DrawingAnchorValues::OneCell {
+                    cell_ref: "E18".to_string(),
+                    size: crate::spreadsheet_2007::models::DrawingSizeValues::Pixel {
+                        width: 480,
+                        height: 288,
+                    },
+                },
+            )
+            .expect("Failed to add bar chart");
+        assert!(worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::BarColumn(
+                    crate::global_2007::charts::BarColumnChart::default(),
+                ),
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "A1".to_string(),
+                    to_cell_ref: "B2".to_string(),
+                },
+            )
+            .is_err());
+        assert!(worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::BarColumn(
+                    crate::global_2007::charts::BarColumnChart {
+                        gap_width: Some(600),
+                        series: vec![crate::global_2007::charts::ChartSeries::from_reference(
+                            "$B$1",
+                            "$A$2:$A$4",
+                            "$B$2:$B$4",
+                        )],
+                        ..Default::default()
+                    },
+                ),
+                crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+                    from_cell_ref: "A1".to_string(),
+                    to_cell_ref: "B2".to_string(),
+                },
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(file_content.clone()))
+        .expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let drawing_content = read_part("xl/drawings/drawing1.xml");
+    let drawing_relationship = read_part("xl/drawings/_rels/drawing1.xml.rels");
+    let column_chart = read_part("xl/charts/chart1.xml");
+    let bar_chart = read_part("xl/charts/chart2.xml");
+    let content_types = read_part("[Content_Types].xml");
+    assert!(drawing_content.contains("<xdr:cNvPr id=\"2\" name=\"Chart 1\" />"));
+    assert!(drawing_content.contains("<c:chart r:id=\"rId1\" />"));
+    assert!(drawing_relationship.contains("Target=\"/xl/charts/chart2.xml\""));
+    assert!(column_chart.contains("<c:barDir val=\"col\" />"));
+    assert!(column_chart.contains("<c:f >&apos;Sales Data&apos;!$B$2:$B$4</c:f>"));
+    assert!(column_chart.contains("<c:pt idx=\"1\" ><c:v >South</c:v></c:pt>"));
+    assert!(column_chart.contains("<c:pt idx=\"2\" ><c:v >15</c:v></c:pt>"));
+    // Empty cell stay out of the cache
+    assert!(column_chart
+        .contains("<c:ptCount val=\"3\" /><c:pt idx=\"0\" ><c:v >4</c:v></c:pt><c:pt idx=\"2\" >"));
+    assert!(column_chart.contains("<a:srgbClr val=\"ED7D31\" />"));
+    assert!(column_chart.contains("<c:legendPos val=\"b\" />"));
+    assert!(column_chart.contains("<a:t >Sales</a:t>"));
+    assert!(bar_chart.contains("<c:barDir val=\"bar\" />"));
+    assert!(bar_chart.contains("<c:overlap val=\"100\" />"));
+    assert!(bar_chart.contains("<c:numLit >"));
+    assert!(bar_chart.contains("<c:numFmt formatCode=\"0%\" sourceLinked=\"0\" />"));
+    assert!(!bar_chart.contains("<c:legend >"));
+    assert!(archive.by_name("xl/charts/chart3.xml").is_err());
+    assert!(content_types.contains(
+        "ContentType=\"application/vnd.openxmlformats-officedocument.drawingml.chart+xml\" PartName=\"/xl/charts/chart1.xml\""
+    ));
+}
+
+#[test]
+fn worksheet_chart_types() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Trend".to_string()))
+            .expect("Failed to add the worksheet");
+        for (row_index, row) in [
+            ["Month", "Visits", "Orders", "Size"],
+            ["1", "120", "12", "3"],
+            ["2", "150", "18", "5"],
+            ["3", "170", "20", "4"],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            worksheet
+                .set_row_value_index_mut(
+                    row_index as u32 + 1,
+                    1,
+                    row.into_iter()
+                        .map(|value| crate::spreadsheet_2007::models::CellProperties {
+                            value: value
+                                .parse::<f64>()
+                                .map_or(value.into(), |number| number.into()),
+                            ..Default::default()
+                        })
+                        .collect(),
+                )
+                .expect("Failed to set chart data");
+        }
+        let anchor =
+            |cell_ref: &str| crate::spreadsheet_2007::models::DrawingAnchorValues::OneCell {
+                cell_ref: cell_ref.to_string(),
+                size: crate::spreadsheet_2007::models::DrawingSizeValues::Pixel {
+                    width: 480,
+                    height: 288,
+                },
+            };
+        let visits = crate::global_2007::charts::ChartSeries::from_reference(
+            "$B$1",
+            "$A$2:$A$4",
+            "$B$2:$B$4",
+        );
+        let orders = crate::global_2007::charts::ChartSeries::from_reference(
+            "$C$1",
+            "$A$2:$A$4",
+            "$C$2:$C$4",
+        );
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Line(
+                    crate::global_2007::charts::LineChart {
+                        marker: crate::global_2007::charts::ChartMarkerValues::Circle,
+                        smooth: true,
+                        series: vec![crate::global_2007::charts::ChartSeries {
+                            color: Some("4472C4".to_string()),
+                            ..visits.clone()
+                        }],
+                        ..Default::default()
+                    },
+                ),
+                anchor("F1"),
+            )
+            .expect("Failed to add line chart");
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Area(
+                    crate::global_2007::charts::AreaChart {
+                        grouping: crate::global_2007::charts::ChartGroupingValues::Stacked,
+                        series: vec![visits.clone(), orders.clone()],
+                        ..Default::default()
+                    },
+                ),
+                anchor("F20"),
+            )
+            .expect("Failed to add area chart");
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Scatter(
+                    crate::global_2007::charts::ScatterChart {
+                        series: vec![crate::global_2007::charts::ChartSeries {
+                            x_values: Some(
+                                crate::global_2007::charts::ChartNumberData::from_reference(
+                                    "$B$2:$B$4",
+                                ),
+                            ),
+                            trendline: Some(crate::global_2007::charts::ChartTrendline {
+                                trendline_type:
+                                    crate::global_2007::charts::ChartTrendlineTypeValues::Polynomial {
+                                        order: 2,
+                                    },
+                                display_r_squared: true,
+                                ..Default::default()
+                            }),
+                            ..orders.clone()
+                        }],
+                        ..Default::default()
+                    },
+                ),
+                anchor("F40"),
+            )
+            .expect("Failed to add scatter chart");
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Pie(
+                    crate::global_2007::charts::PieChart {
+                        first_slice_angle: 90,
+                        point_explosions: [(1, 20)].into_iter().collect(),
+                        base_chart: crate::global_2007::charts::BaseChart {
+                            data_label: Some(crate::global_2007::charts::ChartDataLabel {
+                                show_percent: true,
+                                position: Some(
+                                    crate::global_2007::charts::ChartDataLabelPositionValues::BestFit,
+                                ),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                        series: vec![visits.clone()],
+                        ..Default::default()
+                    },
+                ),
+                anchor("P1"),
+            )
+            .expect("Failed to add pie chart");
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Pie(
+                    crate::global_2007::charts::PieChart {
+                        hole_size: Some(50),
+                        explosion: Some(10),
+                        series: vec![visits.clone(), orders.clone()],
+                        ..Default::default()
+                    },
+                ),
+                anchor("P20"),
+            )
+            .expect("Failed to add doughnut chart");
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Bubble(
+                    crate::global_2007::charts::BubbleChart {
+                        bubble_scale: Some(50),
+                        series: vec![crate::global_2007::charts::ChartSeries {
+                            x_values: Some(
+                                crate::global_2007::charts::ChartNumberData::from_reference(
+                                    "$B$2:$B$4",
+                                ),
+                            ),
+                            bubble_sizes: Some(
+                                crate::global_2007::charts::ChartNumberData::from_reference(
+                                    "$D$2:$D$4",
+                                ),
+                            ),
+                            ..orders.clone()
+                        }],
+                        ..Default::default()
+                    },
+                ),
+                anchor("P40"),
+            )
+            .expect("Failed to add bubble chart");
+        // Pie show single series
+        assert!(worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Pie(
+                    crate::global_2007::charts::PieChart {
+                        series: vec![visits.clone(), orders.clone()],
+                        ..Default::default()
+                    },
+                ),
+                anchor("Z1"),
+            )
+            .is_err());
+        // Stacked area has no trendline
+        assert!(worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Area(
+                    crate::global_2007::charts::AreaChart {
+                        grouping: crate::global_2007::charts::ChartGroupingValues::Stacked,
+                        series: vec![crate::global_2007::charts::ChartSeries {
+                            trendline: Some(crate::global_2007::charts::ChartTrendline::default()),
+                            ..visits.clone()
+                        }],
+                        ..Default::default()
+                    },
+                ),
+                anchor("Z1"),
+            )
+            .is_err());
+        assert!(worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Bubble(
+                    crate::global_2007::charts::BubbleChart {
+                        series: vec![orders.clone()],
+                        ..Default::default()
+                    },
+                ),
+                anchor("Z1"),
+            )
+            .is_err());
+        assert!(worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Scatter(
+                    crate::global_2007::charts::ScatterChart {
+                        marker: crate::global_2007::charts::ChartMarkerValues::None,
+                        series: vec![orders.clone()],
+                        ..Default::default()
+                    },
+                ),
+                anchor("Z1"),
+            )
+            .is_err());
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let line_chart = read_part("xl/charts/chart1.xml");
+    let area_chart = read_part("xl/charts/chart2.xml");
+    let scatter_chart = read_part("xl/charts/chart3.xml");
+    let pie_chart = read_part("xl/charts/chart4.xml");
+    let doughnut_chart = read_part("xl/charts/chart5.xml");
+    let bubble_chart = read_part("xl/charts/chart6.xml");
+    assert!(line_chart.contains("<c:spPr ><a:ln ><a:solidFill ><a:srgbClr val=\"4472C4\" />"));
+    assert!(line_chart.contains("<c:marker ><c:symbol val=\"circle\" />"));
+    assert!(line_chart.contains("<c:smooth val=\"1\" /></c:ser><c:marker val=\"1\" />"));
+    assert!(area_chart.contains("<c:areaChart ><c:grouping val=\"stacked\" />"));
+    assert!(area_chart.contains("<c:crossBetween val=\"midCat\" />"));
+    assert!(scatter_chart.contains("<c:scatterStyle val=\"marker\" />"));
+    assert!(scatter_chart.contains("<c:spPr ><a:ln ><a:noFill /></a:ln></c:spPr>"));
+    assert!(scatter_chart.contains(
+        "<c:trendlineType val=\"poly\" /><c:order val=\"2\" /><c:dispRSqr val=\"1\" /><c:dispEq val=\"0\" />"
+    ));
+    assert!(scatter_chart.contains("<c:xVal ><c:numRef ><c:f >&apos;Trend&apos;!$B$2:$B$4</c:f>"));
+    assert_eq!(scatter_chart.matches("<c:valAx >").count(), 2);
+    assert!(pie_chart.contains("<c:pieChart ><c:varyColors val=\"1\" />"));
+    assert!(pie_chart.contains(
+        "<c:dPt ><c:idx val=\"1\" /><c:bubble3D val=\"0\" /><c:explosion val=\"20\" /></c:dPt>"
+    ));
+    assert!(pie_chart.contains("<c:dLblPos val=\"bestFit\" />"));
+    assert!(pie_chart.contains("<c:firstSliceAng val=\"90\" />"));
+    assert!(!pie_chart.contains("<c:catAx"));
+    assert!(doughnut_chart.contains("<c:doughnutChart >"));
+    assert_eq!(
+        doughnut_chart.matches("<c:explosion val=\"10\" />").count(),
+        1
+    );
+    assert!(doughnut_chart.contains("<c:holeSize val=\"50\" />"));
+    assert!(
+        bubble_chart.contains("<c:bubbleSize ><c:numRef ><c:f >&apos;Trend&apos;!$D$2:$D$4</c:f>")
+    );
+    assert!(bubble_chart.contains("<c:bubbleScale val=\"50\" />"));
+    assert!(archive.by_name("xl/charts/chart7.xml").is_err());
+}
+
+#[test]
+fn worksheet_combo_chart() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Finance".to_string()))
+            .expect("Failed to add the worksheet");
+        for (row_index, row) in [
+            ["Year", "Revenue", "Margin"],
+            ["2022", "1200", "0.12"],
+            ["2023", "1500", "0.18"],
+            ["2024", "1700", "0.21"],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            worksheet
+                .set_row_value_index_mut(
+                    row_index as u32 + 1,
+                    1,
+                    row.into_iter()
+                        .map(|value| crate::spreadsheet_2007::models::CellProperties {
+                            value: value.into(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                )
+                .expect("Failed to set chart data");
+        }
+        let revenue = crate::global_2007::charts::ChartSeries::from_reference(
+            "$B$1",
+            "$A$2:$A$4",
+            "$B$2:$B$4",
+        );
+        let margin = crate::global_2007::charts::ChartSeries::from_reference(
+            "$C$1",
+            "$A$2:$A$4",
+            "$C$2:$C$4",
+        );
+        let anchor = crate::spreadsheet_2007::models::DrawingAnchorValues::TwoCell {
+            from_cell_ref: "E2".to_string(),
+            to_cell_ref: "L16".to_string(),
+        };
+        let combo_chart = crate::global_2007::charts::ComboChart {
+            base_chart: crate::global_2007::charts::BaseChart {
+                title: Some("Revenue and Margin".to_string()),
+                value_axis: crate::global_2007::charts::ChartAxis {
+                    min: Some(0.0),
+                    max: Some(2000.0),
+                    major_unit: Some(500.0),
+                    number_format: Some("#,##0".to_string()),
+                    minor_gridlines: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            secondary_value_axis: crate::global_2007::charts::ChartAxis {
+                number_format: Some("0%".to_string()),
+                ..Default::default()
+            },
+            plots: vec![
+                crate::global_2007::charts::ComboChartPlot {
+                    chart: crate::global_2007::charts::ChartTypeValues::BarColumn(
+                        crate::global_2007::charts::BarColumnChart {
+                            series: vec![revenue.clone()],
+                            ..Default::default()
+                        },
+                    ),
+                    secondary_axis: false,
+                },
+                crate::global_2007::charts::ComboChartPlot {
+                    chart: crate::global_2007::charts::ChartTypeValues::Line(
+                        crate::global_2007::charts::LineChart {
+                            series: vec![margin.clone()],
+                            ..Default::default()
+                        },
+                    ),
+                    secondary_axis: true,
+                },
+            ],
+        };
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Combo(combo_chart.clone()),
+                anchor.clone(),
+            )
+            .expect("Failed to add combo chart");
+        worksheet
+            .add_chart_mut(
+                crate::global_2007::charts::ChartTypeValues::Scatter(
+                    crate::global_2007::charts::ScatterChart {
+                        base_chart: crate::global_2007::charts::BaseChart {
+                            category_axis: crate::global_2007::charts::ChartAxis {
+                                crosses: Some(
+                                    crate::global_2007::charts::ChartAxisCrossesValues::At {
+                                        value: 10.0,
+                                    },
+                                ),
+                                ..Default::default()
+                            },
+                            value_axis: crate::global_2007::charts::ChartAxis {
+                                log_base: Some(10.0),
+                                major_gridlines: Some(false),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        series: vec![revenue.clone()],
+                        ..Default::default()
+                    },
+                ),
+                anchor.clone(),
+            )
+            .expect("Failed to add log scale chart");
+        let invalid_charts = [
+            // Secondary axes only
+            crate::global_2007::charts::ComboChart {
+                plots: vec![crate::global_2007::charts::ComboChartPlot {
+                    secondary_axis: true,
+                    ..combo_chart.plots[1].clone()
+                }],
+                ..Default::default()
+            },
+            // Pie has no axes
+            crate::global_2007::charts::ComboChart {
+                plots: vec![
+                    combo_chart.plots[0].clone(),
+                    crate::global_2007::charts::ComboChartPlot {
+                        chart: crate::global_2007::charts::ChartTypeValues::Pie(
+                            crate::global_2007::charts::PieChart {
+                                series: vec![margin.clone()],
+                                ..Default::default()
+                            },
+                        ),
+                        secondary_axis: false,
+                    },
+                ],
+                ..Default::default()
+            },
+            // Text category axis has no scale
+            crate::global_2007::charts::ComboChart {
+                base_chart: crate::global_2007::charts::BaseChart {
+                    category_axis: crate::global_2007::charts::ChartAxis {
+                        max: Some(10.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..combo_chart.clone()
+            },
+            crate::global_2007::charts::ComboChart {
+                secondary_value_axis: crate::global_2007::charts::ChartAxis {
+                    min: Some(5.0),
+                    max: Some(1.0),
+                    ..Default::default()
+                },
+                ..combo_chart.clone()
+            },
+        ];
+        for invalid_chart in invalid_charts {
+            assert!(worksheet
+                .add_chart_mut(
+                    crate::global_2007::charts::ChartTypeValues::Combo(invalid_chart),
+                    anchor.clone(),
+                )
+                .is_err());
+        }
+    }
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(file_content)).expect("Failed to read archive");
+    let mut read_part = |file_path: &str| {
+        let mut content = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(file_path).expect("Part missing"),
+            &mut content,
+        )
+        .expect("Failed to read part");
+        content
+    };
+    let combo_chart = read_part("xl/charts/chart1.xml");
+    let scatter_chart = read_part("xl/charts/chart2.xml");
+    // Series index continue across the plot groups
+    assert!(combo_chart.contains(
+        "<c:axId val=\"500000001\" /><c:axId val=\"500000002\" /></c:barChart><c:lineChart >"
+    ));
+    assert!(combo_chart.contains("<c:ser ><c:idx val=\"1\" /><c:order val=\"1\" />"));
+    assert!(combo_chart.contains(
+        "<c:axId val=\"500000003\" /><c:axId val=\"500000004\" /></c:lineChart><c:catAx >"
+    ));
+    assert_eq!(combo_chart.matches("<c:catAx >").count(), 2);
+    assert_eq!(combo_chart.matches("<c:valAx >").count(), 2);
+    assert!(combo_chart.contains(
+        "<c:scaling ><c:orientation val=\"minMax\" /><c:max val=\"2000\" /><c:min val=\"0\" /></c:scaling>"
+    ));
+    assert!(combo_chart.contains("<c:majorGridlines /><c:minorGridlines />"));
+    assert!(combo_chart.contains("<c:numFmt formatCode=\"#,##0\" sourceLinked=\"0\" />"));
+    assert!(combo_chart.contains("<c:crossBetween val=\"between\" /><c:majorUnit val=\"500\" />"));
+    // Secondary category axis is hidden and the value axis is on the right
+    assert!(combo_chart.contains(
+        "<c:axId val=\"500000003\" /><c:scaling ><c:orientation val=\"minMax\" /></c:scaling><c:delete val=\"1\" />"
+    ));
+    assert!(combo_chart
+        .contains("<c:axPos val=\"r\" /><c:numFmt formatCode=\"0%\" sourceLinked=\"0\" />"));
+    assert!(combo_chart.contains("<c:crossAx val=\"500000003\" /><c:crosses val=\"max\" />"));
+    assert!(scatter_chart
+        .contains("<c:scaling ><c:logBase val=\"10\" /><c:orientation val=\"minMax\" />"));
+    assert!(scatter_chart.contains("<c:crossesAt val=\"10\" />"));
+    assert!(!scatter_chart.contains("<c:majorGridlines />"));
+    assert!(archive.by_name("xl/charts/chart3.xml").is_err());
+}
+
+#[test]
+fn formula_calculation() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    let get_formula_cell = |formula: &str| crate::spreadsheet_2007::models::CellProperties {
+        formula: Some(formula.to_string()),
+        ..Default::default()
+    };
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Data".to_string()))
+            .expect("Failed to add the worksheet");
+        for (row_index, (item, price)) in [("Apple", 1.5), ("Banana", 0.25), ("Cherry", 4.0)]
+            .into_iter()
+            .enumerate()
+        {
+            worksheet
+                .set_row_value_index_mut(
+                    row_index as u32 + 2,
+                    1,
+                    vec![
+                        crate::spreadsheet_2007::models::CellProperties {
+                            value: crate::spreadsheet_2007::models::CellValue::Text(
+                                item.to_string(),
+                            ),
+                            ..Default::default()
+                        },
+                        crate::spreadsheet_2007::models::CellProperties {
+                            value: crate::spreadsheet_2007::models::CellValue::Number(price),
+                            ..Default::default()
+                        },
+                    ],
+                )
+                .expect("Failed to set the data row");
+        }
+        file.add_sheet_mut(Some("Summary".to_string()))
+            .expect("Failed to add the worksheet");
+    }
+    file.add_defined_name_mut(crate::spreadsheet_2007::models::DefinedName::new(
+        "Total",
+        "Summary!$A$1",
+    ))
+    .expect("Failed to add the defined name");
+    {
+        let mut worksheet = file
+            .get_worksheet_mut("Summary".to_string())
+            .expect("Failed to get the worksheet");
+        for (row_index, formula) in [
+            "SUM(Data!B2:B4)",
+            "IF(A1>5,\"High\",\"Low\")",
+            "VLOOKUP(\"banana\",Data!$A$2:$B$4,2,FALSE)",
+            "_xlfn.XLOOKUP(\"Cherry\",Data!A2:A4,Data!B2:B4)",
+            "INDEX(Data!B:B,MATCH(\"Apple\",Data!A:A,0))",
+            "TEXT(A1*1000,\"#,##0.00\")",
+            "DATE(2024,2,29)+1",
+            "TEXT(A7,\"dd-mmm-yyyy\")",
+            "Total*2",
+            "A11+1",
+            "A10+1",
+            "CUBEVALUE(\"Sales\")",
+            "1/0",
+            "A12",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            worksheet
+                .set_row_value_index_mut(row_index as u32 + 1, 1, vec![get_formula_cell(formula)])
+                .expect("Failed to set the formula");
+        }
+    }
+    let report = file
+        .calculate_formulas_mut()
+        .expect("Failed to calculate the formulas");
+    assert_eq!(report.calculated_count, 10);
+    assert_eq!(
+        report.circular_references,
+        vec!["'Summary'!A10".to_string(), "'Summary'!A11".to_string()]
+    );
+    assert_eq!(
+        report
+            .skipped_cells
+            .iter()
+            .map(|(cell_ref, _)| cell_ref.as_str())
+            .collect::<Vec<_>>(),
+        vec!["'Summary'!A12", "'Summary'!A14"]
+    );
+    assert!(report.skipped_cells[0].1.contains("CUBEVALUE"));
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Memory File Failed");
+    let worksheet = file
+        .get_worksheet_mut("Summary".to_string())
+        .expect("Failed to get the worksheet");
+    let get_value = |cell_ref: &str| {
+        worksheet
+            .get_cell_value(cell_ref)
+            .expect("Failed to read the cell")
+            .expect("Cell missing")
+    };
+    for (cell_ref, expected_value) in [
+        (
+            "A1",
+            crate::spreadsheet_2007::models::CellValue::Number(5.75),
+        ),
+        (
+            "A2",
+            crate::spreadsheet_2007::models::CellValue::Text("High".to_string()),
+        ),
+        (
+            "A3",
+            crate::spreadsheet_2007::models::CellValue::Number(0.25),
+        ),
+        (
+            "A4",
+            crate::spreadsheet_2007::models::CellValue::Number(4.0),
+        ),
+        (
+            "A5",
+            crate::spreadsheet_2007::models::CellValue::Number(1.5),
+        ),
+        (
+            "A6",
+            crate::spreadsheet_2007::models::CellValue::Text("5,750.00".to_string()),
+        ),
+        (
+            "A7",
+            crate::spreadsheet_2007::models::CellValue::Number(45352.0),
+        ),
+        (
+            "A8",
+            crate::spreadsheet_2007::models::CellValue::Text("01-Mar-2024".to_string()),
+        ),
+        (
+            "A9",
+            crate::spreadsheet_2007::models::CellValue::Number(11.5),
+        ),
+        (
+            "A13",
+            crate::spreadsheet_2007::models::CellValue::Error("#DIV/0!".to_string()),
+        ),
+    ] {
+        let cell_record = get_value(cell_ref);
+        assert_eq!(cell_record.value, expected_value, "Value of {}", cell_ref);
+        assert!(cell_record.formula.is_some());
+    }
+    // Circular and unsupported formulas keep the empty value
+    assert_eq!(
+        get_value("A10").value,
+        crate::spreadsheet_2007::models::CellValue::Empty
+    );
+    assert_eq!(
+        get_value("A12").value,
+        crate::spreadsheet_2007::models::CellValue::Empty
+    );
+}
+
+#[test]
+fn formula_reference_operators() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Data".to_string()))
+            .expect("Failed to add the worksheet");
+        for (row_index, price) in [1.5, 0.25, 4.0].into_iter().enumerate() {
+            worksheet
+                .set_row_value_index_mut(
+                    row_index as u32 + 2,
+                    2,
+                    vec![crate::spreadsheet_2007::models::CellProperties {
+                        value: crate::spreadsheet_2007::models::CellValue::Number(price),
+                        ..Default::default()
+                    }],
+                )
+                .expect("Failed to set the data row");
+        }
+        for (row_index, formula) in [
+            "SUM(B2:B4 B3:B5)",
+            "SUM(B2:B3:B4)",
+            "@B2:B4",
+            "SUM(B2 C3)",
+            "SUM((B2,B3))",
+            "SUM(B2#)",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            worksheet
+                .set_row_value_index_mut(
+                    row_index as u32 + 1,
+                    4,
+                    vec![crate::spreadsheet_2007::models::CellProperties {
+                        formula: Some(formula.to_string()),
+                        ..Default::default()
+                    }],
+                )
+                .expect("Failed to set the formula");
+        }
+    }
+    let report = file
+        .calculate_formulas_mut()
+        .expect("Failed to calculate the formulas");
+    assert_eq!(report.calculated_count, 4);
+    assert_eq!(
+        report
+            .skipped_cells
+            .iter()
+            .map(|(cell_ref, _)| cell_ref.as_str())
+            .collect::<Vec<_>>(),
+        vec!["'Data'!D5", "'Data'!D6"]
+    );
+    let worksheet = file
+        .get_worksheet_mut("Data".to_string())
+        .expect("Failed to get the worksheet");
+    for (cell_ref, expected_value) in [
+        (
+            "D1",
+            crate::spreadsheet_2007::models::CellValue::Number(4.25),
+        ),
+        (
+            "D2",
+            crate::spreadsheet_2007::models::CellValue::Number(5.75),
+        ),
+        (
+            "D3",
+            crate::spreadsheet_2007::models::CellValue::Number(0.25),
+        ),
+        (
+            "D4",
+            crate::spreadsheet_2007::models::CellValue::Error("#NULL!".to_string()),
+        ),
+    ] {
+        assert_eq!(
+            worksheet
+                .get_cell_value(cell_ref)
+                .expect("Failed to read the cell")
+                .expect("Cell missing")
+                .value,
+            expected_value,
+            "Value of {}",
+            cell_ref
+        );
+    }
+}
+
+#[test]
+fn formula_evaluation_rules() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    let mut worksheet = file
+        .add_sheet_mut(Some("Rules".to_string()))
+        .expect("Failed to add the worksheet");
+    let formulas = [
+        // Operator precedence
+        (
+            "=-2^2",
+            crate::spreadsheet_2007::models::CellValue::Number(4.0),
+        ),
+        (
+            "=2+3*4",
+            crate::spreadsheet_2007::models::CellValue::Number(14.0),
+        ),
+        (
+            "=2^3^2",
+            crate::spreadsheet_2007::models::CellValue::Number(64.0),
+        ),
+        (
+            "=50%*2",
+            crate::spreadsheet_2007::models::CellValue::Number(1.0),
+        ),
+        (
+            "=\"a\"&1+1",
+            crate::spreadsheet_2007::models::CellValue::Text("a2".to_string()),
+        ),
+        (
+            "=1+2=3",
+            crate::spreadsheet_2007::models::CellValue::Bool(true),
+        ),
+        // Error propagation
+        (
+            "=1/0+1",
+            crate::spreadsheet_2007::models::CellValue::Error("#DIV/0!".to_string()),
+        ),
+        (
+            "=SUM(1,1/0,NA())",
+            crate::spreadsheet_2007::models::CellValue::Error("#DIV/0!".to_string()),
+        ),
+        (
+            "=\"a\"+1",
+            crate::spreadsheet_2007::models::CellValue::Error("#VALUE!".to_string()),
+        ),
+        (
+            "=IFERROR(1/0,\"x\")",
+            crate::spreadsheet_2007::models::CellValue::Text("x".to_string()),
+        ),
+        // Text longer than excel keep in the cell
+        (
+            "=LEN(REPT(\"x\",1E+15))",
+            crate::spreadsheet_2007::models::CellValue::Error("#VALUE!".to_string()),
+        ),
+        (
+            "=LEN(REPT(\"ab\",16383))",
+            crate::spreadsheet_2007::models::CellValue::Number(32766.0),
+        ),
+        (
+            "=LEN(REPT(\"x\",20000)&REPT(\"x\",20000))",
+            crate::spreadsheet_2007::models::CellValue::Error("#VALUE!".to_string()),
+        ),
+        (
+            "=LEN(CONCAT(REPT(\"x\",20000),REPT(\"x\",20000)))",
+            crate::spreadsheet_2007::models::CellValue::Error("#VALUE!".to_string()),
+        ),
+        (
+            "=LEN(TEXTJOIN(\",\",TRUE,REPT(\"x\",20000),REPT(\"x\",20000)))",
+            crate::spreadsheet_2007::models::CellValue::Error("#VALUE!".to_string()),
+        ),
+        (
+            "=LEN(SUBSTITUTE(REPT(\"a\",30000),\"a\",\"bb\"))",
+            crate::spreadsheet_2007::models::CellValue::Error("#VALUE!".to_string()),
+        ),
+        (
+            "=SUBSTITUTE(\"a-b-c\",\"-\",\"+\")",
+            crate::spreadsheet_2007::models::CellValue::Text("a+b+c".to_string()),
+        ),
+        // Date with excel's 1900 leap year
+        (
+            "=DATE(1900,2,28)",
+            crate::spreadsheet_2007::models::CellValue::Number(59.0),
+        ),
+        (
+            "=DATE(1900,2,29)",
+            crate::spreadsheet_2007::models::CellValue::Number(60.0),
+        ),
+        (
+            "=DATE(1900,3,1)",
+            crate::spreadsheet_2007::models::CellValue::Number(61.0),
+        ),
+        (
+            "=DATE(2024,14,0)",
+            crate::spreadsheet_2007::models::CellValue::Number(45688.0),
+        ),
+        (
+            "=EOMONTH(DATE(1900,2,1),0)",
+            crate::spreadsheet_2007::models::CellValue::Number(60.0),
+        ),
+        (
+            "=DAY(DATE(1900,2,29))",
+            crate::spreadsheet_2007::models::CellValue::Number(29.0),
+        ),
+        (
+            "=YEAR(0)",
+            crate::spreadsheet_2007::models::CellValue::Number(1900.0),
+        ),
+        (
+            "=TEXT(60,\"yyyy-mm-dd ddd\")",
+            crate::spreadsheet_2007::models::CellValue::Text("1900-02-29 Wed".to_string()),
+        ),
+        (
+            "=TEXT(1,\"dddd\")",
+            crate::spreadsheet_2007::models::CellValue::Text("Sunday".to_string()),
+        ),
+        // Text format
+        (
+            "=TEXT(DATE(2024,3,5),\"yyyy-mm-dd\")",
+            crate::spreadsheet_2007::models::CellValue::Text("2024-03-05".to_string()),
+        ),
+        (
+            "=TEXT(1234.567,\"#,##0.0\")",
+            crate::spreadsheet_2007::models::CellValue::Text("1,234.6".to_string()),
+        ),
+        (
+            "=TEXT(0.256,\"0.0%\")",
+            crate::spreadsheet_2007::models::CellValue::Text("25.6%".to_string()),
+        ),
+    ];
+    for (row_index, (formula, _)) in formulas.iter().enumerate() {
+        worksheet
+            .set_row_value_index_mut(
+                row_index as u32 + 1,
+                1,
+                vec![crate::spreadsheet_2007::models::CellProperties {
+                    formula: Some(formula.to_string()),
+                    ..Default::default()
+                }],
+            )
+            .expect("Failed to set the formula");
+    }
+    // Sheet handle held by the caller is named in the error
+    let error = file
+        .calculate_formulas_mut()
+        .expect_err("Held sheet should stop the calculation");
+    assert!(error.to_string().contains("Rules"), "{}", error);
+    drop(worksheet);
+    let report = file
+        .calculate_formulas_mut()
+        .expect("Failed to calculate the formulas");
+    assert_eq!(report.calculated_count, formulas.len());
+    let worksheet = file
+        .get_worksheet_mut("Rules".to_string())
+        .expect("Failed to get the worksheet");
+    for (row_index, (formula, expected_value)) in formulas.into_iter().enumerate() {
+        assert_eq!(
+            worksheet
+                .get_cell_value(&format!("A{}", row_index + 1))
+                .expect("Failed to read the cell")
+                .expect("Cell missing")
+                .value,
+            expected_value,
+            "Value of {}",
+            formula
+        );
+    }
+}
+
+#[test]
+fn formula_validation() {
+    let mut file = crate::spreadsheet_2007::Excel::new(
+        None,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Create New File Failed");
+    file.add_sheet_mut(Some("Sheet 2".to_string()))
+        .expect("Failed to add the worksheet");
+    file.add_defined_name_mut(crate::spreadsheet_2007::models::DefinedName::new(
+        "Rate",
+        "'Sheet 2'!$A$1",
+    ))
+    .expect("Failed to add the defined name");
+    let get_formula_cell = |formula: &str| crate::spreadsheet_2007::models::CellProperties {
+        formula: Some(formula.to_string()),
+        ..Default::default()
+    };
+    {
+        let mut worksheet = file
+            .add_sheet_mut(Some("Summary".to_string()))
+            .expect("Failed to add the worksheet");
+        for formula in [
+            "=SUM(A1:B2",
+            "=A1+*2",
+            "=SUM(\"a\",)(",
+            "=XFE1",
+            "=SUM(A1048577)",
+            "=A1:XFE1",
+            "=A1:B",
+            "=A1@B1",
+            "=AND()",
+            "=MAX()",
+            "=ROUND(1.5)",
+            "=IF(A1,1,2,3)",
+        ] {
+            assert!(
+                worksheet
+                    .set_row_value_index_mut(1, 1, vec![get_formula_cell(formula)])
+                    .is_err(),
+                "{} should be rejected",
+                formula
+            );
+        }
+        // Nesting beyond excel's limit is rejected before it exhaust the stack
+        for formula in [
+            format!("={}1{}", "(".repeat(3000), ")".repeat(3000)),
+            format!("={}1", "-".repeat(3000)),
+            format!("={}1{}", "ABS(".repeat(65), ")".repeat(65)),
+        ] {
+            assert!(worksheet
+                .set_row_value_index_mut(1, 1, vec![get_formula_cell(&formula)])
+                .is_err());
+        }
+        worksheet
+            .set_row_value_index_mut(
+                1,
+                1,
+                vec![get_formula_cell(&format!(
+                    "={}1{}",
+                    "ABS(".repeat(64),
+                    ")".repeat(64)
+                ))],
+            )
+            .expect("Failed to set the nested formula");
+        worksheet
+            .set_row_value_index_mut(
+                1,
+                1,
+                vec![
+                    get_formula_cell("=SUM('Sheet 2'!A1:B2)*Rate"),
+                    get_formula_cell("LET(x,2,x*3)"),
+                    get_formula_cell("=A1:INDEX(B1:B3,2)"),
+                    get_formula_cell("=SUM(A1:A3 A2:A4)"),
+                    get_formula_cell("=SUM((A1,B1))"),
+                    get_formula_cell("=SUM(A1#)"),
+                    get_formula_cell("=@A1:A10"),
+                    // Sheet and name added later are checked on request
+                    get_formula_cell("=Later!A1*Unknown"),
+                ],
+            )
+            .expect("Failed to set the valid formula");
+        for formula in [
+            "=Later!A1",
+            "=Unknown*2",
+            "=LET(x,2,x*Unknown)",
+            "=MAP(A1:A3,LAMBDA(x,x+y))",
+        ] {
+            assert!(
+                worksheet.validate_formula_references(formula).is_err(),
+                "{} should be rejected",
+                formula
+            );
+        }
+        worksheet
+            .validate_formula_references("=LET(x,2,y,x+1,SUM(x,y)*Rate)")
+            .expect("Failed to validate the local names");
+    }
+    file.add_sheet_mut(Some("Later".to_string()))
+        .expect("Failed to add the worksheet");
+    file.add_defined_name_mut(crate::spreadsheet_2007::models::DefinedName::new(
+        "Unknown",
+        "Later!$A$1",
+    ))
+    .expect("Failed to add the defined name");
+    file.get_worksheet_mut("Summary".to_string())
+        .expect("Failed to get the worksheet")
+        .validate_formula_references("=Later!A1*Unknown")
+        .expect("Failed to validate the formula references");
+    let file_content = file.save_to_vec().expect("Save File Failed");
+    let mut file = crate::spreadsheet_2007::Excel::from_bytes(
+        &file_content,
+        crate::spreadsheet_2007::ExcelPropertiesModel::default(),
+    )
+    .expect("Open Memory File Failed");
+    let worksheet = file
+        .get_worksheet_mut("Summary".to_string())
+        .expect("Failed to get the worksheet");
+    assert_eq!(
+        worksheet
+            .get_cell_value("A1")
+            .expect("Failed to read the cell")
+            .expect("Cell missing")
+            .formula,
+        Some("SUM('Sheet 2'!A1:B2)*Rate".to_string())
+    );
+    let expression = crate::spreadsheet_2007::services::FormulaParser::parse("SUM(A1:A3 A2:A4)")
+        .expect("Failed to parse the intersection");
+    assert!(matches!(
+        expression,
+        crate::spreadsheet_2007::services::FormulaExpressionValues::Function { arguments, .. }
+            if matches!(
+                arguments.as_slice(),
+                [crate::spreadsheet_2007::services::FormulaExpressionValues::Binary {
+                    operator: crate::spreadsheet_2007::services::FormulaOperatorValues::Intersection,
+                    ..
+                }]
+            )
+    ));
+    for (a1_formula, r1c1_formula) in [
+        (
+            "=SUM($A$1:B2)+'Sheet 2'!C3",
+            "=SUM(R1C1:R[-1]C[-1])+'Sheet 2'!RC",
+        ),
+        ("A2*$B1-C$4", "R[-1]C[-2]*R[-2]C2-R4C"),
+        ("SUM(B:B,2:$3)", "SUM(C[-1],R[-1]:R3)"),
+        ("ROUND(Rate,2)&\"A1\"", "ROUND(Rate,2)&\"A1\""),
+        (
+            "SUM(A1:INDEX(B:B,2) B2)",
+            "SUM(R[-2]C[-2]:INDEX(C[-1],2) R[-1]C[-1])",
+        ),
+    ] {
+        assert_eq!(
+            worksheet
+                .convert_formula_to_r1c1(a1_formula, "C3")
+                .expect("Failed to convert to R1C1"),
+            r1c1_formula
+        );
+        assert_eq!(
+            worksheet
+                .convert_formula_to_a1(r1c1_formula, "C3")
+                .expect("Failed to convert to A1"),
+            a1_formula
+        );
+    }
+    assert!(worksheet.convert_formula_to_a1("R[-3]C", "C3").is_err());
+}
@@ -10,6 +10,25 @@ fn blank_power_point() {
     assert_eq!(true, true);
 }
 
+#[test]
+fn memory_power_point() {
+    let file = crate::presentation_2007::PowerPoint::new(
+        None,
+        crate::presentation_2007::PowerPoint::default(),
+    )
+    .expect("Create New File Failed");
+    let file_content = file
+        .save_to_vec()
+        .expect("Failed to save Power Point to memory");
+    crate::presentation_2007::PowerPoint::from_bytes(
+        &file_content,
+        crate::presentation_2007::PowerPoint::default(),
+    )
+    .expect("Open Memory File Failed")
+    .save_to_vec()
+    .expect("Failed to save Power Point to memory");
+}
+
 #[test]
 fn edit_power_point() {
     let file = crate::presentation_2007::PowerPoint::new(
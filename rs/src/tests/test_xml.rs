@@ -0,0 +1,85 @@
+#[test]
+fn xml_attribute_escape_round_trip() {
+    let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?><root name="A &amp; B &lt;C&gt; &quot;D&quot;"><item value="&amp;amp;"/></root>"#;
+    let mut xml_document =
+        crate::files::XmlSerializer::vec_to_xml_doc_tree(xml_content.as_bytes().to_vec(), "test")
+            .expect("Failed to parse the xml");
+    let get_attribute = |xml_document: &crate::files::XmlDocument, element_id: usize| {
+        xml_document
+            .get_element(&element_id)
+            .and_then(|element| element.get_attribute())
+            .map(|attributes| {
+                (
+                    attributes.get("name").cloned(),
+                    attributes.get("value").cloned(),
+                )
+            })
+            .expect("Attribute missing")
+    };
+    let item_id = xml_document
+        .get_element_ids_by_tag("item", None)
+        .expect("Item missing")[0];
+    assert_eq!(
+        get_attribute(&xml_document, 0).0,
+        Some("A & B <C> \"D\"".to_string())
+    );
+    assert_eq!(
+        get_attribute(&xml_document, item_id).1,
+        Some("&amp;".to_string())
+    );
+    let saved_content = crate::files::XmlDeSerializer::xml_tree_to_vec(&mut xml_document, "test")
+        .expect("Failed to write the xml");
+    let saved_text = String::from_utf8(saved_content.clone()).expect("Invalid UTF-8");
+    assert!(saved_text.contains("&amp;amp;") && !saved_text.contains("&amp;amp;amp;"));
+    let xml_document = crate::files::XmlSerializer::vec_to_xml_doc_tree(saved_content, "test")
+        .expect("Failed to parse the xml");
+    let item_id = xml_document
+        .get_element_ids_by_tag("item", None)
+        .expect("Item missing")[0];
+    assert_eq!(
+        get_attribute(&xml_document, 0).0,
+        Some("A & B <C> \"D\"".to_string())
+    );
+    assert_eq!(
+        get_attribute(&xml_document, item_id).1,
+        Some("&amp;".to_string())
+    );
+}
+
+#[test]
+fn xml_text_space_handling() {
+    let xml_content = "<root>\n  <si><t xml:space=\"preserve\"> Space kept </t></si>\n  <w:t xml:space=\"preserve\"> Word text </w:t>\n  <v>\n    42\n  </v>\n</root>";
+    let mut xml_document =
+        crate::files::XmlSerializer::vec_to_xml_doc_tree(xml_content.as_bytes().to_vec(), "test")
+            .expect("Failed to parse the xml");
+    let get_value = |xml_document: &crate::files::XmlDocument, tag: &str| {
+        xml_document
+            .get_descendant_ids_by_tag(tag)
+            .first()
+            .and_then(|element_id| xml_document.get_element(element_id))
+            .and_then(|element| element.get_value().clone())
+    };
+    // Only the string item text keep the preserved space, other parts are trimmed as before
+    assert_eq!(
+        get_value(&xml_document, "t"),
+        Some(" Space kept ".to_string())
+    );
+    assert_eq!(
+        get_value(&xml_document, "w:t"),
+        Some("Word text".to_string())
+    );
+    assert_eq!(get_value(&xml_document, "v"), Some("42".to_string()));
+    let saved_content = crate::files::XmlDeSerializer::xml_tree_to_vec(&mut xml_document, "test")
+        .expect("Failed to write the xml");
+    let xml_document = crate::files::XmlSerializer::vec_to_xml_doc_tree(saved_content, "test")
+        .expect("Failed to parse the xml");
+    assert_eq!(
+        get_value(&xml_document, "t"),
+        Some(" Space kept ".to_string())
+    );
+    assert_eq!(
+        get_value(&xml_document, "w:t"),
+        Some("Word text".to_string())
+    );
+    assert_eq!(get_value(&xml_document, "v"), Some("42".to_string()));
+}
@@ -1,4 +1,8 @@
 use anyhow::{anyhow, Context, Error as AnyError, Ok, Result as AnyResult};
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// ((start row, start col), (end row, end col)) of the cell range
+pub type CellRange = ((u32, u16), (u32, u16));
 
 pub struct ConverterUtil;
 
@@ -9,10 +13,16 @@ impl ConverterUtil {
         if column_part.is_empty() {
             return Err(anyhow!("Failed to Convert to Column Key Id"));
         }
-        let mut index = 0;
-        for (i, c) in column_part.chars().rev().enumerate() {
+        let mut index: u16 = 0;
+        for c in column_part.chars() {
+            if !c.is_ascii_alphabetic() {
+                return Err(anyhow!("Invalid column reference : {}", column_part));
+            }
             let char_value = c.to_ascii_uppercase() as u16 - 'A' as u16 + 1;
-            index += char_value * 26_usize.pow(i as u32) as u16;
+            index = index
+                .checked_mul(26)
+                .and_then(|index| index.checked_add(char_value))
+                .ok_or(anyhow!("Column reference out of range : {}", column_part))?;
         }
         Ok(index)
     }
@@ -42,6 +52,133 @@ impl ConverterUtil {
         ))
     }
 
+    /// Return ((start row, start col), (end row, end col)) of the range like "A1:D20" or "$A$1".
+    /// Single cell reference return same start and end
+    pub fn get_range_index(range_ref: &str) -> AnyResult<CellRange, AnyError> {
+        let range_ref = range_ref.replace("$", "");
+        let mut range_split = range_ref.trim().split(":");
+        let start_cell = Self::get_cell_index(
+            range_split
+                .next()
+                .ok_or(anyhow!("Failed to get start cell of range"))?,
+        )
+        .context("Failed to get start cell index of range")?;
+        let end_cell = if let Some(end_ref) = range_split.next() {
+            Self::get_cell_index(end_ref).context("Failed to get end cell index of range")?
+        } else {
+            start_cell
+        };
+        if range_split.next().is_some() {
+            return Err(anyhow!("Invalid range reference : {}", range_ref));
+        }
+        Ok((
+            (start_cell.0.min(end_cell.0), start_cell.1.min(end_cell.1)),
+            (start_cell.0.max(end_cell.0), start_cell.1.max(end_cell.1)),
+        ))
+    }
+
+    /// Return absolute reference like "$A$1:$D$20" of the range index, single cell give "$A$1"
+    pub fn get_absolute_range_ref(
+        ((start_row, start_col), (end_row, end_col)): CellRange,
+    ) -> AnyResult<String, AnyError> {
+        let start_ref = format!(
+            "${}${}",
+            Self::get_column_ref(start_col).context("Failed to get start column ref")?,
+            start_row
+        );
+        if start_row == end_row && start_col == end_col {
+            Ok(start_ref)
+        } else {
+            Ok(format!(
+                "{}:${}${}",
+                start_ref,
+                Self::get_column_ref(end_col).context("Failed to get end column ref")?,
+                end_row
+            ))
+        }
+    }
+
+    /// Return excel serial number of the date time (1900 date system).
+    /// Keeps excel's phantom 1900-02-29 so serial 60 and later match excel
+    pub fn get_excel_serial_date(date_time: &NaiveDateTime) -> AnyResult<f64, AnyError> {
+        let first_date = NaiveDate::from_ymd_opt(1900, 1, 1)
+            .context("Failed to create base date")?
+            .and_time(Default::default());
+        if *date_time < first_date {
+            return Err(anyhow!(
+                "Date before 1900-01-01 is not supported : {}",
+                date_time
+            ));
+        }
+        let duration = *date_time - first_date;
+        let mut serial_date = duration.num_days() as f64 + 1.0;
+        if serial_date >= 60.0 {
+            serial_date += 1.0;
+        }
+        let day_seconds = (duration - chrono::Duration::days(duration.num_days()))
+            .num_milliseconds() as f64
+            / 1000.0;
+        Ok(serial_date + day_seconds / 86_400.0)
+    }
+
+    /// Return date time of the excel serial number (1900 date system).
+    /// Excel's phantom 1900-02-29 (serial 60) is read as 1900-02-28
+    pub fn get_date_from_excel_serial(serial_date: f64) -> AnyResult<NaiveDateTime, AnyError> {
+        // Serial of 9999-12-31 is the last date excel support
+        if !(0.0..2_958_466.0).contains(&serial_date) {
+            return Err(anyhow!("Serial date out of range : {}", serial_date));
+        }
+        let first_date = NaiveDate::from_ymd_opt(1900, 1, 1)
+            .context("Failed to create base date")?
+            .and_time(Default::default());
+        let serial_days = serial_date.floor();
+        let day_offset = if serial_days >= 60.0 {
+            serial_days - 2.0
+        } else {
+            serial_days - 1.0
+        };
+        let day_seconds = ((serial_date - serial_days) * 86_400.0).round();
+        Ok(first_date
+            + chrono::Duration::days(day_offset as i64)
+            + chrono::Duration::seconds(day_seconds as i64))
+    }
+
+    /// Percent encode the text for the url, only the unreserved characters stay as is
+    pub(crate) fn percent_encode(text: &str) -> String {
+        text.bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    (byte as char).to_string()
+                }
+                _ => format!("%{:02X}", byte),
+            })
+            .collect()
+    }
+
+    /// Decode the percent encoded url text, multi byte sequence decode as UTF-8.
+    /// Invalid escape is kept as written
+    pub(crate) fn percent_decode(text: &str) -> String {
+        let bytes = text.as_bytes();
+        let mut decoded_bytes = Vec::with_capacity(bytes.len());
+        let mut position = 0;
+        while position < bytes.len() {
+            let decoded_byte = (bytes[position] == b'%')
+                .then(|| bytes.get(position + 1..position + 3))
+                .flatten()
+                .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            if let Some(decoded_byte) = decoded_byte {
+                decoded_bytes.push(decoded_byte);
+                position += 3;
+            } else {
+                decoded_bytes.push(bytes[position]);
+                position += 1;
+            }
+        }
+        String::from_utf8_lossy(&decoded_bytes).to_string()
+    }
+
     /// convert open-xml bool flag property
     pub(crate) fn normalize_bool_property_u8(value: &str) -> u8 {
         match value.trim() {
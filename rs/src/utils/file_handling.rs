@@ -2,21 +2,24 @@ use anyhow::{Error as AnyError, Result as AnyResult};
 use flate2::bufread::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
-use std::io::{Read, Write};
+use std::io::{copy, BufRead, Read, Write};
 
-pub(crate) fn compress_content(
-    uncompressed_data: &[u8],
+/// Compress the reader content into the writer, returns the writer and uncompressed size
+pub(crate) fn compress_stream<R: Read, W: Write>(
+    reader: &mut R,
+    writer: W,
     compression_level: usize,
-) -> AnyResult<Vec<u8>, AnyError> {
-    let mut encoder = GzEncoder::new(Vec::new(), Compression::new(compression_level as u32));
-    encoder.write_all(uncompressed_data)?;
-    let compressed_data = encoder.finish()?;
-    Ok(compressed_data)
+) -> AnyResult<(W, usize), AnyError> {
+    let mut encoder = GzEncoder::new(writer, Compression::new(compression_level as u32));
+    let uncompressed_size = copy(reader, &mut encoder)?;
+    Ok((encoder.finish()?, uncompressed_size as usize))
 }
 
-pub(crate) fn decompress_content(compressed_data: &[u8]) -> AnyResult<Vec<u8>, AnyError> {
-    let mut decoder: GzDecoder<&[u8]> = GzDecoder::new(compressed_data);
-    let mut decompressed_data: Vec<u8> = Vec::new();
-    decoder.read_to_end(&mut decompressed_data)?;
-    Ok(decompressed_data)
+/// Decompress the reader content straight into the writer without buffering full content
+pub(crate) fn decompress_stream<R: BufRead, W: Write>(
+    reader: R,
+    writer: &mut W,
+) -> AnyResult<usize, AnyError> {
+    let mut decoder: GzDecoder<R> = GzDecoder::new(reader);
+    Ok(copy(&mut decoder, writer)? as usize)
 }
@@ -1,2 +1,3 @@
 pub mod converters;
 pub(crate) mod file_handling;
+pub(crate) mod password_hash;
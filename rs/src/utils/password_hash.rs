@@ -0,0 +1,71 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rand::RngCore;
+use sha2::{Digest, Sha512};
+use std::collections::HashMap;
+
+/// Password hash attributes of the protection element like hashValue, saltValue
+pub(crate) type PasswordHashAttributes = HashMap<String, String>;
+
+/// Spin count Excel use when protecting with password
+const SPIN_COUNT: u32 = 100_000;
+
+/// Hash the password with SHA-512 salted spin count algorithm of ISO 29500 protection.
+/// Return base64 hash value
+pub(crate) fn hash_password(password: &str, salt: &[u8], spin_count: u32) -> String {
+    let password_bytes = password
+        .encode_utf16()
+        .flat_map(|code_unit| code_unit.to_le_bytes())
+        .collect::<Vec<u8>>();
+    let mut hash = Sha512::new()
+        .chain_update(salt)
+        .chain_update(&password_bytes)
+        .finalize();
+    for iteration in 0..spin_count {
+        hash = Sha512::new()
+            .chain_update(hash)
+            .chain_update(iteration.to_le_bytes())
+            .finalize();
+    }
+    STANDARD.encode(hash)
+}
+
+/// Hash attributes of the protection element with random salt. Prefix is used by
+/// workbook protection like "workbook" for workbookHashValue
+pub(crate) fn get_password_hash_attributes(password: &str, prefix: &str) -> PasswordHashAttributes {
+    let mut salt = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let get_key = |name: &str| {
+        if prefix.is_empty() {
+            format!("{}{}", name[..1].to_lowercase(), &name[1..])
+        } else {
+            format!("{}{}", prefix, name)
+        }
+    };
+    HashMap::from([
+        (get_key("AlgorithmName"), "SHA-512".to_string()),
+        (
+            get_key("HashValue"),
+            hash_password(password, &salt, SPIN_COUNT),
+        ),
+        (get_key("SaltValue"), STANDARD.encode(salt)),
+        (get_key("SpinCount"), SPIN_COUNT.to_string()),
+    ])
+}
+
+/// Pick the password hash attributes of loaded protection element, legacy password included
+pub(crate) fn filter_password_hash_attributes(
+    attributes: &HashMap<String, String>,
+    prefix: &str,
+) -> PasswordHashAttributes {
+    attributes
+        .iter()
+        .filter(|(key, _)| {
+            let key = key.strip_prefix(prefix).unwrap_or(key).to_lowercase();
+            matches!(
+                key.as_str(),
+                "algorithmname" | "hashvalue" | "saltvalue" | "spincount" | "password"
+            )
+        })
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
@@ -1 +1,2 @@
 pub mod excel;
+pub mod worksheet;
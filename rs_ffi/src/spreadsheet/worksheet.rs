@@ -0,0 +1,87 @@
+use crate::{chain_error, StatusCode};
+use draviavemal_openxml_office::spreadsheet_2007::parts::WorkSheet;
+use std::{
+    ffi::{c_char, c_void, CStr, CString},
+    mem::ManuallyDrop,
+};
+
+#[no_mangle]
+/// Merge the cell range like "A1:C2" in worksheet
+pub extern "C" fn worksheet_set_merge_cell(
+    worksheet_ptr: *const c_void,
+    range_ref: *const c_char,
+    out_error: *mut *const c_char,
+) -> i8 {
+    if worksheet_ptr.is_null() || range_ref.is_null() {
+        eprintln!("Received null pointer");
+        return StatusCode::InvalidArgument as i8;
+    }
+    let worksheet_ptr = worksheet_ptr as *mut WorkSheet;
+    let mut worksheet = unsafe { ManuallyDrop::new(Box::from_raw(worksheet_ptr)) };
+    let range_ref = unsafe { CStr::from_ptr(range_ref) }
+        .to_string_lossy()
+        .into_owned();
+    match worksheet.set_merge_cell_mut(&range_ref) {
+        Result::Ok(()) => StatusCode::Success as i8,
+        Err(e) => {
+            unsafe { *out_error = chain_error(&e) };
+            StatusCode::InvalidArgument as i8
+        }
+    }
+}
+
+#[no_mangle]
+/// List merged cell range of worksheet as space separated references, caller release it with free_string
+pub extern "C" fn worksheet_list_merge_cell(
+    worksheet_ptr: *const c_void,
+    out_merge_cells: *mut *const c_char,
+    out_error: *mut *const c_char,
+) -> i8 {
+    if worksheet_ptr.is_null() {
+        eprintln!("Received null pointer");
+        return StatusCode::InvalidArgument as i8;
+    }
+    let worksheet_ptr = worksheet_ptr as *mut WorkSheet;
+    let worksheet = unsafe { ManuallyDrop::new(Box::from_raw(worksheet_ptr)) };
+    match worksheet.list_merge_cell() {
+        Result::Ok(merge_cells) => match CString::new(merge_cells.join(" ")) {
+            Result::Ok(str) => {
+                unsafe { *out_merge_cells = str.into_raw() };
+                StatusCode::Success as i8
+            }
+            Err(e) => {
+                unsafe { *out_error = chain_error(&e.into()) };
+                StatusCode::UnknownError as i8
+            }
+        },
+        Err(e) => {
+            unsafe { *out_error = chain_error(&e) };
+            StatusCode::UnknownError as i8
+        }
+    }
+}
+
+#[no_mangle]
+/// Remove merged cell range from worksheet
+pub extern "C" fn worksheet_remove_merge_cell(
+    worksheet_ptr: *const c_void,
+    range_ref: *const c_char,
+    out_error: *mut *const c_char,
+) -> i8 {
+    if worksheet_ptr.is_null() || range_ref.is_null() {
+        eprintln!("Received null pointer");
+        return StatusCode::InvalidArgument as i8;
+    }
+    let worksheet_ptr = worksheet_ptr as *mut WorkSheet;
+    let mut worksheet = unsafe { ManuallyDrop::new(Box::from_raw(worksheet_ptr)) };
+    let range_ref = unsafe { CStr::from_ptr(range_ref) }
+        .to_string_lossy()
+        .into_owned();
+    match worksheet.remove_merge_cell_mut(&range_ref) {
+        Result::Ok(()) => StatusCode::Success as i8,
+        Err(e) => {
+            unsafe { *out_error = chain_error(&e) };
+            StatusCode::InvalidArgument as i8
+        }
+    }
+}
@@ -18,3 +18,11 @@ pub(crate) fn chain_error(error: &AnyError) -> *mut c_char {
         }
     }
 }
+
+#[no_mangle]
+/// Release the string handed out to the caller like the merge cell list. Null is ignored
+pub extern "C" fn free_string(str_ptr: *mut c_char) {
+    if !str_ptr.is_null() {
+        drop(unsafe { CString::from_raw(str_ptr) });
+    }
+}